@@ -73,9 +73,12 @@ impl Handle {
         if let Some(message) = response.next().await {
             let (header, payload) = message.into_parts();
             // NetlinkError and AuditMessage are forwarded to the
-            // handle. Ack is signaled by the stream finishing.
+            // handle. Ack is signaled by the stream finishing, unless
+            // it carries a warning: it is forwarded then.
             if let NetlinkPayload::Error(err_msg) = payload {
                 Err(Error::NetlinkError(err_msg))
+            } else if let NetlinkPayload::Ack(_) = payload {
+                Ok(())
             } else {
                 Err(Error::UnexpectedMessage(NetlinkMessage::new(
                     header, payload,
@@ -15,6 +15,8 @@ pub enum GenetlinkError {
     AttributeNotFound(String),
     #[error("Desire netlink message type not received")]
     NoMessageReceived,
+    #[error("Netlink socket error: {0}")]
+    SocketError(std::io::Error),
 }
 
 // Since `netlink_packet_core::error::ErrorMessage` doesn't impl `Error` trait,
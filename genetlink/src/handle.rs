@@ -9,7 +9,12 @@ use futures::{lock::Mutex, Stream, StreamExt};
 use netlink_packet_core::{DecodeError, NetlinkMessage, NetlinkPayload};
 use netlink_packet_generic::{GenlFamily, GenlHeader, GenlMessage};
 use netlink_packet_utils::{Emitable, ParseableParametrized};
-use netlink_proto::{sys::SocketAddr, ConnectionHandle};
+use netlink_proto::{
+    sys::{protocols::NETLINK_GENERIC, AsyncSocket, SocketAddr},
+    ConnectionHandle,
+    NetlinkCodec,
+    NetlinkFramed,
+};
 use std::{fmt::Debug, sync::Arc};
 
 /// The generic netlink connection handle
@@ -63,6 +68,42 @@ impl GenetlinkHandle {
             .await
     }
 
+    /// Join the multicast group `group_name` of the generic family `family_name`
+    /// and return the stream of messages sent to that group.
+    ///
+    /// The group id is resolved from the `CTRL_ATTR_MCAST_GROUPS` attribute of
+    /// the family. Since group membership is a property of the socket, a new
+    /// socket of type `S` is opened for the subscription, and is closed when the
+    /// stream is dropped.
+    pub async fn subscribe_group<S>(
+        &self,
+        family_name: &str,
+        group_name: &str,
+    ) -> Result<impl Stream<Item = NetlinkMessage<RawGenlMessage>>, GenetlinkError>
+    where
+        S: AsyncSocket,
+    {
+        let group_id = self
+            .resolver
+            .lock()
+            .await
+            .query_mcast_group_id(self, family_name, group_name)
+            .await?;
+
+        let mut socket = S::new(NETLINK_GENERIC).map_err(GenetlinkError::SocketError)?;
+        socket
+            .socket_mut()
+            .bind_auto()
+            .map_err(GenetlinkError::SocketError)?;
+        socket
+            .socket_mut()
+            .add_membership(group_id)
+            .map_err(GenetlinkError::SocketError)?;
+
+        let framed = NetlinkFramed::<RawGenlMessage, S, NetlinkCodec>::new(socket);
+        Ok(framed.map(|(message, _addr)| message))
+    }
+
     /// Clear the resolver's fanily id cache
     pub async fn clear_family_id_cache(&self) {
         self.resolver.lock().await.clear_cache();
@@ -151,3 +192,64 @@ impl GenetlinkHandle {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "tokio_socket"))]
+mod test {
+    use super::*;
+    use crate::{message::map_from_rawgenlmsg, new_connection};
+    use netlink_packet_generic::ctrl::{nlas::GenlCtrlAttrs, GenlCtrl, GenlCtrlCmd};
+    use netlink_proto::sys::TokioSocket;
+    use std::{path::Path, process::Command, time::Duration};
+
+    // A module registering a generic family, that is usually not loaded
+    const TEST_MODULE: &str = "wireguard";
+
+    #[tokio::test]
+    #[cfg_attr(not(feature = "test_as_root"), ignore)]
+    async fn test_subscribe_nlctrl_notify() {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+
+        let mut messages = handle
+            .subscribe_group::<TokioSocket>("nlctrl", "notify")
+            .await
+            .unwrap();
+
+        // Leave the module alone if it is already in use, so that it is not unloaded below
+        let loaded = Path::new("/sys/module").join(TEST_MODULE).exists()
+            || Resolver::new()
+                .query_family_id(&handle, TEST_MODULE)
+                .await
+                .is_ok();
+        if loaded {
+            return;
+        }
+        // The module may not be available in this environment
+        let status = Command::new("modprobe").arg(TEST_MODULE).status();
+        if !status.map(|s| s.success()).unwrap_or(false) {
+            return;
+        }
+
+        let found = tokio::time::timeout(Duration::from_secs(5), async {
+            while let Some(message) = messages.next().await {
+                let message = map_from_rawgenlmsg::<GenlCtrl>(message).unwrap();
+                if let NetlinkPayload::InnerMessage(genlmsg) = message.payload {
+                    if genlmsg.payload.cmd == GenlCtrlCmd::NewFamily
+                        && genlmsg
+                            .payload
+                            .nlas
+                            .contains(&GenlCtrlAttrs::FamilyName(TEST_MODULE.to_owned()))
+                    {
+                        return true;
+                    }
+                }
+            }
+            false
+        })
+        .await;
+
+        // The module was loaded by this test
+        let _ = Command::new("modprobe").args(&["-r", TEST_MODULE]).status();
+        assert_eq!(found, Ok(true));
+    }
+}
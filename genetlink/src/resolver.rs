@@ -4,7 +4,11 @@ use crate::{error::GenetlinkError, GenetlinkHandle};
 use futures::{future::Either, StreamExt};
 use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_REQUEST};
 use netlink_packet_generic::{
-    ctrl::{nlas::GenlCtrlAttrs, GenlCtrl, GenlCtrlCmd},
+    ctrl::{
+        nlas::{GenlCtrlAttrs, McastGrpAttrs},
+        GenlCtrl,
+        GenlCtrlCmd,
+    },
     GenlMessage,
 };
 use std::{collections::HashMap, future::Future};
@@ -35,57 +39,109 @@ impl Resolver {
         } else {
             let mut handle = handle.clone();
             Either::Right(async move {
-                let mut genlmsg: GenlMessage<GenlCtrl> = GenlMessage::from_payload(GenlCtrl {
-                    cmd: GenlCtrlCmd::GetFamily,
-                    nlas: vec![GenlCtrlAttrs::FamilyName(family_name.to_owned())],
-                });
-                genlmsg.finalize();
-                // We don't have to set family id here, since nlctrl has static family id (0x10)
-                let mut nlmsg = NetlinkMessage::from(genlmsg);
-                nlmsg.header.flags = NLM_F_REQUEST;
-                nlmsg.finalize();
-
-                let mut res = handle.send_request(nlmsg)?;
-
-                while let Some(result) = res.next().await {
-                    let rx_packet = result?;
-                    match rx_packet.payload {
-                        NetlinkPayload::InnerMessage(genlmsg) => {
-                            let family_id = genlmsg
-                                .payload
-                                .nlas
-                                .iter()
-                                .find_map(|nla| {
-                                    if let GenlCtrlAttrs::FamilyId(id) = nla {
-                                        Some(*id)
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .ok_or_else(|| {
-                                    GenetlinkError::AttributeNotFound(
-                                        "CTRL_ATTR_FAMILY_ID".to_owned(),
-                                    )
-                                })?;
-
-                            self.cache.insert(family_name, family_id);
-                            return Ok(family_id);
+                let nlas = query_family(&mut handle, family_name).await?;
+                let family_id = nlas
+                    .iter()
+                    .find_map(|nla| {
+                        if let GenlCtrlAttrs::FamilyId(id) = nla {
+                            Some(*id)
+                        } else {
+                            None
                         }
-                        NetlinkPayload::Error(e) => return Err(e.into()),
-                        _ => (),
-                    }
-                }
+                    })
+                    .ok_or_else(|| {
+                        GenetlinkError::AttributeNotFound("CTRL_ATTR_FAMILY_ID".to_owned())
+                    })?;
 
-                Err(GenetlinkError::NoMessageReceived)
+                self.cache.insert(family_name, family_id);
+                Ok(family_id)
             })
         }
     }
 
+    /// Query the id of the multicast group `group_name` of the given family
+    ///
+    /// Multicast group ids are not cached, since they are only needed when
+    /// joining a group.
+    pub async fn query_mcast_group_id(
+        &self,
+        handle: &GenetlinkHandle,
+        family_name: &str,
+        group_name: &str,
+    ) -> Result<u32, GenetlinkError> {
+        let mut handle = handle.clone();
+        let nlas = query_family(&mut handle, family_name).await?;
+        nlas.iter()
+            .filter_map(|nla| {
+                if let GenlCtrlAttrs::McastGroups(groups) = nla {
+                    Some(groups)
+                } else {
+                    None
+                }
+            })
+            .flatten()
+            .find_map(|group| {
+                let name = group.iter().find_map(|attr| {
+                    if let McastGrpAttrs::Name(name) = attr {
+                        Some(name)
+                    } else {
+                        None
+                    }
+                })?;
+                if name != group_name {
+                    return None;
+                }
+                group.iter().find_map(|attr| {
+                    if let McastGrpAttrs::Id(id) = attr {
+                        Some(*id)
+                    } else {
+                        None
+                    }
+                })
+            })
+            .ok_or_else(|| {
+                GenetlinkError::AttributeNotFound(format!(
+                    "CTRL_ATTR_MCAST_GROUPS ({})",
+                    group_name
+                ))
+            })
+    }
+
     pub fn clear_cache(&mut self) {
         self.cache.clear();
     }
 }
 
+/// Send a `CTRL_CMD_GETFAMILY` request for the given family, and return the
+/// attributes of the reply
+async fn query_family(
+    handle: &mut GenetlinkHandle,
+    family_name: &str,
+) -> Result<Vec<GenlCtrlAttrs>, GenetlinkError> {
+    let mut genlmsg: GenlMessage<GenlCtrl> = GenlMessage::from_payload(GenlCtrl {
+        cmd: GenlCtrlCmd::GetFamily,
+        nlas: vec![GenlCtrlAttrs::FamilyName(family_name.to_owned())],
+    });
+    genlmsg.finalize();
+    // We don't have to set family id here, since nlctrl has static family id (0x10)
+    let mut nlmsg = NetlinkMessage::from(genlmsg);
+    nlmsg.header.flags = NLM_F_REQUEST;
+    nlmsg.finalize();
+
+    let mut res = handle.send_request(nlmsg)?;
+
+    while let Some(result) = res.next().await {
+        let rx_packet = result?;
+        match rx_packet.payload {
+            NetlinkPayload::InnerMessage(genlmsg) => return Ok(genlmsg.payload.nlas),
+            NetlinkPayload::Error(e) => return Err(e.into()),
+            _ => (),
+        }
+    }
+
+    Err(GenetlinkError::NoMessageReceived)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -103,6 +159,26 @@ mod test {
         assert_eq!(nlctrl_fid, 0x10);
     }
 
+    #[tokio::test]
+    async fn test_resolver_mcast_group() {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+
+        let resolver = Resolver::new();
+        // The "notify" group of nlctrl is always 0x10 as well
+        let notify_id = resolver
+            .query_mcast_group_id(&handle, "nlctrl", "notify")
+            .await
+            .unwrap();
+        assert_eq!(notify_id, 0x10);
+
+        let err = resolver
+            .query_mcast_group_id(&handle, "nlctrl", "no-such-group")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GenetlinkError::AttributeNotFound(_)));
+    }
+
     const TEST_FAMILIES: &[&str] = &[
         "devlink",
         "ethtool",
@@ -37,3 +37,12 @@ pub const NLM_F_NONREC: u16 = 256;
 pub const NLM_F_CAPPED: u16 = 256;
 /// extended ACK TVLs were included
 pub const NLM_F_ACK_TLVS: u16 = 512;
+
+/// Unused extended acknowledgement attribute
+pub const NLMSGERR_ATTR_UNUSED: u16 = 0;
+/// Error or warning message, as a null-terminated string
+pub const NLMSGERR_ATTR_MSG: u16 = 1;
+/// Offset in the request of the attribute that caused the error, as a `u32`
+pub const NLMSGERR_ATTR_OFFS: u16 = 2;
+/// Arbitrary subsystem-specific cookie
+pub const NLMSGERR_ATTR_COOKIE: u16 = 3;
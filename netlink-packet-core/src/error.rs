@@ -4,7 +4,19 @@ use std::{fmt, io, mem::size_of};
 
 use byteorder::{ByteOrder, NativeEndian};
 
-use crate::{DecodeError, Emitable, Field, Parseable, Rest};
+use crate::{
+    constants::{NLMSGERR_ATTR_COOKIE, NLMSGERR_ATTR_MSG, NLMSGERR_ATTR_OFFS},
+    utils::{
+        nla::NlasIterator,
+        parsers::{parse_string, parse_u32},
+    },
+    DecodeError,
+    Emitable,
+    Field,
+    Parseable,
+    Rest,
+    NETLINK_HEADER_LEN,
+};
 
 const CODE: Field = 0..4;
 const PAYLOAD: Rest = 4..;
@@ -120,6 +132,50 @@ impl ErrorMessage {
     pub fn to_io(&self) -> io::Error {
         io::Error::from_raw_os_error(self.code.abs())
     }
+
+    /// Parse the extended acknowledgement attributes that follow the request echoed in this
+    /// message, if any. `capped` tells whether the message has the `NLM_F_CAPPED` flag, in which
+    /// case only the header of the request is echoed. See also
+    /// [`NetlinkMessage::extended_ack`](crate::NetlinkMessage::extended_ack), which reads it
+    /// from the message flags.
+    ///
+    /// Malformed attributes are ignored.
+    pub fn extended_ack(&self, capped: bool) -> Option<ExtendedAck> {
+        let request_len = if capped {
+            NETLINK_HEADER_LEN
+        } else {
+            let len = self.header.get(..4).map(NativeEndian::read_u32)? as usize;
+            (len + 3) & !3
+        };
+        let attributes = self
+            .header
+            .get(request_len..)
+            .filter(|attrs| !attrs.is_empty())?;
+
+        let mut ack = ExtendedAck::default();
+        for nla in NlasIterator::new(attributes).map_while(Result::ok) {
+            match nla.kind() {
+                NLMSGERR_ATTR_MSG => ack.message = parse_string(nla.value()).ok(),
+                NLMSGERR_ATTR_OFFS => ack.offset = parse_u32(nla.value()).ok(),
+                NLMSGERR_ATTR_COOKIE => ack.cookie = Some(nla.value().to_vec()),
+                _ => {}
+            }
+        }
+        Some(ack)
+    }
+}
+
+/// Extended acknowledgement attributes (`NLMSGERR_ATTR_*`), that the kernel attaches to errors
+/// and acknowledgements when the `NETLINK_EXT_ACK` socket option is set. On an acknowledgement,
+/// the message is a warning: the request still succeeded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExtendedAck {
+    /// Error or warning message
+    pub message: Option<String>,
+    /// Offset in the request of the attribute that caused the error
+    pub offset: Option<u32>,
+    /// Subsystem-specific cookie
+    pub cookie: Option<Vec<u8>>,
 }
 
 impl fmt::Display for ErrorMessage {
@@ -151,4 +207,50 @@ mod tests {
         assert_eq!(err_msg.to_string(), io_err.to_string());
         assert_eq!(to_io.raw_os_error(), io_err.raw_os_error());
     }
+
+    #[rustfmt::skip]
+    static ECHOED_REQUEST: [u8; 20] = [
+        0x14, 0x00, 0x00, 0x00, // length = 20
+        0x10, 0x00, 0x05, 0x00, // type = 16, flags = NLM_F_REQUEST | NLM_F_ACK
+        0x01, 0x00, 0x00, 0x00, // sequence number = 1
+        0x00, 0x00, 0x00, 0x00, // port number = 0
+        0x00, 0x00, 0x00, 0x00, // payload
+    ];
+
+    #[test]
+    fn extended_ack_warning() {
+        let mut header = ECHOED_REQUEST[..NETLINK_HEADER_LEN].to_vec();
+        header.extend_from_slice(&[0x0c, 0x00, 0x01, 0x00]); // NLMSGERR_ATTR_MSG, length = 12
+        header.extend_from_slice(b"warning\0");
+        let ack = ErrorMessage { code: 0, header };
+
+        let expected = ExtendedAck {
+            message: Some("warning".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ack.extended_ack(true), Some(expected));
+    }
+
+    #[test]
+    fn extended_ack_uncapped_error() {
+        let mut header = ECHOED_REQUEST.to_vec();
+        header.extend_from_slice(&[0x08, 0x00, 0x02, 0x00]); // NLMSGERR_ATTR_OFFS, length = 8
+        header.extend_from_slice(&[0x10, 0x00, 0x00, 0x00]);
+        let err = ErrorMessage { code: -22, header };
+
+        let expected = ExtendedAck {
+            offset: Some(16),
+            ..Default::default()
+        };
+        assert_eq!(err.extended_ack(false), Some(expected));
+    }
+
+    #[test]
+    fn no_extended_ack() {
+        let ack = ErrorMessage {
+            code: 0,
+            header: ECHOED_REQUEST[..NETLINK_HEADER_LEN].to_vec(),
+        };
+        assert_eq!(ack.extended_ack(true), None);
+    }
 }
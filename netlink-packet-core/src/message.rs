@@ -4,12 +4,14 @@ use anyhow::Context;
 use std::fmt::Debug;
 
 use crate::{
+    constants::{NLM_F_ACK_TLVS, NLM_F_CAPPED},
     payload::{NLMSG_DONE, NLMSG_ERROR, NLMSG_NOOP, NLMSG_OVERRUN},
     AckMessage,
     DecodeError,
     Emitable,
     ErrorBuffer,
     ErrorMessage,
+    ExtendedAck,
     NetlinkBuffer,
     NetlinkDeserializable,
     NetlinkHeader,
@@ -37,6 +39,22 @@ impl<I> NetlinkMessage<I> {
     pub fn into_parts(self) -> (NetlinkHeader, NetlinkPayload<I>) {
         (self.header, self.payload)
     }
+
+    /// Return the extended acknowledgement attributes of an error or an acknowledgement, if the
+    /// kernel attached some (the message has the `NLM_F_ACK_TLVS` flag). An acknowledgement
+    /// with a message means the request succeeded with a warning.
+    pub fn extended_ack(&self) -> Option<ExtendedAck> {
+        if self.header.flags & NLM_F_ACK_TLVS == 0 {
+            return None;
+        }
+        let capped = self.header.flags & NLM_F_CAPPED != 0;
+        match self.payload {
+            NetlinkPayload::Error(ref msg) | NetlinkPayload::Ack(ref msg) => {
+                msg.extended_ack(capped)
+            }
+            _ => None,
+        }
+    }
 }
 
 impl<I> NetlinkMessage<I>
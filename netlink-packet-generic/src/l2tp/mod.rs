@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: MIT
+
+//! L2TP family implementation
+//!
+//! Linux has no rtnetlink link kind for L2TP: the `l2tpeth` interfaces of ethernet pseudowires
+//! are created along with their session, through this generic netlink family (this is what
+//! `ip l2tp add session` does).
+//!
+//! A session belongs to a tunnel, which must be created first, with
+//! [`GenlL2tp::udp_tunnel`] for an unmanaged tunnel (`ip l2tp add tunnel`), or with a
+//! [`GenlL2tpAttrs::Fd`] attribute for a tunnel managed by an L2TP daemon. Creating a session in
+//! a tunnel that does not exist fails with `ENOENT`, and the kernel modules (`l2tp_netlink`,
+//! `l2tp_eth`) must be loaded for the family and the ethernet pseudowires to be available.
+
+use self::nlas::*;
+use crate::{constants::*, traits::*, GenlHeader};
+use anyhow::Context;
+use netlink_packet_utils::{nla::NlasIterator, traits::*, DecodeError};
+use std::{
+    convert::{TryFrom, TryInto},
+    net::Ipv4Addr,
+};
+
+/// Netlink attributes for this family
+pub mod nlas;
+
+/// Command code definition of the L2TP family
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenlL2tpCmd {
+    Noop,
+    TunnelCreate,
+    TunnelDelete,
+    TunnelModify,
+    TunnelGet,
+    SessionCreate,
+    SessionDelete,
+    SessionModify,
+    SessionGet,
+}
+
+impl From<GenlL2tpCmd> for u8 {
+    fn from(cmd: GenlL2tpCmd) -> u8 {
+        use GenlL2tpCmd::*;
+        match cmd {
+            Noop => L2TP_CMD_NOOP,
+            TunnelCreate => L2TP_CMD_TUNNEL_CREATE,
+            TunnelDelete => L2TP_CMD_TUNNEL_DELETE,
+            TunnelModify => L2TP_CMD_TUNNEL_MODIFY,
+            TunnelGet => L2TP_CMD_TUNNEL_GET,
+            SessionCreate => L2TP_CMD_SESSION_CREATE,
+            SessionDelete => L2TP_CMD_SESSION_DELETE,
+            SessionModify => L2TP_CMD_SESSION_MODIFY,
+            SessionGet => L2TP_CMD_SESSION_GET,
+        }
+    }
+}
+
+impl TryFrom<u8> for GenlL2tpCmd {
+    type Error = DecodeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        use GenlL2tpCmd::*;
+        Ok(match value {
+            L2TP_CMD_NOOP => Noop,
+            L2TP_CMD_TUNNEL_CREATE => TunnelCreate,
+            L2TP_CMD_TUNNEL_DELETE => TunnelDelete,
+            L2TP_CMD_TUNNEL_MODIFY => TunnelModify,
+            L2TP_CMD_TUNNEL_GET => TunnelGet,
+            L2TP_CMD_SESSION_CREATE => SessionCreate,
+            L2TP_CMD_SESSION_DELETE => SessionDelete,
+            L2TP_CMD_SESSION_MODIFY => SessionModify,
+            L2TP_CMD_SESSION_GET => SessionGet,
+            cmd => return Err(DecodeError::from(format!("Unknown L2TP command: {}", cmd))),
+        })
+    }
+}
+
+/// Payload of the L2TP family
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenlL2tp {
+    /// Command code of this message
+    pub cmd: GenlL2tpCmd,
+    /// Netlink attributes in this message
+    pub nlas: Vec<GenlL2tpAttrs>,
+}
+
+impl GenlL2tp {
+    /// Create an unmanaged L2TPv3 tunnel over UDP (equivalent to `ip l2tp add tunnel tunnel_id
+    /// <tunnel_id> peer_tunnel_id <peer_tunnel_id> encap udp local <local> remote <remote>
+    /// udp_sport <local_port> udp_dport <remote_port>`)
+    pub fn udp_tunnel(
+        tunnel_id: u32,
+        peer_tunnel_id: u32,
+        local: (Ipv4Addr, u16),
+        remote: (Ipv4Addr, u16),
+    ) -> Self {
+        use GenlL2tpAttrs::*;
+        GenlL2tp {
+            cmd: GenlL2tpCmd::TunnelCreate,
+            nlas: vec![
+                ConnId(tunnel_id),
+                PeerConnId(peer_tunnel_id),
+                ProtoVersion(3),
+                EncapType(L2TP_ENCAPTYPE_UDP),
+                IpSaddr(local.0),
+                IpDaddr(remote.0),
+                UdpSport(local.1),
+                UdpDport(remote.1),
+            ],
+        }
+    }
+
+    /// Create an ethernet pseudowire session, and its `name` interface, in the existing tunnel
+    /// `tunnel_id` (equivalent to `ip l2tp add session name <name> tunnel_id <tunnel_id>
+    /// session_id <session_id> peer_session_id <peer_session_id>`)
+    pub fn eth_session(
+        name: String,
+        tunnel_id: u32,
+        session_id: u32,
+        peer_session_id: u32,
+    ) -> Self {
+        use GenlL2tpAttrs::*;
+        GenlL2tp {
+            cmd: GenlL2tpCmd::SessionCreate,
+            nlas: vec![
+                ConnId(tunnel_id),
+                SessionId(session_id),
+                PeerSessionId(peer_session_id),
+                PwType(L2TP_PWTYPE_ETH),
+                IfName(name),
+            ],
+        }
+    }
+}
+
+impl GenlFamily for GenlL2tp {
+    fn family_name() -> &'static str {
+        L2TP_GENL_NAME
+    }
+
+    fn command(&self) -> u8 {
+        self.cmd.into()
+    }
+
+    fn version(&self) -> u8 {
+        L2TP_GENL_VERSION
+    }
+}
+
+impl Emitable for GenlL2tp {
+    fn emit(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+
+    fn buffer_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+}
+
+impl ParseableParametrized<[u8], GenlHeader> for GenlL2tp {
+    fn parse_with_param(buf: &[u8], header: GenlHeader) -> Result<Self, DecodeError> {
+        let nlas = NlasIterator::new(buf)
+            .map(|nla| nla.and_then(|nla| GenlL2tpAttrs::parse(&nla)))
+            .collect::<Result<Vec<_>, _>>()
+            .context("failed to parse L2TP message attributes")?;
+        Ok(Self {
+            cmd: header.cmd.try_into()?,
+            nlas,
+        })
+    }
+}
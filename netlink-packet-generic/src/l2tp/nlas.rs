@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::*,
+    traits::*,
+    DecodeError,
+};
+use std::{
+    mem::size_of_val,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GenlL2tpAttrs {
+    /// Pseudowire type of a session, such as `L2TP_PWTYPE_ETH`
+    PwType(u16),
+    /// Encapsulation of a tunnel, `L2TP_ENCAPTYPE_UDP` or `L2TP_ENCAPTYPE_IP`
+    EncapType(u16),
+    /// L2TP version of a tunnel, 2 or 3
+    ProtoVersion(u8),
+    /// Name of the interface of an ethernet session
+    IfName(String),
+    /// Local tunnel id
+    ConnId(u32),
+    /// Tunnel id of the peer
+    PeerConnId(u32),
+    /// Local session id
+    SessionId(u32),
+    /// Session id of the peer
+    PeerSessionId(u32),
+    /// Cookie of a L2TPv3 session, 4 or 8 bytes
+    Cookie(Vec<u8>),
+    /// Cookie expected from the peer, 4 or 8 bytes
+    PeerCookie(Vec<u8>),
+    /// UDP socket of a managed tunnel, whose addresses and ports are used for the tunnel
+    Fd(u32),
+    IpSaddr(Ipv4Addr),
+    IpDaddr(Ipv4Addr),
+    UdpSport(u16),
+    UdpDport(u16),
+    Ip6Saddr(Ipv6Addr),
+    Ip6Daddr(Ipv6Addr),
+    Mtu(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for GenlL2tpAttrs {
+    fn value_len(&self) -> usize {
+        use GenlL2tpAttrs::*;
+        match self {
+            PwType(v) | EncapType(v) | UdpSport(v) | UdpDport(v) | Mtu(v) => size_of_val(v),
+            ProtoVersion(v) => size_of_val(v),
+            IfName(s) => s.len() + 1,
+            ConnId(v) | PeerConnId(v) | SessionId(v) | PeerSessionId(v) | Fd(v) => size_of_val(v),
+            Cookie(v) | PeerCookie(v) => v.len(),
+            IpSaddr(_) | IpDaddr(_) => 4,
+            Ip6Saddr(_) | Ip6Daddr(_) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use GenlL2tpAttrs::*;
+        match self {
+            PwType(_) => L2TP_ATTR_PW_TYPE,
+            EncapType(_) => L2TP_ATTR_ENCAP_TYPE,
+            ProtoVersion(_) => L2TP_ATTR_PROTO_VERSION,
+            IfName(_) => L2TP_ATTR_IFNAME,
+            ConnId(_) => L2TP_ATTR_CONN_ID,
+            PeerConnId(_) => L2TP_ATTR_PEER_CONN_ID,
+            SessionId(_) => L2TP_ATTR_SESSION_ID,
+            PeerSessionId(_) => L2TP_ATTR_PEER_SESSION_ID,
+            Cookie(_) => L2TP_ATTR_COOKIE,
+            PeerCookie(_) => L2TP_ATTR_PEER_COOKIE,
+            Fd(_) => L2TP_ATTR_FD,
+            IpSaddr(_) => L2TP_ATTR_IP_SADDR,
+            IpDaddr(_) => L2TP_ATTR_IP_DADDR,
+            UdpSport(_) => L2TP_ATTR_UDP_SPORT,
+            UdpDport(_) => L2TP_ATTR_UDP_DPORT,
+            Ip6Saddr(_) => L2TP_ATTR_IP6_SADDR,
+            Ip6Daddr(_) => L2TP_ATTR_IP6_DADDR,
+            Mtu(_) => L2TP_ATTR_MTU,
+            Other(nla) => nla.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use GenlL2tpAttrs::*;
+        match self {
+            PwType(v) | EncapType(v) | UdpSport(v) | UdpDport(v) | Mtu(v) => {
+                NativeEndian::write_u16(buffer, *v)
+            }
+            ProtoVersion(v) => buffer[0] = *v,
+            IfName(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            ConnId(v) | PeerConnId(v) | SessionId(v) | PeerSessionId(v) | Fd(v) => {
+                NativeEndian::write_u32(buffer, *v)
+            }
+            Cookie(v) | PeerCookie(v) => buffer[..v.len()].copy_from_slice(v),
+            // The addresses are in network byte order
+            IpSaddr(addr) | IpDaddr(addr) => buffer[..4].copy_from_slice(&addr.octets()),
+            Ip6Saddr(addr) | Ip6Daddr(addr) => buffer[..16].copy_from_slice(&addr.octets()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for GenlL2tpAttrs {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            L2TP_ATTR_PW_TYPE => {
+                Self::PwType(parse_u16(payload).context("invalid L2TP_ATTR_PW_TYPE value")?)
+            }
+            L2TP_ATTR_ENCAP_TYPE => {
+                Self::EncapType(parse_u16(payload).context("invalid L2TP_ATTR_ENCAP_TYPE value")?)
+            }
+            L2TP_ATTR_PROTO_VERSION => Self::ProtoVersion(
+                parse_u8(payload).context("invalid L2TP_ATTR_PROTO_VERSION value")?,
+            ),
+            L2TP_ATTR_IFNAME => {
+                Self::IfName(parse_string(payload).context("invalid L2TP_ATTR_IFNAME value")?)
+            }
+            L2TP_ATTR_CONN_ID => {
+                Self::ConnId(parse_u32(payload).context("invalid L2TP_ATTR_CONN_ID value")?)
+            }
+            L2TP_ATTR_PEER_CONN_ID => Self::PeerConnId(
+                parse_u32(payload).context("invalid L2TP_ATTR_PEER_CONN_ID value")?,
+            ),
+            L2TP_ATTR_SESSION_ID => {
+                Self::SessionId(parse_u32(payload).context("invalid L2TP_ATTR_SESSION_ID value")?)
+            }
+            L2TP_ATTR_PEER_SESSION_ID => Self::PeerSessionId(
+                parse_u32(payload).context("invalid L2TP_ATTR_PEER_SESSION_ID value")?,
+            ),
+            L2TP_ATTR_COOKIE => Self::Cookie(payload.to_vec()),
+            L2TP_ATTR_PEER_COOKIE => Self::PeerCookie(payload.to_vec()),
+            L2TP_ATTR_FD => Self::Fd(parse_u32(payload).context("invalid L2TP_ATTR_FD value")?),
+            L2TP_ATTR_IP_SADDR => {
+                Self::IpSaddr(parse_ipv4(payload).context("invalid L2TP_ATTR_IP_SADDR value")?)
+            }
+            L2TP_ATTR_IP_DADDR => {
+                Self::IpDaddr(parse_ipv4(payload).context("invalid L2TP_ATTR_IP_DADDR value")?)
+            }
+            L2TP_ATTR_UDP_SPORT => {
+                Self::UdpSport(parse_u16(payload).context("invalid L2TP_ATTR_UDP_SPORT value")?)
+            }
+            L2TP_ATTR_UDP_DPORT => {
+                Self::UdpDport(parse_u16(payload).context("invalid L2TP_ATTR_UDP_DPORT value")?)
+            }
+            L2TP_ATTR_IP6_SADDR => Self::Ip6Saddr(
+                parse_ipv6(payload)
+                    .context("invalid L2TP_ATTR_IP6_SADDR value")?
+                    .into(),
+            ),
+            L2TP_ATTR_IP6_DADDR => Self::Ip6Daddr(
+                parse_ipv6(payload)
+                    .context("invalid L2TP_ATTR_IP6_DADDR value")?
+                    .into(),
+            ),
+            L2TP_ATTR_MTU => Self::Mtu(parse_u16(payload).context("invalid L2TP_ATTR_MTU value")?),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid L2TP attribute")?),
+        })
+    }
+}
+
+fn parse_ipv4(payload: &[u8]) -> Result<Ipv4Addr, DecodeError> {
+    if payload.len() != 4 {
+        return Err(format!("invalid IPv4 address: {:?}", payload).into());
+    }
+    let mut octets = [0; 4];
+    octets.copy_from_slice(payload);
+    Ok(octets.into())
+}
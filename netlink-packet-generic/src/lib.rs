@@ -76,8 +76,12 @@ pub mod ctrl;
 pub mod header;
 pub use self::header::GenlHeader;
 
+pub mod l2tp;
+
 pub mod message;
 pub use self::message::GenlMessage;
 
+pub mod policy;
+
 pub mod traits;
 pub use self::traits::GenlFamily;
@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: MIT
+
+//! Client-side validation of the attributes of a generic netlink message.
+//!
+//! The kernel validates the attributes of the messages it receives against the policy of the
+//! family (its `struct nla_policy` table), and rejects the invalid ones with `EINVAL`, often
+//! without any detail. Declaring the same policy with [`Policy`] and validating the attributes
+//! before sending them catches most of these mistakes with a descriptive error instead:
+//!
+//! ```rust
+//! use netlink_packet_generic::{ctrl::nlas::NlaType, policy::Policy};
+//! use netlink_packet_utils::nla::DefaultNla;
+//!
+//! const MY_ATTR_ID: u16 = 1;
+//! const MY_ATTR_NAME: u16 = 2;
+//!
+//! let policy = Policy::new()
+//!     .attribute(MY_ATTR_ID, NlaType::U32)
+//!     .attribute_with_max_length(MY_ATTR_NAME, NlaType::NulString, 15);
+//!
+//! // The id is a u16 instead of a u32
+//! let nlas = vec![DefaultNla::new(MY_ATTR_ID, 42u16.to_ne_bytes().to_vec())];
+//! let err = policy.validate(&nlas).unwrap_err();
+//! assert_eq!(
+//!     err.to_string(),
+//!     "attribute 1 has a length of 2 bytes, expected 4 bytes for a U32 attribute"
+//! );
+//! ```
+
+use std::{collections::BTreeMap, error::Error, fmt};
+
+use netlink_packet_utils::{
+    nla::{Nla, NlasIterator, NLA_TYPE_MASK},
+    traits::Emitable,
+};
+
+use crate::ctrl::nlas::NlaType;
+
+/// Expected type and size of an attribute
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttributePolicy {
+    pub nla_type: NlaType,
+    /// Maximum length of the payload of `Binary`, `String` and `NulString` attributes, not
+    /// counting the terminating NUL byte of the latter.
+    pub max_length: Option<usize>,
+}
+
+/// Policy of the attributes of a generic netlink message, the counterpart of the `nla_policy`
+/// table of the family in the kernel.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Policy {
+    attributes: BTreeMap<u16, AttributePolicy>,
+}
+
+/// Reason why an attribute does not match a [`Policy`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PolicyError {
+    /// The attributes are not correctly formatted
+    Malformed(String),
+    /// The policy has no attribute of this type
+    UnknownAttribute(u16),
+    /// The length of the payload does not match the type of the attribute
+    InvalidLength {
+        kind: u16,
+        nla_type: NlaType,
+        length: usize,
+        expected: usize,
+    },
+    /// The payload is longer than the maximum length of the attribute
+    TooLong {
+        kind: u16,
+        length: usize,
+        max_length: usize,
+    },
+    /// The payload of a `NulString` attribute is not terminated by a NUL byte
+    MissingNul(u16),
+    /// The payload of a nested attribute is not a valid list of attributes
+    InvalidNested(u16),
+}
+
+impl fmt::Display for PolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use PolicyError::*;
+        match self {
+            Malformed(e) => write!(f, "malformed attributes: {}", e),
+            UnknownAttribute(kind) => write!(f, "attribute {} is not part of the policy", kind),
+            InvalidLength {
+                kind,
+                nla_type,
+                length,
+                expected,
+            } => write!(
+                f,
+                "attribute {} has a length of {} bytes, expected {} bytes for a {:?} attribute",
+                kind, length, expected, nla_type
+            ),
+            TooLong {
+                kind,
+                length,
+                max_length,
+            } => write!(
+                f,
+                "attribute {} is {} bytes long, longer than the maximum of {} bytes",
+                kind, length, max_length
+            ),
+            MissingNul(kind) => write!(f, "attribute {} is not NUL-terminated", kind),
+            InvalidNested(kind) => {
+                write!(f, "attribute {} does not hold valid nested attributes", kind)
+            }
+        }
+    }
+}
+
+impl Error for PolicyError {}
+
+impl Policy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare an attribute of the given type
+    pub fn attribute(self, kind: u16, nla_type: NlaType) -> Self {
+        self.insert(kind, nla_type, None)
+    }
+
+    /// Declare a `Binary`, `String` or `NulString` attribute with a maximum length
+    pub fn attribute_with_max_length(self, kind: u16, nla_type: NlaType, max: usize) -> Self {
+        self.insert(kind, nla_type, Some(max))
+    }
+
+    fn insert(mut self, kind: u16, nla_type: NlaType, max_length: Option<usize>) -> Self {
+        self.attributes.insert(
+            kind,
+            AttributePolicy {
+                nla_type,
+                max_length,
+            },
+        );
+        self
+    }
+
+    /// Returns the policy of the given attribute type, if it is part of the policy
+    pub fn get(&self, kind: u16) -> Option<&AttributePolicy> {
+        self.attributes.get(&kind)
+    }
+
+    /// Validate attributes before they are sent
+    pub fn validate<T: Nla>(&self, nlas: &[T]) -> Result<(), PolicyError> {
+        let mut buf = vec![0; nlas.buffer_len()];
+        nlas.emit(&mut buf);
+        self.validate_buffer(&buf)
+    }
+
+    /// Validate serialized attributes, such as the payload of a generic netlink message after its
+    /// header
+    pub fn validate_buffer(&self, buf: &[u8]) -> Result<(), PolicyError> {
+        for nla in NlasIterator::new(buf) {
+            let nla = nla.map_err(|e| PolicyError::Malformed(e.to_string()))?;
+            let kind = nla.kind() & NLA_TYPE_MASK;
+            let policy = self.get(kind).ok_or(PolicyError::UnknownAttribute(kind))?;
+            policy.validate(kind, nla.value())?;
+        }
+        Ok(())
+    }
+}
+
+impl AttributePolicy {
+    fn validate(&self, kind: u16, value: &[u8]) -> Result<(), PolicyError> {
+        let length = value.len();
+        let expected = match self.nla_type {
+            NlaType::Flag => Some(0),
+            NlaType::U8 | NlaType::S8 => Some(1),
+            NlaType::U16 | NlaType::S16 => Some(2),
+            NlaType::U32 | NlaType::S32 => Some(4),
+            NlaType::U64 | NlaType::S64 | NlaType::Bitfield32 => Some(8),
+            NlaType::Binary | NlaType::String | NlaType::NulString => None,
+            NlaType::Nested | NlaType::NestedArray => None,
+        };
+        if let Some(expected) = expected.filter(|expected| *expected != length) {
+            return Err(PolicyError::InvalidLength {
+                kind,
+                nla_type: self.nla_type,
+                length,
+                expected,
+            });
+        }
+
+        match self.nla_type {
+            NlaType::NulString => {
+                if value.last() != Some(&0) {
+                    return Err(PolicyError::MissingNul(kind));
+                }
+                self.check_max_length(kind, length - 1)
+            }
+            NlaType::String => {
+                // The kernel accepts strings with or without a terminating NUL byte
+                let length = value.iter().position(|b| *b == 0).unwrap_or(length);
+                self.check_max_length(kind, length)
+            }
+            NlaType::Binary => self.check_max_length(kind, length),
+            NlaType::Nested | NlaType::NestedArray => {
+                if NlasIterator::new(value).any(|nla| nla.is_err()) {
+                    return Err(PolicyError::InvalidNested(kind));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_max_length(&self, kind: u16, length: usize) -> Result<(), PolicyError> {
+        match self.max_length {
+            Some(max_length) if length > max_length => Err(PolicyError::TooLong {
+                kind,
+                length,
+                max_length,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
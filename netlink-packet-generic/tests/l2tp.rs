@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_REQUEST};
+use netlink_packet_generic::{
+    l2tp::{nlas::GenlL2tpAttrs, GenlL2tp, GenlL2tpCmd},
+    GenlFamily,
+    GenlMessage,
+};
+use netlink_packet_utils::traits::Emitable;
+
+#[rustfmt::skip]
+static ETH_SESSION: [u8; 48] = [
+    // L2TP_ATTR_CONN_ID
+    0x08, 0x00, 0x09, 0x00,
+    0x01, 0x00, 0x00, 0x00,
+    // L2TP_ATTR_SESSION_ID
+    0x08, 0x00, 0x0b, 0x00,
+    0x0a, 0x00, 0x00, 0x00,
+    // L2TP_ATTR_PEER_SESSION_ID
+    0x08, 0x00, 0x0c, 0x00,
+    0x14, 0x00, 0x00, 0x00,
+    // L2TP_ATTR_PW_TYPE: L2TP_PWTYPE_ETH
+    0x06, 0x00, 0x01, 0x00,
+    0x05, 0x00, 0x00, 0x00,
+    // L2TP_ATTR_IFNAME: "l2tpeth0", NUL-terminated and padded
+    0x0d, 0x00, 0x08, 0x00,
+    0x6c, 0x32, 0x74, 0x70, 0x65, 0x74, 0x68, 0x30, 0x00, 0x00, 0x00, 0x00,
+];
+
+#[test]
+fn emit_eth_session() {
+    let session = GenlL2tp::eth_session("l2tpeth0".to_owned(), 1, 10, 20);
+    assert_eq!(session.command(), 5);
+    assert_eq!(session.version(), 1);
+    assert_eq!(<GenlL2tp as GenlFamily>::family_name(), "l2tp");
+
+    let mut buf = vec![0; session.buffer_len()];
+    session.emit(&mut buf);
+    assert_eq!(buf, ETH_SESSION);
+}
+
+#[test]
+fn parse_udp_tunnel() {
+    let tunnel = GenlL2tp::udp_tunnel(
+        1,
+        2,
+        (Ipv4Addr::new(192, 0, 2, 1), 5000),
+        (Ipv4Addr::new(192, 0, 2, 2), 6000),
+    );
+    let mut genlmsg = GenlMessage::from_payload(tunnel.clone());
+    // The family id is resolved at runtime, any value does here
+    genlmsg.set_resolved_family_id(0x20);
+    let mut nlmsg = NetlinkMessage::from(genlmsg);
+    nlmsg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+    nlmsg.finalize();
+    let mut buf = vec![0; nlmsg.buffer_len()];
+    nlmsg.serialize(&mut buf);
+
+    let parsed = <NetlinkMessage<GenlMessage<GenlL2tp>>>::deserialize(&buf).unwrap();
+    match parsed.payload {
+        NetlinkPayload::InnerMessage(genlmsg) => {
+            assert_eq!(genlmsg.payload, tunnel);
+            assert_eq!(genlmsg.payload.cmd, GenlL2tpCmd::TunnelCreate);
+            assert!(genlmsg
+                .payload
+                .nlas
+                .contains(&GenlL2tpAttrs::IpDaddr(Ipv4Addr::new(192, 0, 2, 2))));
+        }
+        payload => panic!("unexpected payload {:?}", payload),
+    }
+}
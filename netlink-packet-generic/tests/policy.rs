@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_generic::{
+    ctrl::nlas::NlaType,
+    policy::{Policy, PolicyError},
+};
+use netlink_packet_utils::{
+    nla::{DefaultNla, NLA_F_NESTED},
+    traits::Emitable,
+};
+
+const ATTR_ID: u16 = 1;
+const ATTR_NAME: u16 = 2;
+const ATTR_ENABLED: u16 = 3;
+const ATTR_LABEL: u16 = 4;
+const ATTR_OPTIONS: u16 = 5;
+
+fn policy() -> Policy {
+    Policy::new()
+        .attribute(ATTR_ID, NlaType::U32)
+        .attribute_with_max_length(ATTR_NAME, NlaType::NulString, 7)
+        .attribute(ATTR_ENABLED, NlaType::Flag)
+        .attribute_with_max_length(ATTR_LABEL, NlaType::String, 4)
+        .attribute(ATTR_OPTIONS, NlaType::Nested)
+}
+
+fn id(value: u32) -> DefaultNla {
+    DefaultNla::new(ATTR_ID, value.to_ne_bytes().to_vec())
+}
+
+fn name(value: &str) -> DefaultNla {
+    let mut bytes = value.as_bytes().to_vec();
+    bytes.push(0);
+    DefaultNla::new(ATTR_NAME, bytes)
+}
+
+fn options(nlas: &[DefaultNla]) -> DefaultNla {
+    let mut buf = vec![0; nlas.buffer_len()];
+    nlas.emit(&mut buf);
+    DefaultNla::new(ATTR_OPTIONS | NLA_F_NESTED, buf)
+}
+
+#[test]
+fn valid_attributes() {
+    let nlas = vec![
+        id(42),
+        name("eth0"),
+        DefaultNla::new(ATTR_ENABLED, vec![]),
+        // String attributes do not need to be NUL-terminated
+        DefaultNla::new(ATTR_LABEL, b"wan".to_vec()),
+        options(&[id(1), id(2)]),
+    ];
+    assert_eq!(policy().validate(&nlas), Ok(()));
+    assert_eq!(policy().validate::<DefaultNla>(&[]), Ok(()));
+}
+
+#[test]
+fn invalid_length() {
+    let nlas = vec![name("eth0"), DefaultNla::new(ATTR_ID, vec![42, 0])];
+    assert_eq!(
+        policy().validate(&nlas),
+        Err(PolicyError::InvalidLength {
+            kind: ATTR_ID,
+            nla_type: NlaType::U32,
+            length: 2,
+            expected: 4,
+        })
+    );
+
+    let nlas = vec![DefaultNla::new(ATTR_ENABLED, vec![1])];
+    assert!(matches!(
+        policy().validate(&nlas),
+        Err(PolicyError::InvalidLength { kind: ATTR_ENABLED, .. })
+    ));
+}
+
+#[test]
+fn unknown_attribute() {
+    let nlas = vec![id(42), DefaultNla::new(6, vec![])];
+    let err = policy().validate(&nlas).unwrap_err();
+    assert_eq!(err, PolicyError::UnknownAttribute(6));
+    assert_eq!(err.to_string(), "attribute 6 is not part of the policy");
+}
+
+#[test]
+fn invalid_strings() {
+    let nlas = vec![DefaultNla::new(ATTR_NAME, b"eth0".to_vec())];
+    assert_eq!(policy().validate(&nlas), Err(PolicyError::MissingNul(ATTR_NAME)));
+
+    let nlas = vec![name("enp3s0f0")];
+    assert_eq!(
+        policy().validate(&nlas),
+        Err(PolicyError::TooLong {
+            kind: ATTR_NAME,
+            length: 8,
+            max_length: 7,
+        })
+    );
+
+    let nlas = vec![DefaultNla::new(ATTR_LABEL, b"lan0\0".to_vec())];
+    assert_eq!(policy().validate(&nlas), Ok(()));
+    let nlas = vec![DefaultNla::new(ATTR_LABEL, b"lan01".to_vec())];
+    assert!(matches!(
+        policy().validate(&nlas),
+        Err(PolicyError::TooLong { kind: ATTR_LABEL, .. })
+    ));
+}
+
+#[test]
+fn invalid_nested() {
+    // A nested attribute header announcing more bytes than there are
+    let nlas = vec![DefaultNla::new(ATTR_OPTIONS, vec![0x10, 0x00, 0x01, 0x00])];
+    assert_eq!(policy().validate(&nlas), Err(PolicyError::InvalidNested(ATTR_OPTIONS)));
+}
+
+#[test]
+fn malformed_buffer() {
+    // Attribute header announcing 8 bytes, of which only 6 are present
+    let buf = [0x08, 0x00, 0x01, 0x00, 0x2a, 0x00];
+    assert!(matches!(policy().validate_buffer(&buf), Err(PolicyError::Malformed(_))));
+}
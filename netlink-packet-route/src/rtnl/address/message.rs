@@ -3,6 +3,7 @@
 use anyhow::Context;
 
 use crate::{
+    constants::{IFA_F_DADFAILED, IFA_F_TENTATIVE},
     nlas::address::Nla,
     traits::{Emitable, Parseable},
     AddressMessageBuffer,
@@ -25,6 +26,33 @@ pub struct AddressHeader {
     pub index: u32,
 }
 
+impl AddressMessage {
+    /// Returns the `IFA_F_*` flags of the address. The header only has room for the lower 8 bits
+    /// of the flags, so the `IFA_FLAGS` attribute takes precedence when present.
+    pub fn flags(&self) -> u32 {
+        self.nlas
+            .iter()
+            .find_map(|nla| match nla {
+                Nla::Flags(flags) => Some(*flags),
+                _ => None,
+            })
+            .unwrap_or_else(|| u32::from(self.header.flags))
+    }
+
+    /// Returns whether duplicate address detection is still running for the address
+    /// (`IFA_F_TENTATIVE`). Until it completes, the address cannot be used. A tentative address
+    /// whose detection failed stays tentative, see [`AddressMessage::dad_failed`].
+    pub fn is_tentative(&self) -> bool {
+        self.flags() & IFA_F_TENTATIVE != 0
+    }
+
+    /// Returns whether duplicate address detection found another node using the address
+    /// (`IFA_F_DADFAILED`)
+    pub fn dad_failed(&self) -> bool {
+        self.flags() & IFA_F_DADFAILED != 0
+    }
+}
+
 impl Emitable for AddressHeader {
     fn buffer_len(&self) -> usize {
         ADDRESS_HEADER_LEN
@@ -83,3 +111,84 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressMessageBuffer<&'a T>> for Vec<Nla
         Ok(nlas)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::*,
+        nlas::address::Nla,
+        traits::{Emitable, Parseable},
+        AddressHeader,
+        AddressMessage,
+        AddressMessageBuffer,
+    };
+
+    // RTM_NEWADDR notification for 2001:db8:198::1/64, sent while duplicate address detection is
+    // running
+    #[rustfmt::skip]
+    static TENTATIVE: [u8; 56] = [
+        0x0a, // family AF_INET6
+        0x40, // prefix length 64
+        0xc0, // flags IFA_F_PERMANENT | IFA_F_TENTATIVE
+        0x00, // scope RT_SCOPE_UNIVERSE
+        0x0c, 0x00, 0x00, 0x00, // interface index 12
+
+        // IFA_ADDRESS L=20,T=1
+        0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x01, 0x98, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+
+        // IFA_CACHEINFO L=20,T=6
+        0x14, 0x00, 0x06, 0x00,
+        0xff, 0xff, 0xff, 0xff, // preferred lifetime: forever
+        0xff, 0xff, 0xff, 0xff, // valid lifetime: forever
+        0xd5, 0x4f, 0x0b, 0x00, // creation timestamp
+        0xd5, 0x4f, 0x0b, 0x00, // update timestamp
+
+        // IFA_FLAGS L=8,T=8,V=IFA_F_PERMANENT | IFA_F_TENTATIVE
+        0x08, 0x00, 0x08, 0x00, 0xc0, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn parse_tentative_address() {
+        let packet = AddressMessage::parse(&AddressMessageBuffer::new(&TENTATIVE)).unwrap();
+        assert_eq!(
+            packet.header,
+            AddressHeader {
+                family: AF_INET6 as u8,
+                prefix_len: 64,
+                flags: 0xc0,
+                scope: RT_SCOPE_UNIVERSE,
+                index: 12,
+            }
+        );
+        assert_eq!(packet.flags(), IFA_F_PERMANENT | IFA_F_TENTATIVE);
+        assert!(packet.is_tentative());
+        assert!(!packet.dad_failed());
+
+        let mut buf = vec![0; TENTATIVE.len()];
+        packet.emit(&mut buf);
+        assert_eq!(&buf[..], &TENTATIVE[..]);
+    }
+
+    #[test]
+    fn flags_attribute_takes_precedence() {
+        let mut packet = AddressMessage::default();
+        packet.header.flags = IFA_F_TENTATIVE as u8;
+        assert!(packet.is_tentative());
+
+        // The kernel sets the flags above the 8th bit in the attribute only
+        packet.header.flags = (IFA_F_TENTATIVE | IFA_F_DADFAILED) as u8;
+        packet.nlas.push(Nla::Flags(
+            IFA_F_TENTATIVE | IFA_F_DADFAILED | IFA_F_STABLE_PRIVACY,
+        ));
+        assert_eq!(
+            packet.flags(),
+            IFA_F_TENTATIVE | IFA_F_DADFAILED | IFA_F_STABLE_PRIVACY
+        );
+        assert!(packet.dad_failed());
+
+        packet.nlas = vec![Nla::Flags(IFA_F_PERMANENT)];
+        assert!(!packet.is_tentative());
+    }
+}
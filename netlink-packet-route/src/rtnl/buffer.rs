@@ -9,6 +9,8 @@ use crate::{
     DecodeError,
     LinkMessage,
     LinkMessageBuffer,
+    MdbMessage,
+    MdbMessageBuffer,
     NeighbourMessage,
     NeighbourMessageBuffer,
     NeighbourTableMessage,
@@ -21,6 +23,8 @@ use crate::{
     RtnlMessage,
     RuleMessage,
     RuleMessageBuffer,
+    StatsMessage,
+    StatsMessageBuffer,
     TcMessage,
     TcMessageBuffer,
 };
@@ -189,7 +193,31 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
-            _ => return Err(format!("Unknown message type: {}", message_type).into()),
+            // Bridge multicast database messages
+            RTM_NEWMDB | RTM_DELMDB | RTM_GETMDB => {
+                let err = "invalid mdb message";
+                let msg = MdbMessage::parse(&MdbMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWMDB => NewMdb(msg),
+                    RTM_DELMDB => DelMdb(msg),
+                    RTM_GETMDB => GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Link statistics messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(&StatsMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Keep the messages added by newer kernels instead of failing to decode them
+            _ => Other((message_type, buf.inner().to_vec())),
         };
         Ok(message)
     }
@@ -276,6 +276,53 @@ pub const NETNSA_PID: u16 = 2;
 pub const NETNSA_FD: u16 = 3;
 pub const NETNSA_NSID_NOT_ASSIGNED: i32 = -1;
 
+pub const MDBA_UNSPEC: u16 = 0;
+pub const MDBA_MDB: u16 = 1;
+pub const MDBA_ROUTER: u16 = 2;
+pub const MDBA_MDB_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY: u16 = 1;
+pub const MDBA_MDB_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY_INFO: u16 = 1;
+pub const MDBA_MDB_EATTR_UNSPEC: u16 = 0;
+pub const MDBA_MDB_EATTR_TIMER: u16 = 1;
+pub const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+pub const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+pub const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+pub const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+pub const MDBA_SET_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_SET_ENTRY: u16 = 1;
+pub const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+/// MDB entry state: the entry expires unless the group membership is refreshed
+pub const MDB_TEMPORARY: u8 = 0;
+/// MDB entry state: the entry was added statically and does not expire
+pub const MDB_PERMANENT: u8 = 1;
+pub const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+pub const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+pub const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+pub const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+/// Bits of the `filter_mask` of `RTM_GETSTATS` requests, selecting the `IFLA_STATS_*` attributes
+/// to report (`IFLA_STATS_FILTER_BIT` in the kernel)
+pub const IFLA_STATS_FILTER_LINK_64: u32 = 1 << (IFLA_STATS_LINK_64 - 1);
+pub const IFLA_STATS_FILTER_LINK_XSTATS: u32 = 1 << (IFLA_STATS_LINK_XSTATS - 1);
+pub const IFLA_STATS_FILTER_LINK_XSTATS_SLAVE: u32 = 1 << (IFLA_STATS_LINK_XSTATS_SLAVE - 1);
+pub const IFLA_STATS_FILTER_LINK_OFFLOAD_XSTATS: u32 = 1 << (IFLA_STATS_LINK_OFFLOAD_XSTATS - 1);
+pub const IFLA_STATS_FILTER_AF_SPEC: u32 = 1 << (IFLA_STATS_AF_SPEC - 1);
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+pub const BRIDGE_XSTATS_UNSPEC: u16 = 0;
+pub const BRIDGE_XSTATS_VLAN: u16 = 1;
+pub const BRIDGE_XSTATS_MCAST: u16 = 2;
+pub const BRIDGE_XSTATS_PAD: u16 = 3;
+pub const BRIDGE_XSTATS_STP: u16 = 4;
+
 /// Neighbour cache entry state: the neighbour has not (yet) been resolved
 pub const NUD_INCOMPLETE: u16 = 1;
 /// Neighbour cache entry state: the neighbour entry is valid until its lifetime expires
@@ -306,6 +353,24 @@ pub const NTF_EXT_LEARNED: u8 = 16;
 pub const NTF_OFFLOADED: u8 = 32;
 pub const NTF_ROUTER: u8 = 128;
 
+// Ethernet protocols, such as the protocol a tc filter applies to
+pub const ETH_P_ALL: u16 = 0x0003;
+pub const ETH_P_IP: u16 = 0x0800;
+pub const ETH_P_IPV6: u16 = 0x86dd;
+
+// IP protocols, such as the protocol a tc flower filter matches
+pub const IPPROTO_TCP: u8 = 6;
+pub const IPPROTO_UDP: u8 = 17;
+
+pub const TC_H_MAJ_MASK: u32 = 0xffff_0000;
+pub const TC_H_MIN_MASK: u32 = 0x0000_ffff;
+pub const TC_H_UNSPEC: u32 = 0;
+pub const TC_H_ROOT: u32 = 0xffff_ffff;
+pub const TC_H_INGRESS: u32 = 0xffff_fff1;
+pub const TC_H_CLSACT: u32 = TC_H_INGRESS;
+pub const TC_H_MIN_INGRESS: u32 = 0xfff2;
+pub const TC_H_MIN_EGRESS: u32 = 0xfff3;
+
 pub const TCA_UNSPEC: u16 = 0;
 pub const TCA_KIND: u16 = 1;
 pub const TCA_OPTIONS: u16 = 2;
@@ -330,6 +395,155 @@ pub const TCA_STATS_RATE_EST64: u16 = 5;
 pub const TCA_STATS_PAD: u16 = 6;
 pub const TCA_STATS_BASIC_HW: u16 = 7;
 
+pub const TCA_ACT_TAB: u16 = 1;
+pub const TCA_ACT_UNSPEC: u16 = 0;
+pub const TCA_ACT_KIND: u16 = 1;
+pub const TCA_ACT_OPTIONS: u16 = 2;
+pub const TCA_ACT_INDEX: u16 = 3;
+pub const TCA_ACT_STATS: u16 = 4;
+pub const TCA_ACT_PAD: u16 = 5;
+pub const TCA_ACT_COOKIE: u16 = 6;
+
+pub const TC_ACT_UNSPEC: i32 = -1;
+pub const TC_ACT_OK: i32 = 0;
+pub const TC_ACT_RECLASSIFY: i32 = 1;
+pub const TC_ACT_SHOT: i32 = 2;
+pub const TC_ACT_PIPE: i32 = 3;
+pub const TC_ACT_STOLEN: i32 = 4;
+pub const TC_ACT_QUEUED: i32 = 5;
+pub const TC_ACT_REPEAT: i32 = 6;
+pub const TC_ACT_REDIRECT: i32 = 7;
+pub const TC_ACT_TRAP: i32 = 8;
+
+pub const TCA_MIRRED_UNSPEC: u16 = 0;
+pub const TCA_MIRRED_TM: u16 = 1;
+pub const TCA_MIRRED_PARMS: u16 = 2;
+pub const TCA_MIRRED_PAD: u16 = 3;
+
+pub const TCA_EGRESS_REDIR: i32 = 1;
+pub const TCA_EGRESS_MIRROR: i32 = 2;
+pub const TCA_INGRESS_REDIR: i32 = 3;
+pub const TCA_INGRESS_MIRROR: i32 = 4;
+
+pub const TCA_POLICE_UNSPEC: u16 = 0;
+pub const TCA_POLICE_TBF: u16 = 1;
+pub const TCA_POLICE_RATE: u16 = 2;
+pub const TCA_POLICE_PEAKRATE: u16 = 3;
+pub const TCA_POLICE_AVRATE: u16 = 4;
+pub const TCA_POLICE_RESULT: u16 = 5;
+pub const TCA_POLICE_TM: u16 = 6;
+pub const TCA_POLICE_PAD: u16 = 7;
+pub const TCA_POLICE_RATE64: u16 = 8;
+pub const TCA_POLICE_PEAKRATE64: u16 = 9;
+
+pub const TC_LINKLAYER_UNAWARE: u8 = 0;
+pub const TC_LINKLAYER_ETHERNET: u8 = 1;
+pub const TC_LINKLAYER_ATM: u8 = 2;
+
+pub const TCA_U32_UNSPEC: u16 = 0;
+pub const TCA_U32_CLASSID: u16 = 1;
+pub const TCA_U32_HASH: u16 = 2;
+pub const TCA_U32_LINK: u16 = 3;
+pub const TCA_U32_DIVISOR: u16 = 4;
+pub const TCA_U32_SEL: u16 = 5;
+pub const TCA_U32_POLICE: u16 = 6;
+pub const TCA_U32_ACT: u16 = 7;
+pub const TCA_U32_INDEV: u16 = 8;
+pub const TCA_U32_PCNT: u16 = 9;
+pub const TCA_U32_MARK: u16 = 10;
+pub const TCA_U32_FLAGS: u16 = 11;
+pub const TCA_U32_PAD: u16 = 12;
+
+pub const TC_U32_TERMINAL: u8 = 1;
+pub const TC_U32_OFFSET: u8 = 2;
+pub const TC_U32_VAROFFSET: u8 = 4;
+pub const TC_U32_EAT: u8 = 8;
+
+pub const TCA_FLOWER_UNSPEC: u16 = 0;
+pub const TCA_FLOWER_CLASSID: u16 = 1;
+pub const TCA_FLOWER_INDEV: u16 = 2;
+pub const TCA_FLOWER_ACT: u16 = 3;
+pub const TCA_FLOWER_KEY_ETH_DST: u16 = 4;
+pub const TCA_FLOWER_KEY_ETH_DST_MASK: u16 = 5;
+pub const TCA_FLOWER_KEY_ETH_SRC: u16 = 6;
+pub const TCA_FLOWER_KEY_ETH_SRC_MASK: u16 = 7;
+pub const TCA_FLOWER_KEY_ETH_TYPE: u16 = 8;
+pub const TCA_FLOWER_KEY_IP_PROTO: u16 = 9;
+pub const TCA_FLOWER_KEY_IPV4_SRC: u16 = 10;
+pub const TCA_FLOWER_KEY_IPV4_SRC_MASK: u16 = 11;
+pub const TCA_FLOWER_KEY_IPV4_DST: u16 = 12;
+pub const TCA_FLOWER_KEY_IPV4_DST_MASK: u16 = 13;
+pub const TCA_FLOWER_KEY_IPV6_SRC: u16 = 14;
+pub const TCA_FLOWER_KEY_IPV6_SRC_MASK: u16 = 15;
+pub const TCA_FLOWER_KEY_IPV6_DST: u16 = 16;
+pub const TCA_FLOWER_KEY_IPV6_DST_MASK: u16 = 17;
+pub const TCA_FLOWER_KEY_TCP_SRC: u16 = 18;
+pub const TCA_FLOWER_KEY_TCP_DST: u16 = 19;
+pub const TCA_FLOWER_KEY_UDP_SRC: u16 = 20;
+pub const TCA_FLOWER_KEY_UDP_DST: u16 = 21;
+pub const TCA_FLOWER_FLAGS: u16 = 22;
+pub const TCA_FLOWER_KEY_VLAN_ID: u16 = 23;
+pub const TCA_FLOWER_KEY_VLAN_PRIO: u16 = 24;
+pub const TCA_FLOWER_KEY_VLAN_ETH_TYPE: u16 = 25;
+pub const TCA_FLOWER_KEY_ENC_KEY_ID: u16 = 26;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC: u16 = 27;
+pub const TCA_FLOWER_KEY_ENC_IPV4_SRC_MASK: u16 = 28;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST: u16 = 29;
+pub const TCA_FLOWER_KEY_ENC_IPV4_DST_MASK: u16 = 30;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC: u16 = 31;
+pub const TCA_FLOWER_KEY_ENC_IPV6_SRC_MASK: u16 = 32;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST: u16 = 33;
+pub const TCA_FLOWER_KEY_ENC_IPV6_DST_MASK: u16 = 34;
+pub const TCA_FLOWER_KEY_TCP_SRC_MASK: u16 = 35;
+pub const TCA_FLOWER_KEY_TCP_DST_MASK: u16 = 36;
+pub const TCA_FLOWER_KEY_UDP_SRC_MASK: u16 = 37;
+pub const TCA_FLOWER_KEY_UDP_DST_MASK: u16 = 38;
+pub const TCA_FLOWER_KEY_SCTP_SRC_MASK: u16 = 39;
+pub const TCA_FLOWER_KEY_SCTP_DST_MASK: u16 = 40;
+pub const TCA_FLOWER_KEY_SCTP_SRC: u16 = 41;
+pub const TCA_FLOWER_KEY_SCTP_DST: u16 = 42;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT: u16 = 43;
+pub const TCA_FLOWER_KEY_ENC_UDP_SRC_PORT_MASK: u16 = 44;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT: u16 = 45;
+pub const TCA_FLOWER_KEY_ENC_UDP_DST_PORT_MASK: u16 = 46;
+pub const TCA_FLOWER_KEY_FLAGS: u16 = 47;
+pub const TCA_FLOWER_KEY_FLAGS_MASK: u16 = 48;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE: u16 = 49;
+pub const TCA_FLOWER_KEY_ICMPV4_CODE_MASK: u16 = 50;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE: u16 = 51;
+pub const TCA_FLOWER_KEY_ICMPV4_TYPE_MASK: u16 = 52;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE: u16 = 53;
+pub const TCA_FLOWER_KEY_ICMPV6_CODE_MASK: u16 = 54;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE: u16 = 55;
+pub const TCA_FLOWER_KEY_ICMPV6_TYPE_MASK: u16 = 56;
+pub const TCA_FLOWER_KEY_ARP_SIP: u16 = 57;
+pub const TCA_FLOWER_KEY_ARP_SIP_MASK: u16 = 58;
+pub const TCA_FLOWER_KEY_ARP_TIP: u16 = 59;
+pub const TCA_FLOWER_KEY_ARP_TIP_MASK: u16 = 60;
+pub const TCA_FLOWER_KEY_ARP_OP: u16 = 61;
+pub const TCA_FLOWER_KEY_ARP_OP_MASK: u16 = 62;
+pub const TCA_FLOWER_KEY_ARP_SHA: u16 = 63;
+pub const TCA_FLOWER_KEY_ARP_SHA_MASK: u16 = 64;
+pub const TCA_FLOWER_KEY_ARP_THA: u16 = 65;
+pub const TCA_FLOWER_KEY_ARP_THA_MASK: u16 = 66;
+pub const TCA_FLOWER_KEY_MPLS_TTL: u16 = 67;
+pub const TCA_FLOWER_KEY_MPLS_BOS: u16 = 68;
+pub const TCA_FLOWER_KEY_MPLS_TC: u16 = 69;
+pub const TCA_FLOWER_KEY_MPLS_LABEL: u16 = 70;
+pub const TCA_FLOWER_KEY_TCP_FLAGS: u16 = 71;
+pub const TCA_FLOWER_KEY_TCP_FLAGS_MASK: u16 = 72;
+pub const TCA_FLOWER_KEY_IP_TOS: u16 = 73;
+pub const TCA_FLOWER_KEY_IP_TOS_MASK: u16 = 74;
+pub const TCA_FLOWER_KEY_IP_TTL: u16 = 75;
+pub const TCA_FLOWER_KEY_IP_TTL_MASK: u16 = 76;
+
+// Flags of the filters that can be offloaded to hardware, such as `TCA_FLOWER_FLAGS`
+pub const TCA_CLS_FLAGS_SKIP_HW: u32 = 1;
+pub const TCA_CLS_FLAGS_SKIP_SW: u32 = 2;
+pub const TCA_CLS_FLAGS_IN_HW: u32 = 4;
+pub const TCA_CLS_FLAGS_NOT_IN_HW: u32 = 8;
+pub const TCA_CLS_FLAGS_VERBOSE: u32 = 16;
+
 pub const NDTA_UNSPEC: u16 = 0;
 pub const NDTA_NAME: u16 = 1;
 pub const NDTA_THRESH1: u16 = 2;
@@ -454,6 +668,8 @@ pub const IFLA_VLAN_FLAGS: u16 = 2;
 pub const IFLA_VLAN_EGRESS_QOS: u16 = 3;
 pub const IFLA_VLAN_INGRESS_QOS: u16 = 4;
 pub const IFLA_VLAN_PROTOCOL: u16 = 5;
+pub const IFLA_VLAN_QOS_UNSPEC: u16 = 0;
+pub const IFLA_VLAN_QOS_MAPPING: u16 = 1;
 pub const IFLA_VRF_UNSPEC: u16 = 0;
 pub const IFLA_VRF_TABLE: u16 = 1;
 pub const IFLA_IPVLAN_UNSPEC: u16 = 0;
@@ -463,6 +679,54 @@ pub const IFLA_IPOIB_UNSPEC: u16 = 0;
 pub const IFLA_IPOIB_PKEY: u16 = 1;
 pub const IFLA_IPOIB_MODE: u16 = 2;
 pub const IFLA_IPOIB_UMCAST: u16 = 3;
+
+pub const IPOIB_MODE_DATAGRAM: u16 = 0;
+pub const IPOIB_MODE_CONNECTED: u16 = 1;
+
+pub const IFLA_BOND_UNSPEC: u16 = 0;
+pub const IFLA_BOND_MODE: u16 = 1;
+pub const IFLA_BOND_ACTIVE_SLAVE: u16 = 2;
+pub const IFLA_BOND_MIIMON: u16 = 3;
+pub const IFLA_BOND_UPDELAY: u16 = 4;
+pub const IFLA_BOND_DOWNDELAY: u16 = 5;
+pub const IFLA_BOND_USE_CARRIER: u16 = 6;
+pub const IFLA_BOND_ARP_INTERVAL: u16 = 7;
+pub const IFLA_BOND_ARP_IP_TARGET: u16 = 8;
+pub const IFLA_BOND_ARP_VALIDATE: u16 = 9;
+pub const IFLA_BOND_ARP_ALL_TARGETS: u16 = 10;
+pub const IFLA_BOND_PRIMARY: u16 = 11;
+pub const IFLA_BOND_PRIMARY_RESELECT: u16 = 12;
+pub const IFLA_BOND_FAIL_OVER_MAC: u16 = 13;
+pub const IFLA_BOND_XMIT_HASH_POLICY: u16 = 14;
+pub const IFLA_BOND_RESEND_IGMP: u16 = 15;
+pub const IFLA_BOND_NUM_PEER_NOTIF: u16 = 16;
+pub const IFLA_BOND_ALL_SLAVES_ACTIVE: u16 = 17;
+pub const IFLA_BOND_MIN_LINKS: u16 = 18;
+pub const IFLA_BOND_LP_INTERVAL: u16 = 19;
+pub const IFLA_BOND_PACKETS_PER_SLAVE: u16 = 20;
+pub const IFLA_BOND_AD_LACP_RATE: u16 = 21;
+pub const IFLA_BOND_AD_SELECT: u16 = 22;
+pub const IFLA_BOND_AD_INFO: u16 = 23;
+pub const IFLA_BOND_AD_ACTOR_SYS_PRIO: u16 = 24;
+pub const IFLA_BOND_AD_USER_PORT_KEY: u16 = 25;
+pub const IFLA_BOND_AD_ACTOR_SYSTEM: u16 = 26;
+pub const IFLA_BOND_TLB_DYNAMIC_LB: u16 = 27;
+pub const IFLA_BOND_PEER_NOTIF_DELAY: u16 = 28;
+pub const IFLA_BOND_AD_INFO_UNSPEC: u16 = 0;
+pub const IFLA_BOND_AD_INFO_AGGREGATOR: u16 = 1;
+pub const IFLA_BOND_AD_INFO_NUM_PORTS: u16 = 2;
+pub const IFLA_BOND_AD_INFO_ACTOR_KEY: u16 = 3;
+pub const IFLA_BOND_AD_INFO_PARTNER_KEY: u16 = 4;
+pub const IFLA_BOND_AD_INFO_PARTNER_MAC: u16 = 5;
+pub const IFLA_BOND_SLAVE_UNSPEC: u16 = 0;
+pub const IFLA_BOND_SLAVE_STATE: u16 = 1;
+pub const IFLA_BOND_SLAVE_MII_STATUS: u16 = 2;
+pub const IFLA_BOND_SLAVE_LINK_FAILURE_COUNT: u16 = 3;
+pub const IFLA_BOND_SLAVE_PERM_HWADDR: u16 = 4;
+pub const IFLA_BOND_SLAVE_QUEUE_ID: u16 = 5;
+pub const IFLA_BOND_SLAVE_AD_AGGREGATOR_ID: u16 = 6;
+pub const IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE: u16 = 7;
+pub const IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE: u16 = 8;
 pub const VETH_INFO_UNSPEC: u16 = 0;
 pub const VETH_INFO_PEER: u16 = 1;
 
@@ -600,6 +864,11 @@ pub const IFLA_PROP_LIST: u16 = 52;
 pub const IFLA_ALT_IFNAME: u16 = 53;
 pub const IFLA_PERM_ADDRESS: u16 = 54;
 pub const IFLA_PROTO_DOWN_REASON: u16 = 55;
+pub const IFLA_PARENT_DEV_NAME: u16 = 56;
+pub const IFLA_PARENT_DEV_BUS_NAME: u16 = 57;
+pub const IFLA_GRO_MAX_SIZE: u16 = 58;
+pub const IFLA_TSO_MAX_SIZE: u16 = 59;
+pub const IFLA_TSO_MAX_SEGS: u16 = 60;
 pub const IFLA_INET_UNSPEC: u16 = 0;
 pub const IFLA_INET_CONF: u16 = 1;
 pub const IFLA_INET6_UNSPEC: u16 = 0;
@@ -612,6 +881,66 @@ pub const IFLA_INET6_ICMP6STATS: u16 = 6;
 pub const IFLA_INET6_TOKEN: u16 = 7;
 pub const IFLA_INET6_ADDR_GEN_MODE: u16 = 8;
 
+// Index of the settings in the IFLA_INET6_CONF array
+pub const DEVCONF_FORWARDING: usize = 0;
+pub const DEVCONF_HOPLIMIT: usize = 1;
+pub const DEVCONF_MTU6: usize = 2;
+pub const DEVCONF_ACCEPT_RA: usize = 3;
+pub const DEVCONF_ACCEPT_REDIRECTS: usize = 4;
+pub const DEVCONF_AUTOCONF: usize = 5;
+pub const DEVCONF_DAD_TRANSMITS: usize = 6;
+pub const DEVCONF_RTR_SOLICITS: usize = 7;
+pub const DEVCONF_RTR_SOLICIT_INTERVAL: usize = 8;
+pub const DEVCONF_RTR_SOLICIT_DELAY: usize = 9;
+pub const DEVCONF_USE_TEMPADDR: usize = 10;
+pub const DEVCONF_TEMP_VALID_LFT: usize = 11;
+pub const DEVCONF_TEMP_PREFERED_LFT: usize = 12;
+pub const DEVCONF_REGEN_MAX_RETRY: usize = 13;
+pub const DEVCONF_MAX_DESYNC_FACTOR: usize = 14;
+pub const DEVCONF_MAX_ADDRESSES: usize = 15;
+pub const DEVCONF_FORCE_MLD_VERSION: usize = 16;
+pub const DEVCONF_ACCEPT_RA_DEFRTR: usize = 17;
+pub const DEVCONF_ACCEPT_RA_PINFO: usize = 18;
+pub const DEVCONF_ACCEPT_RA_RTR_PREF: usize = 19;
+pub const DEVCONF_RTR_PROBE_INTERVAL: usize = 20;
+pub const DEVCONF_ACCEPT_RA_RT_INFO_MAX_PLEN: usize = 21;
+pub const DEVCONF_PROXY_NDP: usize = 22;
+pub const DEVCONF_OPTIMISTIC_DAD: usize = 23;
+pub const DEVCONF_ACCEPT_SOURCE_ROUTE: usize = 24;
+pub const DEVCONF_MC_FORWARDING: usize = 25;
+pub const DEVCONF_DISABLE_IPV6: usize = 26;
+pub const DEVCONF_ACCEPT_DAD: usize = 27;
+pub const DEVCONF_FORCE_TLLAO: usize = 28;
+pub const DEVCONF_NDISC_NOTIFY: usize = 29;
+pub const DEVCONF_MLDV1_UNSOLICITED_REPORT_INTERVAL: usize = 30;
+pub const DEVCONF_MLDV2_UNSOLICITED_REPORT_INTERVAL: usize = 31;
+pub const DEVCONF_SUPPRESS_FRAG_NDISC: usize = 32;
+pub const DEVCONF_ACCEPT_RA_FROM_LOCAL: usize = 33;
+pub const DEVCONF_USE_OPTIMISTIC: usize = 34;
+pub const DEVCONF_ACCEPT_RA_MTU: usize = 35;
+pub const DEVCONF_STABLE_SECRET: usize = 36;
+pub const DEVCONF_USE_OIF_ADDRS_ONLY: usize = 37;
+pub const DEVCONF_ACCEPT_RA_MIN_HOP_LIMIT: usize = 38;
+pub const DEVCONF_IGNORE_ROUTES_WITH_LINKDOWN: usize = 39;
+pub const DEVCONF_DROP_UNICAST_IN_L2_MULTICAST: usize = 40;
+pub const DEVCONF_DROP_UNSOLICITED_NA: usize = 41;
+pub const DEVCONF_KEEP_ADDR_ON_DOWN: usize = 42;
+pub const DEVCONF_RTR_SOLICIT_MAX_INTERVAL: usize = 43;
+pub const DEVCONF_SEG6_ENABLED: usize = 44;
+pub const DEVCONF_SEG6_REQUIRE_HMAC: usize = 45;
+pub const DEVCONF_ENHANCED_DAD: usize = 46;
+pub const DEVCONF_ADDR_GEN_MODE: usize = 47;
+pub const DEVCONF_DISABLE_POLICY: usize = 48;
+pub const DEVCONF_ACCEPT_RA_RT_INFO_MIN_PLEN: usize = 49;
+pub const DEVCONF_NDISC_TCLASS: usize = 50;
+
+// IFLA_INET6_FLAGS
+pub const IF_RS_SENT: u32 = 0x10;
+pub const IF_RA_RCVD: u32 = 0x20;
+pub const IF_RA_MANAGED: u32 = 0x40;
+pub const IF_RA_OTHERCONF: u32 = 0x80;
+pub const IF_READY: u32 = 0x8000_0000;
+
 /// Link is up (administratively).
 pub const IFF_UP: u32 = libc::IFF_UP as u32;
 /// Link is up and carrier is OK (RFC2863 OPER_UP)
@@ -782,13 +1111,16 @@ pub const IFA_F_STABLE_PRIVACY: u32 = 2048;
 // pub const RTNL_FAMILY_IP6MR: int = 129;
 // pub const RTNL_FAMILY_MAX: int = 129;
 // pub const RTA_ALIGNTO: int = 4;
-//
-// pub const RTNH_F_DEAD: int = 1;
-// pub const RTNH_F_PERVASIVE: int = 2;
-// pub const RTNH_F_ONLINK: int = 4;
-// pub const RTNH_F_OFFLOAD: int = 8;
-// pub const RTNH_F_LINKDOWN: int = 16;
-// pub const RTNH_F_UNRESOLVED: int = 32;
+
+/// Nexthop flags, found in the `flags` of the route header as well as in the nexthops of
+/// multipath routes
+pub const RTNH_F_DEAD: u32 = 1;
+pub const RTNH_F_PERVASIVE: u32 = 2;
+pub const RTNH_F_ONLINK: u32 = 4;
+pub const RTNH_F_OFFLOAD: u32 = 8;
+pub const RTNH_F_LINKDOWN: u32 = 16;
+pub const RTNH_F_UNRESOLVED: u32 = 32;
+
 // pub const RTNH_COMPARE_MASK: int = 25;
 // pub const RTNH_ALIGNTO: int = 4;
 // pub const RTNETLINK_HAVE_PEERINFO: int = 1;
@@ -805,6 +1137,34 @@ pub const RTEXT_FILTER_VF: u32 = 1;
 pub const RTEXT_FILTER_BRVLAN: u32 = 2;
 pub const RTEXT_FILTER_BRVLAN_COMPRESSED: u32 = 4;
 pub const RTEXT_FILTER_SKIP_STATS: u32 = 8;
+pub const RTEXT_FILTER_MRP: u32 = 16;
+pub const RTEXT_FILTER_CFM_CONFIG: u32 = 32;
+pub const RTEXT_FILTER_CFM_STATUS: u32 = 64;
+pub const RTEXT_FILTER_MST: u32 = 128;
+
+pub const IFLA_BRIDGE_FLAGS: u16 = 0;
+pub const IFLA_BRIDGE_MODE: u16 = 1;
+pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
+pub const IFLA_BRIDGE_VLAN_TUNNEL_INFO: u16 = 3;
+pub const IFLA_BRIDGE_MRP: u16 = 4;
+pub const IFLA_BRIDGE_CFM: u16 = 5;
+
+pub const BRIDGE_FLAGS_MASTER: u16 = 1;
+pub const BRIDGE_FLAGS_SELF: u16 = 2;
+
+pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1 << 0;
+/// VLAN is PVID, ingress untagged
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 1 << 1;
+/// VLAN egresses untagged
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 1 << 2;
+/// VLAN is start of vlan range
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 1 << 3;
+/// VLAN is end of vlan range
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 1 << 4;
+/// Global bridge VLAN entry
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 1 << 5;
+/// Skip creating a VLAN
+pub const BRIDGE_VLAN_INFO_ONLY_OPTS: u16 = 1 << 6;
 
 // pub const ARPOP_REQUEST: int = 1;
 // pub const ARPOP_REPLY: int = 2;
@@ -880,27 +1240,6 @@ pub const MACVLAN_MODE_SOURCE: u32 = 16;
 // pub const IFLA_VRF_PORT_UNSPEC: int = 0;
 // pub const IFLA_VRF_PORT_TABLE: int = 1;
 //
-// pub const IFLA_MACSEC_UNSPEC: int = 0;
-// pub const IFLA_MACSEC_SCI: int = 1;
-// pub const IFLA_MACSEC_PORT: int = 2;
-// pub const IFLA_MACSEC_ICV_LEN: int = 3;
-// pub const IFLA_MACSEC_CIPHER_SUITE: int = 4;
-// pub const IFLA_MACSEC_WINDOW: int = 5;
-// pub const IFLA_MACSEC_ENCODING_SA: int = 6;
-// pub const IFLA_MACSEC_ENCRYPT: int = 7;
-// pub const IFLA_MACSEC_PROTECT: int = 8;
-// pub const IFLA_MACSEC_INC_SCI: int = 9;
-// pub const IFLA_MACSEC_ES: int = 10;
-// pub const IFLA_MACSEC_SCB: int = 11;
-// pub const IFLA_MACSEC_REPLAY_PROTECT: int = 12;
-// pub const IFLA_MACSEC_VALIDATION: int = 13;
-// pub const IFLA_MACSEC_PAD: int = 14;
-//
-// pub const MACSEC_VALIDATE_DISABLED: int = 0;
-// pub const MACSEC_VALIDATE_CHECK: int = 1;
-// pub const MACSEC_VALIDATE_STRICT: int = 2;
-// pub const MACSEC_VALIDATE_MAX: int = 2;
-//
 // pub const IFLA_IPVLAN_UNSPEC: int = 0;
 // pub const IFLA_IPVLAN_MODE: int = 1;
 // pub const IFLA_IPVLAN_FLAGS: int = 2;
@@ -942,6 +1281,98 @@ pub const IFLA_VXLAN_GPE: u16 = 27;
 pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
 pub const IFLA_VXLAN_DF: u16 = 29;
 pub const __IFLA_VXLAN_MAX: u16 = 30;
+
+pub const IFLA_GRE_UNSPEC: u16 = 0;
+pub const IFLA_GRE_LINK: u16 = 1;
+pub const IFLA_GRE_IFLAGS: u16 = 2;
+pub const IFLA_GRE_OFLAGS: u16 = 3;
+pub const IFLA_GRE_IKEY: u16 = 4;
+pub const IFLA_GRE_OKEY: u16 = 5;
+pub const IFLA_GRE_LOCAL: u16 = 6;
+pub const IFLA_GRE_REMOTE: u16 = 7;
+pub const IFLA_GRE_TTL: u16 = 8;
+pub const IFLA_GRE_TOS: u16 = 9;
+pub const IFLA_GRE_PMTUDISC: u16 = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub const IFLA_GRE_FLOWINFO: u16 = 12;
+pub const IFLA_GRE_FLAGS: u16 = 13;
+pub const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+pub const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+pub const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub const IFLA_GRE_FWMARK: u16 = 20;
+
+// GRE flags, for IFLA_GRE_IFLAGS and IFLA_GRE_OFLAGS
+pub const GRE_CSUM: u16 = 0x8000;
+pub const GRE_ROUTING: u16 = 0x4000;
+pub const GRE_KEY: u16 = 0x2000;
+pub const GRE_SEQ: u16 = 0x1000;
+
+// Attributes of the ipip and sit tunnels
+pub const IFLA_IPTUN_UNSPEC: u16 = 0;
+pub const IFLA_IPTUN_LINK: u16 = 1;
+pub const IFLA_IPTUN_LOCAL: u16 = 2;
+pub const IFLA_IPTUN_REMOTE: u16 = 3;
+pub const IFLA_IPTUN_TTL: u16 = 4;
+pub const IFLA_IPTUN_TOS: u16 = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub const IFLA_IPTUN_FLAGS: u16 = 8;
+pub const IFLA_IPTUN_PROTO: u16 = 9;
+pub const IFLA_IPTUN_PMTUDISC: u16 = 10;
+pub const IFLA_IPTUN_6RD_PREFIX: u16 = 11;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = 12;
+pub const IFLA_IPTUN_6RD_PREFIXLEN: u16 = 13;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = 14;
+pub const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub const IFLA_IPTUN_ENCAP_FLAGS: u16 = 16;
+pub const IFLA_IPTUN_ENCAP_SPORT: u16 = 17;
+pub const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+pub const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+pub const IFLA_IPTUN_FWMARK: u16 = 20;
+
+pub const IFLA_MACSEC_UNSPEC: u16 = 0;
+pub const IFLA_MACSEC_SCI: u16 = 1;
+pub const IFLA_MACSEC_PORT: u16 = 2;
+pub const IFLA_MACSEC_ICV_LEN: u16 = 3;
+pub const IFLA_MACSEC_CIPHER_SUITE: u16 = 4;
+pub const IFLA_MACSEC_WINDOW: u16 = 5;
+pub const IFLA_MACSEC_ENCODING_SA: u16 = 6;
+pub const IFLA_MACSEC_ENCRYPT: u16 = 7;
+pub const IFLA_MACSEC_PROTECT: u16 = 8;
+pub const IFLA_MACSEC_INC_SCI: u16 = 9;
+pub const IFLA_MACSEC_ES: u16 = 10;
+pub const IFLA_MACSEC_SCB: u16 = 11;
+pub const IFLA_MACSEC_REPLAY_PROTECT: u16 = 12;
+pub const IFLA_MACSEC_VALIDATION: u16 = 13;
+pub const IFLA_MACSEC_PAD: u16 = 14;
+
+pub const MACSEC_VALIDATE_DISABLED: u8 = 0;
+pub const MACSEC_VALIDATE_CHECK: u8 = 1;
+pub const MACSEC_VALIDATE_STRICT: u8 = 2;
+
+// MACsec cipher suites, for IFLA_MACSEC_CIPHER_SUITE
+pub const MACSEC_CIPHER_ID_GCM_AES_128: u64 = 0x0080_C200_0100_0001;
+pub const MACSEC_CIPHER_ID_GCM_AES_256: u64 = 0x0080_C200_0100_0002;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_128: u64 = 0x0080_C200_0100_0003;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080_C200_0100_0004;
+pub const MACSEC_DEFAULT_CIPHER_ID: u64 = 0x0080_0200_0100_0001;
+
+pub const IFLA_BAREUDP_UNSPEC: u16 = 0;
+pub const IFLA_BAREUDP_PORT: u16 = 1;
+pub const IFLA_BAREUDP_ETHERTYPE: u16 = 2;
+pub const IFLA_BAREUDP_SRCPORT_MIN: u16 = 3;
+pub const IFLA_BAREUDP_MULTIPROTO_MODE: u16 = 4;
+pub const IFLA_GTP_UNSPEC: u16 = 0;
+pub const IFLA_GTP_FD0: u16 = 1;
+pub const IFLA_GTP_FD1: u16 = 2;
+pub const IFLA_GTP_PDP_HASHSIZE: u16 = 3;
+pub const IFLA_GTP_ROLE: u16 = 4;
+
+pub const GTP_ROLE_GGSN: u32 = 0;
+pub const GTP_ROLE_SGSN: u32 = 1;
 //
 // pub const IFLA_GENEVE_UNSPEC: int = 0;
 // pub const IFLA_GENEVE_ID: int = 1;
@@ -1120,9 +1551,6 @@ pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
 // pub const IFLA_IPOIB_MODE: int = 2;
 // pub const IFLA_IPOIB_UMCAST: int = 3;
 //
-// pub const IPOIB_MODE_DATAGRAM: int = 0;
-// pub const IPOIB_MODE_CONNECTED: int = 1;
-//
 // pub const IFLA_HSR_UNSPEC: int = 0;
 // pub const IFLA_HSR_SLAVE1: int = 1;
 // pub const IFLA_HSR_SLAVE2: int = 2;
@@ -1131,16 +1559,6 @@ pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
 // pub const IFLA_HSR_SEQ_NR: int = 5;
 // pub const IFLA_HSR_VERSION: int = 6;
 //
-// pub const IFLA_STATS_UNSPEC: int = 0;
-// pub const IFLA_STATS_LINK_64: int = 1;
-// pub const IFLA_STATS_LINK_XSTATS: int = 2;
-// pub const IFLA_STATS_LINK_XSTATS_SLAVE: int = 3;
-// pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: int = 4;
-// pub const IFLA_STATS_AF_SPEC: int = 5;
-//
-// pub const LINK_XSTATS_TYPE_UNSPEC: int = 0;
-// pub const LINK_XSTATS_TYPE_BRIDGE: int = 1;
-//
 // pub const IFLA_OFFLOAD_XSTATS_UNSPEC: int = 0;
 // pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: int = 1;
 //
@@ -1,12 +1,58 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
+    constants::*,
     traits::{Emitable, Parseable},
     DecodeError,
     LinkMessageBuffer,
     LINK_HEADER_LEN,
 };
 
+bitflags! {
+    /// Flags of a link (`ifi_flags`), as found in [`LinkHeader::flags`] and
+    /// [`LinkHeader::change_mask`]
+    pub struct InterfaceFlags: u32 {
+        /// The link is administratively up
+        const UP = IFF_UP;
+        /// The link supports broadcasting
+        const BROADCAST = IFF_BROADCAST;
+        /// Driver debugging (unused)
+        const DEBUG = IFF_DEBUG;
+        /// Loopback link
+        const LOOPBACK = IFF_LOOPBACK;
+        /// Point-to-point link
+        const POINTOPOINT = IFF_POINTOPOINT;
+        /// The link is operationally up (RFC 2863 `OPER_UP`)
+        const RUNNING = IFF_RUNNING;
+        /// The link does not use ARP
+        const NOARP = IFF_NOARP;
+        /// The link receives all packets
+        const PROMISC = IFF_PROMISC;
+        /// The link receives all multicast packets
+        const ALLMULTI = IFF_ALLMULTI;
+        /// Master of a load balancer (bonding)
+        const MASTER = IFF_MASTER;
+        /// Slave of a load balancer
+        const SLAVE = IFF_SLAVE;
+        /// The link supports multicast
+        const MULTICAST = IFF_MULTICAST;
+        /// The link selects its media port automatically
+        const PORTSEL = IFF_PORTSEL;
+        /// The driver supports setting the media type
+        const AUTOMEDIA = IFF_AUTOMEDIA;
+        /// The link layer is operational
+        const LOWER_UP = IFF_LOWER_UP;
+        /// The driver signals the link is dormant
+        const DORMANT = IFF_DORMANT;
+    }
+}
+
+impl Default for InterfaceFlags {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 /// High level representation of `RTM_GETLINK`, `RTM_SETLINK`, `RTM_NEWLINK` and `RTM_DELLINK`
 /// messages headers.
 ///
@@ -37,7 +83,8 @@ pub struct LinkHeader {
     /// Ethernet.
     pub link_layer_type: u16,
     /// State of the link, described by a combinations of `IFF_*`
-    /// constants, for instance `IFF_UP | IFF_LOWER_UP`.
+    /// constants, for instance `IFF_UP | IFF_LOWER_UP`. See also
+    /// [`InterfaceFlags`].
     pub flags: u32,
     /// Change mask for the `flags` field. Reserved, it should be set
     /// to `0xffff_ffff`.
@@ -3,11 +3,14 @@
 use anyhow::Context;
 
 use crate::{
-    nlas::link::Nla,
+    nlas::link::{Info, InfoData, Nla, PhysId, Prop, State},
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
+    InterfaceFlags,
     LinkHeader,
     LinkMessageBuffer,
+    LinkOffloadInfo,
+    TunnelInfo,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
@@ -16,6 +19,181 @@ pub struct LinkMessage {
     pub nlas: Vec<Nla>,
 }
 
+impl LinkMessage {
+    /// Returns the flags of the link. The bits that do not match any [`InterfaceFlags`] are
+    /// dropped.
+    pub fn interface_flags(&self) -> InterfaceFlags {
+        InterfaceFlags::from_bits_truncate(self.header.flags)
+    }
+
+    /// Returns the alternative names of the link, listed in its `IFLA_PROP_LIST` attribute.
+    /// Unlike the primary name, they can be longer than `IFNAMSIZ`.
+    pub fn alt_names(&self) -> Vec<String> {
+        self.nlas
+            .iter()
+            .filter_map(|nla| match nla {
+                Nla::PropList(props) => Some(props),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|prop| match prop {
+                Prop::AltIfName(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the name of the physical port of the link (`IFLA_PHYS_PORT_NAME`), such as
+    /// `p0` or `pf0vf1` for the representors of a switchdev NIC, if present.
+    pub fn phys_port_name(&self) -> Option<&str> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::PhysPortName(v) = nla {
+                Some(v.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the identifier of the physical port of the link (`IFLA_PHYS_PORT_ID`), if
+    /// present.
+    pub fn phys_port_id(&self) -> Option<PhysId> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::PhysPortId(v) = nla {
+                Some(PhysId(v.clone()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the identifier of the switch the link is a port of (`IFLA_PHYS_SWITCH_ID`), if
+    /// present.
+    pub fn phys_switch_id(&self) -> Option<PhysId> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::PhysSwitchId(v) = nla {
+                Some(PhysId(v.clone()))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the MTU of the link, if present.
+    pub fn mtu(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Mtu(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the RFC 2863 operational state of the link (`IFLA_OPERSTATE`), if present.
+    pub fn oper_state(&self) -> Option<State> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::OperState(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns whether the link has a carrier (`IFLA_CARRIER`), if present.
+    pub fn carrier(&self) -> Option<bool> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Carrier(v) = nla {
+                Some(*v != 0)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the minimum MTU the kernel accepts for the link (`IFLA_MIN_MTU`), if present.
+    pub fn min_mtu(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::MinMtu(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the maximum MTU the kernel accepts for the link (`IFLA_MAX_MTU`), if present.
+    pub fn max_mtu(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::MaxMtu(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the GSO, GRO and TSO limits of the link, read from the `IFLA_GSO_*`, `IFLA_GRO_*`
+    /// and `IFLA_TSO_*` attributes
+    pub fn offload_info(&self) -> LinkOffloadInfo {
+        self.nlas.as_slice().into()
+    }
+
+    fn infos(&self) -> impl Iterator<Item = &Info> {
+        self.nlas
+            .iter()
+            .filter_map(|nla| match nla {
+                Nla::Info(infos) => Some(infos),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Returns the kind of the link (`IFLA_INFO_KIND` in `IFLA_LINKINFO`), such as `"bridge"` or
+    /// `"vlan"`. Physical interfaces have none.
+    pub fn kind(&self) -> Option<&str> {
+        self.infos().find_map(|info| {
+            if let Info::Kind(kind) = info {
+                Some(kind.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the kind of the master of the link (`IFLA_INFO_SLAVE_KIND` in `IFLA_LINKINFO`),
+    /// such as `"bond"` for a port of a bond, if the link is enslaved.
+    pub fn slave_kind(&self) -> Option<&str> {
+        self.infos().find_map(|info| {
+            if let Info::SlaveKind(kind) = info {
+                let kind = kind.strip_suffix(&[0]).unwrap_or(kind);
+                std::str::from_utf8(kind).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the endpoints and parameters of the link if it is a VXLAN, GRE, IPIP or SIT
+    /// tunnel, read from the `IFLA_INFO_DATA` attributes of its `IFLA_LINKINFO`
+    pub fn tunnel_info(&self) -> Option<TunnelInfo> {
+        self.infos().find_map(|info| match info {
+            Info::Data(InfoData::Vxlan(nlas)) => Some(TunnelInfo::Vxlan(nlas.as_slice().into())),
+            Info::Data(
+                InfoData::GreTun(nlas)
+                | InfoData::GreTun6(nlas)
+                | InfoData::GreTap(nlas)
+                | InfoData::GreTap6(nlas),
+            ) => Some(TunnelInfo::Gre(nlas.as_slice().into())),
+            Info::Data(InfoData::IpTun(nlas) | InfoData::SitTun(nlas)) => {
+                Some(TunnelInfo::IpTunnel(nlas.as_slice().into()))
+            }
+            _ => None,
+        })
+    }
+}
+
 impl Emitable for LinkMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.nlas.as_slice().buffer_len()
@@ -57,13 +235,20 @@ impl<'a, T: AsRef<[u8]> + 'a> ParseableParametrized<LinkMessageBuffer<&'a T>, u8
 
 #[cfg(test)]
 mod test {
+    use std::net::{IpAddr, Ipv4Addr};
+
     use crate::{
         constants::*,
-        nlas::link::{Nla, State},
-        traits::{Emitable, ParseableParametrized},
+        nlas::link::{Info, InfoKind, Nla, PhysId, Prop, State},
+        traits::{Emitable, Parseable, ParseableParametrized},
+        GreInfo,
+        InterfaceFlags,
         LinkHeader,
         LinkMessage,
         LinkMessageBuffer,
+        LinkOffloadInfo,
+        TunnelInfo,
+        VxlanInfo,
     };
 
     #[rustfmt::skip]
@@ -93,6 +278,314 @@ mod test {
         0x08, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, // Maximum GSO size L=8,T=41,V=65536
     ];
 
+    // Reply to `ip link show veth0`, stripped down to the name and MTU attributes
+    #[rustfmt::skip]
+    static VETH_MTU: [u8; 52] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x05, 0x00, 0x00, 0x00, // interface index = 5
+        0x02, 0x10, 0x00, 0x00, // device flags: BROADCAST, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0a, 0x00, 0x03, 0x00, 0x76, 0x65, 0x74, 0x68, 0x30, 0x00, // device name L=10,T=3,V=veth0
+        0x00, 0x00, // padding
+        0x08, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00, // MTU L=8,T=4,V=1500
+        0x08, 0x00, 0x32, 0x00, 0x44, 0x00, 0x00, 0x00, // minimum MTU L=8,T=50,V=68
+        0x08, 0x00, 0x33, 0x00, 0xff, 0xff, 0x00, 0x00, // maximum MTU L=8,T=51,V=65535
+    ];
+
+    #[test]
+    fn parse_mtu_bounds() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&VETH_MTU)).unwrap();
+        assert_eq!(packet.header.index, 5);
+        assert_eq!(
+            packet.nlas,
+            vec![
+                Nla::IfName("veth0".into()),
+                Nla::Mtu(1500),
+                Nla::MinMtu(68),
+                Nla::MaxMtu(65535),
+            ]
+        );
+        assert_eq!(packet.mtu(), Some(1500));
+        assert_eq!(packet.min_mtu(), Some(68));
+        assert_eq!(packet.max_mtu(), Some(65535));
+
+        let mut buf = vec![0xff; 52];
+        assert_eq!(packet.buffer_len(), 52);
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &VETH_MTU[..]);
+    }
+
+    // Reply to `ip link show eth0` for an interface that is up, stripped down to the name and
+    // link state attributes
+    #[rustfmt::skip]
+    static ETH_UP: [u8; 44] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x02, 0x00, 0x00, 0x00, // interface index = 2
+        0x43, 0x10, 0x01, 0x00, // device flags: UP, BROADCAST, RUNNING, MULTICAST, LOWER_UP
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x30, 0x00, // device name L=9,T=3,V=eth0
+        0x00, 0x00, 0x00, // padding
+        0x05, 0x00, 0x10, 0x00, 0x06, // OperState L=5,T=16,V=6 (up)
+        0x00, 0x00, 0x00, // padding
+        0x05, 0x00, 0x21, 0x00, 0x01, // Carrier L=5,T=33,V=1
+        0x00, 0x00, 0x00, // padding
+    ];
+
+    #[test]
+    fn parse_link_state() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&ETH_UP)).unwrap();
+        assert_eq!(
+            packet.nlas,
+            vec![
+                Nla::IfName("eth0".into()),
+                Nla::OperState(State::Up),
+                Nla::Carrier(1),
+            ]
+        );
+        assert_eq!(packet.oper_state(), Some(State::Up));
+        assert_eq!(packet.carrier(), Some(true));
+        assert_eq!(LinkMessage::default().oper_state(), None);
+    }
+
+    #[test]
+    fn parse_interface_flags() {
+        let cases = [
+            (
+                &HEADER[..],
+                InterfaceFlags::UP | InterfaceFlags::LOOPBACK | InterfaceFlags::RUNNING,
+            ),
+            (
+                &VETH_MTU[..],
+                InterfaceFlags::BROADCAST | InterfaceFlags::MULTICAST,
+            ),
+            (
+                &ETH_UP[..],
+                InterfaceFlags::UP
+                    | InterfaceFlags::BROADCAST
+                    | InterfaceFlags::RUNNING
+                    | InterfaceFlags::MULTICAST
+                    | InterfaceFlags::LOWER_UP,
+            ),
+        ];
+        for (bytes, flags) in cases.iter() {
+            let header = LinkHeader::parse(&LinkMessageBuffer::new(bytes)).unwrap();
+            let packet = LinkMessage {
+                header,
+                nlas: vec![],
+            };
+            assert_eq!(packet.interface_flags(), *flags);
+            assert_eq!(flags.bits(), packet.header.flags);
+        }
+    }
+
+    #[test]
+    fn interface_flags_unknown_bits() {
+        let mut packet = LinkMessage::default();
+        // IFF_NOTRAILERS, which is not part of InterfaceFlags
+        packet.header.flags = IFF_UP | 0x20;
+        assert_eq!(packet.interface_flags(), InterfaceFlags::UP);
+        assert_eq!(LinkMessage::default().interface_flags(), InterfaceFlags::empty());
+    }
+
+    // Reply to `ip link show eth0` for an interface with the `enp3s0` alternative name, stripped
+    // down to the property list attribute
+    #[rustfmt::skip]
+    static ETH_ALT_NAME: [u8; 32] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x02, 0x00, 0x00, 0x00, // interface index = 2
+        0x00, 0x00, 0x00, 0x00, // device flags
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x10, 0x00, 0x34, 0x80, // property list L=16,T=52 (nested)
+            0x0b, 0x00, 0x35, 0x00, 0x65, 0x6e, 0x70, 0x33, 0x73, 0x30, 0x00, // alt name L=11,T=53,V=enp3s0
+            0x00, // padding
+    ];
+
+    #[test]
+    fn parse_alt_names() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&ETH_ALT_NAME)).unwrap();
+        assert_eq!(
+            packet.nlas,
+            vec![Nla::PropList(vec![Prop::AltIfName("enp3s0".into())])]
+        );
+        assert_eq!(packet.alt_names(), vec!["enp3s0".to_owned()]);
+
+        let mut buf = vec![0xff; 32];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &ETH_ALT_NAME[..]);
+        assert!(LinkMessage::default().alt_names().is_empty());
+    }
+
+    #[test]
+    fn long_alt_names() {
+        // Alternative names can be up to 127 bytes long, much longer than IFNAMSIZ
+        let long_name = "a".repeat(127);
+        let packet = LinkMessage {
+            header: LinkHeader::default(),
+            nlas: vec![Nla::PropList(vec![
+                Prop::AltIfName("enp3s0".into()),
+                Prop::AltIfName(long_name.clone()),
+            ])],
+        };
+        let mut buf = vec![0; packet.buffer_len()];
+        packet.emit(&mut buf[..]);
+        let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&buf)).unwrap();
+        assert_eq!(parsed.alt_names(), vec!["enp3s0".to_owned(), long_name]);
+    }
+
+    // Reply to `ip link show eth2` for the representor of the second VF of a switchdev NIC,
+    // stripped down to the physical port attributes
+    #[rustfmt::skip]
+    static REPRESENTOR: [u8; 40] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x07, 0x00, 0x00, 0x00, // interface index = 7
+        0x00, 0x00, 0x00, 0x00, // device flags
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0b, 0x00, 0x26, 0x00, // physical port name L=11,T=38
+            0x70, 0x66, 0x30, 0x76, 0x66, 0x31, 0x00, // V=pf0vf1
+            0x00, // padding
+        0x0c, 0x00, 0x24, 0x00, // physical switch id L=12,T=36
+            0x1c, 0x34, 0xda, 0xff, 0xfe, 0x52, 0x60, 0x5a, // V=1c34dafffe52605a
+    ];
+
+    #[test]
+    fn parse_phys_port() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&REPRESENTOR)).unwrap();
+        let switch_id = vec![0x1c, 0x34, 0xda, 0xff, 0xfe, 0x52, 0x60, 0x5a];
+        assert_eq!(
+            packet.nlas,
+            vec![
+                Nla::PhysPortName("pf0vf1".into()),
+                Nla::PhysSwitchId(switch_id.clone()),
+            ]
+        );
+        assert_eq!(packet.phys_port_name(), Some("pf0vf1"));
+        assert_eq!(packet.phys_switch_id(), Some(PhysId(switch_id)));
+        assert_eq!(packet.phys_switch_id().unwrap().to_string(), "1c34dafffe52605a");
+        assert_eq!(packet.phys_port_id(), None);
+
+        let mut buf = vec![0xff; 40];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &REPRESENTOR[..]);
+
+        let packet = LinkMessage {
+            header: LinkHeader::default(),
+            nlas: vec![Nla::PhysPortId(vec![0x00, 0x0a, 0xf7, 0x01])],
+        };
+        assert_eq!(packet.phys_port_id().unwrap().to_string(), "000af701");
+    }
+
+    // Reply to `ip link show br0`, stripped down to the name and the kind of the link
+    #[rustfmt::skip]
+    static BRIDGE_KIND: [u8; 40] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x03, 0x00, 0x00, 0x00, // interface index = 3
+        0x02, 0x10, 0x00, 0x00, // device flags: BROADCAST, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x08, 0x00, 0x03, 0x00, 0x62, 0x72, 0x30, 0x00, // device name L=8,T=3,V=br0
+        0x10, 0x00, 0x12, 0x00, // link info L=16,T=18
+            0x0b, 0x00, 0x01, 0x00, // info kind L=11,T=1
+                0x62, 0x72, 0x69, 0x64, 0x67, 0x65, 0x00, // V=bridge
+                0x00, // padding
+    ];
+
+    // Reply to `ip link show eth0.100`, stripped down to the name and the kind of the link
+    #[rustfmt::skip]
+    static VLAN_KIND: [u8; 48] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x04, 0x00, 0x00, 0x00, // interface index = 4
+        0x02, 0x10, 0x00, 0x00, // device flags: BROADCAST, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0d, 0x00, 0x03, 0x00, // device name L=13,T=3
+            0x65, 0x74, 0x68, 0x30, 0x2e, 0x31, 0x30, 0x30, 0x00, // V=eth0.100
+            0x00, 0x00, 0x00, // padding
+        0x10, 0x00, 0x12, 0x00, // link info L=16,T=18
+            0x09, 0x00, 0x01, 0x00, // info kind L=9,T=1
+                0x76, 0x6c, 0x61, 0x6e, 0x00, // V=vlan
+                0x00, 0x00, 0x00, // padding
+    ];
+
+    // Reply to `ip link show eth1` for a physical interface enslaved to a bond, stripped down to
+    // the name and the kind of its master
+    #[rustfmt::skip]
+    static BOND_PORT_KIND: [u8; 44] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x02, 0x00, 0x00, 0x00, // interface index = 2
+        0x03, 0x18, 0x00, 0x00, // device flags: UP, BROADCAST, SLAVE, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x09, 0x00, 0x03, 0x00, 0x65, 0x74, 0x68, 0x31, 0x00, // device name L=9,T=3,V=eth1
+        0x00, 0x00, 0x00, // padding
+        0x10, 0x00, 0x12, 0x00, // link info L=16,T=18
+            0x09, 0x00, 0x04, 0x00, // info slave kind L=9,T=4
+                0x62, 0x6f, 0x6e, 0x64, 0x00, // V=bond
+                0x00, 0x00, 0x00, // padding
+    ];
+
+    #[test]
+    fn parse_link_kind() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&BRIDGE_KIND)).unwrap();
+        assert_eq!(
+            packet.nlas[1],
+            Nla::Info(vec![Info::Kind(InfoKind::Bridge)])
+        );
+        assert_eq!(packet.kind(), Some("bridge"));
+        assert_eq!(packet.slave_kind(), None);
+        let mut buf = vec![0xff; 40];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BRIDGE_KIND[..]);
+
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&VLAN_KIND)).unwrap();
+        assert_eq!(packet.kind(), Some("vlan"));
+        assert_eq!(packet.slave_kind(), None);
+        let mut buf = vec![0xff; 48];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &VLAN_KIND[..]);
+
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&BOND_PORT_KIND)).unwrap();
+        assert_eq!(
+            packet.nlas[1],
+            Nla::Info(vec![Info::SlaveKind(b"bond\0".to_vec())])
+        );
+        assert_eq!(packet.kind(), None);
+        assert_eq!(packet.slave_kind(), Some("bond"));
+        let mut buf = vec![0xff; 44];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BOND_PORT_KIND[..]);
+
+        let packet = LinkMessage {
+            header: LinkHeader::default(),
+            nlas: vec![Nla::Info(vec![Info::Kind(InfoKind::Other("team".into()))])],
+        };
+        assert_eq!(packet.kind(), Some("team"));
+    }
+
     #[test]
     fn packet_header_read() {
         let packet = LinkMessageBuffer::new(&HEADER[0..16]);
@@ -227,4 +720,185 @@ mod test {
         assert_eq!(packet.buffer_len(), 96);
         packet.emit(&mut buf[..]);
     }
+
+    // Reply to `ip link show vx194` for a link created with `ip link add vx194 type vxlan id 194
+    // local 192.0.2.1 remote 198.51.100.1 dstport 4789 ttl 64`, stripped down to the name and the
+    // endpoint attributes of IFLA_INFO_DATA
+    #[rustfmt::skip]
+    static VXLAN: [u8; 96] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x05, 0x00, 0x00, 0x00, // interface index = 5
+        0x02, 0x10, 0x00, 0x00, // device flags: BROADCAST, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0a, 0x00, 0x03, 0x00, 0x76, 0x78, 0x31, 0x39, 0x34, 0x00, // device name L=10,T=3,V=vx194
+        0x00, 0x00, // padding
+        0x44, 0x00, 0x12, 0x00, // link info L=68,T=18
+            0x0a, 0x00, 0x01, 0x00, 0x76, 0x78, 0x6c, 0x61, 0x6e, 0x00, // kind L=10,T=1,V=vxlan
+            0x00, 0x00, // padding
+            0x34, 0x00, 0x02, 0x00, // data L=52,T=2
+                0x08, 0x00, 0x01, 0x00, 0xc2, 0x00, 0x00, 0x00, // IFLA_VXLAN_ID L=8,T=1,V=194
+                0x08, 0x00, 0x02, 0x00, 0xc6, 0x33, 0x64, 0x01, // GROUP L=8,T=2,V=198.51.100.1
+                0x08, 0x00, 0x04, 0x00, 0xc0, 0x00, 0x02, 0x01, // LOCAL L=8,T=4,V=192.0.2.1
+                0x05, 0x00, 0x05, 0x00, 0x40, // IFLA_VXLAN_TTL L=5,T=5,V=64
+                0x00, 0x00, 0x00, // padding
+                0x05, 0x00, 0x06, 0x00, 0x00, // IFLA_VXLAN_TOS L=5,T=6,V=0
+                0x00, 0x00, 0x00, // padding
+                0x06, 0x00, 0x0f, 0x00, 0x12, 0xb5, // IFLA_VXLAN_PORT L=6,T=15,V=4789
+                0x00, 0x00, // padding
+    ];
+
+    #[test]
+    fn parse_vxlan_tunnel_info() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&VXLAN)).unwrap();
+        let info = packet.tunnel_info().unwrap();
+        assert_eq!(
+            info,
+            TunnelInfo::Vxlan(VxlanInfo {
+                id: Some(194),
+                local: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                remote: Some(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1))),
+                port: Some(4789),
+                link: None,
+                ttl: Some(64),
+                tos: Some(0),
+            })
+        );
+        assert_eq!(info.local(), Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))));
+        assert_eq!(
+            info.remote(),
+            Some(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 1)))
+        );
+
+        let mut buf = vec![0xff; 96];
+        assert_eq!(packet.buffer_len(), 96);
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &VXLAN[..]);
+    }
+
+    // Synthetic message, not a capture: what `ip link show gre194` should return for a link
+    // created with `ip link add gre194 type gre local 192.0.2.1 remote 198.51.100.2 ttl 32 key
+    // 1234`, built by hand in the order of the kernel's `ipgre_fill_info` and stripped down to the
+    // name and the first attributes of IFLA_INFO_DATA. The keys and the flags are big endian.
+    #[rustfmt::skip]
+    static GRE: [u8; 124] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x0a, 0x03, // link layer type 778 = IP over GRE
+        0x06, 0x00, 0x00, 0x00, // interface index = 6
+        0x90, 0x00, 0x00, 0x00, // device flags: POINTOPOINT, NOARP
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0b, 0x00, 0x03, 0x00, 0x67, 0x72, 0x65, 0x31, 0x39, 0x34, 0x00, // name L=11,T=3,V=gre194
+        0x00, // padding
+        0x60, 0x00, 0x12, 0x00, // link info L=96,T=18
+            0x08, 0x00, 0x01, 0x00, 0x67, 0x72, 0x65, 0x00, // kind L=8,T=1,V=gre
+            0x54, 0x00, 0x02, 0x00, // data L=84,T=2
+                0x08, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, // IFLA_GRE_LINK L=8,T=1,V=0
+                0x06, 0x00, 0x02, 0x00, 0x20, 0x00, // IFLA_GRE_IFLAGS L=6,T=2,V=GRE_KEY
+                0x00, 0x00, // padding
+                0x06, 0x00, 0x03, 0x00, 0x20, 0x00, // IFLA_GRE_OFLAGS L=6,T=3,V=GRE_KEY
+                0x00, 0x00, // padding
+                0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0xd2, // IFLA_GRE_IKEY L=8,T=4,V=1234
+                0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0xd2, // IFLA_GRE_OKEY L=8,T=5,V=1234
+                0x08, 0x00, 0x06, 0x00, 0xc0, 0x00, 0x02, 0x01, // LOCAL L=8,T=6,V=192.0.2.1
+                0x08, 0x00, 0x07, 0x00, 0xc6, 0x33, 0x64, 0x02, // REMOTE L=8,T=7,V=198.51.100.2
+                0x05, 0x00, 0x08, 0x00, 0x20, // IFLA_GRE_TTL L=5,T=8,V=32
+                0x00, 0x00, 0x00, // padding
+                0x05, 0x00, 0x09, 0x00, 0x00, // IFLA_GRE_TOS L=5,T=9,V=0
+                0x00, 0x00, 0x00, // padding
+                0x05, 0x00, 0x0a, 0x00, 0x01, // IFLA_GRE_PMTUDISC L=5,T=10,V=1
+                0x00, 0x00, 0x00, // padding
+    ];
+
+    #[test]
+    fn parse_gre_tunnel_info() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&GRE)).unwrap();
+        let info = packet.tunnel_info().unwrap();
+        assert_eq!(
+            info,
+            TunnelInfo::Gre(GreInfo {
+                local: Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                remote: Some(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2))),
+                link: None,
+                ttl: Some(32),
+                tos: Some(0),
+                ikey: Some(1234),
+                okey: Some(1234),
+            })
+        );
+        assert_eq!(
+            info.remote(),
+            Some(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 2)))
+        );
+
+        let mut buf = vec![0xff; 124];
+        assert_eq!(packet.buffer_len(), 124);
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &GRE[..]);
+    }
+
+    // Reply to `ip link show vx200` for a link created with `ip link add vx200 type vxlan id 200
+    // dstport 4789`, stripped down to the name and the attributes between IFLA_NUM_TX_QUEUES and
+    // IFLA_NUM_RX_QUEUES. This kernel also reports IFLA_GSO_IPV4_MAX_SIZE and
+    // IFLA_GRO_IPV4_MAX_SIZE, which are not parsed yet.
+    #[rustfmt::skip]
+    static OFFLOAD: [u8; 100] = [
+        0x00, // interface family
+        0x00, // reserved
+        0x01, 0x00, // link layer type 1 = ether
+        0x0f, 0x00, 0x00, 0x00, // interface index = 15
+        0x02, 0x10, 0x00, 0x00, // device flags: BROADCAST, MULTICAST
+        0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+
+        // nlas
+        0x0a, 0x00, 0x03, 0x00, 0x76, 0x78, 0x32, 0x30, 0x30, 0x00, // device name L=10,T=3,V=vx200
+        0x00, 0x00, // padding
+        0x08, 0x00, 0x1f, 0x00, 0x01, 0x00, 0x00, 0x00, // IFLA_NUM_TX_QUEUES L=8,T=31,V=1
+        0x08, 0x00, 0x28, 0x00, 0xff, 0xff, 0x00, 0x00, // IFLA_GSO_MAX_SEGS L=8,T=40,V=65535
+        0x08, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, // IFLA_GSO_MAX_SIZE L=8,T=41,V=65536
+        0x08, 0x00, 0x3a, 0x00, 0x00, 0x00, 0x01, 0x00, // IFLA_GRO_MAX_SIZE L=8,T=58,V=65536
+        0x08, 0x00, 0x3f, 0x00, 0x00, 0x00, 0x01, 0x00, // IFLA_GSO_IPV4_MAX_SIZE L=8,T=63,V=65536
+        0x08, 0x00, 0x40, 0x00, 0x00, 0x00, 0x01, 0x00, // IFLA_GRO_IPV4_MAX_SIZE L=8,T=64,V=65536
+        0x08, 0x00, 0x3b, 0x00, 0x00, 0x00, 0x01, 0x00, // IFLA_TSO_MAX_SIZE L=8,T=59,V=65536
+        0x08, 0x00, 0x3c, 0x00, 0xff, 0xff, 0x00, 0x00, // IFLA_TSO_MAX_SEGS L=8,T=60,V=65535
+        0x08, 0x00, 0x20, 0x00, 0x01, 0x00, 0x00, 0x00, // IFLA_NUM_RX_QUEUES L=8,T=32,V=1
+    ];
+
+    #[test]
+    fn parse_offload_info() {
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&OFFLOAD)).unwrap();
+        assert_eq!(
+            packet.offload_info(),
+            LinkOffloadInfo {
+                gso_max_size: Some(65536),
+                gso_max_segs: Some(65535),
+                gro_max_size: Some(65536),
+                tso_max_size: Some(65536),
+                tso_max_segs: Some(65535),
+            }
+        );
+        assert_eq!(packet.nlas[1], Nla::NumTxQueues(1));
+        assert_eq!(packet.nlas[9], Nla::NumRxQueues(1));
+
+        let mut buf = vec![0xff; 100];
+        assert_eq!(packet.buffer_len(), 100);
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &OFFLOAD[..]);
+
+        // The kernel this message comes from predates the GRO and TSO limits
+        let packet = LinkMessage::parse(&LinkMessageBuffer::new(&HEADER)).unwrap();
+        assert_eq!(
+            packet.offload_info(),
+            LinkOffloadInfo {
+                gso_max_size: Some(65536),
+                gso_max_segs: Some(65535),
+                ..Default::default()
+            }
+        );
+    }
 }
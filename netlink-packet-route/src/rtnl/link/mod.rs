@@ -4,5 +4,7 @@ mod buffer;
 mod header;
 mod message;
 pub mod nlas;
+mod offload;
+mod tunnel;
 
-pub use self::{buffer::*, header::*, message::*};
+pub use self::{buffer::*, header::*, message::*, offload::*, tunnel::*};
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_u16,
+    traits::Parseable,
+    DecodeError,
+};
+
+pub const BRIDGE_VLAN_INFO_LEN: usize = 4;
+
+/// The VLAN membership of a bridge port (`struct bridge_vlan_info`).
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub struct BridgeVlanInfo {
+    /// A combination of the `BRIDGE_VLAN_INFO_*` flags
+    pub flags: u16,
+    pub vid: u16,
+}
+
+impl BridgeVlanInfo {
+    fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() != BRIDGE_VLAN_INFO_LEN {
+            return Err(format!(
+                "invalid bridge_vlan_info: length {} != {}",
+                payload.len(),
+                BRIDGE_VLAN_INFO_LEN
+            )
+            .into());
+        }
+        Ok(BridgeVlanInfo {
+            flags: NativeEndian::read_u16(&payload[0..2]),
+            vid: NativeEndian::read_u16(&payload[2..4]),
+        })
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        NativeEndian::write_u16(&mut buffer[0..2], self.flags);
+        NativeEndian::write_u16(&mut buffer[2..4], self.vid);
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum AfSpecBridge {
+    /// `BRIDGE_FLAGS_MASTER` and/or `BRIDGE_FLAGS_SELF`: whether the request targets the bridge
+    /// the port belongs to, or the device itself.
+    Flags(u16),
+    Mode(u16),
+    VlanInfo(BridgeVlanInfo),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for AfSpecBridge {
+    fn value_len(&self) -> usize {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(_) | Mode(_) => 2,
+            VlanInfo(_) => BRIDGE_VLAN_INFO_LEN,
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(value) | Mode(value) => NativeEndian::write_u16(buffer, value),
+            VlanInfo(ref info) => info.emit(buffer),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::AfSpecBridge::*;
+        match *self {
+            Flags(_) => IFLA_BRIDGE_FLAGS,
+            Mode(_) => IFLA_BRIDGE_MODE,
+            VlanInfo(_) => IFLA_BRIDGE_VLAN_INFO,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecBridge {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::AfSpecBridge::*;
+
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BRIDGE_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_BRIDGE_FLAGS value")?)
+            }
+            IFLA_BRIDGE_MODE => Mode(parse_u16(payload).context("invalid IFLA_BRIDGE_MODE value")?),
+            IFLA_BRIDGE_VLAN_INFO => VlanInfo(
+                BridgeVlanInfo::parse(payload).context("invalid IFLA_BRIDGE_VLAN_INFO value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT
+
+use crate::constants::*;
+
+bitflags! {
+    /// Extra information to include in the link messages dumped by the kernel, sent as an
+    /// `IFLA_EXT_MASK` attribute ([`Nla::ExtMask`](super::Nla::ExtMask)) in `RTM_GETLINK`
+    /// requests
+    pub struct ExtFilterMask: u32 {
+        /// The virtual functions of SR-IOV devices (`IFLA_NUM_VF` and `IFLA_VFINFO_LIST`)
+        const VF = RTEXT_FILTER_VF;
+        /// The VLANs of bridges and bridge ports, in `AF_BRIDGE` dumps
+        const BRVLAN = RTEXT_FILTER_BRVLAN;
+        /// Same as `BRVLAN`, with the consecutive VLANs reported as ranges
+        const BRVLAN_COMPRESSED = RTEXT_FILTER_BRVLAN_COMPRESSED;
+        /// Leave the IPv6 statistics of the links and the statistics of the virtual functions
+        /// out, to make large dumps faster. The `IFLA_STATS` and `IFLA_STATS64` counters are
+        /// still reported.
+        const SKIP_STATS = RTEXT_FILTER_SKIP_STATS;
+        /// The Media Redundancy Protocol state of bridges, in `AF_BRIDGE` dumps
+        const MRP = RTEXT_FILTER_MRP;
+        /// The Connectivity Fault Management configuration of bridges, in `AF_BRIDGE` dumps
+        const CFM_CONFIG = RTEXT_FILTER_CFM_CONFIG;
+        /// The Connectivity Fault Management status of bridges, in `AF_BRIDGE` dumps
+        const CFM_STATUS = RTEXT_FILTER_CFM_STATUS;
+        /// The multiple spanning tree states of bridge ports, in `AF_BRIDGE` dumps
+        const MST = RTEXT_FILTER_MST;
+    }
+}
+
+impl Default for ExtFilterMask {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
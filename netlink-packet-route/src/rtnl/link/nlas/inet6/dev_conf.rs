@@ -5,6 +5,8 @@ use crate::{
     DecodeError,
 };
 
+/// Length of the `IFLA_INET6_CONF` array this crate knows about. Newer kernels send longer
+/// arrays, older ones shorter arrays.
 pub const LINK_INET6_DEV_CONF_LEN: usize = 204;
 buffer!(Inet6DevConfBuffer(LINK_INET6_DEV_CONF_LEN) {
     forwarding: (i32, 0..4),
@@ -114,16 +116,33 @@ impl<T: AsRef<[u8]>> Parseable<Inet6DevConfBuffer<T>> for Inet6DevConf {
             disable_policy: buf.disable_policy(),
             accept_ra_rt_info_min_plen: buf.accept_ra_rt_info_min_plen(),
             ndisc_tclass: buf.ndisc_tclass(),
+            extra: Vec::new(),
         })
     }
 }
 
+impl Inet6DevConf {
+    /// Parse an `IFLA_INET6_CONF` array of any length. The settings missing from a short array
+    /// are left to zero, and the ones following `ndisc_tclass` in a longer array are kept in
+    /// `extra`.
+    pub fn parse_array(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut bytes = [0; LINK_INET6_DEV_CONF_LEN];
+        let len = payload.len().min(LINK_INET6_DEV_CONF_LEN);
+        bytes[..len].copy_from_slice(&payload[..len]);
+        let mut dev_conf = Self::parse(&Inet6DevConfBuffer::new(&bytes[..]))?;
+        dev_conf.extra = payload[len..].to_vec();
+        Ok(dev_conf)
+    }
+}
+
 impl Emitable for Inet6DevConf {
     fn buffer_len(&self) -> usize {
-        LINK_INET6_DEV_CONF_LEN
+        LINK_INET6_DEV_CONF_LEN + self.extra.len()
     }
 
     fn emit(&self, buffer: &mut [u8]) {
+        let (buffer, extra) = buffer.split_at_mut(LINK_INET6_DEV_CONF_LEN);
+        extra.copy_from_slice(&self.extra);
         let mut buffer = Inet6DevConfBuffer::new(buffer);
         buffer.set_forwarding(self.forwarding);
         buffer.set_hoplimit(self.hoplimit);
@@ -179,7 +198,10 @@ impl Emitable for Inet6DevConf {
     }
 }
 
-#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+/// Per-interface IPv6 settings (`/proc/sys/net/ipv6/conf/<interface>/`). In netlink messages,
+/// they are a flat array of `i32` indexed by the `DEVCONF_*` constants, and the fields of this
+/// struct follow the same order.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
 pub struct Inet6DevConf {
     pub forwarding: i32,
     pub hoplimit: i32,
@@ -232,4 +254,7 @@ pub struct Inet6DevConf {
     pub disable_policy: i32,
     pub accept_ra_rt_info_min_plen: i32,
     pub ndisc_tclass: i32,
+    /// Raw settings following `ndisc_tclass`, sent by kernels newer than this crate. They are
+    /// emitted back as is.
+    pub extra: Vec<u8>,
 }
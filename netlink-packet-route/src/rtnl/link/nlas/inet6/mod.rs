@@ -7,7 +7,7 @@ use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer},
     parsers::{parse_ipv6, parse_u32, parse_u8},
-    traits::Parseable,
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -22,9 +22,11 @@ pub use self::stats::*;
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum Inet6 {
+    /// Combination of the `IF_RS_SENT`, `IF_RA_RCVD`, `IF_RA_MANAGED`, `IF_RA_OTHERCONF` and
+    /// `IF_READY` flags
     Flags(u32),
     CacheInfo(Vec<u8>),
-    DevConf(Vec<u8>),
+    DevConf(Inet6DevConf),
     Unspec(Vec<u8>),
     Stats(Vec<u8>),
     IcmpStats(Vec<u8>),
@@ -39,7 +41,7 @@ impl Nla for Inet6 {
         match *self {
             Unspec(ref bytes) => bytes.len(),
             CacheInfo(ref cache_info) => cache_info.len(),
-            DevConf(ref dev_conf) => dev_conf.len(),
+            DevConf(ref dev_conf) => dev_conf.buffer_len(),
             Stats(ref stats) => stats.len(),
             IcmpStats(ref icmp_stats) => icmp_stats.len(),
             Flags(_) => 4,
@@ -55,7 +57,7 @@ impl Nla for Inet6 {
             Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
             Flags(ref value) => NativeEndian::write_u32(buffer, *value),
             CacheInfo(ref cache_info) => buffer.copy_from_slice(cache_info.as_slice()),
-            DevConf(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            DevConf(ref dev_conf) => dev_conf.emit(buffer),
             Stats(ref inet6_stats) => buffer.copy_from_slice(inet6_stats.as_slice()),
             IcmpStats(ref icmp6_stats) => buffer.copy_from_slice(icmp6_stats.as_slice()),
             Token(ref ipv6) => buffer.copy_from_slice(&ipv6[..]),
@@ -90,7 +92,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Inet6 {
                 Flags(parse_u32(payload).context("invalid IFLA_INET6_FLAGS value")?)
             }
             IFLA_INET6_CACHEINFO => CacheInfo(payload.to_vec()),
-            IFLA_INET6_CONF => DevConf(payload.to_vec()),
+            IFLA_INET6_CONF => DevConf(
+                Inet6DevConf::parse_array(payload).context("invalid IFLA_INET6_CONF value")?,
+            ),
             IFLA_INET6_STATS => Stats(payload.to_vec()),
             IFLA_INET6_ICMP6STATS => IcmpStats(payload.to_vec()),
             IFLA_INET6_TOKEN => {
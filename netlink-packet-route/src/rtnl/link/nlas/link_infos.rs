@@ -3,7 +3,17 @@
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
-    parsers::{parse_mac, parse_string, parse_u16, parse_u16_be, parse_u32, parse_u64, parse_u8},
+    parsers::{
+        parse_i32,
+        parse_mac,
+        parse_string,
+        parse_u16,
+        parse_u16_be,
+        parse_u32,
+        parse_u32_be,
+        parse_u64,
+        parse_u8,
+    },
     traits::{Emitable, Parseable},
     DecodeError,
     LinkMessage,
@@ -11,6 +21,7 @@ use crate::{
 };
 use anyhow::Context;
 use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use std::{net::Ipv4Addr, os::unix::io::RawFd};
 
 const DUMMY: &str = "dummy";
 const IFB: &str = "ifb";
@@ -32,6 +43,8 @@ const GRE: &str = "gre";
 const IP6GRE: &str = "ip6gre";
 const VTI: &str = "vti";
 const VRF: &str = "vrf";
+const MACSEC: &str = "macsec";
+const BAREUDP: &str = "bareudp";
 const GTP: &str = "gtp";
 const IPOIB: &str = "ipoib";
 const WIREGUARD: &str = "wireguard";
@@ -43,7 +56,7 @@ pub enum Info {
     Kind(InfoKind),
     Data(InfoData),
     SlaveKind(Vec<u8>),
-    SlaveData(Vec<u8>),
+    SlaveData(InfoSlaveData),
 }
 
 impl Nla for Info {
@@ -54,10 +67,10 @@ impl Nla for Info {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
                 | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => bytes.len(),
             Kind(ref nla) => nla.value_len(),
             Data(ref nla) => nla.value_len(),
+            SlaveData(ref nla) => nla.value_len(),
         }
     }
 
@@ -68,10 +81,10 @@ impl Nla for Info {
             Unspec(ref bytes)
                 | Xstats(ref bytes)
                 | SlaveKind(ref bytes)
-                | SlaveData(ref bytes)
                 => buffer.copy_from_slice(bytes),
             Kind(ref nla) => nla.emit_value(buffer),
             Data(ref nla) => nla.emit_value(buffer),
+            SlaveData(ref nla) => nla.emit_value(buffer),
         }
     }
 
@@ -81,7 +94,7 @@ impl Nla for Info {
             Unspec(_) => IFLA_INFO_UNSPEC,
             Xstats(_) => IFLA_INFO_XSTATS,
             SlaveKind(_) => IFLA_INFO_SLAVE_KIND,
-            SlaveData(_) => IFLA_INFO_DATA,
+            SlaveData(_) => IFLA_INFO_SLAVE_DATA,
             Kind(_) => IFLA_INFO_KIND,
             Data(_) => IFLA_INFO_DATA,
         }
@@ -105,13 +118,40 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
         let mut res = Vec::new();
         let nlas = NlasIterator::new(buf.into_inner());
         let mut link_info_kind: Option<InfoKind> = None;
+        let mut link_info_slave_kind: Option<Vec<u8>> = None;
         for nla in nlas {
             let nla = nla?;
             match nla.kind() {
                 IFLA_INFO_UNSPEC => res.push(Info::Unspec(nla.value().to_vec())),
                 IFLA_INFO_XSTATS => res.push(Info::Xstats(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_KIND => res.push(Info::SlaveKind(nla.value().to_vec())),
-                IFLA_INFO_SLAVE_DATA => res.push(Info::SlaveData(nla.value().to_vec())),
+                IFLA_INFO_SLAVE_KIND => {
+                    let slave_kind = nla.value().to_vec();
+                    res.push(Info::SlaveKind(slave_kind.clone()));
+                    link_info_slave_kind = Some(slave_kind);
+                }
+                IFLA_INFO_SLAVE_DATA => {
+                    let payload = nla.value();
+                    let slave_kind = link_info_slave_kind
+                        .take()
+                        .map(|kind| parse_string(&kind))
+                        .transpose()
+                        .context("invalid IFLA_INFO_SLAVE_KIND value")?;
+                    let slave_data = match slave_kind.as_deref() {
+                        Some(BOND) => {
+                            let mut v = Vec::new();
+                            let err = "failed to parse IFLA_INFO_SLAVE_DATA \
+                                (IFLA_INFO_SLAVE_KIND is 'bond')";
+                            for nla in NlasIterator::new(payload) {
+                                let nla = &nla.context(err)?;
+                                let parsed = InfoBondPort::parse(nla).context(err)?;
+                                v.push(parsed);
+                            }
+                            InfoSlaveData::Bond(v)
+                        }
+                        _ => InfoSlaveData::Other(payload.to_vec()),
+                    };
+                    res.push(Info::SlaveData(slave_data));
+                }
                 IFLA_INFO_KIND => {
                     let parsed = InfoKind::parse(&nla)?;
                     res.push(Info::Kind(parsed.clone()));
@@ -165,7 +205,17 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::Vxlan(v)
                             }
-                            InfoKind::Bond => InfoData::Bond(payload.to_vec()),
+                            InfoKind::Bond => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'bond')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBond::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Bond(v)
+                            }
                             InfoKind::IpVlan => {
                                 let mut v = Vec::new();
                                 let err =
@@ -199,12 +249,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::MacVtap(v)
                             }
-                            InfoKind::GreTap => InfoData::GreTap(payload.to_vec()),
-                            InfoKind::GreTap6 => InfoData::GreTap6(payload.to_vec()),
-                            InfoKind::IpTun => InfoData::IpTun(payload.to_vec()),
-                            InfoKind::SitTun => InfoData::SitTun(payload.to_vec()),
-                            InfoKind::GreTun => InfoData::GreTun(payload.to_vec()),
-                            InfoKind::GreTun6 => InfoData::GreTun6(payload.to_vec()),
+                            InfoKind::GreTap => InfoData::GreTap(parse_gre_nlas(payload)?),
+                            InfoKind::GreTap6 => InfoData::GreTap6(parse_gre_nlas(payload)?),
+                            InfoKind::IpTun => InfoData::IpTun(parse_iptun_nlas(payload)?),
+                            InfoKind::SitTun => InfoData::SitTun(parse_iptun_nlas(payload)?),
+                            InfoKind::GreTun => InfoData::GreTun(parse_gre_nlas(payload)?),
+                            InfoKind::GreTun6 => InfoData::GreTun6(parse_gre_nlas(payload)?),
                             InfoKind::Vti => InfoData::Vti(payload.to_vec()),
                             InfoKind::Vrf => {
                                 let mut v = Vec::new();
@@ -217,7 +267,39 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::Vrf(v)
                             }
-                            InfoKind::Gtp => InfoData::Gtp(payload.to_vec()),
+                            InfoKind::MacSec => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'macsec')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoMacSec::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::MacSec(v)
+                            }
+                            InfoKind::BareUdp => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'bareudp')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoBareUdp::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::BareUdp(v)
+                            }
+                            InfoKind::Gtp => {
+                                let mut v = Vec::new();
+                                let err =
+                                    "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'gtp')";
+                                for nla in NlasIterator::new(payload) {
+                                    let nla = &nla.context(err)?;
+                                    let parsed = InfoGtp::parse(nla).context(err)?;
+                                    v.push(parsed);
+                                }
+                                InfoData::Gtp(v)
+                            }
                             InfoKind::Ipoib => {
                                 let mut v = Vec::new();
                                 let err =
@@ -255,19 +337,21 @@ pub enum InfoData {
     Ifb(Vec<u8>),
     Veth(VethInfo),
     Vxlan(Vec<InfoVxlan>),
-    Bond(Vec<u8>),
+    Bond(Vec<InfoBond>),
     IpVlan(Vec<InfoIpVlan>),
     MacVlan(Vec<InfoMacVlan>),
     MacVtap(Vec<InfoMacVtap>),
-    GreTap(Vec<u8>),
-    GreTap6(Vec<u8>),
-    IpTun(Vec<u8>),
-    SitTun(Vec<u8>),
-    GreTun(Vec<u8>),
-    GreTun6(Vec<u8>),
+    GreTap(Vec<InfoGre>),
+    GreTap6(Vec<InfoGre>),
+    IpTun(Vec<InfoIpTun>),
+    SitTun(Vec<InfoIpTun>),
+    GreTun(Vec<InfoGre>),
+    GreTun6(Vec<InfoGre>),
     Vti(Vec<u8>),
     Vrf(Vec<InfoVrf>),
-    Gtp(Vec<u8>),
+    MacSec(Vec<InfoMacSec>),
+    BareUdp(Vec<InfoBareUdp>),
+    Gtp(Vec<InfoGtp>),
     Ipoib(Vec<InfoIpoib>),
     Wireguard(Vec<u8>),
     Other(Vec<u8>),
@@ -280,26 +364,30 @@ impl Nla for InfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().buffer_len(),
             Vlan(ref nlas) =>  nlas.as_slice().buffer_len(),
+            Bond(ref nlas) => nlas.as_slice().buffer_len(),
             Veth(ref msg) => msg.buffer_len(),
             IpVlan(ref nlas) => nlas.as_slice().buffer_len(),
             Ipoib(ref nlas) => nlas.as_slice().buffer_len(),
             MacVlan(ref nlas) => nlas.as_slice().buffer_len(),
             MacVtap(ref nlas) => nlas.as_slice().buffer_len(),
             Vrf(ref nlas) => nlas.as_slice().buffer_len(),
+            MacSec(ref nlas) => nlas.as_slice().buffer_len(),
+            BareUdp(ref nlas) => nlas.as_slice().buffer_len(),
+            Gtp(ref nlas) => nlas.as_slice().buffer_len(),
             Vxlan(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                => nlas.as_slice().buffer_len(),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | Bond(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
                 | Vti(ref bytes)
-                | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
                 => bytes.len(),
@@ -312,26 +400,30 @@ impl Nla for InfoData {
         match self {
             Bridge(ref nlas) => nlas.as_slice().emit(buffer),
             Vlan(ref nlas) => nlas.as_slice().emit(buffer),
+            Bond(ref nlas) => nlas.as_slice().emit(buffer),
             Veth(ref msg) => msg.emit(buffer),
             IpVlan(ref nlas) => nlas.as_slice().emit(buffer),
             Ipoib(ref nlas) => nlas.as_slice().emit(buffer),
             MacVlan(ref nlas) => nlas.as_slice().emit(buffer),
             MacVtap(ref nlas) => nlas.as_slice().emit(buffer),
             Vrf(ref nlas) => nlas.as_slice().emit(buffer),
+            MacSec(ref nlas) => nlas.as_slice().emit(buffer),
+            BareUdp(ref nlas) => nlas.as_slice().emit(buffer),
+            Gtp(ref nlas) => nlas.as_slice().emit(buffer),
             Vxlan(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                => nlas.as_slice().emit(buffer),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | Bond(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
                 | Vti(ref bytes)
-                | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
                 => buffer.copy_from_slice(bytes),
@@ -343,6 +435,36 @@ impl Nla for InfoData {
     }
 }
 
+/// Content of `IFLA_INFO_SLAVE_DATA`, which depends on the kind of the master
+/// (`IFLA_INFO_SLAVE_KIND`) of the link.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoSlaveData {
+    Bond(Vec<InfoBondPort>),
+    Other(Vec<u8>),
+}
+
+impl Nla for InfoSlaveData {
+    fn value_len(&self) -> usize {
+        use self::InfoSlaveData::*;
+        match self {
+            Bond(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref bytes) => bytes.len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoSlaveData::*;
+        match self {
+            Bond(ref nlas) => nlas.as_slice().emit(buffer),
+            Other(ref bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        IFLA_INFO_SLAVE_DATA
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoKind {
     Dummy,
@@ -365,47 +487,19 @@ pub enum InfoKind {
     GreTun6,
     Vti,
     Vrf,
+    MacSec,
+    BareUdp,
     Gtp,
     Ipoib,
     Wireguard,
     Other(String),
 }
 
-impl Nla for InfoKind {
-    fn value_len(&self) -> usize {
-        use self::InfoKind::*;
-        let len = match *self {
-            Dummy => DUMMY.len(),
-            Ifb => IFB.len(),
-            Bridge => BRIDGE.len(),
-            Tun => TUN.len(),
-            Nlmon => NLMON.len(),
-            Vlan => VLAN.len(),
-            Veth => VETH.len(),
-            Vxlan => VXLAN.len(),
-            Bond => BOND.len(),
-            IpVlan => IPVLAN.len(),
-            MacVlan => MACVLAN.len(),
-            MacVtap => MACVTAP.len(),
-            GreTap => GRETAP.len(),
-            GreTap6 => IP6GRETAP.len(),
-            IpTun => IPIP.len(),
-            SitTun => SIT.len(),
-            GreTun => GRE.len(),
-            GreTun6 => IP6GRE.len(),
-            Vti => VTI.len(),
-            Vrf => VRF.len(),
-            Gtp => GTP.len(),
-            Ipoib => IPOIB.len(),
-            Wireguard => WIREGUARD.len(),
-            Other(ref s) => s.len(),
-        };
-        len + 1
-    }
-
-    fn emit_value(&self, buffer: &mut [u8]) {
+impl InfoKind {
+    /// Returns the kind as it appears in the `IFLA_INFO_KIND` attribute, such as `"bridge"`
+    pub fn as_str(&self) -> &str {
         use self::InfoKind::*;
-        let s = match *self {
+        match *self {
             Dummy => DUMMY,
             Ifb => IFB,
             Bridge => BRIDGE,
@@ -426,11 +520,23 @@ impl Nla for InfoKind {
             GreTun6 => IP6GRE,
             Vti => VTI,
             Vrf => VRF,
+            MacSec => MACSEC,
+            BareUdp => BAREUDP,
             Gtp => GTP,
             Ipoib => IPOIB,
             Wireguard => WIREGUARD,
             Other(ref s) => s.as_str(),
-        };
+        }
+    }
+}
+
+impl Nla for InfoKind {
+    fn value_len(&self) -> usize {
+        self.as_str().len() + 1
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        let s = self.as_str();
         buffer[..s.len()].copy_from_slice(s.as_bytes());
         buffer[s.len()] = 0;
     }
@@ -470,6 +576,8 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             IP6GRE => GreTun6,
             VTI => Vti,
             VRF => Vrf,
+            MACSEC => MacSec,
+            BAREUDP => BareUdp,
             GTP => Gtp,
             IPOIB => Ipoib,
             WIREGUARD => Wireguard,
@@ -714,217 +822,979 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVxlan {
     }
 }
 
-// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
+fn parse_gre_nlas(payload: &[u8]) -> Result<Vec<InfoGre>, DecodeError> {
+    let mut v = Vec::new();
+    let err = "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is a GRE kind)";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(err)?;
+        v.push(InfoGre::parse(nla).context(err)?);
+    }
+    Ok(v)
+}
+
+/// Attributes of the `gre`, `gretap`, `ip6gre` and `ip6gretap` links. The addresses are IPv4 or
+/// IPv6 ones depending on the kind of the link.
+///
+/// The flags, keys, flow info and encapsulation ports are in network byte order on the wire, the
+/// values held here are in host byte order.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoVlan {
+pub enum InfoGre {
     Unspec(Vec<u8>),
-    Id(u16),
-    Flags((u32, u32)),
-    EgressQos(Vec<u8>),
-    IngressQos(Vec<u8>),
-    Protocol(u16),
+    Link(u32),
+    IFlags(u16),
+    OFlags(u16),
+    IKey(u32),
+    OKey(u32),
+    Local(Vec<u8>),
+    Remote(Vec<u8>),
+    Ttl(u8),
+    Tos(u8),
+    PMtuDisc(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    Flags(u32),
+    EncapType(u16),
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    /// The tunnel is in external mode: the keys and endpoints are taken from the metadata of
+    /// each packet rather than from the link configuration.
+    CollectMetadata,
+    IgnoreDf(u8),
+    FwMark(u32),
+    Other(DefaultNla),
 }
 
-impl Nla for InfoVlan {
+impl Nla for InfoGre {
     #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoVlan::*;
+        use self::InfoGre::*;
         match self {
-            Id(_) | Protocol(_) => 2,
-            Flags(_) => 8,
-            Unspec(bytes)
-                | EgressQos(bytes)
-                | IngressQos(bytes)
-                => bytes.len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | PMtuDisc(_)
+                | EncapLimit(_)
+                | IgnoreDf(_)
+            => 1,
+            IFlags(_)
+                | OFlags(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+            => 2,
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | FwMark(_)
+            => 4,
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+            => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
     #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoVlan::*;
+        use self::InfoGre::*;
         match self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | PMtuDisc(value)
+                | EncapLimit(value)
+                | IgnoreDf(value)
+            => buffer[0] = *value,
+            IFlags(value)
+                | OFlags(value)
+                | EncapSport(value)
+                | EncapDport(value)
+            => BigEndian::write_u16(buffer, *value),
+            EncapType(value)
+                | EncapFlags(value)
+            => NativeEndian::write_u16(buffer, *value),
+            IKey(value)
+                | OKey(value)
+                | FlowInfo(value)
+            => BigEndian::write_u32(buffer, *value),
+            Link(value)
+                | Flags(value)
+                | FwMark(value)
+            => NativeEndian::write_u32(buffer, *value),
             Unspec(ref bytes)
-                | EgressQos(ref bytes)
-                | IngressQos(ref bytes)
-                => buffer.copy_from_slice(bytes),
-
-            Id(ref value)
-                | Protocol(ref value)
-                => NativeEndian::write_u16(buffer, *value),
-
-            Flags(ref flags) => {
-                NativeEndian::write_u32(buffer, flags.0);
-                NativeEndian::write_u32(buffer, flags.1)
-            }
+                | Local(ref bytes)
+                | Remote(ref bytes)
+            => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
         }
     }
 
     fn kind(&self) -> u16 {
-        use self::InfoVlan::*;
+        use self::InfoGre::*;
         match self {
-            Unspec(_) => IFLA_VLAN_UNSPEC,
-            Id(_) => IFLA_VLAN_ID,
-            Flags(_) => IFLA_VLAN_FLAGS,
-            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
-            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
-            Protocol(_) => IFLA_VLAN_PROTOCOL,
+            Unspec(_) => IFLA_GRE_UNSPEC,
+            Link(_) => IFLA_GRE_LINK,
+            IFlags(_) => IFLA_GRE_IFLAGS,
+            OFlags(_) => IFLA_GRE_OFLAGS,
+            IKey(_) => IFLA_GRE_IKEY,
+            OKey(_) => IFLA_GRE_OKEY,
+            Local(_) => IFLA_GRE_LOCAL,
+            Remote(_) => IFLA_GRE_REMOTE,
+            Ttl(_) => IFLA_GRE_TTL,
+            Tos(_) => IFLA_GRE_TOS,
+            PMtuDisc(_) => IFLA_GRE_PMTUDISC,
+            EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Flags(_) => IFLA_GRE_FLAGS,
+            EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_GRE_ENCAP_SPORT,
+            EncapDport(_) => IFLA_GRE_ENCAP_DPORT,
+            CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            FwMark(_) => IFLA_GRE_FWMARK,
+            Other(ref nla) => nla.kind(),
         }
     }
 }
 
-impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGre {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
-        use self::InfoVlan::*;
+        use self::InfoGre::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
-            IFLA_VLAN_ID => Id(parse_u16(payload).context("invalid IFLA_VLAN_ID value")?),
-            IFLA_VLAN_FLAGS => {
-                let err = "invalid IFLA_VLAN_FLAGS value";
-                if payload.len() != 8 {
-                    return Err(err.into());
-                }
-                let flags = parse_u32(&payload[0..4]).context(err)?;
-                let mask = parse_u32(&payload[4..]).context(err)?;
-                Flags((flags, mask))
+            IFLA_GRE_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GRE_LINK => Link(parse_u32(payload).context("invalid IFLA_GRE_LINK value")?),
+            IFLA_GRE_IFLAGS => {
+                IFlags(parse_u16_be(payload).context("invalid IFLA_GRE_IFLAGS value")?)
             }
-            IFLA_VLAN_EGRESS_QOS => EgressQos(payload.to_vec()),
-            IFLA_VLAN_INGRESS_QOS => IngressQos(payload.to_vec()),
-            IFLA_VLAN_PROTOCOL => {
-                Protocol(parse_u16_be(payload).context("invalid IFLA_VLAN_PROTOCOL value")?)
+            IFLA_GRE_OFLAGS => {
+                OFlags(parse_u16_be(payload).context("invalid IFLA_GRE_OFLAGS value")?)
             }
-            _ => return Err(format!("unknown NLA type {}", buf.kind()).into()),
+            IFLA_GRE_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?),
+            IFLA_GRE_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?),
+            IFLA_GRE_LOCAL => Local(payload.to_vec()),
+            IFLA_GRE_REMOTE => Remote(payload.to_vec()),
+            IFLA_GRE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GRE_TTL value")?),
+            IFLA_GRE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GRE_TOS value")?),
+            IFLA_GRE_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_GRE_PMTUDISC value")?)
+            }
+            IFLA_GRE_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_GRE_ENCAP_LIMIT value")?)
+            }
+            IFLA_GRE_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_GRE_FLOWINFO value")?)
+            }
+            IFLA_GRE_FLAGS => Flags(parse_u32(payload).context("invalid IFLA_GRE_FLAGS value")?),
+            IFLA_GRE_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_TYPE value")?)
+            }
+            IFLA_GRE_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_FLAGS value")?)
+            }
+            IFLA_GRE_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_SPORT value")?)
+            }
+            IFLA_GRE_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_DPORT value")?)
+            }
+            IFLA_GRE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GRE_IGNORE_DF => {
+                IgnoreDf(parse_u8(payload).context("invalid IFLA_GRE_IGNORE_DF value")?)
+            }
+            IFLA_GRE_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
 
+fn parse_iptun_nlas(payload: &[u8]) -> Result<Vec<InfoIpTun>, DecodeError> {
+    let mut v = Vec::new();
+    let err = "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is 'ipip' or 'sit')";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(err)?;
+        v.push(InfoIpTun::parse(nla).context(err)?);
+    }
+    Ok(v)
+}
+
+/// Attributes of the `ipip` and `sit` links. The endpoints are IPv4 addresses, and the 6rd
+/// attributes only apply to `sit` links.
+///
+/// The flow info, the 6rd relay prefix and the encapsulation ports are in network byte order on
+/// the wire, the values held here are in host byte order.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub enum InfoBridge {
+pub enum InfoIpTun {
     Unspec(Vec<u8>),
-    GroupAddr([u8; 6]),
-    // FIXME: what type is this? putting Vec<u8> for now but it might
-    // be a boolean actually
-    FdbFlush(Vec<u8>),
-    Pad(Vec<u8>),
-    HelloTimer(u64),
-    TcnTimer(u64),
-    TopologyChangeTimer(u64),
-    GcTimer(u64),
-    MulticastMembershipInterval(u64),
-    MulticastQuerierInterval(u64),
-    MulticastQueryInterval(u64),
-    MulticastQueryResponseInterval(u64),
-    MulticastLastMemberInterval(u64),
-    MulticastStartupQueryInterval(u64),
-    ForwardDelay(u32),
-    HelloTime(u32),
-    MaxAge(u32),
-    AgeingTime(u32),
-    StpState(u32),
-    MulticastHashElasticity(u32),
-    MulticastHashMax(u32),
-    MulticastLastMemberCount(u32),
-    MulticastStartupQueryCount(u32),
-    RootPathCost(u32),
-    Priority(u16),
-    VlanProtocol(u16),
-    GroupFwdMask(u16),
-    RootId((u16, [u8; 6])),
-    BridgeId((u16, [u8; 6])),
-    RootPort(u16),
-    VlanDefaultPvid(u16),
-    VlanFiltering(u8),
-    TopologyChange(u8),
-    TopologyChangeDetected(u8),
-    MulticastRouter(u8),
-    MulticastSnooping(u8),
-    MulticastQueryUseIfaddr(u8),
-    MulticastQuerier(u8),
-    NfCallIpTables(u8),
-    NfCallIp6Tables(u8),
-    NfCallArpTables(u8),
-    VlanStatsEnabled(u8),
-    MulticastStatsEnabled(u8),
-    MulticastIgmpVersion(u8),
-    MulticastMldVersion(u8),
-    VlanStatsPerHost(u8),
-    MultiBoolOpt(u64),
+    Link(u32),
+    Local(Vec<u8>),
+    Remote(Vec<u8>),
+    Ttl(u8),
+    Tos(u8),
+    EncapLimit(u8),
+    FlowInfo(u32),
+    /// `SIT_ISATAP` and other tunnel flags of a `sit` link
+    Flags(u16),
+    /// Protocol carried by the tunnel: `IPPROTO_IPIP`, `IPPROTO_MPLS`, `IPPROTO_IPV6` (`sit`
+    /// only) or 0 for any of them
+    Proto(u8),
+    PMtuDisc(u8),
+    SixRdPrefix(Vec<u8>),
+    SixRdRelayPrefix(Vec<u8>),
+    SixRdPrefixLen(u16),
+    SixRdRelayPrefixLen(u16),
+    EncapType(u16),
+    EncapFlags(u16),
+    EncapSport(u16),
+    EncapDport(u16),
+    /// The tunnel is in external mode: the endpoints are taken from the metadata of each packet
+    /// rather than from the link configuration.
+    CollectMetadata,
+    FwMark(u32),
     Other(DefaultNla),
 }
 
-impl Nla for InfoBridge {
+impl Nla for InfoIpTun {
     #[rustfmt::skip]
     fn value_len(&self) -> usize {
-        use self::InfoBridge::*;
+        use self::InfoIpTun::*;
         match self {
-            Unspec(bytes)
-                | FdbFlush(bytes)
-                | Pad(bytes)
-                => bytes.len(),
-            HelloTimer(_)
-                | TcnTimer(_)
-                | TopologyChangeTimer(_)
-                | GcTimer(_)
-                | MulticastMembershipInterval(_)
-                | MulticastQuerierInterval(_)
-                | MulticastQueryInterval(_)
-                | MulticastQueryResponseInterval(_)
-                | MulticastLastMemberInterval(_)
-                | MulticastStartupQueryInterval(_)
-                => 8,
-            ForwardDelay(_)
-                | HelloTime(_)
-                | MaxAge(_)
-                | AgeingTime(_)
-                | StpState(_)
-                | MulticastHashElasticity(_)
-                | MulticastHashMax(_)
-                | MulticastLastMemberCount(_)
-                | MulticastStartupQueryCount(_)
-                | RootPathCost(_)
-                => 4,
-            Priority(_)
-                | VlanProtocol(_)
-                | GroupFwdMask(_)
-                | RootPort(_)
-                | VlanDefaultPvid(_)
-                => 2,
-
-            RootId(_)
-                | BridgeId(_)
-                | MultiBoolOpt(_)
-                => 8,
-
-            GroupAddr(_) => 6,
-
-            VlanFiltering(_)
-                | TopologyChange(_)
-                | TopologyChangeDetected(_)
-                | MulticastRouter(_)
-                | MulticastSnooping(_)
-                | MulticastQueryUseIfaddr(_)
-                | MulticastQuerier(_)
-                | NfCallIpTables(_)
-                | NfCallIp6Tables(_)
-                | NfCallArpTables(_)
-                | VlanStatsEnabled(_)
-                | MulticastStatsEnabled(_)
-                | MulticastIgmpVersion(_)
-                | MulticastMldVersion(_)
-                | VlanStatsPerHost(_)
-                => 1,
-            Other(nla)
-                => nla.value_len(),
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | EncapLimit(_)
+                | Proto(_)
+                | PMtuDisc(_)
+            => 1,
+            Flags(_)
+                | SixRdPrefixLen(_)
+                | SixRdRelayPrefixLen(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSport(_)
+                | EncapDport(_)
+            => 2,
+            Link(_)
+                | FlowInfo(_)
+                | FwMark(_)
+            => 4,
+            Unspec(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                | SixRdPrefix(ref bytes)
+                | SixRdRelayPrefix(ref bytes)
+            => bytes.len(),
+            Other(ref nla) => nla.value_len(),
         }
     }
 
     #[rustfmt::skip]
     fn emit_value(&self, buffer: &mut [u8]) {
-        use self::InfoBridge::*;
+        use self::InfoIpTun::*;
         match self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | EncapLimit(value)
+                | Proto(value)
+                | PMtuDisc(value)
+            => buffer[0] = *value,
+            EncapSport(value)
+                | EncapDport(value)
+            => BigEndian::write_u16(buffer, *value),
+            Flags(value)
+                | SixRdPrefixLen(value)
+                | SixRdRelayPrefixLen(value)
+                | EncapType(value)
+                | EncapFlags(value)
+            => NativeEndian::write_u16(buffer, *value),
+            FlowInfo(value) => BigEndian::write_u32(buffer, *value),
+            Link(value)
+                | FwMark(value)
+            => NativeEndian::write_u32(buffer, *value),
             Unspec(ref bytes)
-                | FdbFlush(ref bytes)
-                | Pad(ref bytes)
+                | Local(ref bytes)
+                | Remote(ref bytes)
+                | SixRdPrefix(ref bytes)
+                | SixRdRelayPrefix(ref bytes)
+            => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoIpTun::*;
+        match self {
+            Unspec(_) => IFLA_IPTUN_UNSPEC,
+            Link(_) => IFLA_IPTUN_LINK,
+            Local(_) => IFLA_IPTUN_LOCAL,
+            Remote(_) => IFLA_IPTUN_REMOTE,
+            Ttl(_) => IFLA_IPTUN_TTL,
+            Tos(_) => IFLA_IPTUN_TOS,
+            EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Flags(_) => IFLA_IPTUN_FLAGS,
+            Proto(_) => IFLA_IPTUN_PROTO,
+            PMtuDisc(_) => IFLA_IPTUN_PMTUDISC,
+            SixRdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            SixRdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            SixRdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            SixRdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            EncapSport(_) => IFLA_IPTUN_ENCAP_SPORT,
+            EncapDport(_) => IFLA_IPTUN_ENCAP_DPORT,
+            CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            FwMark(_) => IFLA_IPTUN_FWMARK,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTun {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoIpTun::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_IPTUN_LINK => Link(parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?),
+            IFLA_IPTUN_LOCAL => Local(payload.to_vec()),
+            IFLA_IPTUN_REMOTE => Remote(payload.to_vec()),
+            IFLA_IPTUN_TTL => Ttl(parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?),
+            IFLA_IPTUN_TOS => Tos(parse_u8(payload).context("invalid IFLA_IPTUN_TOS value")?),
+            IFLA_IPTUN_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_IPTUN_ENCAP_LIMIT value")?)
+            }
+            IFLA_IPTUN_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_IPTUN_FLOWINFO value")?)
+            }
+            IFLA_IPTUN_FLAGS => {
+                Flags(parse_u16(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
+            }
+            IFLA_IPTUN_PROTO => Proto(parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?),
+            IFLA_IPTUN_PMTUDISC => {
+                PMtuDisc(parse_u8(payload).context("invalid IFLA_IPTUN_PMTUDISC value")?)
+            }
+            IFLA_IPTUN_6RD_PREFIX => SixRdPrefix(payload.to_vec()),
+            IFLA_IPTUN_6RD_RELAY_PREFIX => SixRdRelayPrefix(payload.to_vec()),
+            IFLA_IPTUN_6RD_PREFIXLEN => SixRdPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => SixRdRelayPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_RELAY_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_TYPE value")?)
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_FLAGS value")?)
+            }
+            IFLA_IPTUN_ENCAP_SPORT => {
+                EncapSport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_SPORT value")?)
+            }
+            IFLA_IPTUN_ENCAP_DPORT => {
+                EncapDport(parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_DPORT value")?)
+            }
+            IFLA_IPTUN_COLLECT_METADATA => CollectMetadata,
+            IFLA_IPTUN_FWMARK => {
+                FwMark(parse_u32(payload).context("invalid IFLA_IPTUN_FWMARK value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of the `macsec` links. Only the attributes of the link itself are covered here, the
+/// secure channels and associations are configured through the `macsec` generic netlink family.
+///
+/// The SCI and the port are in network byte order on the wire, the values held here are in host
+/// byte order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoMacSec {
+    Unspec(Vec<u8>),
+    /// Secure channel identifier: the MAC address of the link followed by the port
+    Sci(u64),
+    Port(u16),
+    IcvLen(u8),
+    /// One of the `MACSEC_CIPHER_ID_*` constants
+    CipherSuite(u64),
+    Window(u32),
+    EncodingSa(u8),
+    Encrypt(u8),
+    Protect(u8),
+    IncSci(u8),
+    Es(u8),
+    Scb(u8),
+    ReplayProtect(u8),
+    /// One of the `MACSEC_VALIDATE_*` constants
+    Validation(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoMacSec {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoMacSec::*;
+        match self {
+            IcvLen(_)
+                | EncodingSa(_)
+                | Encrypt(_)
+                | Protect(_)
+                | IncSci(_)
+                | Es(_)
+                | Scb(_)
+                | ReplayProtect(_)
+                | Validation(_)
+            => 1,
+            Port(_) => 2,
+            Window(_) => 4,
+            Sci(_) | CipherSuite(_) => 8,
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoMacSec::*;
+        match self {
+            IcvLen(value)
+                | EncodingSa(value)
+                | Encrypt(value)
+                | Protect(value)
+                | IncSci(value)
+                | Es(value)
+                | Scb(value)
+                | ReplayProtect(value)
+                | Validation(value)
+            => buffer[0] = *value,
+            Port(value) => BigEndian::write_u16(buffer, *value),
+            Window(value) => NativeEndian::write_u32(buffer, *value),
+            Sci(value) => BigEndian::write_u64(buffer, *value),
+            CipherSuite(value) => NativeEndian::write_u64(buffer, *value),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoMacSec::*;
+        match self {
+            Unspec(_) => IFLA_MACSEC_UNSPEC,
+            Sci(_) => IFLA_MACSEC_SCI,
+            Port(_) => IFLA_MACSEC_PORT,
+            IcvLen(_) => IFLA_MACSEC_ICV_LEN,
+            CipherSuite(_) => IFLA_MACSEC_CIPHER_SUITE,
+            Window(_) => IFLA_MACSEC_WINDOW,
+            EncodingSa(_) => IFLA_MACSEC_ENCODING_SA,
+            Encrypt(_) => IFLA_MACSEC_ENCRYPT,
+            Protect(_) => IFLA_MACSEC_PROTECT,
+            IncSci(_) => IFLA_MACSEC_INC_SCI,
+            Es(_) => IFLA_MACSEC_ES,
+            Scb(_) => IFLA_MACSEC_SCB,
+            ReplayProtect(_) => IFLA_MACSEC_REPLAY_PROTECT,
+            Validation(_) => IFLA_MACSEC_VALIDATION,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacSec {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoMacSec::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_MACSEC_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_MACSEC_SCI => {
+                Sci(u64::from_be(parse_u64(payload).context("invalid IFLA_MACSEC_SCI value")?))
+            }
+            IFLA_MACSEC_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_MACSEC_PORT value")?)
+            }
+            IFLA_MACSEC_ICV_LEN => {
+                IcvLen(parse_u8(payload).context("invalid IFLA_MACSEC_ICV_LEN value")?)
+            }
+            IFLA_MACSEC_CIPHER_SUITE => CipherSuite(
+                parse_u64(payload).context("invalid IFLA_MACSEC_CIPHER_SUITE value")?,
+            ),
+            IFLA_MACSEC_WINDOW => {
+                Window(parse_u32(payload).context("invalid IFLA_MACSEC_WINDOW value")?)
+            }
+            IFLA_MACSEC_ENCODING_SA => {
+                EncodingSa(parse_u8(payload).context("invalid IFLA_MACSEC_ENCODING_SA value")?)
+            }
+            IFLA_MACSEC_ENCRYPT => {
+                Encrypt(parse_u8(payload).context("invalid IFLA_MACSEC_ENCRYPT value")?)
+            }
+            IFLA_MACSEC_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_MACSEC_PROTECT value")?)
+            }
+            IFLA_MACSEC_INC_SCI => {
+                IncSci(parse_u8(payload).context("invalid IFLA_MACSEC_INC_SCI value")?)
+            }
+            IFLA_MACSEC_ES => Es(parse_u8(payload).context("invalid IFLA_MACSEC_ES value")?),
+            IFLA_MACSEC_SCB => Scb(parse_u8(payload).context("invalid IFLA_MACSEC_SCB value")?),
+            IFLA_MACSEC_REPLAY_PROTECT => ReplayProtect(
+                parse_u8(payload).context("invalid IFLA_MACSEC_REPLAY_PROTECT value")?,
+            ),
+            IFLA_MACSEC_VALIDATION => {
+                Validation(parse_u8(payload).context("invalid IFLA_MACSEC_VALIDATION value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of the `bareudp` links.
+///
+/// The destination port and the ethertype are in network byte order on the wire, the values held
+/// here are in host byte order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBareUdp {
+    Unspec(Vec<u8>),
+    Port(u16),
+    EtherType(u16),
+    SrcPortMin(u16),
+    /// Also accept the multicast (resp. IPv6) flavour of the MPLS (resp. IPv4) ethertype
+    MultiProtoMode,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBareUdp {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBareUdp::*;
+        match self {
+            MultiProtoMode => 0,
+            Port(_)
+                | EtherType(_)
+                | SrcPortMin(_)
+            => 2,
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBareUdp::*;
+        match self {
+            MultiProtoMode => {}
+            Port(value)
+                | EtherType(value)
+            => BigEndian::write_u16(buffer, *value),
+            SrcPortMin(value) => NativeEndian::write_u16(buffer, *value),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBareUdp::*;
+        match self {
+            Unspec(_) => IFLA_BAREUDP_UNSPEC,
+            Port(_) => IFLA_BAREUDP_PORT,
+            EtherType(_) => IFLA_BAREUDP_ETHERTYPE,
+            SrcPortMin(_) => IFLA_BAREUDP_SRCPORT_MIN,
+            MultiProtoMode => IFLA_BAREUDP_MULTIPROTO_MODE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBareUdp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBareUdp::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BAREUDP_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_BAREUDP_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_BAREUDP_PORT value")?)
+            }
+            IFLA_BAREUDP_ETHERTYPE => {
+                EtherType(parse_u16_be(payload).context("invalid IFLA_BAREUDP_ETHERTYPE value")?)
+            }
+            IFLA_BAREUDP_SRCPORT_MIN => SrcPortMin(
+                parse_u16(payload).context("invalid IFLA_BAREUDP_SRCPORT_MIN value")?,
+            ),
+            IFLA_BAREUDP_MULTIPROTO_MODE => MultiProtoMode,
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of the `gtp` links.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoGtp {
+    Unspec(Vec<u8>),
+    /// UDP socket used for GTPv0, bound to port 3386
+    Fd0(RawFd),
+    /// UDP socket used for GTPv1-U, bound to port 2152
+    Fd1(RawFd),
+    PdpHashsize(u32),
+    /// `GTP_ROLE_GGSN` or `GTP_ROLE_SGSN`
+    Role(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGtp {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoGtp::*;
+        match self {
+            Fd0(_)
+                | Fd1(_)
+                | PdpHashsize(_)
+                | Role(_)
+            => 4,
+            Unspec(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoGtp::*;
+        match self {
+            Fd0(value)
+                | Fd1(value)
+            => NativeEndian::write_i32(buffer, *value),
+            PdpHashsize(value)
+                | Role(value)
+            => NativeEndian::write_u32(buffer, *value),
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(ref nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoGtp::*;
+        match self {
+            Unspec(_) => IFLA_GTP_UNSPEC,
+            Fd0(_) => IFLA_GTP_FD0,
+            Fd1(_) => IFLA_GTP_FD1,
+            PdpHashsize(_) => IFLA_GTP_PDP_HASHSIZE,
+            Role(_) => IFLA_GTP_ROLE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGtp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoGtp::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GTP_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_GTP_FD0 => Fd0(parse_i32(payload).context("invalid IFLA_GTP_FD0 value")?),
+            IFLA_GTP_FD1 => Fd1(parse_i32(payload).context("invalid IFLA_GTP_FD1 value")?),
+            IFLA_GTP_PDP_HASHSIZE => PdpHashsize(
+                parse_u32(payload).context("invalid IFLA_GTP_PDP_HASHSIZE value")?,
+            ),
+            IFLA_GTP_ROLE => Role(parse_u32(payload).context("invalid IFLA_GTP_ROLE value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+// https://elixir.bootlin.com/linux/latest/source/net/8021q/vlan_netlink.c#L21
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoVlan {
+    Unspec(Vec<u8>),
+    Id(u16),
+    Flags((u32, u32)),
+    EgressQos(Vec<VlanQosMapping>),
+    IngressQos(Vec<VlanQosMapping>),
+    Protocol(u16),
+}
+
+impl Nla for InfoVlan {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoVlan::*;
+        match self {
+            Id(_) | Protocol(_) => 2,
+            Flags(_) => 8,
+            Unspec(bytes) => bytes.len(),
+            EgressQos(mappings)
+                | IngressQos(mappings)
+                => mappings.as_slice().buffer_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVlan::*;
+        match self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes),
+
+            EgressQos(ref mappings)
+                | IngressQos(ref mappings)
+                => mappings.as_slice().emit(buffer),
+
+            Id(ref value)
+                | Protocol(ref value)
+                => NativeEndian::write_u16(buffer, *value),
+
+            Flags(ref flags) => {
+                NativeEndian::write_u32(buffer, flags.0);
+                NativeEndian::write_u32(buffer, flags.1)
+            }
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoVlan::*;
+        match self {
+            Unspec(_) => IFLA_VLAN_UNSPEC,
+            Id(_) => IFLA_VLAN_ID,
+            Flags(_) => IFLA_VLAN_FLAGS,
+            EgressQos(_) => IFLA_VLAN_EGRESS_QOS,
+            IngressQos(_) => IFLA_VLAN_INGRESS_QOS,
+            Protocol(_) => IFLA_VLAN_PROTOCOL,
+        }
+    }
+}
+
+fn parse_vlan_qos_mappings(payload: &[u8]) -> Result<Vec<VlanQosMapping>, DecodeError> {
+    let mut mappings = Vec::new();
+    let err = "failed to parse IFLA_VLAN_QOS_MAPPING";
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(err)?;
+        let parsed = VlanQosMapping::parse(nla).context(err)?;
+        mappings.push(parsed);
+    }
+    Ok(mappings)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVlan {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVlan::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VLAN_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VLAN_ID => Id(parse_u16(payload).context("invalid IFLA_VLAN_ID value")?),
+            IFLA_VLAN_FLAGS => {
+                let err = "invalid IFLA_VLAN_FLAGS value";
+                if payload.len() != 8 {
+                    return Err(err.into());
+                }
+                let flags = parse_u32(&payload[0..4]).context(err)?;
+                let mask = parse_u32(&payload[4..]).context(err)?;
+                Flags((flags, mask))
+            }
+            IFLA_VLAN_EGRESS_QOS => EgressQos(
+                parse_vlan_qos_mappings(payload).context("invalid IFLA_VLAN_EGRESS_QOS value")?,
+            ),
+            IFLA_VLAN_INGRESS_QOS => IngressQos(
+                parse_vlan_qos_mappings(payload).context("invalid IFLA_VLAN_INGRESS_QOS value")?,
+            ),
+            IFLA_VLAN_PROTOCOL => {
+                Protocol(parse_u16_be(payload).context("invalid IFLA_VLAN_PROTOCOL value")?)
+            }
+            _ => return Err(format!("unknown NLA type {}", buf.kind()).into()),
+        })
+    }
+}
+
+/// Entry of the `IFLA_VLAN_EGRESS_QOS` and `IFLA_VLAN_INGRESS_QOS` maps.
+///
+/// For the ingress map, `from` is the VLAN header priority and `to` the packet
+/// priority. For the egress map, it is the other way around.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanQosMapping {
+    Unspec(Vec<u8>),
+    Mapping { from: u32, to: u32 },
+    Other(DefaultNla),
+}
+
+impl Nla for VlanQosMapping {
+    fn value_len(&self) -> usize {
+        use self::VlanQosMapping::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            // struct ifla_vlan_qos_mapping { __u32 from; __u32 to; }
+            Mapping { .. } => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanQosMapping::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes),
+            Mapping { from, to } => {
+                NativeEndian::write_u32(&mut buffer[0..4], *from);
+                NativeEndian::write_u32(&mut buffer[4..8], *to);
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanQosMapping::*;
+        match self {
+            Unspec(_) => IFLA_VLAN_QOS_UNSPEC,
+            Mapping { .. } => IFLA_VLAN_QOS_MAPPING,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanQosMapping {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanQosMapping::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VLAN_QOS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_VLAN_QOS_MAPPING => {
+                let err = "invalid IFLA_VLAN_QOS_MAPPING value";
+                if payload.len() != 8 {
+                    return Err(err.into());
+                }
+                Mapping {
+                    from: parse_u32(&payload[0..4]).context(err)?,
+                    to: parse_u32(&payload[4..]).context(err)?,
+                }
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBridge {
+    Unspec(Vec<u8>),
+    GroupAddr([u8; 6]),
+    // FIXME: what type is this? putting Vec<u8> for now but it might
+    // be a boolean actually
+    FdbFlush(Vec<u8>),
+    Pad(Vec<u8>),
+    HelloTimer(u64),
+    TcnTimer(u64),
+    TopologyChangeTimer(u64),
+    GcTimer(u64),
+    MulticastMembershipInterval(u64),
+    MulticastQuerierInterval(u64),
+    MulticastQueryInterval(u64),
+    MulticastQueryResponseInterval(u64),
+    MulticastLastMemberInterval(u64),
+    MulticastStartupQueryInterval(u64),
+    ForwardDelay(u32),
+    HelloTime(u32),
+    MaxAge(u32),
+    AgeingTime(u32),
+    StpState(u32),
+    MulticastHashElasticity(u32),
+    MulticastHashMax(u32),
+    MulticastLastMemberCount(u32),
+    MulticastStartupQueryCount(u32),
+    RootPathCost(u32),
+    Priority(u16),
+    VlanProtocol(u16),
+    GroupFwdMask(u16),
+    RootId((u16, [u8; 6])),
+    BridgeId((u16, [u8; 6])),
+    RootPort(u16),
+    VlanDefaultPvid(u16),
+    VlanFiltering(u8),
+    TopologyChange(u8),
+    TopologyChangeDetected(u8),
+    MulticastRouter(u8),
+    MulticastSnooping(u8),
+    MulticastQueryUseIfaddr(u8),
+    MulticastQuerier(u8),
+    NfCallIpTables(u8),
+    NfCallIp6Tables(u8),
+    NfCallArpTables(u8),
+    VlanStatsEnabled(u8),
+    MulticastStatsEnabled(u8),
+    MulticastIgmpVersion(u8),
+    MulticastMldVersion(u8),
+    VlanStatsPerHost(u8),
+    MultiBoolOpt(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBridge {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBridge::*;
+        match self {
+            Unspec(bytes)
+                | FdbFlush(bytes)
+                | Pad(bytes)
+                => bytes.len(),
+            HelloTimer(_)
+                | TcnTimer(_)
+                | TopologyChangeTimer(_)
+                | GcTimer(_)
+                | MulticastMembershipInterval(_)
+                | MulticastQuerierInterval(_)
+                | MulticastQueryInterval(_)
+                | MulticastQueryResponseInterval(_)
+                | MulticastLastMemberInterval(_)
+                | MulticastStartupQueryInterval(_)
+                => 8,
+            ForwardDelay(_)
+                | HelloTime(_)
+                | MaxAge(_)
+                | AgeingTime(_)
+                | StpState(_)
+                | MulticastHashElasticity(_)
+                | MulticastHashMax(_)
+                | MulticastLastMemberCount(_)
+                | MulticastStartupQueryCount(_)
+                | RootPathCost(_)
+                => 4,
+            Priority(_)
+                | VlanProtocol(_)
+                | GroupFwdMask(_)
+                | RootPort(_)
+                | VlanDefaultPvid(_)
+                => 2,
+
+            RootId(_)
+                | BridgeId(_)
+                | MultiBoolOpt(_)
+                => 8,
+
+            GroupAddr(_) => 6,
+
+            VlanFiltering(_)
+                | TopologyChange(_)
+                | TopologyChangeDetected(_)
+                | MulticastRouter(_)
+                | MulticastSnooping(_)
+                | MulticastQueryUseIfaddr(_)
+                | MulticastQuerier(_)
+                | NfCallIpTables(_)
+                | NfCallIp6Tables(_)
+                | NfCallArpTables(_)
+                | VlanStatsEnabled(_)
+                | MulticastStatsEnabled(_)
+                | MulticastIgmpVersion(_)
+                | MulticastMldVersion(_)
+                | VlanStatsPerHost(_)
+                => 1,
+            Other(nla)
+                => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBridge::*;
+        match self {
+            Unspec(ref bytes)
+                | FdbFlush(ref bytes)
+                | Pad(ref bytes)
                 => buffer.copy_from_slice(bytes),
 
             HelloTimer(ref value)
@@ -1191,13 +2061,485 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBridge {
             IFLA_BR_VLAN_STATS_PER_PORT => VlanStatsPerHost(
                 parse_u8(payload).context("invalid IFLA_BR_VLAN_STATS_PER_PORT value")?,
             ),
-            IFLA_BR_MULTI_BOOLOPT => {
-                MultiBoolOpt(parse_u64(payload).context("invalid IFLA_BR_MULTI_BOOLOPT value")?)
+            IFLA_BR_MULTI_BOOLOPT => {
+                MultiBoolOpt(parse_u64(payload).context("invalid IFLA_BR_MULTI_BOOLOPT value")?)
+            }
+            _ => Other(
+                DefaultNla::parse(buf)
+                    .context("invalid link info bridge NLA value (unknown type)")?,
+            ),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BondAdInfo {
+    Aggregator(u16),
+    NumPorts(u16),
+    ActorKey(u16),
+    PartnerKey(u16),
+    PartnerMac([u8; 6]),
+    Other(DefaultNla),
+}
+
+impl Nla for BondAdInfo {
+    fn value_len(&self) -> usize {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(_) | NumPorts(_) | ActorKey(_) | PartnerKey(_) => 2,
+            PartnerMac(_) => 6,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(value) | NumPorts(value) | ActorKey(value) | PartnerKey(value) => {
+                NativeEndian::write_u16(buffer, *value)
+            }
+            PartnerMac(bytes) => buffer.copy_from_slice(bytes),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BondAdInfo::*;
+        match self {
+            Aggregator(_) => IFLA_BOND_AD_INFO_AGGREGATOR,
+            NumPorts(_) => IFLA_BOND_AD_INFO_NUM_PORTS,
+            ActorKey(_) => IFLA_BOND_AD_INFO_ACTOR_KEY,
+            PartnerKey(_) => IFLA_BOND_AD_INFO_PARTNER_KEY,
+            PartnerMac(_) => IFLA_BOND_AD_INFO_PARTNER_MAC,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondAdInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BondAdInfo::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_AD_INFO_AGGREGATOR => Aggregator(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_AGGREGATOR value")?,
+            ),
+            IFLA_BOND_AD_INFO_NUM_PORTS => NumPorts(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_NUM_PORTS value")?,
+            ),
+            IFLA_BOND_AD_INFO_ACTOR_KEY => ActorKey(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_ACTOR_KEY value")?,
+            ),
+            IFLA_BOND_AD_INFO_PARTNER_KEY => PartnerKey(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_INFO_PARTNER_KEY value")?,
+            ),
+            IFLA_BOND_AD_INFO_PARTNER_MAC => PartnerMac(
+                parse_mac(payload).context("invalid IFLA_BOND_AD_INFO_PARTNER_MAC value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+// The IFLA_BOND_ARP_IP_TARGET nest contains one attribute per target, whose
+// type is the index of the target.
+struct BondIpAddrNla {
+    index: u16,
+    addr: Ipv4Addr,
+}
+
+impl Nla for BondIpAddrNla {
+    fn value_len(&self) -> usize {
+        4
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        buffer.copy_from_slice(&self.addr.octets())
+    }
+
+    fn kind(&self) -> u16 {
+        self.index
+    }
+}
+
+fn bond_ip_addr_nlas(addrs: &[Ipv4Addr]) -> Vec<BondIpAddrNla> {
+    addrs
+        .iter()
+        .enumerate()
+        .map(|(index, addr)| BondIpAddrNla {
+            index: index as u16,
+            addr: *addr,
+        })
+        .collect()
+}
+
+/// Attributes of a bond interface (`IFLA_INFO_DATA` when `IFLA_INFO_KIND` is `bond`).
+///
+/// These describe the bond itself. The state of each of its slaves is found in
+/// the `IFLA_INFO_SLAVE_DATA` attribute of the slave links, see [`InfoBondPort`].
+///
+/// https://elixir.bootlin.com/linux/v5.9.8/source/drivers/net/bonding/bond_netlink.c#L90
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBond {
+    Mode(u8),
+    /// Index of the active slave, for the modes that have one (e.g.
+    /// active-backup). This is only reported in the dump of the bond link.
+    ActiveSlave(u32),
+    MiiMon(u32),
+    UpDelay(u32),
+    DownDelay(u32),
+    UseCarrier(u8),
+    ArpInterval(u32),
+    ArpIpTarget(Vec<Ipv4Addr>),
+    ArpValidate(u32),
+    ArpAllTargets(u32),
+    Primary(u32),
+    PrimaryReselect(u8),
+    FailOverMac(u8),
+    XmitHashPolicy(u8),
+    ResendIgmp(u32),
+    NumPeerNotif(u8),
+    AllSlavesActive(u8),
+    MinLinks(u32),
+    LpInterval(u32),
+    PacketsPerSlave(u32),
+    AdLacpRate(u8),
+    AdSelect(u8),
+    AdInfo(Vec<BondAdInfo>),
+    AdActorSysPrio(u16),
+    AdUserPortKey(u16),
+    AdActorSystem([u8; 6]),
+    TlbDynamicLb(u8),
+    PeerNotifDelay(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBond {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBond::*;
+        match self {
+            Mode(_)
+                | UseCarrier(_)
+                | PrimaryReselect(_)
+                | FailOverMac(_)
+                | XmitHashPolicy(_)
+                | NumPeerNotif(_)
+                | AllSlavesActive(_)
+                | AdLacpRate(_)
+                | AdSelect(_)
+                | TlbDynamicLb(_)
+                => 1,
+            AdActorSysPrio(_)
+                | AdUserPortKey(_)
+                => 2,
+            ActiveSlave(_)
+                | MiiMon(_)
+                | UpDelay(_)
+                | DownDelay(_)
+                | ArpInterval(_)
+                | ArpValidate(_)
+                | ArpAllTargets(_)
+                | Primary(_)
+                | ResendIgmp(_)
+                | MinLinks(_)
+                | LpInterval(_)
+                | PacketsPerSlave(_)
+                | PeerNotifDelay(_)
+                => 4,
+            AdActorSystem(_) => 6,
+            ArpIpTarget(ref addrs) => bond_ip_addr_nlas(addrs).as_slice().buffer_len(),
+            AdInfo(ref nlas) => nlas.as_slice().buffer_len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBond::*;
+        match self {
+            Mode(value)
+                | UseCarrier(value)
+                | PrimaryReselect(value)
+                | FailOverMac(value)
+                | XmitHashPolicy(value)
+                | NumPeerNotif(value)
+                | AllSlavesActive(value)
+                | AdLacpRate(value)
+                | AdSelect(value)
+                | TlbDynamicLb(value)
+                => buffer[0] = *value,
+            AdActorSysPrio(value)
+                | AdUserPortKey(value)
+                => NativeEndian::write_u16(buffer, *value),
+            ActiveSlave(value)
+                | MiiMon(value)
+                | UpDelay(value)
+                | DownDelay(value)
+                | ArpInterval(value)
+                | ArpValidate(value)
+                | ArpAllTargets(value)
+                | Primary(value)
+                | ResendIgmp(value)
+                | MinLinks(value)
+                | LpInterval(value)
+                | PacketsPerSlave(value)
+                | PeerNotifDelay(value)
+                => NativeEndian::write_u32(buffer, *value),
+            AdActorSystem(bytes) => buffer.copy_from_slice(bytes),
+            ArpIpTarget(addrs) => bond_ip_addr_nlas(addrs).as_slice().emit(buffer),
+            AdInfo(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBond::*;
+        match self {
+            Mode(_) => IFLA_BOND_MODE,
+            ActiveSlave(_) => IFLA_BOND_ACTIVE_SLAVE,
+            MiiMon(_) => IFLA_BOND_MIIMON,
+            UpDelay(_) => IFLA_BOND_UPDELAY,
+            DownDelay(_) => IFLA_BOND_DOWNDELAY,
+            UseCarrier(_) => IFLA_BOND_USE_CARRIER,
+            ArpInterval(_) => IFLA_BOND_ARP_INTERVAL,
+            ArpIpTarget(_) => IFLA_BOND_ARP_IP_TARGET,
+            ArpValidate(_) => IFLA_BOND_ARP_VALIDATE,
+            ArpAllTargets(_) => IFLA_BOND_ARP_ALL_TARGETS,
+            Primary(_) => IFLA_BOND_PRIMARY,
+            PrimaryReselect(_) => IFLA_BOND_PRIMARY_RESELECT,
+            FailOverMac(_) => IFLA_BOND_FAIL_OVER_MAC,
+            XmitHashPolicy(_) => IFLA_BOND_XMIT_HASH_POLICY,
+            ResendIgmp(_) => IFLA_BOND_RESEND_IGMP,
+            NumPeerNotif(_) => IFLA_BOND_NUM_PEER_NOTIF,
+            AllSlavesActive(_) => IFLA_BOND_ALL_SLAVES_ACTIVE,
+            MinLinks(_) => IFLA_BOND_MIN_LINKS,
+            LpInterval(_) => IFLA_BOND_LP_INTERVAL,
+            PacketsPerSlave(_) => IFLA_BOND_PACKETS_PER_SLAVE,
+            AdLacpRate(_) => IFLA_BOND_AD_LACP_RATE,
+            AdSelect(_) => IFLA_BOND_AD_SELECT,
+            AdInfo(_) => IFLA_BOND_AD_INFO,
+            AdActorSysPrio(_) => IFLA_BOND_AD_ACTOR_SYS_PRIO,
+            AdUserPortKey(_) => IFLA_BOND_AD_USER_PORT_KEY,
+            AdActorSystem(_) => IFLA_BOND_AD_ACTOR_SYSTEM,
+            TlbDynamicLb(_) => IFLA_BOND_TLB_DYNAMIC_LB,
+            PeerNotifDelay(_) => IFLA_BOND_PEER_NOTIF_DELAY,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBond {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBond::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_MODE => Mode(parse_u8(payload).context("invalid IFLA_BOND_MODE value")?),
+            IFLA_BOND_ACTIVE_SLAVE => {
+                ActiveSlave(parse_u32(payload).context("invalid IFLA_BOND_ACTIVE_SLAVE value")?)
+            }
+            IFLA_BOND_MIIMON => {
+                MiiMon(parse_u32(payload).context("invalid IFLA_BOND_MIIMON value")?)
+            }
+            IFLA_BOND_UPDELAY => {
+                UpDelay(parse_u32(payload).context("invalid IFLA_BOND_UPDELAY value")?)
+            }
+            IFLA_BOND_DOWNDELAY => {
+                DownDelay(parse_u32(payload).context("invalid IFLA_BOND_DOWNDELAY value")?)
+            }
+            IFLA_BOND_USE_CARRIER => {
+                UseCarrier(parse_u8(payload).context("invalid IFLA_BOND_USE_CARRIER value")?)
+            }
+            IFLA_BOND_ARP_INTERVAL => {
+                ArpInterval(parse_u32(payload).context("invalid IFLA_BOND_ARP_INTERVAL value")?)
+            }
+            IFLA_BOND_ARP_IP_TARGET => {
+                let mut addrs = Vec::new();
+                let err = "invalid IFLA_BOND_ARP_IP_TARGET value";
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    let value = nla.value();
+                    if value.len() != 4 {
+                        return Err(err.into());
+                    }
+                    addrs.push(Ipv4Addr::new(value[0], value[1], value[2], value[3]));
+                }
+                ArpIpTarget(addrs)
+            }
+            IFLA_BOND_ARP_VALIDATE => {
+                ArpValidate(parse_u32(payload).context("invalid IFLA_BOND_ARP_VALIDATE value")?)
+            }
+            IFLA_BOND_ARP_ALL_TARGETS => ArpAllTargets(
+                parse_u32(payload).context("invalid IFLA_BOND_ARP_ALL_TARGETS value")?,
+            ),
+            IFLA_BOND_PRIMARY => {
+                Primary(parse_u32(payload).context("invalid IFLA_BOND_PRIMARY value")?)
+            }
+            IFLA_BOND_PRIMARY_RESELECT => PrimaryReselect(
+                parse_u8(payload).context("invalid IFLA_BOND_PRIMARY_RESELECT value")?,
+            ),
+            IFLA_BOND_FAIL_OVER_MAC => {
+                FailOverMac(parse_u8(payload).context("invalid IFLA_BOND_FAIL_OVER_MAC value")?)
+            }
+            IFLA_BOND_XMIT_HASH_POLICY => XmitHashPolicy(
+                parse_u8(payload).context("invalid IFLA_BOND_XMIT_HASH_POLICY value")?,
+            ),
+            IFLA_BOND_RESEND_IGMP => {
+                ResendIgmp(parse_u32(payload).context("invalid IFLA_BOND_RESEND_IGMP value")?)
+            }
+            IFLA_BOND_NUM_PEER_NOTIF => {
+                NumPeerNotif(parse_u8(payload).context("invalid IFLA_BOND_NUM_PEER_NOTIF value")?)
+            }
+            IFLA_BOND_ALL_SLAVES_ACTIVE => AllSlavesActive(
+                parse_u8(payload).context("invalid IFLA_BOND_ALL_SLAVES_ACTIVE value")?,
+            ),
+            IFLA_BOND_MIN_LINKS => {
+                MinLinks(parse_u32(payload).context("invalid IFLA_BOND_MIN_LINKS value")?)
+            }
+            IFLA_BOND_LP_INTERVAL => {
+                LpInterval(parse_u32(payload).context("invalid IFLA_BOND_LP_INTERVAL value")?)
+            }
+            IFLA_BOND_PACKETS_PER_SLAVE => PacketsPerSlave(
+                parse_u32(payload).context("invalid IFLA_BOND_PACKETS_PER_SLAVE value")?,
+            ),
+            IFLA_BOND_AD_LACP_RATE => {
+                AdLacpRate(parse_u8(payload).context("invalid IFLA_BOND_AD_LACP_RATE value")?)
+            }
+            IFLA_BOND_AD_SELECT => {
+                AdSelect(parse_u8(payload).context("invalid IFLA_BOND_AD_SELECT value")?)
+            }
+            IFLA_BOND_AD_INFO => {
+                let mut infos = Vec::new();
+                let err = "failed to parse IFLA_BOND_AD_INFO";
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(err)?;
+                    let info = BondAdInfo::parse(nla).context(err)?;
+                    infos.push(info);
+                }
+                AdInfo(infos)
+            }
+            IFLA_BOND_AD_ACTOR_SYS_PRIO => AdActorSysPrio(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_ACTOR_SYS_PRIO value")?,
+            ),
+            IFLA_BOND_AD_USER_PORT_KEY => AdUserPortKey(
+                parse_u16(payload).context("invalid IFLA_BOND_AD_USER_PORT_KEY value")?,
+            ),
+            IFLA_BOND_AD_ACTOR_SYSTEM => AdActorSystem(
+                parse_mac(payload).context("invalid IFLA_BOND_AD_ACTOR_SYSTEM value")?,
+            ),
+            IFLA_BOND_TLB_DYNAMIC_LB => {
+                TlbDynamicLb(parse_u8(payload).context("invalid IFLA_BOND_TLB_DYNAMIC_LB value")?)
+            }
+            IFLA_BOND_PEER_NOTIF_DELAY => PeerNotifDelay(
+                parse_u32(payload).context("invalid IFLA_BOND_PEER_NOTIF_DELAY value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of a bond slave (`IFLA_INFO_SLAVE_DATA` when `IFLA_INFO_SLAVE_KIND` is `bond`).
+///
+/// They are reported on the slave links. Whether a slave is the active one is not part
+/// of these attributes: it is given by [`InfoBond::ActiveSlave`] in the dump of the bond.
+///
+/// https://elixir.bootlin.com/linux/v5.9.8/source/drivers/net/bonding/bond_netlink.c#L36
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBondPort {
+    State(u8),
+    MiiStatus(u8),
+    LinkFailureCount(u32),
+    PermHwaddr(Vec<u8>),
+    QueueId(u16),
+    AdAggregatorId(u16),
+    AdActorOperPortState(u8),
+    AdPartnerOperPortState(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBondPort {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoBondPort::*;
+        match self {
+            State(_)
+                | MiiStatus(_)
+                | AdActorOperPortState(_)
+                => 1,
+            QueueId(_)
+                | AdAggregatorId(_)
+                | AdPartnerOperPortState(_)
+                => 2,
+            LinkFailureCount(_) => 4,
+            PermHwaddr(ref bytes) => bytes.len(),
+            Other(ref nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBondPort::*;
+        match self {
+            State(value)
+                | MiiStatus(value)
+                | AdActorOperPortState(value)
+                => buffer[0] = *value,
+            QueueId(value)
+                | AdAggregatorId(value)
+                | AdPartnerOperPortState(value)
+                => NativeEndian::write_u16(buffer, *value),
+            LinkFailureCount(value) => NativeEndian::write_u32(buffer, *value),
+            PermHwaddr(bytes) => buffer.copy_from_slice(bytes),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBondPort::*;
+        match self {
+            State(_) => IFLA_BOND_SLAVE_STATE,
+            MiiStatus(_) => IFLA_BOND_SLAVE_MII_STATUS,
+            LinkFailureCount(_) => IFLA_BOND_SLAVE_LINK_FAILURE_COUNT,
+            PermHwaddr(_) => IFLA_BOND_SLAVE_PERM_HWADDR,
+            QueueId(_) => IFLA_BOND_SLAVE_QUEUE_ID,
+            AdAggregatorId(_) => IFLA_BOND_SLAVE_AD_AGGREGATOR_ID,
+            AdActorOperPortState(_) => IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE,
+            AdPartnerOperPortState(_) => IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBondPort {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBondPort::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BOND_SLAVE_STATE => {
+                State(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_STATE value")?)
+            }
+            IFLA_BOND_SLAVE_MII_STATUS => {
+                MiiStatus(parse_u8(payload).context("invalid IFLA_BOND_SLAVE_MII_STATUS value")?)
+            }
+            IFLA_BOND_SLAVE_LINK_FAILURE_COUNT => LinkFailureCount(
+                parse_u32(payload).context("invalid IFLA_BOND_SLAVE_LINK_FAILURE_COUNT value")?,
+            ),
+            IFLA_BOND_SLAVE_PERM_HWADDR => PermHwaddr(payload.to_vec()),
+            IFLA_BOND_SLAVE_QUEUE_ID => {
+                QueueId(parse_u16(payload).context("invalid IFLA_BOND_SLAVE_QUEUE_ID value")?)
             }
-            _ => Other(
-                DefaultNla::parse(buf)
-                    .context("invalid link info bridge NLA value (unknown type)")?,
+            IFLA_BOND_SLAVE_AD_AGGREGATOR_ID => AdAggregatorId(
+                parse_u16(payload).context("invalid IFLA_BOND_SLAVE_AD_AGGREGATOR_ID value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE => AdActorOperPortState(
+                parse_u8(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_ACTOR_OPER_PORT_STATE value")?,
+            ),
+            IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE => AdPartnerOperPortState(
+                parse_u16(payload)
+                    .context("invalid IFLA_BOND_SLAVE_AD_PARTNER_OPER_PORT_STATE value")?,
             ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
 }
@@ -1260,6 +2602,36 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpoib {
     }
 }
 
+/// Transport mode of an IPoIB interface (the `IFLA_IPOIB_MODE` attribute).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IpoibMode {
+    /// Unreliable datagram mode (`IPOIB_MODE_DATAGRAM`), the MTU is limited by the IB MTU
+    Datagram,
+    /// Reliable connected mode (`IPOIB_MODE_CONNECTED`), which allows for MTUs up to 64KiB
+    Connected,
+    Other(u16),
+}
+
+impl From<u16> for IpoibMode {
+    fn from(value: u16) -> Self {
+        match value {
+            IPOIB_MODE_DATAGRAM => IpoibMode::Datagram,
+            IPOIB_MODE_CONNECTED => IpoibMode::Connected,
+            other => IpoibMode::Other(other),
+        }
+    }
+}
+
+impl From<IpoibMode> for u16 {
+    fn from(value: IpoibMode) -> Self {
+        match value {
+            IpoibMode::Datagram => IPOIB_MODE_DATAGRAM,
+            IpoibMode::Connected => IPOIB_MODE_CONNECTED,
+            IpoibMode::Other(other) => other,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum VethInfo {
     Unspec(Vec<u8>),
@@ -2093,6 +3465,468 @@ mod tests {
         assert_eq!(&vec[..], &MACVLAN_SOURCE_SET[..]);
     }
 
+    #[rustfmt::skip]
+    static VLAN: [u8; 40] = [
+        0x09, 0x00, // length = 9
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x76, 0x6c, 0x61, 0x6e, 0x00, // V = "vlan\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x1c, 0x00, // length = 28
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x06, 0x00, // length = 6
+            0x01, 0x00, // type = 1 = IFLA_VLAN_ID
+            0x0a, 0x00, // V = 10
+            0x00, 0x00, // padding
+
+            0x10, 0x00, // length = 16
+            0x03, 0x00, // type = 3 = IFLA_VLAN_EGRESS_QOS
+                0x0c, 0x00, // length = 12
+                0x01, 0x00, // type = 1 = IFLA_VLAN_QOS_MAPPING
+                0x01, 0x00, 0x00, 0x00, // from = 1
+                0x03, 0x00, 0x00, 0x00, // to = 3
+    ];
+
+    lazy_static! {
+        static ref VLAN_INFO: Vec<InfoVlan> = vec![
+            InfoVlan::Id(10),
+            InfoVlan::EgressQos(vec![VlanQosMapping::Mapping { from: 1, to: 3 }]),
+        ];
+    }
+
+    #[test]
+    fn parse_info_vlan() {
+        let nla = NlaBuffer::new_checked(&VLAN[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Vlan),
+            Info::Data(InfoData::Vlan(VLAN_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_vlan() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Vlan),
+            Info::Data(InfoData::Vlan(VLAN_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 40);
+
+        let mut vec = vec![0xff; 40];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VLAN[..]);
+    }
+
+    #[rustfmt::skip]
+    static GRETAP: [u8; 44] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x00, // V = "gretap\0"
+        0x00, // padding
+
+        0x20, 0x00, // length = 32
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x06, 0x00, // length = 6
+            0x02, 0x00, // type = 2 = IFLA_GRE_IFLAGS
+            0x20, 0x00, // V = GRE_KEY (big endian)
+            0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x04, 0x00, // type = 4 = IFLA_GRE_IKEY
+            0x00, 0x00, 0x00, 0x2a, // V = 42 (big endian)
+
+            0x05, 0x00, // length = 5
+            0x08, 0x00, // type = 8 = IFLA_GRE_TTL
+            0x40, // V = 64
+            0x00, 0x00, 0x00, // padding
+
+            0x04, 0x00, // length = 4
+            0x12, 0x00, // type = 18 = IFLA_GRE_COLLECT_METADATA
+    ];
+
+    lazy_static! {
+        static ref GRETAP_INFO: Vec<InfoGre> = vec![
+            InfoGre::IFlags(GRE_KEY),
+            InfoGre::IKey(42),
+            InfoGre::Ttl(64),
+            InfoGre::CollectMetadata,
+        ];
+    }
+
+    #[test]
+    fn parse_info_gretap() {
+        let nla = NlaBuffer::new_checked(&GRETAP[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_gretap() {
+        let nlas = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 44);
+
+        let mut vec = vec![0xff; 44];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GRETAP[..]);
+    }
+
+    #[rustfmt::skip]
+    static IPIP: [u8; 40] = [
+        0x09, 0x00, // length = 9
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x69, 0x70, 0x69, 0x70, 0x00, // V = "ipip\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x1c, 0x00, // length = 28
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x02, 0x00, // type = 2 = IFLA_IPTUN_LOCAL
+            0x0a, 0xba, 0x00, 0x01, // V = 10.186.0.1
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_IPTUN_REMOTE
+            0x0a, 0xba, 0x00, 0x02, // V = 10.186.0.2
+
+            0x05, 0x00, // length = 5
+            0x04, 0x00, // type = 4 = IFLA_IPTUN_TTL
+            0x40, // V = 64
+            0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref IPIP_INFO: Vec<InfoIpTun> = vec![
+            InfoIpTun::Local(vec![10, 186, 0, 1]),
+            InfoIpTun::Remote(vec![10, 186, 0, 2]),
+            InfoIpTun::Ttl(64),
+        ];
+    }
+
+    #[test]
+    fn parse_info_ipip() {
+        let nla = NlaBuffer::new_checked(&IPIP[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::IpTun),
+            Info::Data(InfoData::IpTun(IPIP_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_ipip() {
+        let nlas = vec![
+            Info::Kind(InfoKind::IpTun),
+            Info::Data(InfoData::IpTun(IPIP_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 40);
+
+        let mut vec = vec![0xff; 40];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &IPIP[..]);
+    }
+
+    #[rustfmt::skip]
+    static MACSEC: [u8; 48] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x6d, 0x61, 0x63, 0x73, 0x65, 0x63, 0x00, // V = "macsec\0"
+        0x00, // padding
+
+        0x24, 0x00, // length = 36
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x0c, 0x00, // length = 12
+            0x01, 0x00, // type = 1 = IFLA_MACSEC_SCI
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x01, // V = 52:54:00:12:34:56 port 1
+
+            0x0c, 0x00, // length = 12
+            0x04, 0x00, // type = 4 = IFLA_MACSEC_CIPHER_SUITE
+            0x02, 0x00, 0x00, 0x01, 0x00, 0xc2, 0x80, 0x00, // V = GCM-AES-256
+
+            0x05, 0x00, // length = 5
+            0x07, 0x00, // type = 7 = IFLA_MACSEC_ENCRYPT
+            0x01, // V = 1
+            0x00, 0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref MACSEC_INFO: Vec<InfoMacSec> = vec![
+            InfoMacSec::Sci(0x5254_0012_3456_0001),
+            InfoMacSec::CipherSuite(MACSEC_CIPHER_ID_GCM_AES_256),
+            InfoMacSec::Encrypt(1),
+        ];
+    }
+
+    #[test]
+    fn parse_info_macsec() {
+        let nla = NlaBuffer::new_checked(&MACSEC[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_macsec() {
+        let nlas = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 48);
+
+        let mut vec = vec![0xff; 48];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACSEC[..]);
+    }
+
+    #[rustfmt::skip]
+    static BAREUDP: [u8; 36] = [
+        0x0c, 0x00, // length = 12
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x62, 0x61, 0x72, 0x65, 0x75, 0x64, 0x70, 0x00, // V = "bareudp\0"
+
+        0x18, 0x00, // length = 24
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x06, 0x00, // length = 6
+            0x01, 0x00, // type = 1 = IFLA_BAREUDP_PORT
+            0x19, 0xeb, // V = 6635 (big endian)
+            0x00, 0x00, // padding
+
+            0x06, 0x00, // length = 6
+            0x02, 0x00, // type = 2 = IFLA_BAREUDP_ETHERTYPE
+            0x88, 0x47, // V = 0x8847 = ETH_P_MPLS_UC (big endian)
+            0x00, 0x00, // padding
+
+            0x04, 0x00, // length = 4
+            0x04, 0x00, // type = 4 = IFLA_BAREUDP_MULTIPROTO_MODE
+    ];
+
+    lazy_static! {
+        static ref BAREUDP_INFO: Vec<InfoBareUdp> = vec![
+            InfoBareUdp::Port(6635),
+            InfoBareUdp::EtherType(0x8847),
+            InfoBareUdp::MultiProtoMode,
+        ];
+    }
+
+    #[test]
+    fn parse_info_bareudp() {
+        let nla = NlaBuffer::new_checked(&BAREUDP[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::BareUdp),
+            Info::Data(InfoData::BareUdp(BAREUDP_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_bareudp() {
+        let nlas = vec![
+            Info::Kind(InfoKind::BareUdp),
+            Info::Data(InfoData::BareUdp(BAREUDP_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 36);
+
+        let mut vec = vec![0xff; 36];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BAREUDP[..]);
+    }
+
+    #[rustfmt::skip]
+    static GTP_INFO_BYTES: [u8; 28] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x74, 0x70, 0x00, // V = "gtp\0"
+
+        0x14, 0x00, // length = 20
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, // length = 8
+            0x04, 0x00, // type = 4 = IFLA_GTP_ROLE
+            0x01, 0x00, 0x00, 0x00, // V = 1 = GTP_ROLE_SGSN
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_GTP_PDP_HASHSIZE
+            0x00, 0x04, 0x00, 0x00, // V = 1024
+    ];
+
+    #[test]
+    fn parse_info_gtp() {
+        let nla = NlaBuffer::new_checked(&GTP_INFO_BYTES[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Gtp),
+            Info::Data(InfoData::Gtp(vec![
+                InfoGtp::Role(GTP_ROLE_SGSN),
+                InfoGtp::PdpHashsize(1024),
+            ])),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_gtp() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Gtp),
+            Info::Data(InfoData::Gtp(vec![
+                InfoGtp::Role(GTP_ROLE_SGSN),
+                InfoGtp::PdpHashsize(1024),
+            ])),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 28);
+
+        let mut vec = vec![0xff; 28];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GTP_INFO_BYTES[..]);
+    }
+
+    #[rustfmt::skip]
+    static BOND: [u8; 52] = [
+        0x09, 0x00, // length = 9
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // V = "bond\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x28, 0x00, // length = 40
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BOND_MODE
+            0x01, // V = 1 = active-backup
+            0x00, 0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x02, 0x00, // type = 2 = IFLA_BOND_ACTIVE_SLAVE
+            0x05, 0x00, 0x00, 0x00, // V = 5
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BOND_MIIMON
+            0x64, 0x00, 0x00, 0x00, // V = 100
+
+            0x0c, 0x00, // length = 12
+            0x08, 0x00, // type = 8 = IFLA_BOND_ARP_IP_TARGET
+                0x08, 0x00, // length = 8
+                0x00, 0x00, // type = 0 (index of the target)
+                0xc0, 0xa8, 0x01, 0x01, // V = 192.168.1.1
+    ];
+
+    lazy_static! {
+        static ref BOND_INFO: Vec<InfoBond> = vec![
+            InfoBond::Mode(1),
+            InfoBond::ActiveSlave(5),
+            InfoBond::MiiMon(100),
+            InfoBond::ArpIpTarget(vec![Ipv4Addr::new(192, 168, 1, 1)]),
+        ];
+    }
+
+    #[test]
+    fn parse_info_bond() {
+        let nla = NlaBuffer::new_checked(&BOND[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Bond),
+            Info::Data(InfoData::Bond(BOND_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_bond() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Bond),
+            Info::Data(InfoData::Bond(BOND_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 52);
+
+        let mut vec = vec![0xff; 52];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BOND[..]);
+    }
+
+    #[rustfmt::skip]
+    static BOND_SLAVE: [u8; 60] = [
+        0x09, 0x00, // length = 9
+        0x04, 0x00, // type = 4 = IFLA_INFO_SLAVE_KIND
+        0x62, 0x6f, 0x6e, 0x64, 0x00, // V = "bond\0"
+        0x00, 0x00, 0x00, // padding
+
+        0x30, 0x00, // length = 48
+        0x05, 0x00, // type = 5 = IFLA_INFO_SLAVE_DATA
+            0x05, 0x00, // length = 5
+            0x01, 0x00, // type = 1 = IFLA_BOND_SLAVE_STATE
+            0x00, // V = 0 = active
+            0x00, 0x00, 0x00, // padding
+
+            0x05, 0x00, // length = 5
+            0x02, 0x00, // type = 2 = IFLA_BOND_SLAVE_MII_STATUS
+            0x00, // V = 0 = up
+            0x00, 0x00, 0x00, // padding
+
+            0x08, 0x00, // length = 8
+            0x03, 0x00, // type = 3 = IFLA_BOND_SLAVE_LINK_FAILURE_COUNT
+            0x02, 0x00, 0x00, 0x00, // V = 2
+
+            0x0a, 0x00, // length = 10
+            0x04, 0x00, // type = 4 = IFLA_BOND_SLAVE_PERM_HWADDR
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x01, // V = 02:00:00:00:00:01
+            0x00, 0x00, // padding
+
+            0x06, 0x00, // length = 6
+            0x05, 0x00, // type = 5 = IFLA_BOND_SLAVE_QUEUE_ID
+            0x00, 0x00, // V = 0
+            0x00, 0x00, // padding
+    ];
+
+    lazy_static! {
+        static ref BOND_SLAVE_INFO: Vec<InfoBondPort> = vec![
+            InfoBondPort::State(0),
+            InfoBondPort::MiiStatus(0),
+            InfoBondPort::LinkFailureCount(2),
+            InfoBondPort::PermHwaddr(vec![0x02, 0x00, 0x00, 0x00, 0x00, 0x01]),
+            InfoBondPort::QueueId(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_bond_slave() {
+        let nla = NlaBuffer::new_checked(&BOND_SLAVE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::SlaveKind(b"bond\0".to_vec()),
+            Info::SlaveData(InfoSlaveData::Bond(BOND_SLAVE_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_bond_slave() {
+        let nlas = vec![
+            Info::SlaveKind(b"bond\0".to_vec()),
+            Info::SlaveData(InfoSlaveData::Bond(BOND_SLAVE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 60);
+
+        let mut vec = vec![0xff; 60];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &BOND_SLAVE[..]);
+    }
+
     #[test]
     fn parse() {
         let nla = NlaBuffer::new_checked(&BRIDGE[..]).unwrap();
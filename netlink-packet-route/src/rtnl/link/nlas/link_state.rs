@@ -2,6 +2,8 @@
 
 use crate::constants::*;
 
+/// Operational state of a link (`IFLA_OPERSTATE`), as defined by RFC 2863. Each variant maps to
+/// the kernel's `IF_OPER_*` value of the same name.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum State {
     /// Status can't be determined
@@ -9,6 +9,9 @@ pub use self::inet6::*;
 mod af_spec_inet;
 pub use self::af_spec_inet::*;
 
+mod af_spec_bridge;
+pub use self::af_spec_bridge::*;
+
 mod link_infos;
 pub use self::link_infos::*;
 
@@ -27,6 +30,12 @@ pub use self::stats64::*;
 mod link_state;
 pub use self::link_state::*;
 
+mod phys_id;
+pub use self::phys_id::*;
+
+mod ext_mask;
+pub use self::ext_mask::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -103,6 +112,12 @@ pub enum Nla {
     /// [1]: https://lwn.net/ml/netdev/20190719110029.29466-1-jiri@resnulli.us/
     /// [2]: https://lwn.net/ml/netdev/20190930094820.11281-1-jiri@resnulli.us/
     AltIfName(String),
+    /// Name of the parent device of the link on its bus (for instance the PCI address
+    /// `0000:03:00.0`, or the name of an auxiliary device). Unlike [`Nla::Link`], it does not
+    /// refer to another network interface.
+    ParentDevName(String),
+    /// Name of the bus of the parent device (for instance `pci` or `auxiliary`)
+    ParentDevBusName(String),
     // byte
     Mode(u8),
     Carrier(u8),
@@ -123,6 +138,13 @@ pub enum Nla {
     CarrierChanges(u32),
     GsoMaxSegs(u32),
     GsoMaxSize(u32),
+    GroMaxSize(u32),
+    /// The largest TSO packet the device supports. Unlike the GSO limits, which cannot exceed
+    /// it, this is reported by the kernel and cannot be set.
+    TsoMaxSize(u32),
+    /// The maximum number of segments of a TSO packet the device supports, reported by the
+    /// kernel like [`Nla::TsoMaxSize`].
+    TsoMaxSegs(u32),
     /// The minimum MTU for the device.
     /// For additional context see the related [linux kernel message][1].
     ///
@@ -142,8 +164,7 @@ pub enum Nla {
     Map(Vec<u8>),
     // AF_SPEC (the type of af_spec depends on the interface family of the message)
     AfSpecInet(Vec<AfSpecInet>),
-    // AfSpecBridge(Vec<AfSpecBridgeNla>),
-    AfSpecBridge(Vec<u8>),
+    AfSpecBridge(Vec<AfSpecBridge>),
     AfSpecUnknown(Vec<u8>),
     Other(DefaultNla),
 }
@@ -177,7 +198,6 @@ impl nlas::Nla for Nla {
                 | Broadcast(ref bytes)
                 | PermAddress(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
                 | Map(ref bytes)
                 | ProtoDownReason(ref bytes)
                 => bytes.len(),
@@ -188,6 +208,8 @@ impl nlas::Nla for Nla {
                 | IfAlias(ref string)
                 | PhysPortName(ref string)
                 | AltIfName(ref string)
+                | ParentDevName(ref string)
+                | ParentDevBusName(ref string)
                 => string.as_bytes().len() + 1,
 
             // u8
@@ -212,6 +234,9 @@ impl nlas::Nla for Nla {
                 | CarrierChanges(_)
                 | GsoMaxSegs(_)
                 | GsoMaxSize(_)
+                | GroMaxSize(_)
+                | TsoMaxSize(_)
+                | TsoMaxSegs(_)
                 | NetnsId(_)
                 | MinMtu(_)
                 | MaxMtu(_) => 4,
@@ -223,7 +248,7 @@ impl nlas::Nla for Nla {
             Info(ref nlas) => nlas.as_slice().buffer_len(),
             PropList(ref nlas) => nlas.as_slice().buffer_len(),
             AfSpecInet(ref nlas) => nlas.as_slice().buffer_len(),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
+            AfSpecBridge(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr)  => attr.value_len(),
         }
     }
@@ -258,7 +283,6 @@ impl nlas::Nla for Nla {
                 | Broadcast(ref bytes)
                 | PermAddress(ref bytes)
                 | AfSpecUnknown(ref bytes)
-                | AfSpecBridge(ref bytes)
                 | Stats(ref bytes)
                 | Stats64(ref bytes)
                 | Map(ref bytes)
@@ -271,6 +295,8 @@ impl nlas::Nla for Nla {
                 | IfAlias(ref string)
                 | PhysPortName(ref string)
                 | AltIfName(ref string)
+                | ParentDevName(ref string)
+                | ParentDevBusName(ref string)
                 => {
                     buffer[..string.len()].copy_from_slice(string.as_bytes());
                     buffer[string.len()] = 0;
@@ -297,6 +323,9 @@ impl nlas::Nla for Nla {
                 | CarrierChanges(ref value)
                 | GsoMaxSegs(ref value)
                 | GsoMaxSize(ref value)
+                | GroMaxSize(ref value)
+                | TsoMaxSize(ref value)
+                | TsoMaxSegs(ref value)
                 | MinMtu(ref value)
                 | MaxMtu(ref value)
                 => NativeEndian::write_u32(buffer, *value),
@@ -309,7 +338,7 @@ impl nlas::Nla for Nla {
             Info(ref nlas) => nlas.as_slice().emit(buffer),
             PropList(ref nlas) => nlas.as_slice().emit(buffer),
             AfSpecInet(ref nlas) => nlas.as_slice().emit(buffer),
-            // AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
+            AfSpecBridge(ref nlas) => nlas.as_slice().emit(buffer),
             // default nlas
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -351,6 +380,8 @@ impl nlas::Nla for Nla {
             IfAlias(_) => IFLA_IFALIAS,
             PhysPortName(_) => IFLA_PHYS_PORT_NAME,
             AltIfName(_) => IFLA_ALT_IFNAME,
+            ParentDevName(_) => IFLA_PARENT_DEV_NAME,
+            ParentDevBusName(_) => IFLA_PARENT_DEV_BUS_NAME,
             // u8
             Mode(_) => IFLA_LINKMODE,
             Carrier(_) => IFLA_CARRIER,
@@ -371,6 +402,9 @@ impl nlas::Nla for Nla {
             CarrierChanges(_) => IFLA_CARRIER_CHANGES,
             GsoMaxSegs(_) => IFLA_GSO_MAX_SEGS,
             GsoMaxSize(_) => IFLA_GSO_MAX_SIZE,
+            GroMaxSize(_) => IFLA_GRO_MAX_SIZE,
+            TsoMaxSize(_) => IFLA_TSO_MAX_SIZE,
+            TsoMaxSegs(_) => IFLA_TSO_MAX_SEGS,
             MinMtu(_) => IFLA_MIN_MTU,
             MaxMtu(_) => IFLA_MAX_MTU,
             // i32
@@ -440,6 +474,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
             IFLA_ALT_IFNAME => {
                 AltIfName(parse_string(payload).context("invalid IFLA_ALT_IFNAME value")?)
             }
+            IFLA_PARENT_DEV_NAME => {
+                ParentDevName(parse_string(payload).context("invalid IFLA_PARENT_DEV_NAME value")?)
+            }
+            IFLA_PARENT_DEV_BUS_NAME => ParentDevBusName(
+                parse_string(payload).context("invalid IFLA_PARENT_DEV_BUS_NAME value")?,
+            ),
 
             // u8
             IFLA_LINKMODE => Mode(parse_u8(payload).context("invalid IFLA_LINKMODE value")?),
@@ -477,6 +517,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
             IFLA_GSO_MAX_SIZE => {
                 GsoMaxSize(parse_u32(payload).context("invalid IFLA_GSO_MAX_SIZE value")?)
             }
+            IFLA_GRO_MAX_SIZE => {
+                GroMaxSize(parse_u32(payload).context("invalid IFLA_GRO_MAX_SIZE value")?)
+            }
+            IFLA_TSO_MAX_SIZE => {
+                TsoMaxSize(parse_u32(payload).context("invalid IFLA_TSO_MAX_SIZE value")?)
+            }
+            IFLA_TSO_MAX_SEGS => {
+                TsoMaxSegs(parse_u32(payload).context("invalid IFLA_TSO_MAX_SEGS value")?)
+            }
             IFLA_MIN_MTU => MinMtu(parse_u32(payload).context("invalid IFLA_MIN_MTU value")?),
             IFLA_MAX_MTU => MaxMtu(parse_u32(payload).context("invalid IFLA_MAX_MTU value")?),
             IFLA_LINK_NETNSID => {
@@ -500,7 +549,15 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> f
                     }
                     AfSpecInet(nlas)
                 }
-                AF_BRIDGE => AfSpecBridge(payload.to_vec()),
+                AF_BRIDGE => {
+                    let mut nlas = vec![];
+                    let err = "invalid IFLA_AF_SPEC value for AF_BRIDGE";
+                    for nla in NlasIterator::new(payload) {
+                        let nla = nla.context(err)?;
+                        nlas.push(af_spec_bridge::AfSpecBridge::parse(&nla).context(err)?);
+                    }
+                    AfSpecBridge(nlas)
+                }
                 _ => AfSpecUnknown(payload.to_vec()),
             },
             IFLA_LINKINFO => {
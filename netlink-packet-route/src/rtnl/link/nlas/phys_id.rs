@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+/// Opaque identifier of a physical port (`IFLA_PHYS_PORT_ID`) or of the switch a port belongs to
+/// (`IFLA_PHYS_SWITCH_ID`). Links reporting the same switch id, such as the representors of a
+/// switchdev NIC, are ports of the same switch.
+///
+/// It is displayed in hexadecimal, like `ip -d link show` does.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PhysId(pub Vec<u8>);
+
+impl fmt::Display for PhysId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Vec<u8>> for PhysId {
+    fn from(id: Vec<u8>) -> Self {
+        PhysId(id)
+    }
+}
@@ -33,7 +33,7 @@ buffer!(Stats64Buffer(LINK_STATS64_LEN) {
     rx_nohandler: (u64, 184..192),
 });
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct Stats64 {
     /// total packets received
     pub rx_packets: u64,
@@ -147,3 +147,139 @@ impl Emitable for Stats64 {
         buffer.set_rx_nohandler(self.rx_nohandler);
     }
 }
+
+impl Stats64 {
+    /// Compute the difference between these counters and an earlier snapshot `previous` of the
+    /// same link, for instance to compute rates.
+    ///
+    /// A counter lower than in `previous` is either a counter that wrapped around `u64::MAX`,
+    /// or a counter that was reset (by a driver reload, for instance). It is considered to have
+    /// wrapped around if the delta modulo 2^64 is less than half the range of the counter, and
+    /// to have been reset otherwise, in which case its delta is `None`.
+    pub fn delta(&self, previous: &Stats64) -> Stats64Delta {
+        Stats64Delta {
+            rx_packets: counter_delta(self.rx_packets, previous.rx_packets),
+            tx_packets: counter_delta(self.tx_packets, previous.tx_packets),
+            rx_bytes: counter_delta(self.rx_bytes, previous.rx_bytes),
+            tx_bytes: counter_delta(self.tx_bytes, previous.tx_bytes),
+            rx_errors: counter_delta(self.rx_errors, previous.rx_errors),
+            tx_errors: counter_delta(self.tx_errors, previous.tx_errors),
+            rx_dropped: counter_delta(self.rx_dropped, previous.rx_dropped),
+            tx_dropped: counter_delta(self.tx_dropped, previous.tx_dropped),
+            multicast: counter_delta(self.multicast, previous.multicast),
+            collisions: counter_delta(self.collisions, previous.collisions),
+            rx_length_errors: counter_delta(self.rx_length_errors, previous.rx_length_errors),
+            rx_over_errors: counter_delta(self.rx_over_errors, previous.rx_over_errors),
+            rx_crc_errors: counter_delta(self.rx_crc_errors, previous.rx_crc_errors),
+            rx_frame_errors: counter_delta(self.rx_frame_errors, previous.rx_frame_errors),
+            rx_fifo_errors: counter_delta(self.rx_fifo_errors, previous.rx_fifo_errors),
+            rx_missed_errors: counter_delta(self.rx_missed_errors, previous.rx_missed_errors),
+            tx_aborted_errors: counter_delta(self.tx_aborted_errors, previous.tx_aborted_errors),
+            tx_carrier_errors: counter_delta(self.tx_carrier_errors, previous.tx_carrier_errors),
+            tx_fifo_errors: counter_delta(self.tx_fifo_errors, previous.tx_fifo_errors),
+            tx_heartbeat_errors: counter_delta(
+                self.tx_heartbeat_errors,
+                previous.tx_heartbeat_errors,
+            ),
+            tx_window_errors: counter_delta(self.tx_window_errors, previous.tx_window_errors),
+            rx_compressed: counter_delta(self.rx_compressed, previous.rx_compressed),
+            tx_compressed: counter_delta(self.tx_compressed, previous.tx_compressed),
+            rx_nohandler: counter_delta(self.rx_nohandler, previous.rx_nohandler),
+        }
+    }
+}
+
+fn counter_delta(current: u64, previous: u64) -> Option<u64> {
+    let delta = current.wrapping_sub(previous);
+    if delta <= u64::MAX / 2 {
+        Some(delta)
+    } else {
+        None
+    }
+}
+
+/// Difference between two [`Stats64`] snapshots (see [`Stats64::delta`]). A `None` field means
+/// the counter was reset between the two snapshots.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Stats64Delta {
+    pub rx_packets: Option<u64>,
+    pub tx_packets: Option<u64>,
+    pub rx_bytes: Option<u64>,
+    pub tx_bytes: Option<u64>,
+    pub rx_errors: Option<u64>,
+    pub tx_errors: Option<u64>,
+    pub rx_dropped: Option<u64>,
+    pub tx_dropped: Option<u64>,
+    pub multicast: Option<u64>,
+    pub collisions: Option<u64>,
+    pub rx_length_errors: Option<u64>,
+    pub rx_over_errors: Option<u64>,
+    pub rx_crc_errors: Option<u64>,
+    pub rx_frame_errors: Option<u64>,
+    pub rx_fifo_errors: Option<u64>,
+    pub rx_missed_errors: Option<u64>,
+    pub tx_aborted_errors: Option<u64>,
+    pub tx_carrier_errors: Option<u64>,
+    pub tx_fifo_errors: Option<u64>,
+    pub tx_heartbeat_errors: Option<u64>,
+    pub tx_window_errors: Option<u64>,
+    pub rx_compressed: Option<u64>,
+    pub tx_compressed: Option<u64>,
+    pub rx_nohandler: Option<u64>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn delta() {
+        let previous = Stats64 {
+            rx_packets: 10,
+            rx_bytes: 1_000,
+            tx_packets: 5,
+            ..Default::default()
+        };
+        let current = Stats64 {
+            rx_packets: 25,
+            rx_bytes: 3_500,
+            tx_packets: 5,
+            ..Default::default()
+        };
+        let delta = current.delta(&previous);
+        assert_eq!(delta.rx_packets, Some(15));
+        assert_eq!(delta.rx_bytes, Some(2_500));
+        assert_eq!(delta.tx_packets, Some(0));
+        assert_eq!(delta.rx_errors, Some(0));
+    }
+
+    #[test]
+    fn delta_wrap_around() {
+        let previous = Stats64 {
+            rx_bytes: u64::MAX - 99,
+            ..Default::default()
+        };
+        let current = Stats64 {
+            rx_bytes: 400,
+            ..Default::default()
+        };
+        assert_eq!(current.delta(&previous).rx_bytes, Some(500));
+    }
+
+    #[test]
+    fn delta_reset() {
+        let previous = Stats64 {
+            rx_packets: 1_000_000,
+            tx_packets: 2_000,
+            ..Default::default()
+        };
+        let current = Stats64 {
+            rx_packets: 42,
+            tx_packets: 2_010,
+            ..Default::default()
+        };
+        let delta = current.delta(&previous);
+        assert_eq!(delta.rx_packets, None);
+        assert_eq!(delta.tx_packets, Some(10));
+    }
+}
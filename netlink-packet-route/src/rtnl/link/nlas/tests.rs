@@ -191,7 +191,7 @@ lazy_static! {
             reachable_time: 25730,
             retrans_time: 1000,
         })),
-        Inet6::DevConf(get_byte_buffer(&Inet6DevConf {
+        Inet6::DevConf(Inet6DevConf {
             forwarding: 0,
             hoplimit: 64,
             mtu6: 65536,
@@ -243,7 +243,8 @@ lazy_static! {
             disable_policy: 0,
             accept_ra_rt_info_min_plen: 0,
             ndisc_tclass: 0,
-        })),
+            extra: vec![],
+        }),
         Inet6::Stats(get_byte_buffer(&Inet6Stats {
             num: 36,
             in_pkts: 6,
@@ -411,3 +412,97 @@ fn parse_af_inet6() {
     // Normally this is the end of the nla iterator
     assert!(nlas.next().is_none());
 }
+
+#[test]
+fn parse_af_inet6_dev_conf() {
+    let inet6_buf = get_nlas().nth(1).unwrap().unwrap();
+    let dev_conf = match AfSpecInet::parse(&inet6_buf).unwrap() {
+        AfSpecInet::Inet6(nlas) => nlas.into_iter().find_map(|nla| match nla {
+            Inet6::DevConf(dev_conf) => Some(dev_conf),
+            _ => None,
+        }),
+        nla => panic!("unexpected nla {:?}", nla),
+    }
+    .unwrap();
+    assert_eq!(dev_conf.forwarding, 0);
+    assert_eq!(dev_conf.mtu6, 65536);
+    assert_eq!(dev_conf.accept_ra, 1);
+}
+
+#[test]
+fn parse_inet6_dev_conf_any_length() {
+    let mut array = vec![0u8; (DEVCONF_NDISC_TCLASS + 3) * 4];
+    array[DEVCONF_FORWARDING * 4] = 1;
+    array[DEVCONF_MTU6 * 4..DEVCONF_MTU6 * 4 + 4].copy_from_slice(&1280i32.to_ne_bytes());
+    array[DEVCONF_NDISC_TCLASS * 4] = 4;
+    // settings added by newer kernels
+    array[(DEVCONF_NDISC_TCLASS + 1) * 4] = 0xff;
+
+    let dev_conf = Inet6DevConf::parse_array(&array).unwrap();
+    assert_eq!(dev_conf.forwarding, 1);
+    assert_eq!(dev_conf.mtu6, 1280);
+    assert_eq!(dev_conf.ndisc_tclass, 4);
+    assert_eq!(dev_conf.extra.len(), 8);
+
+    // the unknown settings are emitted back
+    let mut emitted = vec![0; dev_conf.buffer_len()];
+    dev_conf.emit(&mut emitted);
+    assert_eq!(emitted, array);
+
+    // older kernels only know about the first settings
+    let dev_conf = Inet6DevConf::parse_array(&array[..(DEVCONF_MTU6 + 1) * 4]).unwrap();
+    assert_eq!(dev_conf.forwarding, 1);
+    assert_eq!(dev_conf.mtu6, 1280);
+    assert_eq!(dev_conf.accept_ra, 0);
+    assert_eq!(dev_conf.ndisc_tclass, 0);
+}
+
+// IFLA_AF_SPEC of a bridge port with `bridge vlan add vid 10 dev dummy0 pvid untagged` and
+// `bridge vlan add vid 20-30 dev dummy0`, dumped with RTEXT_FILTER_BRVLAN
+#[rustfmt::skip]
+static AF_BRIDGE_BYTES: [u8; 28] = [
+    0x1c, 0x00, 0x1a, 0x00, // IFLA_AF_SPEC (L=28, T=26)
+
+    0x08, 0x00, 0x02, 0x00, // IFLA_BRIDGE_VLAN_INFO (L=8, T=2)
+    0x06, 0x00, // flags: PVID | UNTAGGED
+    0x0a, 0x00, // vid: 10
+
+    0x08, 0x00, 0x02, 0x00, // IFLA_BRIDGE_VLAN_INFO (L=8, T=2)
+    0x08, 0x00, // flags: RANGE_BEGIN
+    0x14, 0x00, // vid: 20
+
+    0x08, 0x00, 0x02, 0x00, // IFLA_BRIDGE_VLAN_INFO (L=8, T=2)
+    0x10, 0x00, // flags: RANGE_END
+    0x1e, 0x00, // vid: 30
+];
+
+lazy_static! {
+    static ref PARSED_AF_BRIDGE: super::Nla = super::Nla::AfSpecBridge(vec![
+        AfSpecBridge::VlanInfo(BridgeVlanInfo {
+            flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+            vid: 10,
+        }),
+        AfSpecBridge::VlanInfo(BridgeVlanInfo {
+            flags: BRIDGE_VLAN_INFO_RANGE_BEGIN,
+            vid: 20,
+        }),
+        AfSpecBridge::VlanInfo(BridgeVlanInfo {
+            flags: BRIDGE_VLAN_INFO_RANGE_END,
+            vid: 30,
+        }),
+    ]);
+}
+
+#[test]
+fn parse_af_bridge() {
+    let buf = NlaBuffer::new_checked(&AF_BRIDGE_BYTES[..]).unwrap();
+    let parsed = super::Nla::parse_with_param(&buf, AF_BRIDGE).unwrap();
+    assert_eq!(parsed, *PARSED_AF_BRIDGE);
+}
+
+#[test]
+fn emit_af_bridge() {
+    let mut buf = vec![0xff; PARSED_AF_BRIDGE.buffer_len()];
+    PARSED_AF_BRIDGE.emit(&mut buf);
+    assert_eq!(&buf[..], &AF_BRIDGE_BYTES[..]);
+}
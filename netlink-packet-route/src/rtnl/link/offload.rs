@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+
+use crate::nlas::link::Nla;
+
+/// Segmentation and aggregation limits of a link, as returned by
+/// [`LinkMessage::offload_info`](crate::LinkMessage::offload_info). The limits that the kernel
+/// does not report, for instance because it predates them, are `None`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct LinkOffloadInfo {
+    /// Largest GSO packet the stack builds for the link (`IFLA_GSO_MAX_SIZE`)
+    pub gso_max_size: Option<u32>,
+    /// Maximum number of segments of a GSO packet (`IFLA_GSO_MAX_SEGS`)
+    pub gso_max_segs: Option<u32>,
+    /// Largest packet GRO builds from the segments received on the link (`IFLA_GRO_MAX_SIZE`)
+    pub gro_max_size: Option<u32>,
+    /// Largest TSO packet the device supports (`IFLA_TSO_MAX_SIZE`). The GSO limits cannot be
+    /// set above the TSO ones.
+    pub tso_max_size: Option<u32>,
+    /// Maximum number of segments of a TSO packet the device supports (`IFLA_TSO_MAX_SEGS`)
+    pub tso_max_segs: Option<u32>,
+}
+
+impl<'a> From<&'a [Nla]> for LinkOffloadInfo {
+    fn from(nlas: &'a [Nla]) -> Self {
+        let mut info = LinkOffloadInfo::default();
+        for nla in nlas {
+            match nla {
+                Nla::GsoMaxSize(size) => info.gso_max_size = Some(*size),
+                Nla::GsoMaxSegs(segments) => info.gso_max_segs = Some(*segments),
+                Nla::GroMaxSize(size) => info.gro_max_size = Some(*size),
+                Nla::TsoMaxSize(size) => info.tso_max_size = Some(*size),
+                Nla::TsoMaxSegs(segments) => info.tso_max_segs = Some(*segments),
+                _ => {}
+            }
+        }
+        info
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: MIT
+
+use std::{convert::TryFrom, net::IpAddr};
+
+use crate::{
+    constants::GRE_KEY,
+    nlas::link::{InfoGre, InfoIpTun, InfoVxlan},
+};
+
+/// Parse the address of a tunnel endpoint. The kernel reports the endpoints that are not set as
+/// the unspecified address, so these are dropped.
+fn endpoint(octets: &[u8]) -> Option<IpAddr> {
+    let addr = if let Ok(octets) = <[u8; 4]>::try_from(octets) {
+        IpAddr::from(octets)
+    } else {
+        IpAddr::from(<[u8; 16]>::try_from(octets).ok()?)
+    };
+    Some(addr).filter(|addr| !addr.is_unspecified())
+}
+
+/// Endpoints and parameters of a tunnel, as returned by
+/// [`LinkMessage::tunnel_info`](crate::LinkMessage::tunnel_info)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum TunnelInfo {
+    Vxlan(VxlanInfo),
+    /// GRE tunnels, over IPv4 or IPv6, carrying IP packets (`gre` and `ip6gre` links) or
+    /// Ethernet frames (`gretap` and `ip6gretap` links)
+    Gre(GreInfo),
+    /// IPv4 tunnels carrying IPv4 (`ipip` links) or IPv6 packets (`sit` links)
+    IpTunnel(IpTunnelInfo),
+}
+
+impl TunnelInfo {
+    /// Returns the local endpoint of the tunnel, if it is set
+    pub fn local(&self) -> Option<IpAddr> {
+        match self {
+            TunnelInfo::Vxlan(info) => info.local,
+            TunnelInfo::Gre(info) => info.local,
+            TunnelInfo::IpTunnel(info) => info.local,
+        }
+    }
+
+    /// Returns the remote endpoint of the tunnel, if it is set. For VXLAN links, this can be the
+    /// multicast group the link joins.
+    pub fn remote(&self) -> Option<IpAddr> {
+        match self {
+            TunnelInfo::Vxlan(info) => info.remote,
+            TunnelInfo::Gre(info) => info.remote,
+            TunnelInfo::IpTunnel(info) => info.remote,
+        }
+    }
+}
+
+/// Endpoints and parameters of a VXLAN link, read from its [`InfoVxlan`] attributes
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct VxlanInfo {
+    /// VXLAN network identifier
+    pub id: Option<u32>,
+    pub local: Option<IpAddr>,
+    /// Remote endpoint, or multicast group (`IFLA_VXLAN_GROUP` or `IFLA_VXLAN_GROUP6`)
+    pub remote: Option<IpAddr>,
+    /// Destination UDP port
+    pub port: Option<u16>,
+    /// Index of the link the encapsulated packets are sent through, if the tunnel is bound to one
+    pub link: Option<u32>,
+    pub ttl: Option<u8>,
+    pub tos: Option<u8>,
+}
+
+impl<'a> From<&'a [InfoVxlan]> for VxlanInfo {
+    fn from(nlas: &'a [InfoVxlan]) -> Self {
+        let mut info = VxlanInfo::default();
+        for nla in nlas {
+            match nla {
+                InfoVxlan::Id(id) => info.id = Some(*id),
+                InfoVxlan::Local(addr) | InfoVxlan::Local6(addr) => info.local = endpoint(addr),
+                InfoVxlan::Group(addr) | InfoVxlan::Group6(addr) => info.remote = endpoint(addr),
+                InfoVxlan::Port(port) => info.port = Some(*port),
+                InfoVxlan::Link(link) => info.link = Some(*link).filter(|link| *link != 0),
+                InfoVxlan::Ttl(ttl) => info.ttl = Some(*ttl),
+                InfoVxlan::Tos(tos) => info.tos = Some(*tos),
+                _ => {}
+            }
+        }
+        info
+    }
+}
+
+/// Endpoints and parameters of a GRE link, read from its [`InfoGre`] attributes
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GreInfo {
+    pub local: Option<IpAddr>,
+    pub remote: Option<IpAddr>,
+    /// Index of the link the encapsulated packets are sent through, if the tunnel is bound to one
+    pub link: Option<u32>,
+    pub ttl: Option<u8>,
+    pub tos: Option<u8>,
+    /// Key of the received packets. The kernel always reports a key, so it is only set when the
+    /// `GRE_KEY` flag of the link is set.
+    pub ikey: Option<u32>,
+    /// Key of the sent packets, only set when the `GRE_KEY` flag of the link is set
+    pub okey: Option<u32>,
+}
+
+impl<'a> From<&'a [InfoGre]> for GreInfo {
+    fn from(nlas: &'a [InfoGre]) -> Self {
+        let mut info = GreInfo::default();
+        let (mut iflags, mut oflags) = (0, 0);
+        for nla in nlas {
+            match nla {
+                InfoGre::Local(addr) => info.local = endpoint(addr),
+                InfoGre::Remote(addr) => info.remote = endpoint(addr),
+                InfoGre::Link(link) => info.link = Some(*link).filter(|link| *link != 0),
+                InfoGre::Ttl(ttl) => info.ttl = Some(*ttl),
+                InfoGre::Tos(tos) => info.tos = Some(*tos),
+                InfoGre::IFlags(flags) => iflags = *flags,
+                InfoGre::OFlags(flags) => oflags = *flags,
+                InfoGre::IKey(key) => info.ikey = Some(*key),
+                InfoGre::OKey(key) => info.okey = Some(*key),
+                _ => {}
+            }
+        }
+        info.ikey = info.ikey.filter(|_| iflags & GRE_KEY != 0);
+        info.okey = info.okey.filter(|_| oflags & GRE_KEY != 0);
+        info
+    }
+}
+
+/// Endpoints and parameters of an IPIP or SIT link, read from its [`InfoIpTun`] attributes
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct IpTunnelInfo {
+    pub local: Option<IpAddr>,
+    pub remote: Option<IpAddr>,
+    /// Index of the link the encapsulated packets are sent through, if the tunnel is bound to one
+    pub link: Option<u32>,
+    pub ttl: Option<u8>,
+    pub tos: Option<u8>,
+}
+
+impl<'a> From<&'a [InfoIpTun]> for IpTunnelInfo {
+    fn from(nlas: &'a [InfoIpTun]) -> Self {
+        let mut info = IpTunnelInfo::default();
+        for nla in nlas {
+            match nla {
+                InfoIpTun::Local(addr) => info.local = endpoint(addr),
+                InfoIpTun::Remote(addr) => info.remote = endpoint(addr),
+                InfoIpTun::Link(link) => info.link = Some(*link).filter(|link| *link != 0),
+                InfoIpTun::Ttl(ttl) => info.ttl = Some(*ttl),
+                InfoIpTun::Tos(tos) => info.tos = Some(*tos),
+                _ => {}
+            }
+        }
+        info
+    }
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const MDB_HEADER_LEN: usize = 8;
+
+buffer!(MdbMessageBuffer(MDB_HEADER_LEN) {
+    family: (u8, 0),
+    ifindex: (u32, 4..8),
+    payload: (slice, MDB_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> MdbMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT
+
+use super::{MdbMessageBuffer, MDB_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Header of the bridge multicast database messages (`struct br_port_msg`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbHeader {
+    /// Address family, always `AF_BRIDGE`
+    pub family: u8,
+    /// Index of the bridge. When dumping the database, zero dumps the entries of all the bridges.
+    pub ifindex: u32,
+}
+
+impl Emitable for MdbHeader {
+    fn buffer_len(&self) -> usize {
+        MDB_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = MdbMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<MdbMessageBuffer<T>> for MdbHeader {
+    fn parse(buf: &MdbMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(MdbHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::mdb::{MdbEntryInfo, Nla},
+    traits::{Emitable, Parseable},
+    DecodeError,
+    MdbHeader,
+    MdbMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbMessage {
+    pub header: MdbHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl MdbMessage {
+    /// Returns the entries of the `MDBA_MDB` attributes of the message, as found in the dumps
+    /// and notifications of the multicast database.
+    pub fn entries(&self) -> impl Iterator<Item = &MdbEntryInfo> {
+        self.nlas.iter().flat_map(|nla| match nla {
+            Nla::Mdb(entries) => entries.as_slice(),
+            _ => &[][..],
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>> for MdbMessage {
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: MdbHeader::parse(buf).context("failed to parse mdb message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse mdb message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<MdbMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &MdbMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for MdbMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        nlas::mdb::{MdbEntry, MdbEntryInfo, MdbEntryNla, MdbGroup, Nla},
+        traits::{Emitable, ParseableParametrized},
+        MdbHeader,
+        MdbMessage,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_BRIDGE,
+        MDB_PERMANENT,
+        RTM_NEWMDB,
+        RTPROT_STATIC,
+    };
+
+    #[rustfmt::skip]
+    static ADD_ENTRY: [u8; 40] = [
+        // br_port_msg
+        0x07, // family = AF_BRIDGE
+        0x00, 0x00, 0x00, // padding
+        0x0a, 0x00, 0x00, 0x00, // ifindex = 10 (bridge)
+        // NLA
+        0x20, 0x00, // length = 32
+        0x01, 0x00, // type = MDBA_SET_ENTRY
+        0x0b, 0x00, 0x00, 0x00, // ifindex = 11 (port)
+        0x01, // state = MDB_PERMANENT
+        0x00, // flags
+        0x00, 0x00, // vid
+        0xef, 0x01, 0x01, 0x01, // group = 239.1.1.1
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, // proto = ETH_P_IP (network byte order)
+        0x00, 0x00, // padding
+    ];
+
+    #[rustfmt::skip]
+    static DUMPED_ENTRY: [u8; 64] = [
+        // br_port_msg
+        0x07, // family = AF_BRIDGE
+        0x00, 0x00, 0x00, // padding
+        0x0a, 0x00, 0x00, 0x00, // ifindex = 10 (bridge)
+        // NLA
+        0x38, 0x00, // length = 56
+        0x01, 0x00, // type = MDBA_MDB
+            // NLA
+            0x34, 0x00, // length = 52
+            0x01, 0x00, // type = MDBA_MDB_ENTRY
+                // NLA
+                0x30, 0x00, // length = 48
+                0x01, 0x00, // type = MDBA_MDB_ENTRY_INFO
+                0x0b, 0x00, 0x00, 0x00, // ifindex = 11 (port)
+                0x01, // state = MDB_PERMANENT
+                0x00, // flags
+                0x00, 0x00, // vid
+                0xef, 0x01, 0x01, 0x01, // group = 239.1.1.1
+                0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00,
+                0x08, 0x00, // proto = ETH_P_IP (network byte order)
+                0x00, 0x00, // padding
+                    // NLA
+                    0x08, 0x00, // length = 8
+                    0x01, 0x00, // type = MDBA_MDB_EATTR_TIMER
+                    0x00, 0x00, 0x00, 0x00, // 0
+                    // NLA
+                    0x05, 0x00, // length = 5
+                    0x05, 0x00, // type = MDBA_MDB_EATTR_RTPROT
+                    0x04, // RTPROT_STATIC
+                    0x00, 0x00, 0x00, // padding
+    ];
+
+    fn entry() -> MdbEntry {
+        MdbEntry {
+            ifindex: 11,
+            state: MDB_PERMANENT,
+            flags: 0,
+            vid: 0,
+            group: MdbGroup::Ipv4(Ipv4Addr::new(239, 1, 1, 1)),
+        }
+    }
+
+    fn header() -> MdbHeader {
+        MdbHeader {
+            family: AF_BRIDGE as u8,
+            ifindex: 10,
+        }
+    }
+
+    #[test]
+    fn emit_add_entry() {
+        let msg = RtnlMessage::NewMdb(MdbMessage {
+            header: header(),
+            nlas: vec![Nla::SetEntry(entry())],
+        });
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &ADD_ENTRY[..]);
+
+        let parsed =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&ADD_ENTRY), RTM_NEWMDB).unwrap();
+        assert_eq!(parsed, msg);
+    }
+
+    #[test]
+    fn parse_dumped_entry() {
+        let expected = MdbMessage {
+            header: header(),
+            nlas: vec![Nla::Mdb(vec![MdbEntryInfo {
+                entry: entry(),
+                nlas: vec![MdbEntryNla::Timer(0), MdbEntryNla::Protocol(RTPROT_STATIC)],
+            }])],
+        };
+        let parsed =
+            RtnlMessage::parse_with_param(&RtnlMessageBuffer::new(&DUMPED_ENTRY), RTM_NEWMDB)
+                .unwrap();
+        assert_eq!(parsed, RtnlMessage::NewMdb(expected.clone()));
+        assert_eq!(expected.entries().count(), 1);
+        assert_eq!(expected.entries().next().unwrap().timer(), Some(0));
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &DUMPED_ENTRY[..]);
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Multicast group of an MDB entry. The kernel tells the address families apart with the
+/// ethertype of the group (`ETH_P_IP`, `ETH_P_IPV6`, or zero for layer 2 groups).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MdbGroup {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    /// Layer 2 multicast group, supported since Linux 5.11
+    Mac([u8; 6]),
+}
+
+impl MdbGroup {
+    /// The ethertype the kernel identifies the family of the group with
+    pub fn protocol(&self) -> u16 {
+        match self {
+            MdbGroup::Ipv4(_) => ETH_P_IP,
+            MdbGroup::Ipv6(_) => ETH_P_IPV6,
+            MdbGroup::Mac(_) => 0,
+        }
+    }
+}
+
+impl From<IpAddr> for MdbGroup {
+    fn from(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(addr) => MdbGroup::Ipv4(addr),
+            IpAddr::V6(addr) => MdbGroup::Ipv6(addr),
+        }
+    }
+}
+
+/// An entry of the multicast database of a bridge (`struct br_mdb_entry`): the packets sent to
+/// `group` are forwarded to the port `ifindex`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MdbEntry {
+    /// Index of the bridge port. It is the index of the bridge itself for the groups the host
+    /// joined.
+    pub ifindex: u32,
+    /// `MDB_TEMPORARY` or `MDB_PERMANENT`
+    pub state: u8,
+    /// Combination of `MDB_FLAGS_*` constants, only set by the kernel
+    pub flags: u8,
+    /// VLAN of the entry, zero for none
+    pub vid: u16,
+    pub group: MdbGroup,
+}
+
+pub const MDB_ENTRY_LEN: usize = 28;
+
+buffer!(MdbEntryBuffer(MDB_ENTRY_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    // union of an IPv4, IPv6 or MAC address
+    address: (slice, 8..24),
+    // ethertype, in network byte order
+    protocol: (u16, 24..26),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryBuffer<&'a T>> for MdbEntry {
+    fn parse(buf: &MdbEntryBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let address = buf.address();
+        let group = match u16::from_be(buf.protocol()) {
+            ETH_P_IP => {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(&address[..4]);
+                MdbGroup::Ipv4(octets.into())
+            }
+            ETH_P_IPV6 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(address);
+                MdbGroup::Ipv6(octets.into())
+            }
+            0 => {
+                let mut octets = [0; 6];
+                octets.copy_from_slice(&address[..6]);
+                MdbGroup::Mac(octets)
+            }
+            protocol => return Err(format!("unknown MDB group protocol {:#06x}", protocol).into()),
+        };
+        Ok(MdbEntry {
+            ifindex: buf.ifindex(),
+            state: buf.state(),
+            flags: buf.flags(),
+            vid: buf.vid(),
+            group,
+        })
+    }
+}
+
+impl Emitable for MdbEntry {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MdbEntryBuffer::new(buffer);
+        buffer.set_ifindex(self.ifindex);
+        buffer.set_state(self.state);
+        buffer.set_flags(self.flags);
+        buffer.set_vid(self.vid);
+        let address = buffer.address_mut();
+        for byte in address.iter_mut() {
+            *byte = 0;
+        }
+        match self.group {
+            MdbGroup::Ipv4(addr) => address[..4].copy_from_slice(&addr.octets()),
+            MdbGroup::Ipv6(addr) => address.copy_from_slice(&addr.octets()),
+            MdbGroup::Mac(addr) => address[..6].copy_from_slice(&addr),
+        }
+        buffer.set_protocol(self.group.protocol().to_be());
+    }
+}
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: MIT
+
+mod entry;
+pub use self::entry::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attribute of a bridge multicast database message.
+///
+/// Requests and kernel messages give the attribute type 1 different meanings: in requests, it is
+/// `MDBA_SET_ENTRY`, holding a bare `struct br_mdb_entry`, while in dumps and notifications it is
+/// `MDBA_MDB`, holding nested entries. They are told apart with the length of the payload, since
+/// a non-empty `MDBA_MDB` attribute is always longer than a `struct br_mdb_entry`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    /// Entry to add or delete (`MDBA_SET_ENTRY`)
+    SetEntry(MdbEntry),
+    /// Entries of the database (`MDBA_MDB`). The kernel groups them by multicast group in
+    /// `MDBA_MDB_ENTRY` attributes, they are all emitted in a single one.
+    Mdb(Vec<MdbEntryInfo>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            SetEntry(ref entry) => entry.buffer_len(),
+            Mdb(ref entries) if entries.is_empty() => 0,
+            Mdb(ref entries) => EntryGroup(entries).buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            SetEntry(ref entry) => entry.emit(buffer),
+            Mdb(ref entries) if entries.is_empty() => {}
+            Mdb(ref entries) => EntryGroup(entries).emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            SetEntry(_) => MDBA_SET_ENTRY,
+            Mdb(_) => MDBA_MDB,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_SET_ENTRY if payload.len() == MDB_ENTRY_LEN => SetEntry(
+                MdbEntry::parse(&MdbEntryBuffer::new(payload)).context("invalid MDBA_SET_ENTRY")?,
+            ),
+            MDBA_MDB => {
+                let err = "invalid MDBA_MDB value";
+                let mut entries = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context(err)?;
+                    if nla.kind() != MDBA_MDB_ENTRY {
+                        continue;
+                    }
+                    for nla in NlasIterator::new(nla.value()) {
+                        let nla = nla.context(err)?;
+                        if nla.kind() == MDBA_MDB_ENTRY_INFO {
+                            entries.push(MdbEntryInfo::parse(&nla).context(err)?);
+                        }
+                    }
+                }
+                Mdb(entries)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// An entry of the database as reported by the kernel (`MDBA_MDB_ENTRY_INFO`), with the
+/// attributes following it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MdbEntryInfo {
+    pub entry: MdbEntry,
+    pub nlas: Vec<MdbEntryNla>,
+}
+
+impl MdbEntryInfo {
+    /// Returns the time left before the entry expires, in hundredths of a second. It is zero for
+    /// permanent entries.
+    pub fn timer(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let MdbEntryNla::Timer(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl nlas::Nla for MdbEntryInfo {
+    fn value_len(&self) -> usize {
+        self.entry.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.entry.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.entry.buffer_len()..]);
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_ENTRY_INFO
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        let err = "invalid MDBA_MDB_ENTRY_INFO value";
+        let entry =
+            MdbEntry::parse(&MdbEntryBuffer::new_checked(payload).context(err)?).context(err)?;
+        let mut nlas = vec![];
+        for nla in NlasIterator::new(&payload[MDB_ENTRY_LEN..]) {
+            let nla = nla.context(err)?;
+            nlas.push(MdbEntryNla::parse(&nla).context(err)?);
+        }
+        Ok(MdbEntryInfo { entry, nlas })
+    }
+}
+
+/// `MDBA_MDB_ENTRY` attribute, grouping entries
+struct EntryGroup<'a>(&'a [MdbEntryInfo]);
+
+impl nlas::Nla for EntryGroup<'_> {
+    fn value_len(&self) -> usize {
+        self.0.buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.0.emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBA_MDB_ENTRY
+    }
+}
+
+/// Attribute of an entry reported by the kernel (`MDBA_MDB_EATTR_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryNla {
+    /// Time left before the entry expires, in hundredths of a second
+    Timer(u32),
+    /// Protocol which installed the entry (Linux 5.11 and later), one of the `RTPROT_*` constants
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for MdbEntryNla {
+    fn value_len(&self) -> usize {
+        use self::MdbEntryNla::*;
+        match *self {
+            Timer(_) => 4,
+            Protocol(_) => 1,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbEntryNla::*;
+        match *self {
+            Timer(value) => NativeEndian::write_u32(buffer, value),
+            Protocol(value) => buffer[0] = value,
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbEntryNla::*;
+        match *self {
+            Timer(_) => MDBA_MDB_EATTR_TIMER,
+            Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MdbEntryNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_MDB_EATTR_TIMER value")?)
+            }
+            MDBA_MDB_EATTR_RTPROT => {
+                Protocol(parse_u8(payload).context("invalid MDBA_MDB_EATTR_RTPROT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     AddressMessage,
     DecodeError,
     LinkMessage,
+    MdbMessage,
     NeighbourMessage,
     NeighbourTableMessage,
     NetlinkDeserializable,
@@ -16,6 +17,7 @@ use crate::{
     RouteMessage,
     RtnlMessageBuffer,
     RuleMessage,
+    StatsMessage,
     TcMessage,
 };
 
@@ -57,6 +59,14 @@ pub enum RtnlMessage {
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    /// A message of a type this crate does not know about, with its type and raw payload. It is
+    /// emitted as is.
+    Other((u16, Vec<u8>)),
 }
 
 impl RtnlMessage {
@@ -196,6 +206,30 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::DelRule(_))
     }
 
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::GetMdb(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RtnlMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RtnlMessage::GetStats(_))
+    }
+
+    pub fn is_other(&self) -> bool {
+        matches!(self, RtnlMessage::Other(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RtnlMessage::*;
 
@@ -236,6 +270,12 @@ impl RtnlMessage {
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            Other((message_type, _)) => *message_type,
         }
     }
 }
@@ -295,7 +335,18 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
-            => msg.buffer_len()
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
+            Other((_, ref payload)) => payload.len(),
         }
     }
 
@@ -353,7 +404,18 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
-            => msg.emit(buffer)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
+            Other((_, ref payload)) => buffer.copy_from_slice(payload),
         }
     }
 }
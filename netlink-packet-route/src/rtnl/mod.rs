@@ -4,13 +4,28 @@ pub mod address;
 pub use address::{AddressHeader, AddressMessage, AddressMessageBuffer, ADDRESS_HEADER_LEN};
 
 pub mod link;
-pub use link::{LinkHeader, LinkMessage, LinkMessageBuffer, LINK_HEADER_LEN};
+pub use link::{
+    GreInfo,
+    InterfaceFlags,
+    IpTunnelInfo,
+    LinkHeader,
+    LinkMessage,
+    LinkMessageBuffer,
+    LinkOffloadInfo,
+    TunnelInfo,
+    VxlanInfo,
+    LINK_HEADER_LEN,
+};
+
+pub mod mdb;
+pub use mdb::{MdbHeader, MdbMessage, MdbMessageBuffer, MDB_HEADER_LEN};
 
 pub mod neighbour;
 pub use neighbour::{
     NeighbourHeader,
     NeighbourMessage,
     NeighbourMessageBuffer,
+    NeighbourState,
     NEIGHBOUR_HEADER_LEN,
 };
 
@@ -31,8 +46,11 @@ pub use route::{RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer, ROUTE
 pub mod rule;
 pub use rule::{RuleHeader, RuleMessage, RuleMessageBuffer, RULE_HEADER_LEN};
 
+pub mod stats;
+pub use stats::{StatsHeader, StatsMessage, StatsMessageBuffer, STATS_HEADER_LEN};
+
 pub mod tc;
-pub use tc::{TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
+pub use tc::{TcHandle, TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
 
 pub mod constants;
 pub use self::constants::*;
@@ -47,11 +65,13 @@ pub mod nlas {
     pub use super::{
         address::nlas as address,
         link::nlas as link,
+        mdb::nlas as mdb,
         neighbour::nlas as neighbour,
         neighbour_table::nlas as neighbour_table,
         nsid::nlas as nsid,
         route::nlas as route,
         rule::nlas as rule,
+        stats::nlas as stats,
         tc::nlas as tc,
     };
     pub use crate::utils::nla::*;
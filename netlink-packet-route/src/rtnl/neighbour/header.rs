@@ -1,12 +1,42 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
+    constants::*,
     traits::{Emitable, Parseable},
     DecodeError,
     NeighbourMessageBuffer,
     NEIGHBOUR_HEADER_LEN,
 };
 
+bitflags! {
+    /// States of a neighbour cache entry, as found in [`NeighbourHeader::state`]. A state
+    /// without any bit set (`NUD_NONE`) cannot be represented by combining these flags.
+    pub struct NeighbourState: u16 {
+        /// Address resolution is in progress
+        const INCOMPLETE = NUD_INCOMPLETE;
+        /// The neighbour was recently confirmed to be reachable
+        const REACHABLE = NUD_REACHABLE;
+        /// The reachability of the neighbour has not been confirmed for a while
+        const STALE = NUD_STALE;
+        /// Waiting a bit before probing the neighbour
+        const DELAY = NUD_DELAY;
+        /// The neighbour is being probed
+        const PROBE = NUD_PROBE;
+        /// Address resolution failed
+        const FAILED = NUD_FAILED;
+        /// The neighbour does not need address resolution
+        const NOARP = NUD_NOARP;
+        /// Static entry, never garbage collected
+        const PERMANENT = NUD_PERMANENT;
+    }
+}
+
+impl Default for NeighbourState {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
 /// Neighbour headers have the following structure:
 ///
 /// ```no_rust
@@ -3,7 +3,7 @@
 use anyhow::Context;
 
 use crate::{
-    nlas::neighbour::Nla,
+    nlas::neighbour::{NeighbourCacheInfo, Nla},
     traits::{Emitable, Parseable},
     DecodeError,
     NeighbourHeader,
@@ -16,6 +16,32 @@ pub struct NeighbourMessage {
     pub nlas: Vec<Nla>,
 }
 
+impl NeighbourMessage {
+    /// Returns the cache information of the entry (`NDA_CACHEINFO`), if present. The kernel
+    /// reports it for the entries it dumps.
+    pub fn cache_info(&self) -> Option<NeighbourCacheInfo> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::CacheInfo(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the number of probes sent since the neighbour was last confirmed (`NDA_PROBES`),
+    /// if present.
+    pub fn probes(&self) -> Option<u32> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Probes(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+}
+
 impl Emitable for NeighbourMessage {
     fn buffer_len(&self) -> usize {
         self.header.buffer_len() + self.nlas.as_slice().buffer_len()
@@ -53,7 +79,8 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourMessageBuffer<&'a T>> for Vec<N
 mod test {
     use crate::{
         constants::*,
-        traits::Emitable,
+        nlas::neighbour::{NeighbourCacheInfo, Nla},
+        traits::{Emitable, Parseable},
         NeighbourHeader,
         NeighbourMessage,
         NeighbourMessageBuffer,
@@ -119,4 +146,54 @@ mod test {
         assert_eq!(packet.buffer_len(), 12);
         packet.emit(&mut buf[..]);
     }
+
+    // A reachable ARP entry, as dumped by the kernel
+    #[rustfmt::skip]
+    static REACHABLE: [u8; 60] = [
+        0x02, // family (inet)
+        0x00, 0x00, 0x00, // padding
+        0x04, 0x00, 0x00, 0x00, // interface index = 4
+        0x02, 0x00, // state NUD_REACHABLE
+        0x00, // flags
+        0x01, // ntype RTN_UNICAST
+
+        // nlas
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = NDA_DST
+        0xc0, 0x00, 0x02, 0x01, // 192.0.2.1
+
+        0x0a, 0x00, 0x02, 0x00, // length = 10, type = NDA_LLADDR
+        0x02, 0xfc, 0x00, 0x00, 0x00, 0x05, // 02:fc:00:00:00:05
+        0x00, 0x00, // padding
+
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = NDA_PROBES
+        0x01, 0x00, 0x00, 0x00, // 1
+
+        0x14, 0x00, 0x03, 0x00, // length = 20, type = NDA_CACHEINFO
+        0x2d, 0x00, 0x00, 0x00, // confirmed = 45
+        0x2d, 0x00, 0x00, 0x00, // used = 45
+        0x2d, 0x00, 0x00, 0x00, // updated = 45
+        0x01, 0x00, 0x00, 0x00, // refcnt = 1
+    ];
+
+    #[test]
+    fn parse_reachable() {
+        let msg = NeighbourMessage::parse(&NeighbourMessageBuffer::new(&REACHABLE)).unwrap();
+        assert_eq!(msg.header.ifindex, 4);
+        assert_eq!(msg.header.state, NUD_REACHABLE);
+        assert_eq!(msg.probes(), Some(1));
+        assert_eq!(
+            msg.cache_info(),
+            Some(NeighbourCacheInfo {
+                confirmed: 45,
+                used: 45,
+                updated: 45,
+                refcnt: 1,
+            })
+        );
+        assert_eq!(msg.nlas[0], Nla::Destination(vec![192, 0, 2, 1]));
+
+        let mut buf = vec![0; REACHABLE.len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &REACHABLE[..]);
+    }
 }
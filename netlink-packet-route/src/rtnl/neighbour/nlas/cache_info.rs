@@ -5,25 +5,31 @@ use crate::{
     DecodeError,
 };
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct CacheInfo {
+/// Neighbour cache information (`struct nda_cacheinfo`), found in the `NDA_CACHEINFO` attribute
+/// of dumped entries. The timestamps are the time elapsed since the event, in clock ticks (see
+/// `sysconf(_SC_CLK_TCK)`).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct NeighbourCacheInfo {
+    /// Time since the neighbour was last confirmed to be reachable
     pub confirmed: u32,
+    /// Time since the entry was last used to send a packet
     pub used: u32,
+    /// Time since the state of the entry last changed
     pub updated: u32,
     pub refcnt: u32,
 }
 
 pub const NEIGHBOUR_CACHE_INFO_LEN: usize = 16;
 
-buffer!(CacheInfoBuffer(NEIGHBOUR_CACHE_INFO_LEN) {
+buffer!(NeighbourCacheInfoBuffer(NEIGHBOUR_CACHE_INFO_LEN) {
     confirmed: (u32, 0..4),
     used: (u32, 4..8),
     updated: (u32, 8..12),
     refcnt: (u32, 12..16),
 });
 
-impl<T: AsRef<[u8]>> Parseable<CacheInfoBuffer<T>> for CacheInfo {
-    fn parse(buf: &CacheInfoBuffer<T>) -> Result<Self, DecodeError> {
+impl<T: AsRef<[u8]>> Parseable<NeighbourCacheInfoBuffer<T>> for NeighbourCacheInfo {
+    fn parse(buf: &NeighbourCacheInfoBuffer<T>) -> Result<Self, DecodeError> {
         Ok(Self {
             confirmed: buf.confirmed(),
             used: buf.used(),
@@ -33,13 +39,13 @@ impl<T: AsRef<[u8]>> Parseable<CacheInfoBuffer<T>> for CacheInfo {
     }
 }
 
-impl Emitable for CacheInfo {
+impl Emitable for NeighbourCacheInfo {
     fn buffer_len(&self) -> usize {
         NEIGHBOUR_CACHE_INFO_LEN
     }
 
     fn emit(&self, buffer: &mut [u8]) {
-        let mut buffer = CacheInfoBuffer::new(buffer);
+        let mut buffer = NeighbourCacheInfoBuffer::new(buffer);
         buffer.set_confirmed(self.confirmed);
         buffer.set_used(self.used);
         buffer.set_updated(self.updated);
@@ -10,7 +10,7 @@ use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
     parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -19,8 +19,9 @@ pub enum Nla {
     Unspec(Vec<u8>),
     Destination(Vec<u8>),
     LinkLocalAddress(Vec<u8>),
-    CacheInfo(Vec<u8>),
-    Probes(Vec<u8>),
+    CacheInfo(NeighbourCacheInfo),
+    /// Number of probes sent to the neighbour since it was last confirmed (`NDA_PROBES`)
+    Probes(u32),
     Vlan(u16),
     Port(Vec<u8>),
     Vni(u32),
@@ -39,13 +40,13 @@ impl nlas::Nla for Nla {
             Unspec(ref bytes)
             | Destination(ref bytes)
             | LinkLocalAddress(ref bytes)
-            | Probes(ref bytes)
             | Port(ref bytes)
             | Master(ref bytes)
-            | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => bytes.len(),
+            CacheInfo(ref info) => info.buffer_len(),
             Vlan(_) => 2,
-            Vni(_)
+            Probes(_)
+            | Vni(_)
             | IfIndex(_)
             | SourceVni(_) => 4,
             Other(ref attr) => attr.value_len(),
@@ -59,13 +60,13 @@ impl nlas::Nla for Nla {
             Unspec(ref bytes)
             | Destination(ref bytes)
             | LinkLocalAddress(ref bytes)
-            | Probes(ref bytes)
             | Port(ref bytes)
             | Master(ref bytes)
-            | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CacheInfo(ref info) => info.emit(buffer),
             Vlan(ref value) => NativeEndian::write_u16(buffer, *value),
-            Vni(ref value)
+            Probes(ref value)
+            | Vni(ref value)
             | IfIndex(ref value)
             | SourceVni(ref value) => NativeEndian::write_u32(buffer, *value),
             Other(ref attr) => attr.emit_value(buffer),
@@ -101,8 +102,14 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             NDA_UNSPEC => Unspec(payload.to_vec()),
             NDA_DST => Destination(payload.to_vec()),
             NDA_LLADDR => LinkLocalAddress(payload.to_vec()),
-            NDA_CACHEINFO => CacheInfo(payload.to_vec()),
-            NDA_PROBES => Probes(payload.to_vec()),
+            NDA_CACHEINFO => CacheInfo(
+                NeighbourCacheInfo::parse(
+                    &NeighbourCacheInfoBuffer::new_checked(payload)
+                        .context("invalid NDA_CACHEINFO value")?,
+                )
+                .context("invalid NDA_CACHEINFO value")?,
+            ),
+            NDA_PROBES => Probes(parse_u32(payload).context("invalid NDA_PROBES value")?),
             NDA_VLAN => Vlan(parse_u16(payload)?),
             NDA_PORT => Port(payload.to_vec()),
             NDA_VNI => Vni(parse_u32(payload)?),
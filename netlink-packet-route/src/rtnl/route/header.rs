@@ -9,8 +9,23 @@ use crate::{
 };
 
 bitflags! {
-    /// Flags that can be set in a `RTM_GETROUTE` ([`RtnlMessage::GetRoute`]) message.
+    /// Flags of a route message. The `RTM_F_*` flags are mostly found in `RTM_GETROUTE`
+    /// ([`RtnlMessage::GetRoute`]) messages, the `RTNH_F_*` flags are the flags of the nexthop of
+    /// the route.
     pub struct RouteFlags: u32 {
+        /// The nexthop is dead because its interface is down or being removed
+        const RTNH_F_DEAD = RTNH_F_DEAD;
+        /// Do recursive gateway lookup
+        const RTNH_F_PERVASIVE = RTNH_F_PERVASIVE;
+        /// The gateway is directly reachable through the output interface, even if no route
+        /// through that interface covers it
+        const RTNH_F_ONLINK = RTNH_F_ONLINK;
+        /// The route is offloaded to the hardware
+        const RTNH_F_OFFLOAD = RTNH_F_OFFLOAD;
+        /// The carrier of the output interface is down
+        const RTNH_F_LINKDOWN = RTNH_F_LINKDOWN;
+        /// The gateway is not resolved yet
+        const RTNH_F_UNRESOLVED = RTNH_F_UNRESOLVED;
         /// If the route changes, notify the user via rtnetlink
         const RTM_F_NOTIFY = RTM_F_NOTIFY;
         /// This route is cloned. Cloned routes are routes coming from the cache instead of the
@@ -127,7 +142,8 @@ pub struct RouteHeader {
     pub scope: u8,
     /// Route type. It should be set to one of the `RTN_*` constants.
     pub kind: u8,
-    /// Flags when querying the kernel with a `RTM_GETROUTE` message. See [`RouteFlags`].
+    /// Flags of the route and of its nexthop, and flags when querying the kernel with a
+    /// `RTM_GETROUTE` message. See [`RouteFlags`].
     pub flags: RouteFlags,
 }
 
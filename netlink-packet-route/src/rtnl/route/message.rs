@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    nlas::route::Nla,
+    nlas::route::{Nla, Realm, RouteCacheInfo},
     traits::{Emitable, Parseable},
     DecodeError,
     RouteHeader,
@@ -111,6 +111,44 @@ impl RouteMessage {
         })
     }
 
+    /// Returns the routing realms (`RTA_FLOW`), if present.
+    pub fn realm(&self) -> Option<Realm> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Flow(v) = nla {
+                Some(Realm::from(*v))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the cached route information (`RTA_CACHEINFO`), if present. It is only reported
+    /// for resolved routes, such as the ones returned by a route lookup.
+    pub fn cache_info(&self) -> Option<RouteCacheInfo> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::CacheInfo(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the routing table id. The header only holds 8 bits, so tables above 255 are
+    /// reported as `RT_TABLE_COMPAT` there and the actual id is taken from `RTA_TABLE`.
+    pub fn table(&self) -> u32 {
+        self.nlas
+            .iter()
+            .find_map(|nla| {
+                if let Nla::Table(v) = nla {
+                    Some(*v)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(self.header.table as u32)
+    }
+
     /// Returns the gateway address, if present.
     pub fn gateway(&self) -> Option<IpAddr> {
         self.nlas.iter().find_map(|nla| {
@@ -121,4 +159,171 @@ impl RouteMessage {
             }
         })
     }
+
+    /// Returns the preferred source address (`RTA_PREFSRC`), if present.
+    pub fn pref_source(&self) -> Option<IpAddr> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::PrefSource(v) = nla {
+                octets_to_addr(v).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the gateway address given in another address family than the one of the route
+    /// (`RTA_VIA`), if present.
+    pub fn via(&self) -> Option<IpAddr> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Via(v) = nla {
+                v.ip()
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        constants::*,
+        nlas::route::{Nla, RouteCacheInfo, RouteVia},
+        traits::{Emitable, Parseable},
+        RouteFlags,
+        RouteMessage,
+        RouteMessageBuffer,
+    };
+    use std::net::{IpAddr, Ipv6Addr};
+
+    // Reply to `ip -6 route get 2001:db8::1`
+    #[rustfmt::skip]
+    static CACHED_ROUTE: [u8; 84] = [
+        // header
+        0x0a, // address family: AF_INET6
+        0x80, // destination prefix length: 128
+        0x00, // source prefix length
+        0x00, // tos
+        0xfe, // table: RT_TABLE_MAIN
+        0x00, // protocol
+        0x00, // scope: RT_SCOPE_UNIVERSE
+        0x01, // kind: RTN_UNICAST
+        0x00, 0x02, 0x00, 0x00, // flags: RTM_F_CLONED
+
+        // RTA_TABLE
+        0x08, 0x00, 0x0f, 0x00,
+        0xfe, 0x00, 0x00, 0x00,
+
+        // RTA_DST
+        0x14, 0x00, 0x01, 0x00,
+        0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+
+        // RTA_OIF
+        0x08, 0x00, 0x04, 0x00,
+        0x02, 0x00, 0x00, 0x00,
+
+        // RTA_CACHEINFO
+        0x24, 0x00, 0x0c, 0x00,
+        0x01, 0x00, 0x00, 0x00, // clntref
+        0x34, 0x12, 0x00, 0x00, // last use
+        0x64, 0x00, 0x00, 0x00, // expires
+        0x00, 0x00, 0x00, 0x00, // error
+        0x02, 0x00, 0x00, 0x00, // used
+        0x00, 0x00, 0x00, 0x00, // id
+        0x00, 0x00, 0x00, 0x00, // ts
+        0x00, 0x00, 0x00, 0x00, // ts age
+    ];
+
+    lazy_static! {
+        static ref CACHE_INFO: RouteCacheInfo = RouteCacheInfo {
+            clntref: 1,
+            last_use: 0x1234,
+            expires: 100,
+            error: 0,
+            used: 2,
+            id: 0,
+            ts: 0,
+            ts_age: 0,
+        };
+    }
+
+    #[test]
+    fn parse_cached_route() {
+        let msg = RouteMessage::parse(&RouteMessageBuffer::new(&CACHED_ROUTE)).unwrap();
+        assert!(msg.header.flags.contains(RouteFlags::RTM_F_CLONED));
+        assert_eq!(msg.output_interface(), Some(2));
+        assert_eq!(msg.cache_info(), Some(*CACHE_INFO));
+        assert_eq!(msg.table(), RT_TABLE_MAIN as u32);
+    }
+
+    #[test]
+    fn table_above_255() {
+        let mut msg = RouteMessage::default();
+        msg.header.table = RT_TABLE_COMPAT;
+        assert_eq!(msg.table(), RT_TABLE_COMPAT as u32);
+        msg.nlas.push(Nla::Table(1000));
+        assert_eq!(msg.table(), 1000);
+    }
+
+    #[test]
+    fn emit_cached_route() {
+        let msg = RouteMessage::parse(&RouteMessageBuffer::new(&CACHED_ROUTE)).unwrap();
+        assert_eq!(msg.nlas.last(), Some(&Nla::CacheInfo(*CACHE_INFO)));
+
+        let mut buf = vec![0xff; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &CACHED_ROUTE[..]);
+    }
+
+    // `ip route add 203.0.113.0/24 via inet6 fe80::1 dev eth0`
+    #[rustfmt::skip]
+    static ROUTE_VIA_INET6: [u8; 60] = [
+        // header
+        0x02, // address family: AF_INET
+        0x18, // destination prefix length: 24
+        0x00, // source prefix length
+        0x00, // tos
+        0xfe, // table: RT_TABLE_MAIN
+        0x04, // protocol: RTPROT_STATIC
+        0x00, // scope: RT_SCOPE_UNIVERSE
+        0x01, // kind: RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+        // RTA_TABLE
+        0x08, 0x00, 0x0f, 0x00,
+        0xfe, 0x00, 0x00, 0x00,
+
+        // RTA_DST
+        0x08, 0x00, 0x01, 0x00,
+        0xcb, 0x00, 0x71, 0x00,
+
+        // RTA_VIA
+        0x16, 0x00, 0x12, 0x00,
+        0x0a, 0x00, // family: AF_INET6
+        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, // padding
+
+        // RTA_OIF
+        0x08, 0x00, 0x04, 0x00,
+        0x02, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn parse_route_via_inet6() {
+        let msg = RouteMessage::parse(&RouteMessageBuffer::new(&ROUTE_VIA_INET6)).unwrap();
+        let via = IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+        assert!(msg.nlas.contains(&Nla::Via(RouteVia::from(via))));
+        assert_eq!(msg.via(), Some(via));
+        assert_eq!(msg.gateway(), None);
+    }
+
+    #[test]
+    fn emit_route_via_inet6() {
+        let msg = RouteMessage::parse(&RouteMessageBuffer::new(&ROUTE_VIA_INET6)).unwrap();
+        let mut buf = vec![0xff; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &ROUTE_VIA_INET6[..]);
+    }
 }
@@ -5,11 +5,13 @@ use crate::{
     DecodeError,
 };
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
-pub struct CacheInfo {
+/// Cached route information (`struct rta_cacheinfo`), found in the `RTA_CACHEINFO` attribute of
+/// resolved (cloned) routes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct RouteCacheInfo {
     pub clntref: u32,
     pub last_use: u32,
-    pub expires: u32,
+    pub expires: i32,
     pub error: u32,
     pub used: u32,
     pub id: u32,
@@ -17,12 +19,12 @@ pub struct CacheInfo {
     pub ts_age: u32,
 }
 
-pub const CACHE_INFO_LEN: usize = 32;
+pub const ROUTE_CACHE_INFO_LEN: usize = 32;
 
-buffer!(CacheInfoBuffer(CACHE_INFO_LEN) {
+buffer!(RouteCacheInfoBuffer(ROUTE_CACHE_INFO_LEN) {
     clntref: (u32, 0..4),
     last_use: (u32, 4..8),
-    expires: (u32, 8..12),
+    expires: (i32, 8..12),
     error: (u32, 12..16),
     used: (u32, 16..20),
     id: (u32, 20..24),
@@ -30,8 +32,8 @@ buffer!(CacheInfoBuffer(CACHE_INFO_LEN) {
     ts_age: (u32, 28..32),
 });
 
-impl<T: AsRef<[u8]>> Parseable<CacheInfoBuffer<T>> for CacheInfo {
-    fn parse(buf: &CacheInfoBuffer<T>) -> Result<Self, DecodeError> {
+impl<T: AsRef<[u8]>> Parseable<RouteCacheInfoBuffer<T>> for RouteCacheInfo {
+    fn parse(buf: &RouteCacheInfoBuffer<T>) -> Result<Self, DecodeError> {
         Ok(Self {
             clntref: buf.clntref(),
             last_use: buf.last_use(),
@@ -45,13 +47,13 @@ impl<T: AsRef<[u8]>> Parseable<CacheInfoBuffer<T>> for CacheInfo {
     }
 }
 
-impl Emitable for CacheInfo {
+impl Emitable for RouteCacheInfo {
     fn buffer_len(&self) -> usize {
-        CACHE_INFO_LEN
+        ROUTE_CACHE_INFO_LEN
     }
 
     fn emit(&self, buffer: &mut [u8]) {
-        let mut buffer = CacheInfoBuffer::new(buffer);
+        let mut buffer = RouteCacheInfoBuffer::new(buffer);
         buffer.set_clntref(self.clntref);
         buffer.set_last_use(self.last_use);
         buffer.set_expires(self.expires);
@@ -9,6 +9,12 @@ pub use self::metrics::*;
 mod mfc_stats;
 pub use self::mfc_stats::*;
 
+mod realm;
+pub use self::realm::*;
+
+mod via;
+pub use self::via::*;
+
 use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 
@@ -16,7 +22,7 @@ use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
     parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -29,11 +35,11 @@ pub enum Nla {
     PrefSource(Vec<u8>),
     Metrics(Vec<u8>),
     MultiPath(Vec<u8>),
-    CacheInfo(Vec<u8>),
+    CacheInfo(RouteCacheInfo),
     Session(Vec<u8>),
     MpAlgo(Vec<u8>),
     MfcStats(Vec<u8>),
-    Via(Vec<u8>),
+    Via(RouteVia),
     NewDestination(Vec<u8>),
     Pref(Vec<u8>),
     Encap(Vec<u8>),
@@ -65,7 +71,6 @@ impl nlas::Nla for Nla {
                 | MultiPath(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
                 | NewDestination(ref bytes)
                 | Pref(ref bytes)
                 | Encap(ref bytes)
@@ -73,11 +78,13 @@ impl nlas::Nla for Nla {
                 | Pad(ref bytes)
                 | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
-                | CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
                 | Metrics(ref bytes)
                 => bytes.len(),
 
+            CacheInfo(ref info) => info.buffer_len(),
+            Via(ref via) => via.buffer_len(),
+
             EncapType(_) => 2,
             Iif(_)
                 | Oif(_)
@@ -104,7 +111,6 @@ impl nlas::Nla for Nla {
                 | MultiPath(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
                 | NewDestination(ref bytes)
                 | Pref(ref bytes)
                 | Encap(ref bytes)
@@ -112,10 +118,11 @@ impl nlas::Nla for Nla {
                 | Pad(ref bytes)
                 | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
-                | CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
                 | Metrics(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
+            CacheInfo(ref info) => info.emit(buffer),
+            Via(ref via) => via.emit(buffer),
             EncapType(value) => NativeEndian::write_u16(buffer, value),
             Iif(value)
                 | Oif(value)
@@ -178,7 +185,12 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_MULTIPATH => MultiPath(payload.to_vec()),
             RTA_SESSION => Session(payload.to_vec()),
             RTA_MP_ALGO => MpAlgo(payload.to_vec()),
-            RTA_VIA => Via(payload.to_vec()),
+            RTA_VIA => Via(
+                RouteVia::parse(
+                    &RouteViaBuffer::new_checked(payload).context("invalid RTA_VIA value")?,
+                )
+                .context("invalid RTA_VIA value")?,
+            ),
             RTA_NEWDST => NewDestination(payload.to_vec()),
             RTA_PREF => Pref(payload.to_vec()),
             RTA_ENCAP => Encap(payload.to_vec()),
@@ -198,7 +210,13 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_FLOW => Flow(parse_u32(payload).context("invalid RTA_FLOW value")?),
             RTA_TABLE => Table(parse_u32(payload).context("invalid RTA_TABLE value")?),
             RTA_MARK => Mark(parse_u32(payload).context("invalid RTA_MARK value")?),
-            RTA_CACHEINFO => CacheInfo(payload.to_vec()),
+            RTA_CACHEINFO => CacheInfo(
+                RouteCacheInfo::parse(
+                    &RouteCacheInfoBuffer::new_checked(payload)
+                        .context("invalid RTA_CACHEINFO value")?,
+                )
+                .context("invalid RTA_CACHEINFO value")?,
+            ),
             RTA_MFC_STATS => MfcStats(payload.to_vec()),
             RTA_METRICS => Metrics(payload.to_vec()),
             _ => Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+/// Routing realms, carried by the `RTA_FLOW` attribute.
+///
+/// The kernel packs both realms in a single `u32`: the source realm is stored
+/// in the 16 high bits, and the destination realm in the 16 low bits. This is
+/// what `ip route add ... realms FROM/TO` sets. `ip route add ... realm TO`
+/// only sets the destination realm.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Realm {
+    pub from: u16,
+    pub to: u16,
+}
+
+impl Realm {
+    pub fn new(from: u16, to: u16) -> Self {
+        Realm { from, to }
+    }
+}
+
+impl From<u32> for Realm {
+    fn from(value: u32) -> Self {
+        Realm {
+            from: (value >> 16) as u16,
+            to: (value & 0xffff) as u16,
+        }
+    }
+}
+
+impl From<Realm> for u32 {
+    fn from(realm: Realm) -> Self {
+        (u32::from(realm.from) << 16) | u32::from(realm.to)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn realm_encoding() {
+        assert_eq!(u32::from(Realm::new(0, 5)), 5);
+        assert_eq!(u32::from(Realm::new(3, 5)), 0x0003_0005);
+        assert_eq!(u32::from(Realm::new(0xffff, 0)), 0xffff_0000);
+    }
+
+    #[test]
+    fn realm_decoding() {
+        assert_eq!(Realm::from(5), Realm::new(0, 5));
+        assert_eq!(Realm::from(0x0003_0005), Realm::new(3, 5));
+        let realm = Realm::new(0x1234, 0xabcd);
+        assert_eq!(Realm::from(u32::from(realm)), realm);
+    }
+}
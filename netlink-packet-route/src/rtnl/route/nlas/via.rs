@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use crate::{
+    constants::{AF_INET, AF_INET6},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Gateway of a route in an address family that differs from the one of the route (`struct
+/// rtvia`, carried by the `RTA_VIA` attribute), such as an IPv6 gateway for an IPv4 route. This is
+/// what `ip route add ... via inet6 ADDR` sets.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct RouteVia {
+    /// Address family of the gateway, `AF_INET` or `AF_INET6` for instance
+    pub family: u16,
+    pub address: Vec<u8>,
+}
+
+impl RouteVia {
+    /// Returns the gateway as an IP address, if it is an IPv4 or IPv6 one.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match (self.family, self.address.len()) {
+            (AF_INET, 4) => {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(&self.address);
+                Some(IpAddr::from(octets))
+            }
+            (AF_INET6, 16) => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(&self.address);
+                Some(IpAddr::from(octets))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<IpAddr> for RouteVia {
+    fn from(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(addr) => RouteVia {
+                family: AF_INET,
+                address: addr.octets().to_vec(),
+            },
+            IpAddr::V6(addr) => RouteVia {
+                family: AF_INET6,
+                address: addr.octets().to_vec(),
+            },
+        }
+    }
+}
+
+pub const ROUTE_VIA_HEADER_LEN: usize = 2;
+
+buffer!(RouteViaBuffer(ROUTE_VIA_HEADER_LEN) {
+    family: (u16, 0..2),
+    address: (slice, ROUTE_VIA_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<RouteViaBuffer<&'a T>> for RouteVia {
+    fn parse(buf: &RouteViaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(RouteVia {
+            family: buf.family(),
+            address: buf.address().to_vec(),
+        })
+    }
+}
+
+impl Emitable for RouteVia {
+    fn buffer_len(&self) -> usize {
+        ROUTE_VIA_HEADER_LEN + self.address.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = RouteViaBuffer::new(buffer);
+        buffer.set_family(self.family);
+        buffer.address_mut().copy_from_slice(&self.address);
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use super::{StatsMessageBuffer, STATS_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Header of the link statistics messages (`struct if_stats_msg`)
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsHeader {
+    /// Address family, `AF_UNSPEC` for the statistics of links
+    pub family: u8,
+    /// Index of the link. When dumping the statistics, it must be zero.
+    pub ifindex: u32,
+    /// Attributes to report, as a combination of the `IFLA_STATS_FILTER_*` bits. The kernel
+    /// answers with the same mask.
+    pub filter_mask: u32,
+}
+
+impl Emitable for StatsHeader {
+    fn buffer_len(&self) -> usize {
+        STATS_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = StatsMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_ifindex(self.ifindex);
+        packet.set_filter_mask(self.filter_mask);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<StatsMessageBuffer<T>> for StatsHeader {
+    fn parse(buf: &StatsMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(StatsHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+            filter_mask: buf.filter_mask(),
+        })
+    }
+}
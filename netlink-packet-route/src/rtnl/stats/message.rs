@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::{link::Stats64, stats::Nla},
+    traits::{Emitable, Parseable},
+    DecodeError,
+    StatsHeader,
+    StatsMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct StatsMessage {
+    pub header: StatsHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl StatsMessage {
+    /// Returns the counters of the link (`IFLA_STATS_LINK_64`), if they were requested with
+    /// `IFLA_STATS_FILTER_LINK_64`.
+    pub fn link_64(&self) -> Option<Stats64> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Link64(v) = nla {
+                Some(*v)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for StatsMessage {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: StatsHeader::parse(buf).context("failed to parse stats message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse stats message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<StatsMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &StatsMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for StatsMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::{
+            link::Stats64,
+            stats::{BridgeStpXstats, BridgeXstats, Nla, Xstats},
+        },
+        traits::{Emitable, Parseable},
+        StatsHeader,
+        StatsMessage,
+        StatsMessageBuffer,
+        IFLA_STATS_FILTER_LINK_64,
+        IFLA_STATS_FILTER_LINK_XSTATS_SLAVE,
+    };
+
+    // Reply to a RTM_GETSTATS request for the IFLA_STATS_LINK_64 attribute of a dummy link
+    #[rustfmt::skip]
+    static LINK_64: [u8; 208] = [
+        0x00, // family
+        0x00, 0x00, 0x00, // padding
+        0x05, 0x00, 0x00, 0x00, // interface index = 5
+        0x01, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_LINK_64
+
+        // nlas
+        0xc4, 0x00, 0x01, 0x00, // IFLA_STATS_LINK_64 L=196,T=1
+            0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_packets = 10
+            0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_packets = 20
+            0x48, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bytes = 840
+            0x90, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bytes = 1680
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_dropped = 0
+            0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_dropped = 2
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // multicast = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // collisions = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_length_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_over_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_crc_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_frame_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_fifo_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_missed_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_aborted_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_carrier_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_fifo_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_heartbeat_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_window_errors = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_compressed = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_compressed = 0
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_nohandler = 0
+    ];
+
+    #[test]
+    fn parse_link_64() {
+        let packet = StatsMessage::parse(&StatsMessageBuffer::new(&LINK_64)).unwrap();
+        assert_eq!(
+            packet.header,
+            StatsHeader {
+                family: 0,
+                ifindex: 5,
+                filter_mask: IFLA_STATS_FILTER_LINK_64,
+            }
+        );
+        let stats = Stats64 {
+            rx_packets: 10,
+            tx_packets: 20,
+            rx_bytes: 840,
+            tx_bytes: 1680,
+            tx_dropped: 2,
+            ..Default::default()
+        };
+        assert_eq!(packet.nlas, vec![Nla::Link64(stats)]);
+        assert_eq!(packet.link_64(), Some(stats));
+
+        let mut buf = vec![0; 208];
+        assert_eq!(packet.buffer_len(), 208);
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &LINK_64[..]);
+    }
+
+    // Reply to a RTM_GETSTATS request for the IFLA_STATS_LINK_XSTATS_SLAVE attribute of a bridge
+    // port
+    #[rustfmt::skip]
+    static BRIDGE_PORT_XSTATS: [u8; 72] = [
+        0x00, // family
+        0x00, 0x00, 0x00, // padding
+        0x06, 0x00, 0x00, 0x00, // interface index = 6
+        0x04, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_LINK_XSTATS_SLAVE
+
+        // nlas
+        0x3c, 0x00, 0x03, 0x00, // IFLA_STATS_LINK_XSTATS_SLAVE L=60,T=3
+            0x38, 0x00, 0x01, 0x00, // LINK_XSTATS_TYPE_BRIDGE L=56,T=1
+                0x34, 0x00, 0x04, 0x00, // BRIDGE_XSTATS_STP L=52,T=4
+                    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // transition_blk = 1
+                    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // transition_fwd = 2
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_bpdu = 0
+                    0x23, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_bpdu = 35
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // rx_tcn = 0
+                    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // tx_tcn = 1
+    ];
+
+    #[test]
+    fn parse_bridge_port_xstats() {
+        let packet = StatsMessage::parse(&StatsMessageBuffer::new(&BRIDGE_PORT_XSTATS)).unwrap();
+        assert_eq!(packet.header.ifindex, 6);
+        assert_eq!(
+            packet.header.filter_mask,
+            IFLA_STATS_FILTER_LINK_XSTATS_SLAVE
+        );
+        let stp = BridgeStpXstats {
+            transition_blk: 1,
+            transition_fwd: 2,
+            rx_bpdu: 0,
+            tx_bpdu: 35,
+            rx_tcn: 0,
+            tx_tcn: 1,
+        };
+        assert_eq!(
+            packet.nlas,
+            vec![Nla::LinkXstatsSlave(vec![Xstats::Bridge(vec![
+                BridgeXstats::Stp(stp)
+            ])])]
+        );
+        assert_eq!(packet.link_64(), None);
+
+        let mut buf = vec![0; 72];
+        packet.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &BRIDGE_PORT_XSTATS[..]);
+    }
+}
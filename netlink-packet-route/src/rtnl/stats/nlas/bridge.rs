@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Statistics of a bridge or of a bridge port, nested in `LINK_XSTATS_TYPE_BRIDGE`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BridgeXstats {
+    /// Counters of a VLAN of the bridge (`BRIDGE_XSTATS_VLAN`). The kernel reports one attribute
+    /// per VLAN.
+    Vlan(BridgeVlanXstats),
+    /// STP counters of a bridge port (`BRIDGE_XSTATS_STP`)
+    Stp(BridgeStpXstats),
+    /// Other statistics, like the multicast counters (`BRIDGE_XSTATS_MCAST`)
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for BridgeXstats {
+    fn value_len(&self) -> usize {
+        use self::BridgeXstats::*;
+        match *self {
+            Vlan(ref stats) => stats.buffer_len(),
+            Stp(ref stats) => stats.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BridgeXstats::*;
+        match *self {
+            Vlan(ref stats) => stats.emit(buffer),
+            Stp(ref stats) => stats.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BridgeXstats::*;
+        match *self {
+            Vlan(_) => BRIDGE_XSTATS_VLAN,
+            Stp(_) => BRIDGE_XSTATS_STP,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BridgeXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_XSTATS_VLAN => {
+                let err = "invalid BRIDGE_XSTATS_VLAN value";
+                let buf = BridgeVlanXstatsBuffer::new_checked(payload).context(err)?;
+                Vlan(BridgeVlanXstats::parse(&buf).context(err)?)
+            }
+            BRIDGE_XSTATS_STP => {
+                let err = "invalid BRIDGE_XSTATS_STP value";
+                let buf = BridgeStpXstatsBuffer::new_checked(payload).context(err)?;
+                Stp(BridgeStpXstats::parse(&buf).context(err)?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+pub const BRIDGE_VLAN_XSTATS_LEN: usize = 40;
+buffer!(BridgeVlanXstatsBuffer(BRIDGE_VLAN_XSTATS_LEN) {
+    rx_bytes: (u64, 0..8),
+    rx_packets: (u64, 8..16),
+    tx_bytes: (u64, 16..24),
+    tx_packets: (u64, 24..32),
+    vid: (u16, 32..34),
+    flags: (u16, 34..36),
+    pad: (u32, 36..40),
+});
+
+/// Counters of a VLAN of a bridge (`struct bridge_vlan_xstats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct BridgeVlanXstats {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    pub vid: u16,
+    /// `BRIDGE_VLAN_INFO_*` flags of the VLAN
+    pub flags: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanXstatsBuffer<T>> for BridgeVlanXstats {
+    fn parse(buf: &BridgeVlanXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_bytes: buf.rx_bytes(),
+            rx_packets: buf.rx_packets(),
+            tx_bytes: buf.tx_bytes(),
+            tx_packets: buf.tx_packets(),
+            vid: buf.vid(),
+            flags: buf.flags(),
+        })
+    }
+}
+
+impl Emitable for BridgeVlanXstats {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeVlanXstatsBuffer::new(buffer);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_vid(self.vid);
+        buffer.set_flags(self.flags);
+        buffer.set_pad(0);
+    }
+}
+
+pub const BRIDGE_STP_XSTATS_LEN: usize = 48;
+buffer!(BridgeStpXstatsBuffer(BRIDGE_STP_XSTATS_LEN) {
+    transition_blk: (u64, 0..8),
+    transition_fwd: (u64, 8..16),
+    rx_bpdu: (u64, 16..24),
+    tx_bpdu: (u64, 24..32),
+    rx_tcn: (u64, 32..40),
+    tx_tcn: (u64, 40..48),
+});
+
+/// STP counters of a bridge port (`struct bridge_stp_xstats`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct BridgeStpXstats {
+    /// Number of transitions to the blocking state
+    pub transition_blk: u64,
+    /// Number of transitions to the forwarding state
+    pub transition_fwd: u64,
+    pub rx_bpdu: u64,
+    pub tx_bpdu: u64,
+    /// Topology change notifications received
+    pub rx_tcn: u64,
+    /// Topology change notifications sent
+    pub tx_tcn: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeStpXstatsBuffer<T>> for BridgeStpXstats {
+    fn parse(buf: &BridgeStpXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            transition_blk: buf.transition_blk(),
+            transition_fwd: buf.transition_fwd(),
+            rx_bpdu: buf.rx_bpdu(),
+            tx_bpdu: buf.tx_bpdu(),
+            rx_tcn: buf.rx_tcn(),
+            tx_tcn: buf.tx_tcn(),
+        })
+    }
+}
+
+impl Emitable for BridgeStpXstats {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_STP_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeStpXstatsBuffer::new(buffer);
+        buffer.set_transition_blk(self.transition_blk);
+        buffer.set_transition_fwd(self.transition_fwd);
+        buffer.set_rx_bpdu(self.rx_bpdu);
+        buffer.set_tx_bpdu(self.tx_bpdu);
+        buffer.set_rx_tcn(self.rx_tcn);
+        buffer.set_tx_tcn(self.tx_tcn);
+    }
+}
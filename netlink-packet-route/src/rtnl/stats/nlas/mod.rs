@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: MIT
+
+mod bridge;
+pub use self::bridge::*;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{
+        self,
+        link::{Stats64, Stats64Buffer, LINK_STATS64_LEN},
+        DefaultNla,
+        NlaBuffer,
+        NlasIterator,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attribute of a link statistics message
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    /// Counters of the link (`IFLA_STATS_LINK_64`), the same as `IFLA_STATS64` in link messages
+    Link64(Stats64),
+    /// Statistics specific to the kind of the link (`IFLA_STATS_LINK_XSTATS`), for instance the
+    /// VLAN counters of a bridge
+    LinkXstats(Vec<Xstats>),
+    /// Statistics specific to the kind of the master of the link
+    /// (`IFLA_STATS_LINK_XSTATS_SLAVE`), for instance the STP counters of a bridge port
+    LinkXstatsSlave(Vec<Xstats>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Link64(_) => LINK_STATS64_LEN,
+            LinkXstats(ref xstats) | LinkXstatsSlave(ref xstats) => xstats.as_slice().buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Link64(ref stats) => stats.emit(buffer),
+            LinkXstats(ref xstats) | LinkXstatsSlave(ref xstats) => xstats.as_slice().emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+fn parse_xstats(payload: &[u8], err: &'static str) -> Result<Vec<Xstats>, DecodeError> {
+    let mut xstats = vec![];
+    for nla in NlasIterator::new(payload) {
+        xstats.push(Xstats::parse(&nla.context(err)?).context(err)?);
+    }
+    Ok(xstats)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_LINK_64 => {
+                let err = "invalid IFLA_STATS_LINK_64 value";
+                let buf = Stats64Buffer::new_checked(payload).context(err)?;
+                Link64(Stats64::parse(&buf).context(err)?)
+            }
+            IFLA_STATS_LINK_XSTATS => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS value";
+                LinkXstats(parse_xstats(payload, err)?)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => {
+                let err = "invalid IFLA_STATS_LINK_XSTATS_SLAVE value";
+                LinkXstatsSlave(parse_xstats(payload, err)?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Extended statistics of a link, nested in `IFLA_STATS_LINK_XSTATS` and
+/// `IFLA_STATS_LINK_XSTATS_SLAVE`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Xstats {
+    /// Statistics of a bridge or of a bridge port (`LINK_XSTATS_TYPE_BRIDGE`)
+    Bridge(Vec<BridgeXstats>),
+    /// Other kinds of statistics, like the ones of bonds (`LINK_XSTATS_TYPE_BOND`)
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Xstats {
+    fn value_len(&self) -> usize {
+        match *self {
+            Xstats::Bridge(ref nlas) => nlas.as_slice().buffer_len(),
+            Xstats::Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match *self {
+            Xstats::Bridge(ref nlas) => nlas.as_slice().emit(buffer),
+            Xstats::Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match *self {
+            Xstats::Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Xstats::Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Xstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let err = "invalid LINK_XSTATS_TYPE_BRIDGE value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    nlas.push(BridgeXstats::parse(&nla.context(err)?).context(err)?);
+                }
+                Xstats::Bridge(nlas)
+            }
+            kind => {
+                Xstats::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    constants::{TC_H_INGRESS, TC_H_MAJ_MASK, TC_H_MIN_MASK, TC_H_ROOT, TC_H_UNSPEC},
+    DecodeError,
+};
+
+/// A traffic control handle, identifying a qdisc, a class or a filter. It packs a 16 bits
+/// major number and a 16 bits minor number into a `u32` (`major << 16 | minor`), and uses the
+/// same textual representation as `tc`: both numbers are written in hexadecimal and separated
+/// by a colon, as in `1:10`. The special values `TC_H_ROOT`, `TC_H_INGRESS` and `TC_H_UNSPEC`
+/// are written `root`, `ingress` and `none`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Hash, PartialOrd, Ord)]
+pub struct TcHandle(pub u32);
+
+impl TcHandle {
+    pub const UNSPEC: TcHandle = TcHandle(TC_H_UNSPEC);
+    pub const ROOT: TcHandle = TcHandle(TC_H_ROOT);
+    pub const INGRESS: TcHandle = TcHandle(TC_H_INGRESS);
+
+    pub fn new(major: u16, minor: u16) -> Self {
+        TcHandle(((major as u32) << 16) | minor as u32)
+    }
+
+    pub fn major(&self) -> u16 {
+        ((self.0 & TC_H_MAJ_MASK) >> 16) as u16
+    }
+
+    pub fn minor(&self) -> u16 {
+        (self.0 & TC_H_MIN_MASK) as u16
+    }
+}
+
+impl From<u32> for TcHandle {
+    fn from(value: u32) -> Self {
+        TcHandle(value)
+    }
+}
+
+impl From<TcHandle> for u32 {
+    fn from(handle: TcHandle) -> Self {
+        handle.0
+    }
+}
+
+impl FromStr for TcHandle {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "root" => return Ok(TcHandle::ROOT),
+            "ingress" => return Ok(TcHandle::INGRESS),
+            "none" => return Ok(TcHandle::UNSPEC),
+            _ => {}
+        }
+        let (major, minor) = s
+            .split_once(':')
+            .ok_or_else(|| DecodeError::from(format!("invalid tc handle {:?}: missing ':'", s)))?;
+        let parse = |n: &str| {
+            if n.is_empty() {
+                Ok(0)
+            } else {
+                u16::from_str_radix(n, 16)
+                    .map_err(|e| DecodeError::from(format!("invalid tc handle {:?}: {}", s, e)))
+            }
+        };
+        Ok(TcHandle::new(parse(major)?, parse(minor)?))
+    }
+}
+
+impl fmt::Display for TcHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            TC_H_ROOT => write!(f, "root"),
+            TC_H_INGRESS => write!(f, "ingress"),
+            TC_H_UNSPEC => write!(f, "none"),
+            _ if self.minor() == 0 => write!(f, "{:x}:", self.major()),
+            _ if self.major() == 0 => write!(f, ":{:x}", self.minor()),
+            _ => write!(f, "{:x}:{:x}", self.major(), self.minor()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_tc_handle() {
+        let handle: TcHandle = "1:10".parse().unwrap();
+        assert_eq!(handle.major(), 1);
+        assert_eq!(handle.minor(), 0x10);
+        assert_eq!(u32::from(handle), 0x0001_0010);
+
+        assert_eq!("ffff:".parse::<TcHandle>().unwrap(), TcHandle(0xffff_0000));
+        assert_eq!(":a".parse::<TcHandle>().unwrap(), TcHandle(0x0000_000a));
+        assert_eq!("root".parse::<TcHandle>().unwrap(), TcHandle(TC_H_ROOT));
+        assert_eq!("ingress".parse::<TcHandle>().unwrap(), TcHandle(TC_H_INGRESS));
+        assert_eq!("none".parse::<TcHandle>().unwrap(), TcHandle(TC_H_UNSPEC));
+
+        assert!("10".parse::<TcHandle>().is_err());
+        assert!("1:10000".parse::<TcHandle>().is_err());
+        assert!("x:1".parse::<TcHandle>().is_err());
+    }
+
+    #[test]
+    fn format_tc_handle() {
+        assert_eq!(TcHandle::new(1, 0x10).to_string(), "1:10");
+        assert_eq!(TcHandle::new(0x8001, 0).to_string(), "8001:");
+        assert_eq!(TcHandle::new(0, 0xa).to_string(), ":a");
+        assert_eq!(TcHandle(TC_H_ROOT).to_string(), "root");
+        assert_eq!(TcHandle(TC_H_INGRESS).to_string(), "ingress");
+        assert_eq!(TcHandle(TC_H_UNSPEC).to_string(), "none");
+
+        for s in ["1:10", "ffff:", "root", "ingress"] {
+            assert_eq!(s.parse::<TcHandle>().unwrap().to_string(), s);
+        }
+    }
+}
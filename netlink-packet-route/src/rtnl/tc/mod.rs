@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: MIT
 
 mod buffer;
+mod handle;
 mod message;
 pub mod nlas;
 
-pub use self::{buffer::*, message::*, nlas::*};
+pub use self::{buffer::*, handle::*, message::*, nlas::*};
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT
+
+//! The `mirred` action mirrors or redirects the packets to another interface (see
+//! `tc-mirred(8)`).
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "mirred";
+pub const TC_MIRRED_BUF_LEN: usize = 28;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Install and usage timestamps, only present in dumps
+    Tm(Vec<u8>),
+    Parms(TcMirred),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) => bytes.len(),
+            Parms(_) => TC_MIRRED_BUF_LEN,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Tm(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Parms(parms) => parms.emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_MIRRED_UNSPEC,
+            Tm(_) => TCA_MIRRED_TM,
+            Parms(_) => TCA_MIRRED_PARMS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_MIRRED_UNSPEC => Self::Unspec(payload.to_vec()),
+            TCA_MIRRED_TM => Self::Tm(payload.to_vec()),
+            TCA_MIRRED_PARMS => Self::Parms(
+                TcMirred::parse(&TcMirredBuffer::new_checked(payload)?)
+                    .context("invalid TCA_MIRRED_PARMS")?,
+            ),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid mirred nla")?),
+        })
+    }
+}
+
+/// Parameters of a `mirred` action (`struct tc_mirred`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcMirred {
+    pub index: u32,
+    pub capab: u32,
+    /// Verdict once the packet has been mirrored or redirected, such as `TC_ACT_STOLEN` or
+    /// `TC_ACT_PIPE`
+    pub action: i32,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+    /// What to do with the packet: one of `TCA_EGRESS_REDIR`, `TCA_EGRESS_MIRROR`,
+    /// `TCA_INGRESS_REDIR` or `TCA_INGRESS_MIRROR`
+    pub eaction: i32,
+    /// Index of the interface the packet is mirrored or redirected to
+    pub ifindex: u32,
+}
+
+impl TcMirred {
+    /// Parameters redirecting the packets to the egress of the interface `ifindex`, like
+    /// `action mirred egress redirect dev <interface>`
+    pub fn egress_redirect(ifindex: u32) -> Self {
+        TcMirred {
+            action: TC_ACT_STOLEN,
+            eaction: TCA_EGRESS_REDIR,
+            ifindex,
+            ..Default::default()
+        }
+    }
+
+    /// Parameters copying the packets to the egress of the interface `ifindex`, like
+    /// `action mirred egress mirror dev <interface>`
+    pub fn egress_mirror(ifindex: u32) -> Self {
+        TcMirred {
+            action: TC_ACT_PIPE,
+            eaction: TCA_EGRESS_MIRROR,
+            ifindex,
+            ..Default::default()
+        }
+    }
+}
+
+buffer!(TcMirredBuffer(TC_MIRRED_BUF_LEN) {
+    index: (u32, 0..4),
+    capab: (u32, 4..8),
+    action: (i32, 8..12),
+    refcnt: (i32, 12..16),
+    bindcnt: (i32, 16..20),
+    eaction: (i32, 20..24),
+    ifindex: (u32, 24..28),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcMirredBuffer<T>> for TcMirred {
+    fn parse(buf: &TcMirredBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            capab: buf.capab(),
+            action: buf.action(),
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            eaction: buf.eaction(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
+
+impl Emitable for TcMirred {
+    fn buffer_len(&self) -> usize {
+        TC_MIRRED_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcMirredBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_capab(self.capab);
+        buffer.set_action(self.action);
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+        buffer.set_eaction(self.eaction);
+        buffer.set_ifindex(self.ifindex);
+    }
+}
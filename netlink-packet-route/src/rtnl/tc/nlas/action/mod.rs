@@ -0,0 +1,328 @@
+// SPDX-License-Identifier: MIT
+
+//! Traffic control actions, as attached to a filter. A filter carries a list of actions, each
+//! of them nested in an attribute whose type is the position of the action in the list
+//! (starting at [`TCA_ACT_TAB`]). The options of an action are nested in its
+//! `TCA_ACT_OPTIONS` attribute, and their format depends on the kind of the action.
+
+pub mod mirred;
+pub mod police;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Action {
+    /// Position of the action in the list of actions of a filter
+    pub tab: u16,
+    pub nlas: Vec<ActNla>,
+}
+
+impl Default for Action {
+    fn default() -> Self {
+        Action {
+            tab: TCA_ACT_TAB,
+            nlas: Vec::new(),
+        }
+    }
+}
+
+impl Action {
+    /// Create a `mirred` action with the given parameters
+    pub fn mirred(parms: mirred::TcMirred) -> Self {
+        Action {
+            tab: TCA_ACT_TAB,
+            nlas: vec![
+                ActNla::Kind(mirred::KIND.to_string()),
+                ActNla::Options(vec![ActOpt::Mirred(mirred::Nla::Parms(parms))]),
+            ],
+        }
+    }
+
+    /// Create a `police` action with the given parameters. The rate table the kernel requires
+    /// along with the rate is built from [`police::TcPolice::rate`].
+    pub fn police(parms: police::TcPolice) -> Self {
+        let mut opts = vec![ActOpt::Police(police::Nla::Tbf(parms))];
+        if parms.rate.rate != 0 {
+            opts.push(ActOpt::Police(police::Nla::Rate(parms.rate.rate_table())));
+        }
+        Action {
+            tab: TCA_ACT_TAB,
+            nlas: vec![ActNla::Kind(police::KIND.to_string()), ActNla::Options(opts)],
+        }
+    }
+}
+
+impl nlas::Nla for Action {
+    fn value_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        self.tab
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Action {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        let mut kind = String::new();
+        for nla in NlasIterator::new(buf.value()) {
+            let nla = nla.context("invalid action nla")?;
+            let nla = ActNla::parse_with_param(&nla, &kind)?;
+            if let ActNla::Kind(ref k) = nla {
+                kind = k.clone();
+            }
+            nlas.push(nla);
+        }
+        Ok(Action {
+            tab: buf.kind(),
+            nlas,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ActNla {
+    Unspec(Vec<u8>),
+    /// Name of the action, such as `mirred`
+    Kind(String),
+    /// Action-specific options
+    Options(Vec<ActOpt>),
+    Index(u32),
+    Stats(Vec<u8>),
+    Cookie(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for ActNla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::ActNla::*;
+        match *self {
+            Unspec(ref bytes)
+                | Stats(ref bytes)
+                | Cookie(ref bytes) => bytes.len(),
+            Kind(ref string) => string.as_bytes().len() + 1,
+            Options(ref opts) => opts.as_slice().buffer_len(),
+            Index(_) => 4,
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ActNla::*;
+        match *self {
+            Unspec(ref bytes) | Stats(ref bytes) | Cookie(ref bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Kind(ref string) => {
+                buffer[..string.as_bytes().len()].copy_from_slice(string.as_bytes());
+                buffer[string.as_bytes().len()] = 0;
+            }
+            Options(ref opts) => opts.as_slice().emit(buffer),
+            Index(value) => NativeEndian::write_u32(buffer, value),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ActNla::*;
+        match *self {
+            Unspec(_) => TCA_ACT_UNSPEC,
+            Kind(_) => TCA_ACT_KIND,
+            Options(_) => TCA_ACT_OPTIONS,
+            Index(_) => TCA_ACT_INDEX,
+            Stats(_) => TCA_ACT_STATS,
+            Cookie(_) => TCA_ACT_COOKIE,
+            Other(ref nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T, S> ParseableParametrized<NlaBuffer<&'a T>, S> for ActNla
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: AsRef<str>,
+{
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: S) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ACT_UNSPEC => Self::Unspec(payload.to_vec()),
+            TCA_ACT_KIND => Self::Kind(parse_string(payload).context("invalid TCA_ACT_KIND")?),
+            TCA_ACT_OPTIONS => {
+                let mut opts = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_ACT_OPTIONS nla")?;
+                    opts.push(ActOpt::parse_with_param(&nla, kind.as_ref())?);
+                }
+                Self::Options(opts)
+            }
+            TCA_ACT_INDEX => Self::Index(parse_u32(payload).context("invalid TCA_ACT_INDEX")?),
+            TCA_ACT_STATS => Self::Stats(payload.to_vec()),
+            TCA_ACT_COOKIE => Self::Cookie(payload.to_vec()),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid action nla")?),
+        })
+    }
+}
+
+/// Options of an action. Their format depends on the kind of the action, and the options of
+/// the actions this crate does not know about are kept as [`ActOpt::Other`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ActOpt {
+    Mirred(mirred::Nla),
+    Police(police::Nla),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for ActOpt {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Mirred(nla) => nla.value_len(),
+            Self::Police(nla) => nla.value_len(),
+            Self::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Mirred(nla) => nla.emit_value(buffer),
+            Self::Police(nla) => nla.emit_value(buffer),
+            Self::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Mirred(nla) => nla.kind(),
+            Self::Police(nla) => nla.kind(),
+            Self::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T, S> ParseableParametrized<NlaBuffer<&'a T>, S> for ActOpt
+where
+    T: AsRef<[u8]> + ?Sized,
+    S: AsRef<str>,
+{
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, kind: S) -> Result<Self, DecodeError> {
+        Ok(match kind.as_ref() {
+            mirred::KIND => Self::Mirred(mirred::Nla::parse(buf).context("invalid mirred nla")?),
+            police::KIND => Self::Police(police::Nla::parse(buf).context("invalid police nla")?),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid action option")?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::nlas::Nla;
+
+    #[rustfmt::skip]
+    static MIRRED_REDIRECT: [u8; 52] = [
+        0x34, 0x00, 0x01, 0x00, // action 1, length 52
+            0x0b, 0x00, 0x01, 0x00, // TCA_ACT_KIND, length 11
+                0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00, // "mirred\0" + padding
+            0x24, 0x00, 0x02, 0x00, // TCA_ACT_OPTIONS, length 36
+                0x20, 0x00, 0x02, 0x00, // TCA_MIRRED_PARMS, length 32
+                    0x00, 0x00, 0x00, 0x00, // index
+                    0x00, 0x00, 0x00, 0x00, // capab
+                    0x04, 0x00, 0x00, 0x00, // action: TC_ACT_STOLEN
+                    0x00, 0x00, 0x00, 0x00, // refcnt
+                    0x00, 0x00, 0x00, 0x00, // bindcnt
+                    0x01, 0x00, 0x00, 0x00, // eaction: TCA_EGRESS_REDIR
+                    0x2a, 0x00, 0x00, 0x00, // ifindex: 42
+    ];
+
+    fn mirred_redirect() -> Action {
+        Action::mirred(mirred::TcMirred::egress_redirect(42))
+    }
+
+    #[test]
+    fn parse_mirred() {
+        let nla = NlaBuffer::new_checked(&MIRRED_REDIRECT[..]).unwrap();
+        assert_eq!(Action::parse(&nla).unwrap(), mirred_redirect());
+    }
+
+    #[test]
+    fn emit_mirred() {
+        let action = mirred_redirect();
+        assert_eq!(action.buffer_len(), MIRRED_REDIRECT.len());
+        let mut buf = vec![0; action.buffer_len()];
+        action.emit(&mut buf);
+        assert_eq!(buf, MIRRED_REDIRECT);
+    }
+
+    #[rustfmt::skip]
+    static POLICE_1MBIT: [u8; 56] = [
+        0x00, 0x00, 0x00, 0x00, // index
+        0x02, 0x00, 0x00, 0x00, // action: TC_ACT_SHOT
+        0x00, 0x00, 0x00, 0x00, // limit
+        0xd0, 0x12, 0x13, 0x00, // burst: 1250000 ticks (10000 bytes at 1mbit)
+        0x00, 0x00, 0x00, 0x00, // mtu
+        // rate
+        0x03, // cell_log
+        0x01, // linklayer: TC_LINKLAYER_ETHERNET
+        0x00, 0x00, // overhead
+        0xff, 0xff, // cell_align: -1
+        0x00, 0x00, // mpu
+        0x48, 0xe8, 0x01, 0x00, // rate: 125000 bytes/s
+        // peakrate
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // refcnt
+        0x00, 0x00, 0x00, 0x00, // bindcnt
+        0x00, 0x00, 0x00, 0x00, // capab
+    ];
+
+    #[test]
+    fn emit_police() {
+        let parms = police::TcPolice::new(125_000, 10_000, 0, TC_ACT_SHOT);
+        let mut buf = vec![0; parms.buffer_len()];
+        parms.emit(&mut buf);
+        assert_eq!(buf, POLICE_1MBIT);
+
+        let action = Action::police(parms);
+        let mut buf = vec![0; action.buffer_len()];
+        action.emit(&mut buf);
+        assert_eq!(Action::parse(&NlaBuffer::new_checked(&buf[..]).unwrap()).unwrap(), action);
+
+        let rate_table = match &action.nlas[1] {
+            ActNla::Options(opts) => match &opts[1] {
+                ActOpt::Police(police::Nla::Rate(table)) => table.clone(),
+                opt => panic!("unexpected option {:?}", opt),
+            },
+            nla => panic!("unexpected nla {:?}", nla),
+        };
+        assert_eq!(rate_table.len(), 1024);
+        // The first size class holds packets of up to 8 bytes, the last one up to 2048 bytes
+        assert_eq!(NativeEndian::read_u32(&rate_table[..4]), 1000);
+        assert_eq!(NativeEndian::read_u32(&rate_table[1020..]), 256_000);
+    }
+
+    #[test]
+    fn parse_unknown_action_options() {
+        let mut buf = MIRRED_REDIRECT;
+        buf[8..15].copy_from_slice(b"foobar\0");
+        let action = Action::parse(&NlaBuffer::new_checked(&buf[..]).unwrap()).unwrap();
+        match &action.nlas[1] {
+            ActNla::Options(opts) => {
+                assert!(matches!(&opts[0], ActOpt::Other(nla) if nla.kind() == TCA_MIRRED_PARMS))
+            }
+            nla => panic!("unexpected nla {:?}", nla),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: MIT
+
+//! The `police` action limits the rate of the packets, and drops or lets through the packets
+//! exceeding it (see `tc-police(8)`).
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_i32, parse_u32},
+    tc::{TcRateSpec, TcRateSpecBuffer},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "police";
+pub const TC_POLICE_BUF_LEN: usize = 56;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Tbf(TcPolice),
+    /// Rate table of the rate of the [`TcPolice`] parameters (see
+    /// [`TcRateSpec::rate_table`])
+    Rate(Vec<u8>),
+    /// Rate table of the peak rate of the [`TcPolice`] parameters
+    PeakRate(Vec<u8>),
+    /// Average rate, in bytes per second, above which the packets exceed the rate. This relies
+    /// on a rate estimator rather than a token bucket.
+    AvRate(u32),
+    /// Verdict for the packets conforming to the rate, `TC_ACT_OK` by default
+    Result(i32),
+    /// Install and usage timestamps, only present in dumps
+    Tm(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes)
+                | Rate(bytes)
+                | PeakRate(bytes)
+                | Tm(bytes) => bytes.len(),
+            Tbf(_) => TC_POLICE_BUF_LEN,
+            AvRate(_) | Result(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Rate(bytes) | PeakRate(bytes) | Tm(bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Tbf(parms) => parms.emit(buffer),
+            AvRate(value) => NativeEndian::write_u32(buffer, *value),
+            Result(value) => NativeEndian::write_i32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_POLICE_UNSPEC,
+            Tbf(_) => TCA_POLICE_TBF,
+            Rate(_) => TCA_POLICE_RATE,
+            PeakRate(_) => TCA_POLICE_PEAKRATE,
+            AvRate(_) => TCA_POLICE_AVRATE,
+            Result(_) => TCA_POLICE_RESULT,
+            Tm(_) => TCA_POLICE_TM,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_POLICE_UNSPEC => Self::Unspec(payload.to_vec()),
+            TCA_POLICE_TBF => Self::Tbf(
+                TcPolice::parse(&TcPoliceBuffer::new_checked(payload)?)
+                    .context("invalid TCA_POLICE_TBF")?,
+            ),
+            TCA_POLICE_RATE => Self::Rate(payload.to_vec()),
+            TCA_POLICE_PEAKRATE => Self::PeakRate(payload.to_vec()),
+            TCA_POLICE_AVRATE => {
+                Self::AvRate(parse_u32(payload).context("invalid TCA_POLICE_AVRATE")?)
+            }
+            TCA_POLICE_RESULT => {
+                Self::Result(parse_i32(payload).context("invalid TCA_POLICE_RESULT")?)
+            }
+            TCA_POLICE_TM => Self::Tm(payload.to_vec()),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid police nla")?),
+        })
+    }
+}
+
+/// Parameters of a `police` action (`struct tc_police`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcPolice {
+    pub index: u32,
+    /// Verdict for the packets exceeding the rate, such as `TC_ACT_SHOT` (`drop`) or
+    /// `TC_ACT_UNSPEC` (`continue`)
+    pub action: i32,
+    pub limit: u32,
+    /// Size of the token bucket, as the time it takes to transmit the burst at `rate`, in packet
+    /// scheduler ticks (see [`TcRateSpec::transmit_time`])
+    pub burst: u32,
+    /// Packets larger than this exceed the rate
+    pub mtu: u32,
+    pub rate: TcRateSpec,
+    pub peakrate: TcRateSpec,
+    pub refcnt: i32,
+    pub bindcnt: i32,
+    pub capab: u32,
+}
+
+impl TcPolice {
+    /// Parameters limiting the packets to `rate` bytes per second, with bursts of up to `burst`
+    /// bytes, like `action police rate RATE burst BURST mtu MTU conform-exceed EXCEED`. `exceed`
+    /// is the verdict for the packets exceeding the rate, such as `TC_ACT_SHOT`. If `mtu` is 0,
+    /// the kernel picks one.
+    pub fn new(rate: u32, burst: u32, mtu: u32, exceed: i32) -> Self {
+        let rate = TcRateSpec::new(rate, mtu);
+        TcPolice {
+            action: exceed,
+            burst: rate.transmit_time(burst),
+            mtu,
+            rate,
+            ..Default::default()
+        }
+    }
+}
+
+buffer!(TcPoliceBuffer(TC_POLICE_BUF_LEN) {
+    index: (u32, 0..4),
+    action: (i32, 4..8),
+    limit: (u32, 8..12),
+    burst: (u32, 12..16),
+    mtu: (u32, 16..20),
+    rate: (slice, 20..32),
+    peakrate: (slice, 32..44),
+    refcnt: (i32, 44..48),
+    bindcnt: (i32, 48..52),
+    capab: (u32, 52..56),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcPoliceBuffer<T>> for TcPolice {
+    fn parse(buf: &TcPoliceBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            index: buf.index(),
+            action: buf.action(),
+            limit: buf.limit(),
+            burst: buf.burst(),
+            mtu: buf.mtu(),
+            rate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.rate()))?,
+            peakrate: TcRateSpec::parse(&TcRateSpecBuffer::new(buf.peakrate()))?,
+            refcnt: buf.refcnt(),
+            bindcnt: buf.bindcnt(),
+            capab: buf.capab(),
+        })
+    }
+}
+
+impl Emitable for TcPolice {
+    fn buffer_len(&self) -> usize {
+        TC_POLICE_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcPoliceBuffer::new(buffer);
+        buffer.set_index(self.index);
+        buffer.set_action(self.action);
+        buffer.set_limit(self.limit);
+        buffer.set_burst(self.burst);
+        buffer.set_mtu(self.mtu);
+        self.rate.emit(buffer.rate_mut());
+        self.peakrate.emit(buffer.peakrate_mut());
+        buffer.set_refcnt(self.refcnt);
+        buffer.set_bindcnt(self.bindcnt);
+        buffer.set_capab(self.capab);
+    }
+}
@@ -0,0 +1,363 @@
+// SPDX-License-Identifier: MIT
+
+//! Options of the `flower` filter, which matches packets on the fields of their headers, each
+//! field being compared under a mask (see `tc-flower(8)`).
+//!
+//! The kernel applies the full mask to the keys sent without their mask attribute, and always
+//! reports both when dumping the filter.
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, tc::action::Action, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_ipv6, parse_mac, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "flower";
+
+/// Options of a `flower` filter. The ports and the ethernet type are in host byte order.
+///
+/// The IP keys only apply if the ethernet type of the packets is matched with
+/// [`Nla::KeyEthType`], and the TCP and UDP keys if their IP protocol is matched with
+/// [`Nla::KeyIpProto`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    ClassId(u32),
+    Indev(Vec<u8>),
+    /// Actions applied to the matching packets
+    Act(Vec<Action>),
+    KeyEthDst([u8; 6]),
+    KeyEthDstMask([u8; 6]),
+    KeyEthSrc([u8; 6]),
+    KeyEthSrcMask([u8; 6]),
+    /// Ethernet protocol, such as `ETH_P_IP`
+    KeyEthType(u16),
+    /// IP protocol, for instance 6 for TCP
+    KeyIpProto(u8),
+    KeyIpv4Src(Ipv4Addr),
+    KeyIpv4SrcMask(Ipv4Addr),
+    KeyIpv4Dst(Ipv4Addr),
+    KeyIpv4DstMask(Ipv4Addr),
+    KeyIpv6Src(Ipv6Addr),
+    KeyIpv6SrcMask(Ipv6Addr),
+    KeyIpv6Dst(Ipv6Addr),
+    KeyIpv6DstMask(Ipv6Addr),
+    KeyTcpSrc(u16),
+    KeyTcpSrcMask(u16),
+    KeyTcpDst(u16),
+    KeyTcpDstMask(u16),
+    KeyUdpSrc(u16),
+    KeyUdpSrcMask(u16),
+    KeyUdpDst(u16),
+    KeyUdpDstMask(u16),
+    /// `TCA_CLS_FLAGS_*` flags
+    Flags(u32),
+    /// Type of service (IPv4) or traffic class (IPv6) byte. The DSCP is its 6 high bits.
+    KeyIpTos(u8),
+    KeyIpTosMask(u8),
+    KeyIpTtl(u8),
+    KeyIpTtlMask(u8),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Indev(bytes) => bytes.len(),
+            KeyEthDst(_)
+                | KeyEthDstMask(_)
+                | KeyEthSrc(_)
+                | KeyEthSrcMask(_) => 6,
+            KeyIpProto(_)
+                | KeyIpTos(_)
+                | KeyIpTosMask(_)
+                | KeyIpTtl(_)
+                | KeyIpTtlMask(_) => 1,
+            KeyEthType(_)
+                | KeyTcpSrc(_)
+                | KeyTcpSrcMask(_)
+                | KeyTcpDst(_)
+                | KeyTcpDstMask(_)
+                | KeyUdpSrc(_)
+                | KeyUdpSrcMask(_)
+                | KeyUdpDst(_)
+                | KeyUdpDstMask(_) => 2,
+            ClassId(_)
+                | Flags(_)
+                | KeyIpv4Src(_)
+                | KeyIpv4SrcMask(_)
+                | KeyIpv4Dst(_)
+                | KeyIpv4DstMask(_) => 4,
+            KeyIpv6Src(_)
+                | KeyIpv6SrcMask(_)
+                | KeyIpv6Dst(_)
+                | KeyIpv6DstMask(_) => 16,
+            Act(actions) => actions.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) | Indev(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            KeyEthDst(addr)
+                | KeyEthDstMask(addr)
+                | KeyEthSrc(addr)
+                | KeyEthSrcMask(addr) => buffer.copy_from_slice(&addr[..]),
+            KeyIpProto(value)
+                | KeyIpTos(value)
+                | KeyIpTosMask(value)
+                | KeyIpTtl(value)
+                | KeyIpTtlMask(value) => buffer[0] = *value,
+            KeyEthType(value)
+                | KeyTcpSrc(value)
+                | KeyTcpSrcMask(value)
+                | KeyTcpDst(value)
+                | KeyTcpDstMask(value)
+                | KeyUdpSrc(value)
+                | KeyUdpSrcMask(value)
+                | KeyUdpDst(value)
+                | KeyUdpDstMask(value) => BigEndian::write_u16(buffer, *value),
+            ClassId(value) | Flags(value) => NativeEndian::write_u32(buffer, *value),
+            KeyIpv4Src(addr)
+                | KeyIpv4SrcMask(addr)
+                | KeyIpv4Dst(addr)
+                | KeyIpv4DstMask(addr) => buffer.copy_from_slice(&addr.octets()),
+            KeyIpv6Src(addr)
+                | KeyIpv6SrcMask(addr)
+                | KeyIpv6Dst(addr)
+                | KeyIpv6DstMask(addr) => buffer.copy_from_slice(&addr.octets()),
+            Act(actions) => actions.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_FLOWER_UNSPEC,
+            ClassId(_) => TCA_FLOWER_CLASSID,
+            Indev(_) => TCA_FLOWER_INDEV,
+            Act(_) => TCA_FLOWER_ACT,
+            KeyEthDst(_) => TCA_FLOWER_KEY_ETH_DST,
+            KeyEthDstMask(_) => TCA_FLOWER_KEY_ETH_DST_MASK,
+            KeyEthSrc(_) => TCA_FLOWER_KEY_ETH_SRC,
+            KeyEthSrcMask(_) => TCA_FLOWER_KEY_ETH_SRC_MASK,
+            KeyEthType(_) => TCA_FLOWER_KEY_ETH_TYPE,
+            KeyIpProto(_) => TCA_FLOWER_KEY_IP_PROTO,
+            KeyIpv4Src(_) => TCA_FLOWER_KEY_IPV4_SRC,
+            KeyIpv4SrcMask(_) => TCA_FLOWER_KEY_IPV4_SRC_MASK,
+            KeyIpv4Dst(_) => TCA_FLOWER_KEY_IPV4_DST,
+            KeyIpv4DstMask(_) => TCA_FLOWER_KEY_IPV4_DST_MASK,
+            KeyIpv6Src(_) => TCA_FLOWER_KEY_IPV6_SRC,
+            KeyIpv6SrcMask(_) => TCA_FLOWER_KEY_IPV6_SRC_MASK,
+            KeyIpv6Dst(_) => TCA_FLOWER_KEY_IPV6_DST,
+            KeyIpv6DstMask(_) => TCA_FLOWER_KEY_IPV6_DST_MASK,
+            KeyTcpSrc(_) => TCA_FLOWER_KEY_TCP_SRC,
+            KeyTcpSrcMask(_) => TCA_FLOWER_KEY_TCP_SRC_MASK,
+            KeyTcpDst(_) => TCA_FLOWER_KEY_TCP_DST,
+            KeyTcpDstMask(_) => TCA_FLOWER_KEY_TCP_DST_MASK,
+            KeyUdpSrc(_) => TCA_FLOWER_KEY_UDP_SRC,
+            KeyUdpSrcMask(_) => TCA_FLOWER_KEY_UDP_SRC_MASK,
+            KeyUdpDst(_) => TCA_FLOWER_KEY_UDP_DST,
+            KeyUdpDstMask(_) => TCA_FLOWER_KEY_UDP_DST_MASK,
+            Flags(_) => TCA_FLOWER_FLAGS,
+            KeyIpTos(_) => TCA_FLOWER_KEY_IP_TOS,
+            KeyIpTosMask(_) => TCA_FLOWER_KEY_IP_TOS_MASK,
+            KeyIpTtl(_) => TCA_FLOWER_KEY_IP_TTL,
+            KeyIpTtlMask(_) => TCA_FLOWER_KEY_IP_TTL_MASK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+fn parse_ipv4(payload: &[u8]) -> Result<Ipv4Addr, DecodeError> {
+    if payload.len() != 4 {
+        return Err(format!("invalid IPv4 address: {:?}", payload).into());
+    }
+    let mut octets = [0; 4];
+    octets.copy_from_slice(payload);
+    Ok(Ipv4Addr::from(octets))
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_FLOWER_UNSPEC => Unspec(payload.to_vec()),
+            TCA_FLOWER_CLASSID => {
+                ClassId(parse_u32(payload).context("invalid TCA_FLOWER_CLASSID")?)
+            }
+            TCA_FLOWER_INDEV => Indev(payload.to_vec()),
+            TCA_FLOWER_ACT => {
+                let mut actions = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_FLOWER_ACT nla")?;
+                    actions.push(Action::parse(&nla).context("invalid TCA_FLOWER_ACT")?);
+                }
+                Act(actions)
+            }
+            TCA_FLOWER_KEY_ETH_DST => {
+                KeyEthDst(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_DST")?)
+            }
+            TCA_FLOWER_KEY_ETH_DST_MASK => {
+                KeyEthDstMask(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_DST_MASK")?)
+            }
+            TCA_FLOWER_KEY_ETH_SRC => {
+                KeyEthSrc(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_SRC")?)
+            }
+            TCA_FLOWER_KEY_ETH_SRC_MASK => {
+                KeyEthSrcMask(parse_mac(payload).context("invalid TCA_FLOWER_KEY_ETH_SRC_MASK")?)
+            }
+            TCA_FLOWER_KEY_ETH_TYPE => {
+                KeyEthType(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_ETH_TYPE")?)
+            }
+            TCA_FLOWER_KEY_IP_PROTO => {
+                KeyIpProto(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_PROTO")?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC => {
+                KeyIpv4Src(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_SRC")?)
+            }
+            TCA_FLOWER_KEY_IPV4_SRC_MASK => {
+                KeyIpv4SrcMask(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_SRC_MASK")?)
+            }
+            TCA_FLOWER_KEY_IPV4_DST => {
+                KeyIpv4Dst(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_DST")?)
+            }
+            TCA_FLOWER_KEY_IPV4_DST_MASK => {
+                KeyIpv4DstMask(parse_ipv4(payload).context("invalid TCA_FLOWER_KEY_IPV4_DST_MASK")?)
+            }
+            TCA_FLOWER_KEY_IPV6_SRC => KeyIpv6Src(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_SRC")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_SRC_MASK => KeyIpv6SrcMask(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_SRC_MASK")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_DST => KeyIpv6Dst(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_DST")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_IPV6_DST_MASK => KeyIpv6DstMask(
+                parse_ipv6(payload)
+                    .context("invalid TCA_FLOWER_KEY_IPV6_DST_MASK")?
+                    .into(),
+            ),
+            TCA_FLOWER_KEY_TCP_SRC => {
+                KeyTcpSrc(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_SRC")?)
+            }
+            TCA_FLOWER_KEY_TCP_SRC_MASK => {
+                KeyTcpSrcMask(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_SRC_MASK")?)
+            }
+            TCA_FLOWER_KEY_TCP_DST => {
+                KeyTcpDst(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_DST")?)
+            }
+            TCA_FLOWER_KEY_TCP_DST_MASK => {
+                KeyTcpDstMask(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_TCP_DST_MASK")?)
+            }
+            TCA_FLOWER_KEY_UDP_SRC => {
+                KeyUdpSrc(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_SRC")?)
+            }
+            TCA_FLOWER_KEY_UDP_SRC_MASK => {
+                KeyUdpSrcMask(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_SRC_MASK")?)
+            }
+            TCA_FLOWER_KEY_UDP_DST => {
+                KeyUdpDst(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_DST")?)
+            }
+            TCA_FLOWER_KEY_UDP_DST_MASK => {
+                KeyUdpDstMask(parse_u16_be(payload).context("invalid TCA_FLOWER_KEY_UDP_DST_MASK")?)
+            }
+            TCA_FLOWER_FLAGS => Flags(parse_u32(payload).context("invalid TCA_FLOWER_FLAGS")?),
+            TCA_FLOWER_KEY_IP_TOS => {
+                KeyIpTos(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TOS")?)
+            }
+            TCA_FLOWER_KEY_IP_TOS_MASK => {
+                KeyIpTosMask(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TOS_MASK")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL => {
+                KeyIpTtl(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TTL")?)
+            }
+            TCA_FLOWER_KEY_IP_TTL_MASK => {
+                KeyIpTtlMask(parse_u8(payload).context("invalid TCA_FLOWER_KEY_IP_TTL_MASK")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid flower nla")?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Options of the request sent by `tc filter add dev eth0 ingress protocol ip pref 1 flower
+    // dst_ip 192.0.2.0/24 ip_proto tcp dst_port 80 ip_tos 0xb8/0xfc classid 1:10`
+    #[rustfmt::skip]
+    static FLOWER_DST: [u8; 72] = [
+        0x08, 0x00, 0x0c, 0x00, // TCA_FLOWER_KEY_IPV4_DST, length 8
+            0xc0, 0x00, 0x02, 0x00, // 192.0.2.0
+        0x08, 0x00, 0x0d, 0x00, // TCA_FLOWER_KEY_IPV4_DST_MASK, length 8
+            0xff, 0xff, 0xff, 0x00, // 255.255.255.0
+        0x05, 0x00, 0x09, 0x00, // TCA_FLOWER_KEY_IP_PROTO, length 5
+            0x06, 0x00, 0x00, 0x00, // TCP + padding
+        0x06, 0x00, 0x13, 0x00, // TCA_FLOWER_KEY_TCP_DST, length 6
+            0x00, 0x50, 0x00, 0x00, // 80 (big endian) + padding
+        0x05, 0x00, 0x49, 0x00, // TCA_FLOWER_KEY_IP_TOS, length 5
+            0xb8, 0x00, 0x00, 0x00, // DSCP 46 (EF) + padding
+        0x05, 0x00, 0x4a, 0x00, // TCA_FLOWER_KEY_IP_TOS_MASK, length 5
+            0xfc, 0x00, 0x00, 0x00, // DSCP bits + padding
+        0x08, 0x00, 0x01, 0x00, // TCA_FLOWER_CLASSID, length 8
+            0x10, 0x00, 0x01, 0x00, // 1:10
+        0x08, 0x00, 0x16, 0x00, // TCA_FLOWER_FLAGS, length 8
+            0x00, 0x00, 0x00, 0x00,
+        0x06, 0x00, 0x08, 0x00, // TCA_FLOWER_KEY_ETH_TYPE, length 6
+            0x08, 0x00, 0x00, 0x00, // ETH_P_IP (big endian) + padding
+    ];
+
+    fn flower_dst() -> Vec<Nla> {
+        vec![
+            Nla::KeyIpv4Dst(Ipv4Addr::new(192, 0, 2, 0)),
+            Nla::KeyIpv4DstMask(Ipv4Addr::new(255, 255, 255, 0)),
+            Nla::KeyIpProto(6),
+            Nla::KeyTcpDst(80),
+            Nla::KeyIpTos(0xb8),
+            Nla::KeyIpTosMask(0xfc),
+            Nla::ClassId(0x0001_0010),
+            Nla::Flags(0),
+            Nla::KeyEthType(ETH_P_IP),
+        ]
+    }
+
+    #[test]
+    fn parse_flower_options() {
+        let nlas = NlasIterator::new(&FLOWER_DST[..])
+            .map(|nla| Nla::parse(&nla.unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(nlas, flower_dst());
+    }
+
+    #[test]
+    fn emit_flower_options() {
+        let nlas = flower_dst();
+        assert_eq!(nlas.as_slice().buffer_len(), FLOWER_DST.len());
+        let mut buf = vec![0; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buf);
+        assert_eq!(buf, FLOWER_DST);
+    }
+}
@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+//! Options of the filters (classifiers), nested in the `TCA_OPTIONS` attribute of a filter
+//! message. Their format depends on the kind of the filter.
+
+pub mod flower;
+pub mod u32;
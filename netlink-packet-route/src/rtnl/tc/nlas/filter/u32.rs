@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: MIT
+
+//! Options of the `u32` filter, which matches packets on 32 bits words at given offsets (see
+//! `tc-u32(8)`).
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, tc::action::Action, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::parse_u32,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const KIND: &str = "u32";
+pub const TC_U32_SEL_BUF_LEN: usize = 16;
+pub const TC_U32_KEY_BUF_LEN: usize = 16;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    ClassId(u32),
+    Hash(u32),
+    Link(u32),
+    Divisor(u32),
+    Sel(Sel),
+    Police(Vec<u8>),
+    /// Actions applied to the matching packets
+    Act(Vec<Action>),
+    Indev(Vec<u8>),
+    Pcnt(Vec<u8>),
+    Mark(Vec<u8>),
+    Flags(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes)
+                | Police(bytes)
+                | Indev(bytes)
+                | Pcnt(bytes)
+                | Mark(bytes) => bytes.len(),
+            ClassId(_)
+                | Hash(_)
+                | Link(_)
+                | Divisor(_)
+                | Flags(_) => 4,
+            Sel(sel) => sel.buffer_len(),
+            Act(actions) => actions.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes)
+                | Police(bytes)
+                | Indev(bytes)
+                | Pcnt(bytes)
+                | Mark(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            ClassId(value)
+                | Hash(value)
+                | Link(value)
+                | Divisor(value)
+                | Flags(value) => NativeEndian::write_u32(buffer, *value),
+            Sel(sel) => sel.emit(buffer),
+            Act(actions) => actions.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_U32_UNSPEC,
+            ClassId(_) => TCA_U32_CLASSID,
+            Hash(_) => TCA_U32_HASH,
+            Link(_) => TCA_U32_LINK,
+            Divisor(_) => TCA_U32_DIVISOR,
+            Sel(_) => TCA_U32_SEL,
+            Police(_) => TCA_U32_POLICE,
+            Act(_) => TCA_U32_ACT,
+            Indev(_) => TCA_U32_INDEV,
+            Pcnt(_) => TCA_U32_PCNT,
+            Mark(_) => TCA_U32_MARK,
+            Flags(_) => TCA_U32_FLAGS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_U32_UNSPEC => Self::Unspec(payload.to_vec()),
+            TCA_U32_CLASSID => {
+                Self::ClassId(parse_u32(payload).context("invalid TCA_U32_CLASSID")?)
+            }
+            TCA_U32_HASH => Self::Hash(parse_u32(payload).context("invalid TCA_U32_HASH")?),
+            TCA_U32_LINK => Self::Link(parse_u32(payload).context("invalid TCA_U32_LINK")?),
+            TCA_U32_DIVISOR => {
+                Self::Divisor(parse_u32(payload).context("invalid TCA_U32_DIVISOR")?)
+            }
+            TCA_U32_SEL => Self::Sel(
+                Sel::parse(&TcU32SelBuffer::new_checked(payload)?)
+                    .context("invalid TCA_U32_SEL")?,
+            ),
+            TCA_U32_POLICE => Self::Police(payload.to_vec()),
+            TCA_U32_ACT => {
+                let mut actions = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = nla.context("invalid TCA_U32_ACT nla")?;
+                    actions.push(Action::parse(&nla).context("invalid TCA_U32_ACT")?);
+                }
+                Self::Act(actions)
+            }
+            TCA_U32_INDEV => Self::Indev(payload.to_vec()),
+            TCA_U32_PCNT => Self::Pcnt(payload.to_vec()),
+            TCA_U32_MARK => Self::Mark(payload.to_vec()),
+            TCA_U32_FLAGS => Self::Flags(parse_u32(payload).context("invalid TCA_U32_FLAGS")?),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid u32 nla")?),
+        })
+    }
+}
+
+/// Selector of a `u32` filter (`struct tc_u32_sel`). The fields that the kernel stores in
+/// network byte order (`offmask`, `hmask` and the masks and values of the keys) are converted
+/// to host byte order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Sel {
+    /// `TC_U32_*` flags. A selector without the `TC_U32_TERMINAL` flag does not classify the
+    /// packets nor run the actions of the filter.
+    pub flags: u8,
+    pub offshift: u8,
+    pub offmask: u16,
+    pub off: u16,
+    pub offoff: i16,
+    pub hoff: i16,
+    pub hmask: u32,
+    /// Keys that all must match. A selector without keys matches every packet.
+    pub keys: Vec<Key>,
+}
+
+buffer!(TcU32SelBuffer(TC_U32_SEL_BUF_LEN) {
+    flags: (u8, 0),
+    offshift: (u8, 1),
+    nkeys: (u8, 2),
+    offmask: (u16, 4..6),
+    off: (u16, 6..8),
+    offoff: (i16, 8..10),
+    hoff: (i16, 10..12),
+    hmask: (u32, 12..16),
+    keys: (slice, TC_U32_SEL_BUF_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<TcU32SelBuffer<&'a T>> for Sel {
+    fn parse(buf: &TcU32SelBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let nkeys = buf.nkeys() as usize;
+        if buf.keys().len() < nkeys * TC_U32_KEY_BUF_LEN {
+            return Err(format!("invalid u32 selector: missing keys (expected {})", nkeys).into());
+        }
+        let keys = buf
+            .keys()
+            .chunks_exact(TC_U32_KEY_BUF_LEN)
+            .take(nkeys)
+            .map(|key| Key::parse(&TcU32KeyBuffer::new(key)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Sel {
+            flags: buf.flags(),
+            offshift: buf.offshift(),
+            offmask: u16::from_be(buf.offmask()),
+            off: buf.off(),
+            offoff: buf.offoff(),
+            hoff: buf.hoff(),
+            hmask: u32::from_be(buf.hmask()),
+            keys,
+        })
+    }
+}
+
+impl Emitable for Sel {
+    fn buffer_len(&self) -> usize {
+        TC_U32_SEL_BUF_LEN + self.keys.len() * TC_U32_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32SelBuffer::new(buffer);
+        buffer.set_flags(self.flags);
+        buffer.set_offshift(self.offshift);
+        buffer.set_nkeys(self.keys.len() as u8);
+        buffer.inner_mut()[3] = 0;
+        buffer.set_offmask(self.offmask.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offoff(self.offoff);
+        buffer.set_hoff(self.hoff);
+        buffer.set_hmask(self.hmask.to_be());
+        for (key, chunk) in self
+            .keys
+            .iter()
+            .zip(buffer.keys_mut().chunks_exact_mut(TC_U32_KEY_BUF_LEN))
+        {
+            key.emit(chunk);
+        }
+    }
+}
+
+/// Key of a `u32` selector (`struct tc_u32_key`): the packet matches if the 32 bits word at
+/// offset `off` masked with `mask` is equal to `val`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Key {
+    pub mask: u32,
+    pub val: u32,
+    pub off: i32,
+    pub offmask: i32,
+}
+
+buffer!(TcU32KeyBuffer(TC_U32_KEY_BUF_LEN) {
+    mask: (u32, 0..4),
+    val: (u32, 4..8),
+    off: (i32, 8..12),
+    offmask: (i32, 12..16),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcU32KeyBuffer<T>> for Key {
+    fn parse(buf: &TcU32KeyBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Key {
+            mask: u32::from_be(buf.mask()),
+            val: u32::from_be(buf.val()),
+            off: buf.off(),
+            offmask: buf.offmask(),
+        })
+    }
+}
+
+impl Emitable for Key {
+    fn buffer_len(&self) -> usize {
+        TC_U32_KEY_BUF_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcU32KeyBuffer::new(buffer);
+        buffer.set_mask(self.mask.to_be());
+        buffer.set_val(self.val.to_be());
+        buffer.set_off(self.off);
+        buffer.set_offmask(self.offmask);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::nlas::tc::action::mirred::TcMirred;
+
+    #[rustfmt::skip]
+    static U32_REDIRECT: [u8; 100] = [
+        0x08, 0x00, 0x01, 0x00, // TCA_U32_CLASSID, length 8
+            0x01, 0x00, 0x01, 0x00, // 1:1
+        0x24, 0x00, 0x05, 0x00, // TCA_U32_SEL, length 36
+            0x01, 0x00, 0x01, 0x00, // flags: TC_U32_TERMINAL, offshift, nkeys: 1, padding
+            0x00, 0x00, 0x00, 0x00, // offmask, off
+            0x00, 0x00, 0x00, 0x00, // offoff, hoff
+            0x00, 0x00, 0x00, 0x00, // hmask
+            0xff, 0x00, 0x00, 0x00, // key mask: 0xff000000
+            0x0a, 0x00, 0x00, 0x00, // key val: 0x0a000000
+            0x10, 0x00, 0x00, 0x00, // key off: 16
+            0x00, 0x00, 0x00, 0x00, // key offmask
+        0x38, 0x00, 0x07, 0x00, // TCA_U32_ACT, length 56
+            0x34, 0x00, 0x01, 0x00, // action 1, length 52
+                0x0b, 0x00, 0x01, 0x00, // TCA_ACT_KIND, length 11
+                    0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, 0x00, // "mirred\0" + padding
+                0x24, 0x00, 0x02, 0x00, // TCA_ACT_OPTIONS, length 36
+                    0x20, 0x00, 0x02, 0x00, // TCA_MIRRED_PARMS, length 32
+                        0x00, 0x00, 0x00, 0x00, // index
+                        0x00, 0x00, 0x00, 0x00, // capab
+                        0x04, 0x00, 0x00, 0x00, // action: TC_ACT_STOLEN
+                        0x00, 0x00, 0x00, 0x00, // refcnt
+                        0x00, 0x00, 0x00, 0x00, // bindcnt
+                        0x01, 0x00, 0x00, 0x00, // eaction: TCA_EGRESS_REDIR
+                        0x2a, 0x00, 0x00, 0x00, // ifindex: 42
+    ];
+
+    fn u32_redirect() -> Vec<Nla> {
+        vec![
+            Nla::ClassId(0x0001_0001),
+            Nla::Sel(Sel {
+                flags: TC_U32_TERMINAL,
+                keys: vec![Key {
+                    mask: 0xff00_0000,
+                    val: 0x0a00_0000,
+                    off: 16,
+                    offmask: 0,
+                }],
+                ..Default::default()
+            }),
+            Nla::Act(vec![Action::mirred(TcMirred::egress_redirect(42))]),
+        ]
+    }
+
+    #[test]
+    fn parse_u32_options() {
+        let nlas = NlasIterator::new(&U32_REDIRECT[..])
+            .map(|nla| Nla::parse(&nla.unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(nlas, u32_redirect());
+    }
+
+    #[test]
+    fn emit_u32_options() {
+        let nlas = u32_redirect();
+        assert_eq!(nlas.as_slice().buffer_len(), U32_REDIRECT.len());
+        let mut buf = vec![0; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buf);
+        assert_eq!(buf, U32_REDIRECT);
+    }
+}
@@ -1,5 +1,8 @@
 // SPDX-License-Identifier: MIT
 
+pub mod action;
+pub mod filter;
+
 mod stats;
 pub use self::stats::*;
 
@@ -9,6 +12,9 @@ pub use self::stats_queue::*;
 mod stats_basic;
 pub use self::stats_basic::*;
 
+mod rate_spec;
+pub use self::rate_spec::*;
+
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const TC_RATESPEC_LEN: usize = 12;
+/// Size of a rate table: the transmission times of 256 packet size classes
+pub const TC_RTAB_SIZE: usize = 1024;
+/// Resolution of the clock of the packet scheduler, whose ticks last 64 nanoseconds
+pub const PSCHED_TICKS_PER_SEC: u64 = 1_000_000_000 >> 6;
+
+/// Rate of a shaping qdisc or of a policing action (`struct tc_ratespec`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct TcRateSpec {
+    /// Binary logarithm of the size of the packet size classes of the rate table
+    pub cell_log: u8,
+    /// One of the `TC_LINKLAYER_*` constants
+    pub linklayer: u8,
+    pub overhead: u16,
+    pub cell_align: i16,
+    /// Minimum packet size accounted for
+    pub mpu: u16,
+    /// Rate, in bytes per second
+    pub rate: u32,
+}
+
+impl TcRateSpec {
+    /// Specification of a rate of `rate` bytes per second on an ethernet link, for packets of at
+    /// most `mtu` bytes (2047 if `mtu` is 0). Like `tc`, the size classes of the rate table are
+    /// made as small as possible while still covering packets of `mtu` bytes.
+    pub fn new(rate: u32, mtu: u32) -> Self {
+        let mtu = if mtu == 0 { 2047 } else { mtu };
+        let mut cell_log = 0;
+        while (mtu >> cell_log) > 255 {
+            cell_log += 1;
+        }
+        TcRateSpec {
+            cell_log,
+            linklayer: TC_LINKLAYER_ETHERNET,
+            cell_align: -1,
+            rate,
+            ..Default::default()
+        }
+    }
+
+    /// Time it takes to transmit `size` bytes at this rate, in packet scheduler ticks
+    pub fn transmit_time(&self, size: u32) -> u32 {
+        if self.rate == 0 {
+            return 0;
+        }
+        let ticks = u64::from(size) * PSCHED_TICKS_PER_SEC / u64::from(self.rate);
+        ticks.min(u64::from(u32::MAX)) as u32
+    }
+
+    /// Build the rate table the kernel expects along with this rate specification (the
+    /// `TCA_POLICE_RATE` attribute of a policing action for instance): the time it takes to
+    /// transmit a packet of each size class, as 256 native endian `u32`.
+    pub fn rate_table(&self) -> Vec<u8> {
+        let cell_size = 1u32
+            .checked_shl(u32::from(self.cell_log))
+            .unwrap_or(u32::MAX);
+        let mut table = vec![0; TC_RTAB_SIZE];
+        for (i, slot) in table.chunks_exact_mut(4).enumerate() {
+            let size = (i as u32 + 1)
+                .saturating_mul(cell_size)
+                .max(u32::from(self.mpu));
+            NativeEndian::write_u32(slot, self.transmit_time(size));
+        }
+        table
+    }
+}
+
+buffer!(TcRateSpecBuffer(TC_RATESPEC_LEN) {
+    cell_log: (u8, 0),
+    linklayer: (u8, 1),
+    overhead: (u16, 2..4),
+    cell_align: (i16, 4..6),
+    mpu: (u16, 6..8),
+    rate: (u32, 8..12),
+});
+
+impl<T: AsRef<[u8]>> Parseable<TcRateSpecBuffer<T>> for TcRateSpec {
+    fn parse(buf: &TcRateSpecBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            cell_log: buf.cell_log(),
+            linklayer: buf.linklayer(),
+            overhead: buf.overhead(),
+            cell_align: buf.cell_align(),
+            mpu: buf.mpu(),
+            rate: buf.rate(),
+        })
+    }
+}
+
+impl Emitable for TcRateSpec {
+    fn buffer_len(&self) -> usize {
+        TC_RATESPEC_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = TcRateSpecBuffer::new(buffer);
+        buffer.set_cell_log(self.cell_log);
+        buffer.set_linklayer(self.linklayer);
+        buffer.set_overhead(self.overhead);
+        buffer.set_cell_align(self.cell_align);
+        buffer.set_mpu(self.mpu);
+        buffer.set_rate(self.rate);
+    }
+}
@@ -8,6 +8,8 @@ use crate::{
     LinkHeader,
     LinkMessage,
     NetlinkBuffer,
+    NetlinkMessage,
+    NetlinkPayload,
     RtnlMessage,
     RtnlMessageBuffer,
     RTM_NEWLINK,
@@ -98,3 +100,29 @@ fn test_attach_to_bridge() {
     });
     assert_eq!(expected, actual);
 }
+
+// A message type added by a newer kernel is kept as is, so that it can be skipped
+#[rustfmt::skip]
+#[test]
+fn test_unknown_message_type() {
+    let data = vec![
+        0x18, 0x00, 0x00, 0x00, // length = 24
+        0xc8, 0x00, // message type = 200 (unknown)
+        0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // seq id
+        0x00, 0x00, 0x00, 0x00, // pid
+        // payload
+        0x02, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00,
+    ];
+    let msg = NetlinkMessage::<RtnlMessage>::deserialize(&data).unwrap();
+    assert_eq!(msg.header.message_type, 200);
+    assert_eq!(
+        msg.payload,
+        NetlinkPayload::InnerMessage(RtnlMessage::Other((200, data[16..].to_vec())))
+    );
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf);
+    assert_eq!(buf, data);
+}
@@ -26,6 +26,7 @@ fn main() {
             extensions: ExtensionFlags::empty(),
             states: StateFlags::all(),
             socket_id: SocketId::new_v4(),
+            bytecode: None,
         })
         .into(),
     };
@@ -132,6 +132,28 @@ pub const INET_DIAG_BBRINFO: u16 = 16;
 pub const INET_DIAG_CLASS_ID: u16 = 17;
 pub const INET_DIAG_MD5SIG: u16 = 18;
 
+// Attributes of inet requests
+pub const INET_DIAG_REQ_NONE: u16 = 0;
+pub const INET_DIAG_REQ_BYTECODE: u16 = 1;
+pub const INET_DIAG_REQ_SK_BPF_STORAGES: u16 = 2;
+pub const INET_DIAG_REQ_PROTOCOL: u16 = 3;
+
+// Instructions of the INET_DIAG_REQ_BYTECODE programs
+pub const INET_DIAG_BC_NOP: u8 = 0;
+pub const INET_DIAG_BC_JMP: u8 = 1;
+pub const INET_DIAG_BC_S_GE: u8 = 2;
+pub const INET_DIAG_BC_S_LE: u8 = 3;
+pub const INET_DIAG_BC_D_GE: u8 = 4;
+pub const INET_DIAG_BC_D_LE: u8 = 5;
+pub const INET_DIAG_BC_AUTO: u8 = 6;
+pub const INET_DIAG_BC_S_COND: u8 = 7;
+pub const INET_DIAG_BC_D_COND: u8 = 8;
+pub const INET_DIAG_BC_DEV_COND: u8 = 9;
+pub const INET_DIAG_BC_MARK_COND: u8 = 10;
+pub const INET_DIAG_BC_S_EQ: u8 = 11;
+pub const INET_DIAG_BC_D_EQ: u8 = 12;
+pub const INET_DIAG_BC_CGROUP_COND: u8 = 13;
+
 /// (both server and client) represents an open connection, data
 /// received can be delivered to the user. The normal state for the
 /// data transfer phase of the connection.
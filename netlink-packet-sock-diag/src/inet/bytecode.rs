@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+
+use std::{net::IpAddr, ops::Not};
+
+use crate::constants::*;
+
+/// A predicate on the sockets of an inet dump, like the filters of `ss`. Once compiled (see
+/// [`Filter::compile`]) and attached to the request (see
+/// [`InetRequest::bytecode`](super::InetRequest::bytecode)), the kernel only reports the sockets
+/// matching it.
+///
+/// ```rust
+/// use netlink_packet_sock_diag::inet::Filter;
+///
+/// // Equivalent to `ss '( dport = :443 or dport = :80 ) and not sport < :1024'`
+/// let filter = Filter::destination_port(443)
+///     .or(Filter::destination_port(80))
+///     .and(!Filter::SourcePortLe(1023));
+/// let bytecode = filter.compile();
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Filter {
+    /// The local address and port of the socket match (`INET_DIAG_BC_S_COND`)
+    Source(HostCondition),
+    /// The peer address and port of the socket match (`INET_DIAG_BC_D_COND`)
+    Destination(HostCondition),
+    /// The local port is greater than or equal to the given one (`INET_DIAG_BC_S_GE`)
+    SourcePortGe(u16),
+    /// The local port is lower than or equal to the given one (`INET_DIAG_BC_S_LE`)
+    SourcePortLe(u16),
+    /// The peer port is greater than or equal to the given one (`INET_DIAG_BC_D_GE`)
+    DestinationPortGe(u16),
+    /// The peer port is lower than or equal to the given one (`INET_DIAG_BC_D_LE`)
+    DestinationPortLe(u16),
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+    Not(Box<Filter>),
+}
+
+/// Condition on the address and port of one end of a socket
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct HostCondition {
+    /// Prefix the address must be in, `None` matches any address. IPv4 prefixes also match the
+    /// IPv4-mapped addresses of IPv6 sockets.
+    pub prefix: Option<(IpAddr, u8)>,
+    /// `None` matches any port
+    pub port: Option<u16>,
+}
+
+/// An instruction of the program, whose `no` jump target is relative to the start of the program
+/// instead of the instruction itself until the program is encoded.
+///
+/// As in the kernel, a program accepts a socket when it jumps right to its end, and rejects it
+/// when it jumps 4 bytes past its end. Fragments of the program are compiled separately with
+/// these conventions, then their targets are adjusted when they are combined.
+struct Op {
+    code: u8,
+    no: usize,
+    /// Data following the `inet_diag_bc_op` of the instruction
+    data: Vec<u8>,
+}
+
+impl Op {
+    fn len(&self) -> usize {
+        4 + self.data.len()
+    }
+
+    /// Unconditional jump to `target`
+    fn jump(target: usize) -> Self {
+        Op {
+            code: INET_DIAG_BC_JMP,
+            no: target,
+            data: vec![],
+        }
+    }
+
+    /// Port comparison, which the kernel reads from the `no` field of a second `inet_diag_bc_op`
+    fn port_comparison(code: u8, port: u16) -> Self {
+        let mut data = vec![INET_DIAG_BC_NOP, 0];
+        data.extend_from_slice(&port.to_ne_bytes());
+        Op { code, no: 12, data }
+    }
+
+    /// Address and port condition, whose `struct inet_diag_hostcond` follows the instruction
+    fn host_condition(code: u8, condition: &HostCondition) -> Self {
+        let (family, prefix_len, address) = match condition.prefix {
+            None => (AF_UNSPEC, 0, vec![]),
+            Some((IpAddr::V4(address), len)) => (AF_INET, len, address.octets().to_vec()),
+            Some((IpAddr::V6(address), len)) => (AF_INET6, len, address.octets().to_vec()),
+        };
+        let port = condition.port.map_or(-1, i32::from);
+
+        let mut data = vec![family, prefix_len, 0, 0];
+        data.extend_from_slice(&port.to_ne_bytes());
+        data.extend_from_slice(&address);
+        let no = 4 + data.len() + 4;
+        Op { code, no, data }
+    }
+}
+
+fn program_len(ops: &[Op]) -> usize {
+    ops.iter().map(Op::len).sum()
+}
+
+impl Filter {
+    /// Sockets whose local port is `port`
+    pub fn source_port(port: u16) -> Self {
+        Filter::Source(HostCondition {
+            prefix: None,
+            port: Some(port),
+        })
+    }
+
+    /// Sockets whose peer port is `port`
+    pub fn destination_port(port: u16) -> Self {
+        Filter::Destination(HostCondition {
+            prefix: None,
+            port: Some(port),
+        })
+    }
+
+    /// Sockets whose local address is in the given prefix
+    pub fn source_prefix(address: IpAddr, prefix_len: u8) -> Self {
+        Filter::Source(HostCondition {
+            prefix: Some((address, prefix_len)),
+            port: None,
+        })
+    }
+
+    /// Sockets whose peer address is in the given prefix
+    pub fn destination_prefix(address: IpAddr, prefix_len: u8) -> Self {
+        Filter::Destination(HostCondition {
+            prefix: Some((address, prefix_len)),
+            port: None,
+        })
+    }
+
+    /// Sockets matching both `self` and `other`
+    pub fn and(self, other: Filter) -> Self {
+        Filter::And(Box::new(self), Box::new(other))
+    }
+
+    /// Sockets matching `self`, `other`, or both
+    pub fn or(self, other: Filter) -> Self {
+        Filter::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Compile the filter into an `INET_DIAG_REQ_BYTECODE` program
+    pub fn compile(&self) -> Vec<u8> {
+        let mut bytecode = vec![];
+        for op in self.ops() {
+            let offset = bytecode.len();
+            bytecode.push(op.code);
+            bytecode.push(op.len() as u8);
+            bytecode.extend_from_slice(&((op.no - offset) as u16).to_ne_bytes());
+            bytecode.extend_from_slice(&op.data);
+        }
+        bytecode
+    }
+
+    fn ops(&self) -> Vec<Op> {
+        use Filter::*;
+        match self {
+            Source(condition) => vec![Op::host_condition(INET_DIAG_BC_S_COND, condition)],
+            Destination(condition) => vec![Op::host_condition(INET_DIAG_BC_D_COND, condition)],
+            SourcePortGe(port) => vec![Op::port_comparison(INET_DIAG_BC_S_GE, *port)],
+            SourcePortLe(port) => vec![Op::port_comparison(INET_DIAG_BC_S_LE, *port)],
+            DestinationPortGe(port) => vec![Op::port_comparison(INET_DIAG_BC_D_GE, *port)],
+            DestinationPortLe(port) => vec![Op::port_comparison(INET_DIAG_BC_D_LE, *port)],
+            And(left, right) => {
+                let (mut ops, right) = (left.ops(), right.ops());
+                let (left_len, right_len) = (program_len(&ops), program_len(&right));
+                // When the left side rejects the socket, so does the whole program. When it
+                // accepts it, execution goes on with the right side.
+                for op in ops.iter_mut().filter(|op| op.no == left_len + 4) {
+                    op.no += right_len;
+                }
+                ops.extend(right.into_iter().map(|op| Op {
+                    no: op.no + left_len,
+                    ..op
+                }));
+                ops
+            }
+            Or(left, right) => {
+                let (mut ops, right) = (left.ops(), right.ops());
+                let (left_len, right_len) = (program_len(&ops), program_len(&right));
+                // When the left side accepts the socket, jump to the end of the program. When it
+                // rejects it, it jumps past that jump, to the right side.
+                ops.push(Op::jump(left_len + 4 + right_len));
+                ops.extend(right.into_iter().map(|op| Op {
+                    no: op.no + left_len + 4,
+                    ..op
+                }));
+                ops
+            }
+            Not(filter) => {
+                let mut ops = filter.ops();
+                // When the inner program rejects the socket, it jumps past this jump, to the end
+                // of the program where the socket is accepted. When it accepts it, this jump
+                // rejects it.
+                let len = program_len(&ops);
+                ops.push(Op::jump(len + 8));
+                ops
+            }
+        }
+    }
+}
+
+impl Not for Filter {
+    type Output = Filter;
+
+    fn not(self) -> Self::Output {
+        Filter::Not(Box::new(self))
+    }
+}
@@ -1,5 +1,8 @@
 // SPDX-License-Identifier: MIT
 
+mod bytecode;
+pub use self::bytecode::*;
+
 mod socket_id;
 pub use self::socket_id::*;
 
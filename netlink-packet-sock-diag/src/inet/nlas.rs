@@ -62,10 +62,14 @@ impl Emitable for LegacyMemInfo {
 }
 
 pub const MEM_INFO_LEN: usize = 36;
-
-// FIXME: the last 2 fields are not present on old linux kernels. We
-// should support optional fields in the `buffer!` macro.
-buffer!(MemInfoBuffer(MEM_INFO_LEN) {
+/// Length of the `INET_DIAG_SKMEMINFO` array on kernels older than 3.9,
+/// which don't report the backlog queue length nor the drops.
+pub const MEM_INFO_MIN_LEN: usize = 28;
+
+// The array is indexed by the kernel's `SK_MEMINFO_*` constants. The
+// last 2 fields are not present on old linux kernels, so the getters
+// must only be called after checking the buffer length.
+buffer!(MemInfoBuffer(MEM_INFO_MIN_LEN) {
     receive_queue: (u32, 0..4),
     receive_queue_max: (u32, 4..8),
     bottom_send_queues: (u32, 8..12),
@@ -169,19 +173,19 @@ pub struct MemInfo {
     /// half of network stack waiting for the process to release the
     /// socket, the packets are enqueued in the backlog queue. Upon
     /// releasing the socket, those packets are processed and put in
-    /// the regular receive queue.
-    // FIXME: this should be an Option because it's not present on old
-    // linux kernels.
-    pub backlog_queue_length: u32,
-    /// The amount of packets dropped. Depending on the kernel
-    /// version, this field may not be present.
-    // FIXME: this should be an Option because it's not present on old
-    // linux kernels.
-    pub drops: u32,
+    /// the regular receive queue. This attribute is known as
+    /// `sk_backlog.len` in the kernel. It is not reported by kernels
+    /// older than 3.9.
+    pub backlog_queue_length: Option<u32>,
+    /// The amount of packets dropped. This attribute is known as
+    /// `sk_drops` in the kernel. It is not reported by kernels older
+    /// than 4.7.
+    pub drops: Option<u32>,
 }
 
 impl<T: AsRef<[u8]>> Parseable<MemInfoBuffer<T>> for MemInfo {
     fn parse(buf: &MemInfoBuffer<T>) -> Result<Self, DecodeError> {
+        let len = buf.buffer.as_ref().len();
         Ok(Self {
             receive_queue: buf.receive_queue(),
             receive_queue_max: buf.receive_queue_max(),
@@ -190,15 +194,29 @@ impl<T: AsRef<[u8]>> Parseable<MemInfoBuffer<T>> for MemInfo {
             cache: buf.cache(),
             send_queue: buf.send_queue(),
             options: buf.options(),
-            backlog_queue_length: buf.backlog_queue_length(),
-            drops: buf.drops(),
+            backlog_queue_length: if len >= 32 {
+                Some(buf.backlog_queue_length())
+            } else {
+                None
+            },
+            drops: if len >= MEM_INFO_LEN {
+                Some(buf.drops())
+            } else {
+                None
+            },
         })
     }
 }
 
 impl Emitable for MemInfo {
     fn buffer_len(&self) -> usize {
-        MEM_INFO_LEN
+        if self.drops.is_some() {
+            MEM_INFO_LEN
+        } else if self.backlog_queue_length.is_some() {
+            32
+        } else {
+            MEM_INFO_MIN_LEN
+        }
     }
 
     fn emit(&self, buf: &mut [u8]) {
@@ -210,8 +228,427 @@ impl Emitable for MemInfo {
         buf.set_cache(self.cache);
         buf.set_send_queue(self.send_queue);
         buf.set_options(self.options);
-        buf.set_backlog_queue_length(self.backlog_queue_length);
-        buf.set_drops(self.drops);
+        // the kernel never reports the drops without the backlog
+        // queue length, so emit a zero backlog in that case
+        if self.backlog_queue_length.is_some() || self.drops.is_some() {
+            buf.set_backlog_queue_length(self.backlog_queue_length.unwrap_or(0));
+        }
+        if let Some(drops) = self.drops {
+            buf.set_drops(drops);
+        }
+    }
+}
+
+pub const TCP_INFO_LEN: usize = 232;
+/// Length of `struct tcp_info` before Linux 3.15. The struct grew
+/// with most kernel releases since then, so only this prefix is
+/// mandatory.
+pub const TCP_INFO_MIN_LEN: usize = 104;
+
+// The fields following `total_retrans` are not present on older
+// kernels: their getters must only be called after checking the
+// buffer length.
+buffer!(TcpInfoBuffer(TCP_INFO_MIN_LEN) {
+    state: (u8, 0),
+    ca_state: (u8, 1),
+    retransmits: (u8, 2),
+    probes: (u8, 3),
+    backoff: (u8, 4),
+    options: (u8, 5),
+    wscale: (u8, 6),
+    delivery_rate_app_limited: (u8, 7),
+    rto: (u32, 8..12),
+    ato: (u32, 12..16),
+    snd_mss: (u32, 16..20),
+    rcv_mss: (u32, 20..24),
+    unacked: (u32, 24..28),
+    sacked: (u32, 28..32),
+    lost: (u32, 32..36),
+    retrans: (u32, 36..40),
+    fackets: (u32, 40..44),
+    last_data_sent: (u32, 44..48),
+    last_ack_sent: (u32, 48..52),
+    last_data_recv: (u32, 52..56),
+    last_ack_recv: (u32, 56..60),
+    pmtu: (u32, 60..64),
+    rcv_ssthresh: (u32, 64..68),
+    rtt: (u32, 68..72),
+    rttvar: (u32, 72..76),
+    snd_ssthresh: (u32, 76..80),
+    snd_cwnd: (u32, 80..84),
+    advmss: (u32, 84..88),
+    reordering: (u32, 88..92),
+    rcv_rtt: (u32, 92..96),
+    rcv_space: (u32, 96..100),
+    total_retrans: (u32, 100..104),
+    pacing_rate: (u64, 104..112),
+    max_pacing_rate: (u64, 112..120),
+    bytes_acked: (u64, 120..128),
+    bytes_received: (u64, 128..136),
+    segs_out: (u32, 136..140),
+    segs_in: (u32, 140..144),
+    notsent_bytes: (u32, 144..148),
+    min_rtt: (u32, 148..152),
+    data_segs_in: (u32, 152..156),
+    data_segs_out: (u32, 156..160),
+    delivery_rate: (u64, 160..168),
+    busy_time: (u64, 168..176),
+    rwnd_limited: (u64, 176..184),
+    sndbuf_limited: (u64, 184..192),
+    delivered: (u32, 192..196),
+    delivered_ce: (u32, 196..200),
+    bytes_sent: (u64, 200..208),
+    bytes_retrans: (u64, 208..216),
+    dsack_dups: (u32, 216..220),
+    reord_seen: (u32, 220..224),
+    rcv_ooopack: (u32, 224..228),
+    snd_wnd: (u32, 228..232),
+});
+
+/// TCP level information about a socket (`struct tcp_info`), as
+/// reported by `ss --info`. The kernel appends new fields to the
+/// struct over time, and the ones a kernel does not know about are
+/// `None`.
+// https://unix.stackexchange.com/questions/542712/detailed-output-of-ss-command
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TcpInfo {
+    /// State of the TCP connection: one of `TCP_ESTABLISHED`,
+    /// `TCP_SYN_SENT`, `TCP_SYN_RECV`, `TCP_FIN_WAIT1`,
+    /// `TCP_FIN_WAIT2`, `TCP_TIME_WAIT`, `TCP_CLOSE`,
+    /// `TCP_CLOSE_WAIT`, `TCP_LAST_ACK`, `TCP_LISTEN`, `TCP_CLOSING`.
+    /// This attribute is known as `tcpi_state` in the kernel.
+    pub state: u8,
+    /// Congestion avoidance state: one of `TCP_CA_OPEN`,
+    /// `TCP_CA_DISORDER`, `TCP_CA_CWR`, `TCP_CA_RECOVERY`,
+    /// `TCP_CA_LOSS`. This attribute is known as `tcpi_ca_state` in
+    /// the kernel.
+    pub ca_state: u8,
+    /// Number of retransmissions on timeout invoked
+    pub retransmits: u8,
+    /// Number of window or keep alive probes sent
+    pub probes: u8,
+    /// Number of times the retransmission backoff timer invoked
+    pub backoff: u8,
+    /// Options enabled on the connection: a combination of the
+    /// `TCPI_OPT_*` flags
+    pub options: u8,
+    /// Window scale factors: the send window scale is in the lower
+    /// 4 bits, the receive window scale in the upper 4 bits
+    pub wscale: u8,
+    /// Bit field. The lowest bit indicates whether the goodput was
+    /// measured when the socket's throughput was limited by the
+    /// sending application, the next 2 bits hold the reason of the
+    /// last TCP fast open failure. This byte was padding on old
+    /// kernels.
+    pub delivery_rate_app_limited: u8,
+    /// Value of the RTO (Retransmission TimeOut) timer, in
+    /// microseconds
+    pub rto: u32,
+    /// Value of the ATO (ACK TimeOut) timer, in microseconds
+    pub ato: u32,
+    /// MSS (Maximum Segment Size) used for sending
+    pub snd_mss: u32,
+    /// MSS (Maximum Segment Size) estimated for the peer
+    pub rcv_mss: u32,
+    /// Number of segments that have not been ACKnowledged yet, ie the
+    /// number of in-flight segments.
+    pub unacked: u32,
+    /// Number of segments that have been SACKed
+    pub sacked: u32,
+    /// Number of segments that have been lost
+    pub lost: u32,
+    /// Number of segments that are being retransmitted
+    pub retrans: u32,
+    /// Number of segments that have been FACKed
+    pub fackets: u32,
+    /// Time since the last data was sent, in milliseconds
+    pub last_data_sent: u32,
+    /// Not implemented by the kernel, always 0
+    pub last_ack_sent: u32,
+    /// Time since the last data was received, in milliseconds
+    pub last_data_recv: u32,
+    /// Time since the last ACK was received, in milliseconds
+    pub last_ack_recv: u32,
+    /// Path MTU
+    pub pmtu: u32,
+    /// Current window clamp
+    pub rcv_ssthresh: u32,
+    /// Smoothed RTT (Round Trip Time), in microseconds
+    pub rtt: u32,
+    /// Medium deviation of the RTT, in microseconds
+    pub rttvar: u32,
+    /// Slow-Start Threshold
+    pub snd_ssthresh: u32,
+    /// Size of the congestion window, in segments
+    pub snd_cwnd: u32,
+    /// MSS advertised by this peer
+    pub advmss: u32,
+    /// Reordering metric, in segments
+    pub reordering: u32,
+    /// Receive side RTT estimate, in microseconds
+    pub rcv_rtt: u32,
+    /// Receive buffer space advertised to the peer
+    pub rcv_space: u32,
+    /// Total number of retransmitted segments
+    pub total_retrans: u32,
+    /// Current pacing rate, in bytes per second (Linux 3.15)
+    pub pacing_rate: Option<u64>,
+    /// Maximum pacing rate, in bytes per second (Linux 3.15)
+    pub max_pacing_rate: Option<u64>,
+    /// Number of bytes ACKed (RFC4898 tcpEStatsAppHCThruOctetsAcked,
+    /// Linux 4.1)
+    pub bytes_acked: Option<u64>,
+    /// Number of bytes received (RFC4898
+    /// tcpEStatsAppHCThruOctetsReceived, Linux 4.1)
+    pub bytes_received: Option<u64>,
+    /// Number of segments sent (RFC4898 tcpEStatsPerfSegsOut, Linux
+    /// 4.2)
+    pub segs_out: Option<u32>,
+    /// Number of segments received (RFC4898 tcpEStatsPerfSegsIn,
+    /// Linux 4.2)
+    pub segs_in: Option<u32>,
+    /// Number of bytes in the send queue that have not been sent yet
+    /// (Linux 4.6)
+    pub notsent_bytes: Option<u32>,
+    /// Minimum RTT observed, in microseconds (Linux 4.6)
+    pub min_rtt: Option<u32>,
+    /// Number of segments received carrying data (RFC4898
+    /// tcpEStatsDataSegsIn, Linux 4.6)
+    pub data_segs_in: Option<u32>,
+    /// Number of segments sent carrying data (RFC4898
+    /// tcpEStatsDataSegsOut, Linux 4.6)
+    pub data_segs_out: Option<u32>,
+    /// The most recent goodput, in bytes per second (Linux 4.9)
+    pub delivery_rate: Option<u64>,
+    /// Time spent busy sending data, in microseconds (Linux 4.10)
+    pub busy_time: Option<u64>,
+    /// Time spent limited by the receive window, in microseconds
+    /// (Linux 4.10)
+    pub rwnd_limited: Option<u64>,
+    /// Time spent limited by the send buffer, in microseconds
+    /// (Linux 4.10)
+    pub sndbuf_limited: Option<u64>,
+    /// Number of segments delivered (Linux 4.18)
+    pub delivered: Option<u32>,
+    /// Number of segments delivered with the CE mark (Linux 4.18)
+    pub delivered_ce: Option<u32>,
+    /// Number of bytes sent, retransmissions included (RFC4898
+    /// tcpEStatsPerfHCDataOctetsOut, Linux 4.19)
+    pub bytes_sent: Option<u64>,
+    /// Number of bytes retransmitted (RFC4898
+    /// tcpEStatsPerfOctetsRetrans, Linux 4.19)
+    pub bytes_retrans: Option<u64>,
+    /// Number of DSACKs received (RFC4898 tcpEStatsStackDSACKDups,
+    /// Linux 4.19)
+    pub dsack_dups: Option<u32>,
+    /// Number of reordering events seen (Linux 4.19)
+    pub reord_seen: Option<u32>,
+    /// Number of out-of-order packets received (Linux 5.4)
+    pub rcv_ooopack: Option<u32>,
+    /// Peer's advertised receive window after scaling, in bytes
+    /// (Linux 5.4)
+    pub snd_wnd: Option<u32>,
+}
+
+impl<T: AsRef<[u8]>> Parseable<TcpInfoBuffer<T>> for TcpInfo {
+    fn parse(buf: &TcpInfoBuffer<T>) -> Result<Self, DecodeError> {
+        let len = buf.buffer.as_ref().len();
+        Ok(Self {
+            state: buf.state(),
+            ca_state: buf.ca_state(),
+            retransmits: buf.retransmits(),
+            probes: buf.probes(),
+            backoff: buf.backoff(),
+            options: buf.options(),
+            wscale: buf.wscale(),
+            delivery_rate_app_limited: buf.delivery_rate_app_limited(),
+            rto: buf.rto(),
+            ato: buf.ato(),
+            snd_mss: buf.snd_mss(),
+            rcv_mss: buf.rcv_mss(),
+            unacked: buf.unacked(),
+            sacked: buf.sacked(),
+            lost: buf.lost(),
+            retrans: buf.retrans(),
+            fackets: buf.fackets(),
+            last_data_sent: buf.last_data_sent(),
+            last_ack_sent: buf.last_ack_sent(),
+            last_data_recv: buf.last_data_recv(),
+            last_ack_recv: buf.last_ack_recv(),
+            pmtu: buf.pmtu(),
+            rcv_ssthresh: buf.rcv_ssthresh(),
+            rtt: buf.rtt(),
+            rttvar: buf.rttvar(),
+            snd_ssthresh: buf.snd_ssthresh(),
+            snd_cwnd: buf.snd_cwnd(),
+            advmss: buf.advmss(),
+            reordering: buf.reordering(),
+            rcv_rtt: buf.rcv_rtt(),
+            rcv_space: buf.rcv_space(),
+            total_retrans: buf.total_retrans(),
+            pacing_rate: (len >= 112).then(|| buf.pacing_rate()),
+            max_pacing_rate: (len >= 120).then(|| buf.max_pacing_rate()),
+            bytes_acked: (len >= 128).then(|| buf.bytes_acked()),
+            bytes_received: (len >= 136).then(|| buf.bytes_received()),
+            segs_out: (len >= 140).then(|| buf.segs_out()),
+            segs_in: (len >= 144).then(|| buf.segs_in()),
+            notsent_bytes: (len >= 148).then(|| buf.notsent_bytes()),
+            min_rtt: (len >= 152).then(|| buf.min_rtt()),
+            data_segs_in: (len >= 156).then(|| buf.data_segs_in()),
+            data_segs_out: (len >= 160).then(|| buf.data_segs_out()),
+            delivery_rate: (len >= 168).then(|| buf.delivery_rate()),
+            busy_time: (len >= 176).then(|| buf.busy_time()),
+            rwnd_limited: (len >= 184).then(|| buf.rwnd_limited()),
+            sndbuf_limited: (len >= 192).then(|| buf.sndbuf_limited()),
+            delivered: (len >= 196).then(|| buf.delivered()),
+            delivered_ce: (len >= 200).then(|| buf.delivered_ce()),
+            bytes_sent: (len >= 208).then(|| buf.bytes_sent()),
+            bytes_retrans: (len >= 216).then(|| buf.bytes_retrans()),
+            dsack_dups: (len >= 220).then(|| buf.dsack_dups()),
+            reord_seen: (len >= 224).then(|| buf.reord_seen()),
+            rcv_ooopack: (len >= 228).then(|| buf.rcv_ooopack()),
+            snd_wnd: (len >= 232).then(|| buf.snd_wnd()),
+        })
+    }
+}
+
+impl Emitable for TcpInfo {
+    fn buffer_len(&self) -> usize {
+        // the struct ends with the last field that is set
+        let ends = [
+            (self.pacing_rate.is_some(), 112),
+            (self.max_pacing_rate.is_some(), 120),
+            (self.bytes_acked.is_some(), 128),
+            (self.bytes_received.is_some(), 136),
+            (self.segs_out.is_some(), 140),
+            (self.segs_in.is_some(), 144),
+            (self.notsent_bytes.is_some(), 148),
+            (self.min_rtt.is_some(), 152),
+            (self.data_segs_in.is_some(), 156),
+            (self.data_segs_out.is_some(), 160),
+            (self.delivery_rate.is_some(), 168),
+            (self.busy_time.is_some(), 176),
+            (self.rwnd_limited.is_some(), 184),
+            (self.sndbuf_limited.is_some(), 192),
+            (self.delivered.is_some(), 196),
+            (self.delivered_ce.is_some(), 200),
+            (self.bytes_sent.is_some(), 208),
+            (self.bytes_retrans.is_some(), 216),
+            (self.dsack_dups.is_some(), 220),
+            (self.reord_seen.is_some(), 224),
+            (self.rcv_ooopack.is_some(), 228),
+            (self.snd_wnd.is_some(), 232),
+        ];
+        ends.iter()
+            .rev()
+            .find(|(is_set, _)| *is_set)
+            .map(|(_, end)| *end)
+            .unwrap_or(TCP_INFO_MIN_LEN)
+    }
+
+    fn emit(&self, buf: &mut [u8]) {
+        let len = self.buffer_len();
+        // zero the fields that are not set but precede a field that is
+        buf[..len].fill(0);
+        let mut buf = TcpInfoBuffer::new(buf);
+        buf.set_state(self.state);
+        buf.set_ca_state(self.ca_state);
+        buf.set_retransmits(self.retransmits);
+        buf.set_probes(self.probes);
+        buf.set_backoff(self.backoff);
+        buf.set_options(self.options);
+        buf.set_wscale(self.wscale);
+        buf.set_delivery_rate_app_limited(self.delivery_rate_app_limited);
+        buf.set_rto(self.rto);
+        buf.set_ato(self.ato);
+        buf.set_snd_mss(self.snd_mss);
+        buf.set_rcv_mss(self.rcv_mss);
+        buf.set_unacked(self.unacked);
+        buf.set_sacked(self.sacked);
+        buf.set_lost(self.lost);
+        buf.set_retrans(self.retrans);
+        buf.set_fackets(self.fackets);
+        buf.set_last_data_sent(self.last_data_sent);
+        buf.set_last_ack_sent(self.last_ack_sent);
+        buf.set_last_data_recv(self.last_data_recv);
+        buf.set_last_ack_recv(self.last_ack_recv);
+        buf.set_pmtu(self.pmtu);
+        buf.set_rcv_ssthresh(self.rcv_ssthresh);
+        buf.set_rtt(self.rtt);
+        buf.set_rttvar(self.rttvar);
+        buf.set_snd_ssthresh(self.snd_ssthresh);
+        buf.set_snd_cwnd(self.snd_cwnd);
+        buf.set_advmss(self.advmss);
+        buf.set_reordering(self.reordering);
+        buf.set_rcv_rtt(self.rcv_rtt);
+        buf.set_rcv_space(self.rcv_space);
+        buf.set_total_retrans(self.total_retrans);
+        if let Some(value) = self.pacing_rate {
+            buf.set_pacing_rate(value);
+        }
+        if let Some(value) = self.max_pacing_rate {
+            buf.set_max_pacing_rate(value);
+        }
+        if let Some(value) = self.bytes_acked {
+            buf.set_bytes_acked(value);
+        }
+        if let Some(value) = self.bytes_received {
+            buf.set_bytes_received(value);
+        }
+        if let Some(value) = self.segs_out {
+            buf.set_segs_out(value);
+        }
+        if let Some(value) = self.segs_in {
+            buf.set_segs_in(value);
+        }
+        if let Some(value) = self.notsent_bytes {
+            buf.set_notsent_bytes(value);
+        }
+        if let Some(value) = self.min_rtt {
+            buf.set_min_rtt(value);
+        }
+        if let Some(value) = self.data_segs_in {
+            buf.set_data_segs_in(value);
+        }
+        if let Some(value) = self.data_segs_out {
+            buf.set_data_segs_out(value);
+        }
+        if let Some(value) = self.delivery_rate {
+            buf.set_delivery_rate(value);
+        }
+        if let Some(value) = self.busy_time {
+            buf.set_busy_time(value);
+        }
+        if let Some(value) = self.rwnd_limited {
+            buf.set_rwnd_limited(value);
+        }
+        if let Some(value) = self.sndbuf_limited {
+            buf.set_sndbuf_limited(value);
+        }
+        if let Some(value) = self.delivered {
+            buf.set_delivered(value);
+        }
+        if let Some(value) = self.delivered_ce {
+            buf.set_delivered_ce(value);
+        }
+        if let Some(value) = self.bytes_sent {
+            buf.set_bytes_sent(value);
+        }
+        if let Some(value) = self.bytes_retrans {
+            buf.set_bytes_retrans(value);
+        }
+        if let Some(value) = self.dsack_dups {
+            buf.set_dsack_dups(value);
+        }
+        if let Some(value) = self.reord_seen {
+            buf.set_reord_seen(value);
+        }
+        if let Some(value) = self.rcv_ooopack {
+            buf.set_rcv_ooopack(value);
+        }
+        if let Some(value) = self.snd_wnd {
+            buf.set_snd_wnd(value);
+        }
     }
 }
 
@@ -223,8 +660,7 @@ pub enum Nla {
     // ref: https://patchwork.ozlabs.org/patch/154816/
     LegacyMemInfo(LegacyMemInfo),
     /// the TCP information
-    // TODO: parse tcp_info properly
-    TcpInfo(Vec<u8>),
+    TcpInfo(TcpInfo),
     /// the congestion control algorithm used
     Congestion(String),
     /// the TOS of the socket.
@@ -252,11 +688,11 @@ impl crate::utils::nla::Nla for Nla {
         use self::Nla::*;
         match *self {
             LegacyMemInfo(_) => LEGACY_MEM_INFO_LEN,
-            TcpInfo(ref bytes) => bytes.len(),
+            TcpInfo(ref value) => value.buffer_len(),
             // +1 because we need to append a null byte
             Congestion(ref s) => s.as_bytes().len() + 1,
             Tos(_) | Tc(_) | Shutdown(_) | Protocol(_) | SkV6Only(_) => 1,
-            MemInfo(_) => MEM_INFO_LEN,
+            MemInfo(ref value) => value.buffer_len(),
             Mark(_) | ClassId(_) => 4,
             Other(ref attr) => attr.value_len(),
         }
@@ -266,7 +702,7 @@ impl crate::utils::nla::Nla for Nla {
         use self::Nla::*;
         match *self {
             LegacyMemInfo(ref value) => value.emit(buffer),
-            TcpInfo(ref bytes) => buffer[..bytes.len()].copy_from_slice(&bytes[..]),
+            TcpInfo(ref value) => value.emit(buffer),
             Congestion(ref s) => {
                 buffer[..s.len()].copy_from_slice(s.as_bytes());
                 buffer[s.len()] = 0;
@@ -307,7 +743,11 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
                 let buf = LegacyMemInfoBuffer::new_checked(payload).context(err)?;
                 Self::LegacyMemInfo(LegacyMemInfo::parse(&buf).context(err)?)
             }
-            INET_DIAG_INFO => Self::TcpInfo(payload.to_vec()),
+            INET_DIAG_INFO => {
+                let err = "invalid INET_DIAG_INFO value";
+                let buf = TcpInfoBuffer::new_checked(payload).context(err)?;
+                Self::TcpInfo(TcpInfo::parse(&buf).context(err)?)
+            }
             INET_DIAG_CONG => {
                 Self::Congestion(parse_string(payload).context("invalid INET_DIAG_CONG value")?)
             }
@@ -341,191 +781,3 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
         })
     }
 }
-
-// buffer!(TcpInfoBuffer(TCP_INFO_LEN) {
-//     // State of the TCP connection. This should be set to one of the
-//     // `TCP_*` constants: `TCP_ESTABLISHED`, `TCP_SYN_SENT`, etc. This
-//     // attribute is known as `tcpi_state` in the kernel.
-//     state: (u8, 0),
-//     // State of congestion avoidance. Sender's congestion state
-//     // indicating normal or abnormal situations in the last round of
-//     // packets sent. The state is driven by the ACK information and
-//     // timer events. This should be set to one of the `TCP_CA_*`
-//     // constants. This attribute is known as `tcpi_ca_state` in the
-//     // kernel.
-//     congestion_avoidance_state: (u8, 1),
-//     // Number of retranmissions on timeout invoked. This attribute is
-//     // known as `tcpi_retransmits` in the kernel.
-//     retransmits: (u8, 2),
-//     // Number of window or keep alive probes sent. This attribute is
-//     // known as `tcpi_probes`.
-//     probes: (u8, 3),
-//     // Number of times the retransmission backoff timer invoked
-//     backoff: (u8, 4),
-
-//     //
-//     options: (u8, 5),
-//     wscale: (u8, 6),
-//     delivery_rate_app_limited: (u8, 7),
-//     rto: (u32, 8..12),
-//     ato: (u32, 12..16),
-//     snd_mss: (u32, 16..20),
-//     rcv_mss: (u32, 20..24),
-//     unacked: (u32, 24..28),
-//     sacked: (u32, 28..32),
-//     lost: (u32, 32..36),
-//     retrans: (u32, 36..40),
-//     fackets: (u32, 40..44),
-//     last_data_sent: (u32, 44..48),
-//     last_ack_sent: (u32, 48..52),
-//     last_data_recv: (u32, 52..56),
-//     last_ack_recv: (u32, 56..60),
-//     pmtu: (u32, 60..64),
-//     rcv_ssthresh: (u32, 64..68),
-//     rtt: (u32, 68..72),
-//     rttvar: (u32, 72..76),
-//     snd_ssthresh: (u32, 76..80),
-//     snd_cwnd: (u32, 80..84),
-//     advmss: (u32, 84..88),
-//     reordering: (u32, 88..92),
-//     rcv_rtt: (u32, 92..96),
-//     rcv_space: (u32, 96..100),
-//     total_retrans: (u32, 100..104),
-//     pacing_rate: (u64, 104..112),
-//     max_pacing_rate: (u64, 112..120),
-//     bytes_acked: (u64, 120..128),
-//     bytes_received: (u64, 128..136),
-//     segs_out: (u32, 136..140),
-//     segs_in: (u32, 140..144),
-//     notsent_bytes: (u32, 144..148),
-//     min_rtt: (u32, 148..152),
-//     data_segs_in: (u32, 152..156),
-//     data_segs_out: (u32, 156..160),
-//     delivery_rate: (u64, 160..168),
-//     busy_time: (u64, 168..176),
-//     rwnd_limited: (u64, 176..184),
-//     sndbuf_limited: (u64, 184..192),
-//     delivered: (u32, 192..196),
-//     delivered_ce: (u32, 196..200),
-//     bytes_sent: (u64, 200..208),
-//     bytes_retrans: (u64, 208..216),
-//     dsack_dups: (u32,   216..220),
-//     reord_seen: (u32,   220..224),
-//     // These are pretty recent addition, we should hide them behing
-//     // `#[cfg]` flag
-//     rcv_ooopack: (u32, 224..228),
-//     snd_wnd: (u32, 228..232),
-// });
-
-// // https://unix.stackexchange.com/questions/542712/detailed-output-of-ss-command
-
-// #[derive(Debug, Clone, PartialEq, Eq)]
-// pub struct TcpInfo {
-//     /// State of the TCP connection: one of `TCP_ESTABLISHED`,
-//     /// `TCP_SYN_SENT`, `TP_SYN_RECV`, `TCP_FIN_WAIT1`,
-//     /// `TCP_FIN_WAIT2` `TCP_TIME_WAIT`, `TCP_CLOSE`,
-//     /// `TCP_CLOSE_WAIT`, `TCP_LAST_ACK` `TCP_LISTEN`, `TCP_CLOSING`.
-//     pub state: u8,
-//     /// Congestion algorithm state: one of `TCP_CA_OPEN`,
-//     /// `TCP_CA_DISORDER`, `TCP_CA_CWR`, `TCP_CA_RECOVERY`,
-//     /// `TCP_CA_LOSS`
-//     pub ca_state: u8,
-//     ///
-//     pub retransmits: u8,
-//     pub probes: u8,
-//     pub backoff: u8,
-//     pub options: u8,
-//     pub wscale: u8,
-//     /// A boolean indicating if the goodput was measured when the
-//     /// socket's throughput was limited by the sending application.
-//     pub delivery_rate_app_limited: u8,
-
-//     /// Value of the RTO (Retransmission TimeOut) timer. This value is
-//     /// calculated using the RTT.
-//     pub rto: u32,
-//     /// Value of the ATO (ACK TimeOut) timer.
-//     pub ato: u32,
-//     /// MSS (Maximum Segment Size). Not shure how it differs from
-//     /// `advmss`.
-//     pub snd_mss: u32,
-//     /// MSS (Maximum Segment Size) advertised by peer
-//     pub rcv_mss: u32,
-
-//     /// Number of segments that have not been ACKnowledged yet, ie the
-//     /// number of in-flight segments.
-//     pub unacked: u32,
-//     /// Number of segments that have been SACKed
-//     pub sacked: u32,
-//     /// Number of segments that have been lost
-//     pub lost: u32,
-//     /// Number of segments that have been retransmitted
-//     pub retrans: u32,
-//     /// Number of segments that have been FACKed
-//     pub fackets: u32,
-
-//     pub last_data_sent: u32,
-//     pub last_ack_sent: u32,
-//     pub last_data_recv: u32,
-//     pub last_ack_recv: u32,
-
-//     pub pmtu: u32,
-//     pub rcv_ssthresh: u32,
-//     /// RTT (Round Trip Time). There RTT is the time between the
-//     /// moment a segment is sent out and the moment it is
-//     /// acknowledged. There are different kinds of RTT values, and I
-//     /// don't know which one this value corresponds to: mRTT (measured
-//     /// RTT), sRTT (smoothed RTT), RTTd (deviated RTT), etc.
-//     pub rtt: u32,
-//     /// RTT variance (or variation?)
-//     pub rttvar: u32,
-//     /// Slow-Start Threshold
-//     pub snd_ssthresh: u32,
-//     /// Size of the congestion window
-//     pub snd_cwnd: u32,
-//     /// MSS advertised by this peer
-//     pub advmss: u32,
-
-//     pub reordering: u32,
-
-//     pub rcv_rtt: u32,
-//     pub rcv_space: u32,
-
-//     pub total_retrans: u32,
-
-//     pub pacing_rate: u64,
-//     pub max_pacing_rate: u64,
-//     pub bytes_acked: u64,    // RFC4898 tcpEStatsAppHCThruOctetsAcked
-//     pub bytes_received: u64, // RFC4898 tcpEStatsAppHCThruOctetsReceived
-//     pub segs_out: u32,       // RFC4898 tcpEStatsPerfSegsOut
-//     pub segs_in: u32,        // RFC4898 tcpEStatsPerfSegsIn
-
-//     pub notsent_bytes: u32,
-//     pub min_rtt: u32,
-//     pub data_segs_in: u32,  // RFC4898 tcpEStatsDataSegsIn
-//     pub data_segs_out: u32, // RFC4898 tcpEStatsDataSegsOut
-
-//     /// The most recent goodput, as measured by tcp_rate_gen(). If the
-//     /// socket is limited by the sending application (e.g., no data to
-//     /// send), it reports the highest measurement instead of the most
-//     /// recent. The unit is bytes per second (like other rate fields
-//     /// in tcp_info).
-//     pub delivery_rate: u64,
-
-//     pub busy_time: u64,      // Time (usec) busy sending data
-//     pub rwnd_limited: u64,   // Time (usec) limited by receive window
-//     pub sndbuf_limited: u64, // Time (usec) limited by send buffer
-
-//     pub delivered: u32,
-//     pub delivered_ce: u32,
-
-//     pub bytes_sent: u64,    // RFC4898 tcpEStatsPerfHCDataOctetsOut
-//     pub bytes_retrans: u64, // RFC4898 tcpEStatsPerfOctetsRetrans
-//     pub dsack_dups: u32,    // RFC4898 tcpEStatsStackDSACKDups
-//     /// reordering events seen
-//     pub reord_seen: u32,
-
-//     /// Out-of-order packets received
-//     pub rcv_ooopack: u32,
-//     /// peer's advertised receive window after scaling (bytes)
-//     pub snd_wnd: u32,
-// }
@@ -1,10 +1,16 @@
 // SPDX-License-Identifier: MIT
 
+use std::net::SocketAddr;
+
 use anyhow::Context;
 
 use crate::{
     constants::*,
-    inet::{SocketId, SocketIdBuffer},
+    inet::{
+        nlas::{DefaultNla, NlasIterator},
+        SocketId,
+        SocketIdBuffer,
+    },
     traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
@@ -18,6 +24,7 @@ buffer!(InetRequestBuffer(REQUEST_LEN) {
     pad: (u8, 3),
     states: (u32, 4..8),
     socket_id: (slice, 8..56),
+    payload: (slice, REQUEST_LEN..),
 });
 
 /// A request for Ipv4 and Ipv6 sockets
@@ -41,6 +48,10 @@ pub struct InetRequest {
     /// Unlike UNIX domain sockets, IPv4 and IPv6 sockets are
     /// identified using addresses and ports.
     pub socket_id: SocketId,
+    /// Program run by the kernel on each socket of a dump, sent as an `INET_DIAG_REQ_BYTECODE`
+    /// attribute: only the sockets it accepts are reported. See [`Filter`](super::Filter) to
+    /// build one.
+    pub bytecode: Option<Vec<u8>>,
 }
 
 bitflags! {
@@ -105,6 +116,36 @@ bitflags! {
     }
 }
 
+impl InetRequest {
+    /// Request the information about the socket of the given protocol bound to `source` and
+    /// connected to `destination` (the local and peer addresses of a connected socket), instead
+    /// of dumping all the sockets.
+    ///
+    /// The request must be sent without the `NLM_F_DUMP` flag: the kernel then answers with a
+    /// single [`InetResponse`](super::InetResponse), or with an `ENOENT` error if no socket
+    /// matches.
+    pub fn for_socket(protocol: u8, source: SocketAddr, destination: SocketAddr) -> Self {
+        let family = match source {
+            SocketAddr::V4(_) => AF_INET,
+            SocketAddr::V6(_) => AF_INET6,
+        };
+        InetRequest {
+            family,
+            protocol,
+            extensions: ExtensionFlags::empty(),
+            states: StateFlags::all(),
+            socket_id: SocketId::new(source, destination),
+            bytecode: None,
+        }
+    }
+
+    fn bytecode_nla(&self) -> Option<DefaultNla> {
+        self.bytecode
+            .as_ref()
+            .map(|bytecode| DefaultNla::new(INET_DIAG_REQ_BYTECODE, bytecode.clone()))
+    }
+}
+
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<InetRequestBuffer<&'a T>> for InetRequest {
     fn parse(buf: &InetRequestBuffer<&'a T>) -> Result<Self, DecodeError> {
         let err = "invalid socket_id value";
@@ -114,22 +155,34 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<InetRequestBuffer<&'a T>> for InetReques
         )
         .context(err)?;
 
+        let mut bytecode = None;
+        for nla in NlasIterator::new(buf.payload()) {
+            let nla = nla.context("invalid inet request attribute")?;
+            if nla.kind() == INET_DIAG_REQ_BYTECODE {
+                bytecode = Some(nla.value().to_vec());
+            }
+        }
+
         Ok(Self {
             family: buf.family(),
             protocol: buf.protocol(),
             extensions: ExtensionFlags::from_bits_truncate(buf.extensions()),
             states: StateFlags::from_bits_truncate(buf.states()),
             socket_id,
+            bytecode,
         })
     }
 }
 
 impl Emitable for InetRequest {
     fn buffer_len(&self) -> usize {
-        REQUEST_LEN
+        REQUEST_LEN + self.bytecode_nla().map_or(0, |nla| nla.buffer_len())
     }
 
     fn emit(&self, buf: &mut [u8]) {
+        if let Some(nla) = self.bytecode_nla() {
+            nla.emit(&mut buf[REQUEST_LEN..]);
+        }
         let mut buf = InetRequestBuffer::new(buf);
         buf.set_family(self.family);
         buf.set_protocol(self.protocol);
@@ -3,7 +3,7 @@
 use byteorder::{BigEndian, ByteOrder};
 use std::{
     convert::{TryFrom, TryInto},
-    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
 };
 
 use crate::{
@@ -58,6 +58,24 @@ impl SocketId {
             cookie: [0; 8],
         }
     }
+    /// Socket id of the socket bound to `source` and connected to `destination`, for queries
+    /// about an individual socket (see
+    /// [`InetRequest::for_socket`](super::InetRequest::for_socket)). The interface id is the scope
+    /// id of `source` for IPv6 sockets, and the cookie is set to all `0xff` so that it is ignored.
+    pub fn new(source: SocketAddr, destination: SocketAddr) -> Self {
+        let interface_id = match source {
+            SocketAddr::V4(_) => 0,
+            SocketAddr::V6(addr) => addr.scope_id(),
+        };
+        Self {
+            source_port: source.port(),
+            destination_port: destination.port(),
+            source_address: source.ip(),
+            destination_address: destination.ip(),
+            interface_id,
+            cookie: [0xff; 8],
+        }
+    }
 }
 
 impl<'a, T: AsRef<[u8]> + 'a> ParseableParametrized<SocketIdBuffer<&'a T>, u8> for SocketId {
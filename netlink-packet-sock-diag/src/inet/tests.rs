@@ -1,15 +1,19 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
-    net::{IpAddr, Ipv4Addr},
+    io::Write,
+    net::{self, IpAddr, Ipv4Addr, TcpListener, TcpStream},
     time::Duration,
 };
 
+use netlink_sys::{protocols::NETLINK_SOCK_DIAG, Socket, SocketAddr};
+
 use crate::{
     constants::*,
     inet::{
-        nlas::Nla,
+        nlas::{MemInfo, MemInfoBuffer, Nla, TcpInfo, TcpInfoBuffer},
         ExtensionFlags,
+        Filter,
         InetRequest,
         InetRequestBuffer,
         InetResponse,
@@ -20,6 +24,10 @@ use crate::{
         Timer,
     },
     traits::{Emitable, Parseable},
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    SockDiagMessage,
 };
 
 lazy_static! {
@@ -29,6 +37,7 @@ lazy_static! {
         extensions: ExtensionFlags::empty(),
         states: StateFlags::ESTABLISHED,
         socket_id: SocketId::new_v4(),
+        bytecode: None,
     };
 }
 
@@ -137,3 +146,407 @@ fn emit_tcp_resp() {
     RESP_TCP.emit(&mut buf);
     assert_eq!(&buf[..], &RESP_TCP_BUF[..]);
 }
+
+// INET_DIAG_SKMEMINFO as reported by a 3.2 kernel, without the
+// backlog queue length and drops
+#[rustfmt::skip]
+static SHORT_SKMEMINFO: [u8; 28] = [
+    0x00, 0x03, 0x00, 0x00, // rmem_alloc (768)
+    0x00, 0x00, 0x10, 0x00, // rcvbuf (1048576)
+    0x00, 0x00, 0x00, 0x00, // wmem_alloc
+    0x00, 0x40, 0x00, 0x00, // sndbuf (16384)
+    0x00, 0x0d, 0x00, 0x00, // fwd_alloc (3328)
+    0x00, 0x00, 0x00, 0x00, // wmem_queued
+    0x00, 0x00, 0x00, 0x00, // optmem
+];
+
+#[test]
+fn parse_short_skmeminfo() {
+    let parsed =
+        MemInfo::parse(&MemInfoBuffer::new_checked(&SHORT_SKMEMINFO[..]).unwrap()).unwrap();
+    assert_eq!(
+        parsed,
+        MemInfo {
+            receive_queue: 768,
+            receive_queue_max: 1_048_576,
+            bottom_send_queues: 0,
+            send_queue_max: 16384,
+            cache: 3328,
+            send_queue: 0,
+            options: 0,
+            backlog_queue_length: None,
+            drops: None,
+        }
+    );
+    assert_eq!(parsed.buffer_len(), 28);
+    let mut buf = vec![0xff; 28];
+    parsed.emit(&mut buf);
+    assert_eq!(&buf[..], &SHORT_SKMEMINFO[..]);
+}
+
+// INET_DIAG_INFO as reported by a 3.2 kernel, which predates the
+// pacing rate and all the fields added after it
+#[rustfmt::skip]
+static TCP_INFO_3_2: [u8; 104] = [
+    0x01, // state (TCP_ESTABLISHED)
+    0x00, // ca_state (TCP_CA_OPEN)
+    0x00, // retransmits
+    0x00, // probes
+    0x00, // backoff
+    0x07, // options (TCPI_OPT_TIMESTAMPS | TCPI_OPT_SACK | TCPI_OPT_WSCALE)
+    0x77, // wscale (snd_wscale 7, rcv_wscale 7)
+    0x00, // delivery_rate_app_limited (padding)
+    0xe0, 0x1c, 0x03, 0x00, // rto (204000)
+    0x40, 0x9c, 0x00, 0x00, // ato (40000)
+    0xa8, 0x05, 0x00, 0x00, // snd_mss (1448)
+    0x18, 0x02, 0x00, 0x00, // rcv_mss (536)
+    0x00, 0x00, 0x00, 0x00, // unacked
+    0x00, 0x00, 0x00, 0x00, // sacked
+    0x00, 0x00, 0x00, 0x00, // lost
+    0x00, 0x00, 0x00, 0x00, // retrans
+    0x00, 0x00, 0x00, 0x00, // fackets
+    0xf0, 0x05, 0x00, 0x00, // last_data_sent (1520)
+    0x00, 0x00, 0x00, 0x00, // last_ack_sent
+    0xf0, 0x05, 0x00, 0x00, // last_data_recv (1520)
+    0xc8, 0x05, 0x00, 0x00, // last_ack_recv (1480)
+    0xdc, 0x05, 0x00, 0x00, // pmtu (1500)
+    0x08, 0x39, 0x00, 0x00, // rcv_ssthresh (14600)
+    0x53, 0x07, 0x00, 0x00, // rtt (1875)
+    0xee, 0x02, 0x00, 0x00, // rttvar (750)
+    0xff, 0xff, 0xff, 0x7f, // snd_ssthresh (2147483647)
+    0x0a, 0x00, 0x00, 0x00, // snd_cwnd (10)
+    0xa8, 0x05, 0x00, 0x00, // advmss (1448)
+    0x03, 0x00, 0x00, 0x00, // reordering (3)
+    0x00, 0x00, 0x00, 0x00, // rcv_rtt
+    0x08, 0x39, 0x00, 0x00, // rcv_space (14600)
+    0x02, 0x00, 0x00, 0x00, // total_retrans (2)
+];
+
+lazy_static! {
+    static ref TCP_INFO: TcpInfo = TcpInfo {
+        state: TCP_ESTABLISHED,
+        ca_state: TCP_CA_OPEN,
+        retransmits: 0,
+        probes: 0,
+        backoff: 0,
+        options: TCPI_OPT_TIMESTAMPS | TCPI_OPT_SACK | TCPI_OPT_WSCALE,
+        wscale: 0x77,
+        delivery_rate_app_limited: 0,
+        rto: 204000,
+        ato: 40000,
+        snd_mss: 1448,
+        rcv_mss: 536,
+        unacked: 0,
+        sacked: 0,
+        lost: 0,
+        retrans: 0,
+        fackets: 0,
+        last_data_sent: 1520,
+        last_ack_sent: 0,
+        last_data_recv: 1520,
+        last_ack_recv: 1480,
+        pmtu: 1500,
+        rcv_ssthresh: 14600,
+        rtt: 1875,
+        rttvar: 750,
+        snd_ssthresh: 0x7fff_ffff,
+        snd_cwnd: 10,
+        advmss: 1448,
+        reordering: 3,
+        rcv_rtt: 0,
+        rcv_space: 14600,
+        total_retrans: 2,
+        ..Default::default()
+    };
+}
+
+#[test]
+fn parse_short_tcp_info() {
+    let parsed = TcpInfo::parse(&TcpInfoBuffer::new_checked(&TCP_INFO_3_2[..]).unwrap()).unwrap();
+    assert_eq!(parsed, *TCP_INFO);
+    assert_eq!(parsed.pacing_rate, None);
+    assert_eq!(parsed.snd_wnd, None);
+}
+
+#[test]
+fn emit_short_tcp_info() {
+    assert_eq!(TCP_INFO.buffer_len(), 104);
+    let mut buf = vec![0xff; TCP_INFO.buffer_len()];
+    TCP_INFO.emit(&mut buf);
+    assert_eq!(&buf[..], &TCP_INFO_3_2[..]);
+}
+
+#[test]
+fn parse_partial_tcp_info_field() {
+    // A 4.2 kernel appends the pacing rates, the bytes counters and
+    // the segments counters. A trailing field that is only partially
+    // present must not be read.
+    let mut payload = TCP_INFO_3_2.to_vec();
+    payload.extend_from_slice(&[0x01; 40]);
+    payload.extend_from_slice(&[0x02; 2]);
+    let parsed = TcpInfo::parse(&TcpInfoBuffer::new_checked(&payload[..]).unwrap()).unwrap();
+    assert_eq!(parsed.pacing_rate, Some(0x0101_0101_0101_0101));
+    assert_eq!(parsed.segs_out, Some(0x0101_0101));
+    assert_eq!(parsed.segs_in, None);
+    assert_eq!(parsed.buffer_len(), 140);
+
+    assert!(TcpInfoBuffer::new_checked(&TCP_INFO_3_2[..100]).is_err());
+}
+
+/// Dump the IPv4 TCP sockets of the host accepted by `bytecode`,
+/// requesting the given extensions.
+fn dump_tcp_sockets(extensions: ExtensionFlags, bytecode: Option<Vec<u8>>) -> Vec<InetResponse> {
+    let mut socket = Socket::new(NETLINK_SOCK_DIAG).unwrap();
+    socket.bind_auto().unwrap();
+    socket.connect(&SocketAddr::new(0, 0)).unwrap();
+
+    let mut packet = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_REQUEST | NLM_F_DUMP,
+            ..Default::default()
+        },
+        payload: SockDiagMessage::InetRequest(InetRequest {
+            family: AF_INET,
+            protocol: IPPROTO_TCP,
+            extensions,
+            states: StateFlags::all(),
+            socket_id: SocketId::new_v4(),
+            bytecode,
+        })
+        .into(),
+    };
+    packet.finalize();
+    let mut buf = vec![0; packet.buffer_len()];
+    packet.serialize(&mut buf[..]);
+    socket.send(&buf[..], 0).unwrap();
+
+    let mut responses = vec![];
+    let mut receive_buffer = vec![0; 32768];
+    loop {
+        let size = socket.recv(&mut &mut receive_buffer[..], 0).unwrap();
+        let mut offset = 0;
+        while offset < size {
+            let bytes = &receive_buffer[offset..size];
+            let rx_packet = <NetlinkMessage<SockDiagMessage>>::deserialize(bytes).unwrap();
+            match rx_packet.payload {
+                NetlinkPayload::InnerMessage(SockDiagMessage::InetResponse(response)) => {
+                    responses.push(*response)
+                }
+                NetlinkPayload::Done => return responses,
+                payload => panic!("unexpected payload {:?}", payload),
+            }
+            offset += rx_packet.header.length as usize;
+        }
+    }
+}
+
+#[test]
+fn dump_socket_meminfo() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    // Never read from the server side, so that the data stays in its
+    // receive queue
+    let (server, _) = listener.accept().unwrap();
+    client.write_all(&[0xaa; 4096]).unwrap();
+    client.flush().unwrap();
+    std::thread::sleep(Duration::from_millis(50));
+
+    let server_port = server.local_addr().unwrap().port();
+    let client_port = client.local_addr().unwrap().port();
+    let response = dump_tcp_sockets(ExtensionFlags::SKMEMINFO, None)
+        .into_iter()
+        .find(|response| {
+            response.header.socket_id.source_port == server_port
+                && response.header.socket_id.destination_port == client_port
+        })
+        .expect("accepted socket not found in the dump");
+
+    let meminfo = response
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::MemInfo(meminfo) => Some(meminfo.clone()),
+            _ => None,
+        })
+        .expect("INET_DIAG_SKMEMINFO missing from the response");
+    assert!(meminfo.receive_queue > 0);
+    assert!(meminfo.receive_queue_max > 0);
+    assert!(meminfo.send_queue_max > 0);
+    assert_eq!(response.header.recv_queue, 4096);
+}
+
+#[rustfmt::skip]
+static REQ_TCP_SOCKET_BUF: [u8; 56] = [
+    0x02, // family (AF_INET)
+    0x06, // protocol (IPPROTO_TCP)
+    0x00, // extensions
+    0x00, // padding
+    0xfe, 0x0f, 0x00, 0x00, // states (all)
+
+    // socket id
+    0x30, 0x39, // source port (12345)
+    0x00, 0x50, // destination port (80)
+    // source address
+    0x0a, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    // destination address
+    0x0a, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, // interface id
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // cookie (ignored)
+];
+
+#[test]
+fn emit_socket_req() {
+    let req = InetRequest::for_socket(
+        IPPROTO_TCP,
+        "10.0.0.1:12345".parse().unwrap(),
+        "10.0.0.2:80".parse().unwrap(),
+    );
+    let mut buf = [0; 56];
+    req.emit(&mut buf);
+    assert_eq!(&buf[..], &REQ_TCP_SOCKET_BUF[..]);
+}
+
+#[test]
+fn query_single_socket() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let _server = listener.accept().unwrap();
+    let (source, destination) = (client.local_addr().unwrap(), client.peer_addr().unwrap());
+
+    let mut socket = Socket::new(NETLINK_SOCK_DIAG).unwrap();
+    socket.bind_auto().unwrap();
+    socket.connect(&SocketAddr::new(0, 0)).unwrap();
+
+    let mut packet = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: SockDiagMessage::InetRequest(InetRequest::for_socket(
+            IPPROTO_TCP,
+            source,
+            destination,
+        ))
+        .into(),
+    };
+    packet.finalize();
+    let mut buf = vec![0; packet.buffer_len()];
+    packet.serialize(&mut buf[..]);
+    socket.send(&buf[..], 0).unwrap();
+
+    let mut receive_buffer = vec![0; 32768];
+    let size = socket.recv(&mut &mut receive_buffer[..], 0).unwrap();
+    let bytes = &receive_buffer[..size];
+    let rx_packet = <NetlinkMessage<SockDiagMessage>>::deserialize(bytes).unwrap();
+    // A single socket was requested, so the response is not a multipart one
+    assert_eq!(rx_packet.header.length as usize, size);
+    let response = match rx_packet.payload {
+        NetlinkPayload::InnerMessage(SockDiagMessage::InetResponse(response)) => response,
+        payload => panic!("unexpected payload {:?}", payload),
+    };
+    let socket_id = &response.header.socket_id;
+    assert_eq!(
+        net::SocketAddr::new(socket_id.source_address, socket_id.source_port),
+        source
+    );
+    assert_eq!(
+        net::SocketAddr::new(socket_id.destination_address, socket_id.destination_port),
+        destination
+    );
+}
+
+// Programs of the `ss -t` filters `dport = :443`, `dport = :443 or dport = :80` and
+// `dst 127.0.0.1/8 and not sport >= :1024`, encoded the way `ss` does
+#[rustfmt::skip]
+static BYTECODE_DPORT: [u8; 12] = [
+    0x08, 0x0c, 0x10, 0x00, // INET_DIAG_BC_D_COND, yes = 12, no = 16
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC, prefix_len = 0
+    0xbb, 0x01, 0x00, 0x00, // port = 443
+];
+
+#[rustfmt::skip]
+static BYTECODE_DPORT_OR: [u8; 28] = [
+    0x08, 0x0c, 0x10, 0x00, // INET_DIAG_BC_D_COND, yes = 12, no = 16
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC, prefix_len = 0
+    0xbb, 0x01, 0x00, 0x00, // port = 443
+    0x01, 0x04, 0x10, 0x00, // INET_DIAG_BC_JMP, yes = 4, no = 16
+    0x08, 0x0c, 0x10, 0x00, // INET_DIAG_BC_D_COND, yes = 12, no = 16
+    0x00, 0x00, 0x00, 0x00, // AF_UNSPEC, prefix_len = 0
+    0x50, 0x00, 0x00, 0x00, // port = 80
+];
+
+#[rustfmt::skip]
+static BYTECODE_DST_AND_NOT: [u8; 28] = [
+    0x08, 0x10, 0x20, 0x00, // INET_DIAG_BC_D_COND, yes = 16, no = 32
+    0x02, 0x08, 0x00, 0x00, // AF_INET, prefix_len = 8
+    0xff, 0xff, 0xff, 0xff, // any port
+    0x7f, 0x00, 0x00, 0x01, // 127.0.0.1
+    0x02, 0x08, 0x0c, 0x00, // INET_DIAG_BC_S_GE, yes = 8, no = 12
+    0x00, 0x00, 0x00, 0x04, // port = 1024
+    0x01, 0x04, 0x08, 0x00, // INET_DIAG_BC_JMP, yes = 4, no = 8
+];
+
+#[test]
+fn compile_bytecode() {
+    assert_eq!(Filter::destination_port(443).compile(), BYTECODE_DPORT);
+
+    let filter = Filter::destination_port(443).or(Filter::destination_port(80));
+    assert_eq!(filter.compile(), BYTECODE_DPORT_OR);
+
+    let loopback = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let filter = Filter::destination_prefix(loopback, 8).and(!Filter::SourcePortGe(1024));
+    assert_eq!(filter.compile(), BYTECODE_DST_AND_NOT);
+}
+
+#[test]
+fn emit_and_parse_bytecode_req() {
+    let mut req = REQ_UDP.clone();
+    req.bytecode = Some(BYTECODE_DPORT.to_vec());
+    assert_eq!(req.buffer_len(), 56 + 4 + 12);
+    let mut buf = vec![0; req.buffer_len()];
+    req.emit(&mut buf);
+    assert_eq!(&buf[..56], &REQ_UDP_BUF[..]);
+    // INET_DIAG_REQ_BYTECODE attribute
+    assert_eq!(&buf[56..60], &[0x10, 0x00, 0x01, 0x00]);
+    assert_eq!(&buf[60..], &BYTECODE_DPORT[..]);
+
+    let parsed = InetRequest::parse(&InetRequestBuffer::new_checked(&buf[..]).unwrap()).unwrap();
+    assert_eq!(parsed, req);
+}
+
+#[test]
+fn dump_filtered_sockets() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let server_port = listener.local_addr().unwrap().port();
+    let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+    let _server = listener.accept().unwrap();
+    let client_port = client.local_addr().unwrap().port();
+
+    let filter = Filter::destination_port(server_port);
+    let responses = dump_tcp_sockets(ExtensionFlags::empty(), Some(filter.compile()));
+    assert!(responses
+        .iter()
+        .all(|response| response.header.socket_id.destination_port == server_port));
+    assert!(responses
+        .iter()
+        .any(|response| response.header.socket_id.source_port == client_port));
+
+    // The kernel rejects invalid programs, check that it accepts the combined ones and that they
+    // are evaluated as expected
+    let loopback = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    let filter = Filter::destination_prefix(loopback, 8)
+        .and(Filter::destination_port(server_port).or(Filter::source_port(server_port)))
+        .and(!Filter::SourcePortLe(0));
+    let responses = dump_tcp_sockets(ExtensionFlags::empty(), Some(filter.compile()));
+    let mut ports: Vec<_> = responses
+        .iter()
+        .map(|response| response.header.socket_id.source_port)
+        .collect();
+    ports.sort_unstable();
+    ports.dedup();
+    let mut expected = vec![server_port, client_port];
+    expected.sort_unstable();
+    assert_eq!(ports, expected);
+}
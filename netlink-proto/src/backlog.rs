@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    fmt::Debug,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use futures::{channel::mpsc::UnboundedReceiver, task::AtomicWaker, Stream};
+use netlink_packet_core::NetlinkMessage;
+
+use crate::Error;
+
+/// Number of responses forwarded by the connection to the handles, that the handles did not
+/// consume yet. It is shared between the connection and all its handles.
+#[derive(Debug, Default)]
+pub(crate) struct Backlog {
+    len: AtomicUsize,
+    /// Waker of the connection, registered when it stops reading from the socket
+    waker: AtomicWaker,
+}
+
+impl Backlog {
+    /// Called by the connection before forwarding a response
+    pub fn push(&self) {
+        self.len.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Called by the handles when a response has been consumed or dropped
+    pub fn pop(&self) {
+        self.len.fetch_sub(1, Ordering::SeqCst);
+        self.waker.wake();
+    }
+
+    /// Return whether `queued` more responses can be forwarded without the backlog exceeding
+    /// `max`. If not, the connection task is woken up again once a response is consumed.
+    pub fn poll_has_room(&self, cx: &mut Context, queued: usize, max: usize) -> bool {
+        if self.len.load(Ordering::SeqCst) + queued < max {
+            return true;
+        }
+        self.waker.register(cx.waker());
+        // A response may have been consumed before the waker was registered
+        self.len.load(Ordering::SeqCst) + queued < max
+    }
+}
+
+/// Stream of the responses to a request, as returned by
+/// [`ConnectionHandle::try_request`](crate::ConnectionHandle::try_request).
+///
+/// It keeps track of the number of messages received so far (see [`ResponseStream::received`]),
+/// so that the progress of a large dump can be reported while it is processed.
+pub struct ResponseStream<T>
+where
+    T: Debug,
+{
+    rx: UnboundedReceiver<Result<NetlinkMessage<T>, Error<T>>>,
+    backlog: Arc<Backlog>,
+    received: usize,
+}
+
+impl<T> ResponseStream<T>
+where
+    T: Debug,
+{
+    pub(crate) fn new(
+        rx: UnboundedReceiver<Result<NetlinkMessage<T>, Error<T>>>,
+        backlog: Arc<Backlog>,
+    ) -> Self {
+        ResponseStream {
+            rx,
+            backlog,
+            received: 0,
+        }
+    }
+
+    /// Number of messages the stream yielded so far, not counting the error that may end it
+    pub fn received(&self) -> usize {
+        self.received
+    }
+}
+
+impl<T> Stream for ResponseStream<T>
+where
+    T: Debug,
+{
+    type Item = Result<NetlinkMessage<T>, Error<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let item = ready!(Pin::new(&mut self.rx).poll_next(cx));
+        if let Some(ref response) = item {
+            self.backlog.pop();
+            if response.is_ok() {
+                self.received += 1;
+            }
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl<T> Drop for ResponseStream<T>
+where
+    T: Debug,
+{
+    fn drop(&mut self) {
+        // Once the channel is closed the connection cannot forward responses anymore, so the ones
+        // already in the channel are the last ones to account for.
+        self.rx.close();
+        while let Ok(Some(_)) = self.rx.try_next() {
+            self.backlog.pop();
+        }
+    }
+}
@@ -2,8 +2,9 @@
 
 use std::{fmt::Debug, io};
 
-use bytes::{BufMut, BytesMut};
+use bytes::{Buf, BufMut, BytesMut};
 use netlink_packet_core::{
+    DecodeError,
     NetlinkBuffer,
     NetlinkDeserializable,
     NetlinkMessage,
@@ -52,53 +53,7 @@ impl NetlinkMessageCodec for NetlinkCodec {
         T: NetlinkDeserializable + Debug,
     {
         debug!("NetlinkCodec: decoding next message");
-
-        loop {
-            // If there's nothing to read, return Ok(None)
-            if src.as_ref().is_empty() {
-                trace!("buffer is empty");
-                src.clear();
-                return Ok(None);
-            }
-
-            // This is a bit hacky because we don't want to keep `src`
-            // borrowed, since we need to mutate it later.
-            let len_res = match NetlinkBuffer::new_checked(src.as_ref()) {
-                Ok(buf) => Ok(buf.length() as usize),
-                Err(e) => {
-                    // We either received a truncated packet, or the
-                    // packet if malformed (invalid length field). In
-                    // both case, we can't decode the datagram, and we
-                    // cannot find the start of the next one (if
-                    // any). The only solution is to clear the buffer
-                    // and potentially lose some datagrams.
-                    error!("failed to decode datagram: {:?}: {:#x?}.", e, src.as_ref());
-                    Err(())
-                }
-            };
-
-            if len_res.is_err() {
-                error!("clearing the whole socket buffer. Datagrams may have been lost");
-                src.clear();
-                return Ok(None);
-            }
-
-            let len = len_res.unwrap();
-
-            let bytes = src.split_to(len);
-
-            let parsed = NetlinkMessage::<T>::deserialize(&bytes);
-            match parsed {
-                Ok(packet) => {
-                    trace!("<<< {:?}", packet);
-                    return Ok(Some(packet));
-                }
-                Err(e) => {
-                    error!("failed to decode packet {:#x?}: {}", &bytes, e);
-                    // continue looping, there may be more datagrams in the buffer
-                }
-            }
-        }
+        Ok(decode_next_message(src, false))
     }
 
     fn encode<T>(msg: NetlinkMessage<T>, buf: &mut BytesMut) -> io::Result<()>
@@ -128,3 +83,139 @@ impl NetlinkMessageCodec for NetlinkCodec {
         Ok(())
     }
 }
+
+/// Implementation of `NetlinkMessageCodec` that parses the messages in place
+///
+/// [`NetlinkCodec`] splits every message off the receive buffer before parsing it, which turns the
+/// buffer into a shared one that has to be allocated again for the next datagram. This codec only
+/// advances the buffer once a message is parsed, so that a single allocation is reused for all the
+/// datagrams, which saves allocations on large dumps. Use it with
+/// [`new_connection_with_codec`](crate::new_connection_with_codec).
+pub struct NetlinkInPlaceCodec {
+    // we don't need an instance of this, just the type
+    _private: (),
+}
+
+impl NetlinkMessageCodec for NetlinkInPlaceCodec {
+    fn decode<T>(src: &mut BytesMut) -> io::Result<Option<NetlinkMessage<T>>>
+    where
+        T: NetlinkDeserializable + Debug,
+    {
+        debug!("NetlinkInPlaceCodec: decoding next message");
+        Ok(decode_next_message(src, true))
+    }
+
+    fn encode<T>(msg: NetlinkMessage<T>, buf: &mut BytesMut) -> io::Result<()>
+    where
+        T: Debug + NetlinkSerializable,
+    {
+        NetlinkCodec::encode(msg, buf)
+    }
+}
+
+// Decode the next message of the datagram buffer. When `in_place` is set, the message is parsed
+// from `src` before advancing it, instead of being split off it first.
+fn decode_next_message<T>(src: &mut BytesMut, in_place: bool) -> Option<NetlinkMessage<T>>
+where
+    T: NetlinkDeserializable + Debug,
+{
+    loop {
+        // If there's nothing to read, return None
+        if src.as_ref().is_empty() {
+            trace!("buffer is empty");
+            src.clear();
+            return None;
+        }
+
+        // This is a bit hacky because we don't want to keep `src`
+        // borrowed, since we need to mutate it later.
+        let len_res = match NetlinkBuffer::new_checked(src.as_ref()) {
+            Ok(buf) => Ok(buf.length() as usize),
+            Err(e) => {
+                // We either received a truncated packet, or the
+                // packet if malformed (invalid length field). In
+                // both case, we can't decode the datagram, and we
+                // cannot find the start of the next one (if
+                // any). The only solution is to clear the buffer
+                // and potentially lose some datagrams.
+                error!("failed to decode datagram: {:?}: {:#x?}.", e, src.as_ref());
+                Err(())
+            }
+        };
+
+        if len_res.is_err() {
+            error!("clearing the whole socket buffer. Datagrams may have been lost");
+            src.clear();
+            return None;
+        }
+
+        let len = len_res.unwrap();
+
+        let parsed = if in_place {
+            let parsed = NetlinkMessage::<T>::deserialize(&src[..len]);
+            if let Err(ref e) = parsed {
+                error!("failed to decode packet {:#x?}: {}", &src[..len], e);
+            }
+            src.advance(len);
+            parsed
+        } else {
+            let bytes = src.split_to(len);
+            let parsed = NetlinkMessage::<T>::deserialize(&bytes);
+            if let Err(ref e) = parsed {
+                error!("failed to decode packet {:#x?}: {}", &bytes, e);
+            }
+            parsed
+        };
+        if let Ok(packet) = parsed {
+            trace!("<<< {:?}", packet);
+            return Some(packet);
+        }
+        // continue looping, there may be more datagrams in the buffer
+    }
+}
+
+// Netlink messages are aligned on 4 bytes within a datagram
+fn align(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Decode a buffer holding exactly one netlink message, as captured from a netlink socket.
+///
+/// This goes through the same parsing as the one performed by [`NetlinkCodec`] on the received
+/// datagrams, without requiring a socket. It fails if the buffer contains anything else than a
+/// single message (and its padding). Use [`decode_datagram`] for buffers that hold multiple
+/// messages.
+pub fn decode_message<T>(buf: &[u8]) -> Result<NetlinkMessage<T>, DecodeError>
+where
+    T: NetlinkDeserializable,
+{
+    let len = NetlinkBuffer::new_checked(buf)?.length() as usize;
+    if align(len) < buf.len() {
+        return Err(format!(
+            "{} bytes left after the {} bytes message",
+            buf.len() - len,
+            len
+        )
+        .into());
+    }
+    NetlinkMessage::deserialize(buf)
+}
+
+/// Decode all the netlink messages contained in a datagram, as captured from a netlink socket.
+///
+/// Unlike [`NetlinkCodec`], which skips the messages it cannot parse, this fails on the first
+/// invalid message.
+pub fn decode_datagram<T>(datagram: &[u8]) -> Result<Vec<NetlinkMessage<T>>, DecodeError>
+where
+    T: NetlinkDeserializable,
+{
+    let mut messages = vec![];
+    let mut offset = 0;
+    while offset < datagram.len() {
+        let buf = &datagram[offset..];
+        let len = NetlinkBuffer::new_checked(buf)?.length() as usize;
+        messages.push(NetlinkMessage::deserialize(&buf[..len])?);
+        offset += align(len);
+    }
+    Ok(messages)
+}
@@ -4,6 +4,10 @@ use std::{
     fmt::Debug,
     io,
     pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
 };
 
@@ -15,6 +19,7 @@ use futures::{
 };
 use log::{error, warn};
 use netlink_packet_core::{
+    constants::NLM_F_DUMP,
     NetlinkDeserializable,
     NetlinkMessage,
     NetlinkPayload,
@@ -25,9 +30,13 @@ use crate::{
     codecs::{NetlinkCodec, NetlinkMessageCodec},
     framed::NetlinkFramed,
     sys::{AsyncSocket, SocketAddr},
+    Backlog,
+    Counters,
+    ErrorKind,
     Protocol,
     Request,
     Response,
+    ResponseSender,
 };
 
 #[cfg(feature = "tokio_socket")]
@@ -39,13 +48,28 @@ type DefaultSocket = ();
 ///
 /// [`ConnectionHandle`](struct.ConnectionHandle.html) are used to pass new requests to the
 /// `Connection`, that in turn, sends them through the netlink socket.
+///
+/// By default, the connection reads from the socket as fast as it can, and queues the responses
+/// until they are consumed: a large dump read by a slow consumer ends up entirely in memory. See
+/// [`Connection::set_max_pending_responses`] to bound that queue. Independently of that, a request
+/// receiving an unreasonable number of messages is aborted (see
+/// [`Connection::set_max_dump_messages`]).
+///
+/// When the socket receive buffer overflows, the kernel drops messages and reports `ENOBUFS`.
+/// The connection survives this: it sends a [`NetlinkPayload::Overrun`] message with a zero
+/// sequence number to the unsolicited messages stream, and keeps reading. Since some multicast
+/// messages were lost, code that tracks the state of the kernel from them should dump that state
+/// again upon receiving it. The responses to the requests sent so far may have been lost as well,
+/// so these requests fail with an [`ErrorKind::SocketIo`] `ENOBUFS` error. On a socket with
+/// `NETLINK_NO_ENOBUFS` set (see [`Socket::set_no_enobufs`](netlink_sys::Socket::set_no_enobufs)),
+/// the kernel drops the messages silently instead, and no overrun message is sent.
 pub struct Connection<T, S = DefaultSocket, C = NetlinkCodec>
 where
     T: Debug + NetlinkSerializable + NetlinkDeserializable,
 {
     socket: NetlinkFramed<T, S, C>,
 
-    protocol: Protocol<T, UnboundedSender<NetlinkMessage<T>>>,
+    protocol: Protocol<T, ResponseSender<T>>,
 
     /// Channel used by the user to pass requests to the connection.
     requests_rx: Option<UnboundedReceiver<Request<T>>>,
@@ -55,6 +79,18 @@ where
     unsolicited_messages_tx: Option<UnboundedSender<(NetlinkMessage<T>, SocketAddr)>>,
 
     socket_closed: bool,
+
+    /// Set by [`ConnectionHandle::shutdown`](struct.ConnectionHandle.html#method.shutdown)
+    shutdown: Arc<AtomicBool>,
+
+    /// Responses forwarded to the handles that have not been consumed yet
+    backlog: Arc<Backlog>,
+
+    /// Activity of the connection, shared with the handles (see
+    /// [`ConnectionHandle::stats`](crate::ConnectionHandle::stats))
+    counters: Arc<Counters>,
+
+    max_pending_responses: Option<usize>,
 }
 
 impl<T, S, C> Connection<T, S, C>
@@ -66,6 +102,9 @@ where
     pub(crate) fn new(
         requests_rx: UnboundedReceiver<Request<T>>,
         unsolicited_messages_tx: UnboundedSender<(NetlinkMessage<T>, SocketAddr)>,
+        shutdown: Arc<AtomicBool>,
+        backlog: Arc<Backlog>,
+        counters: Arc<Counters>,
         protocol: isize,
     ) -> io::Result<Self> {
         let socket = S::new(protocol)?;
@@ -75,6 +114,10 @@ where
             requests_rx: Some(requests_rx),
             unsolicited_messages_tx: Some(unsolicited_messages_tx),
             socket_closed: false,
+            shutdown,
+            backlog,
+            counters,
+            max_pending_responses: None,
         })
     }
 
@@ -82,11 +125,52 @@ where
         self.socket.get_mut()
     }
 
+    /// Stop reading from the socket while `max` responses or more are waiting to be consumed by
+    /// the request streams, instead of queuing them without limit. `None`, the default, disables
+    /// the limit.
+    ///
+    /// The kernel generates the responses to a dump request as the socket is read, so a slow
+    /// consumer merely slows the dump down. However, the socket is shared by all the requests:
+    /// while the limit is reached, no response to any request is read. Iterating over a dump while
+    /// waiting for the responses to other requests (deleting every dumped address for instance)
+    /// deadlocks once the limit is reached, so such dumps must be collected first.
+    ///
+    /// Multicast messages are not read either while the limit is reached. They pile up in the
+    /// socket receive buffer, and once it is full the kernel drops them and reports `ENOBUFS`. On
+    /// connections that also receive multicast messages, the receive buffer must be large enough
+    /// to hold the notifications received while a consumer lags behind. It is set with
+    /// `SO_RCVBUF`, for instance through
+    /// [`Socket::set_recv_buffer_size`](netlink_sys::Socket::set_recv_buffer_size), keeping in
+    /// mind that it is capped by the `net.core.rmem_max` sysctl for unprivileged processes. See the
+    /// [`Connection`] documentation for how overflows are reported.
+    pub fn set_max_pending_responses(&mut self, max: Option<usize>) {
+        self.max_pending_responses = max;
+    }
+
+    /// Set the maximum number of messages a single request may receive, `None` meaning no limit.
+    /// Defaults to [`DEFAULT_MAX_DUMP_MESSAGES`](crate::DEFAULT_MAX_DUMP_MESSAGES).
+    ///
+    /// Once a dump goes past the limit, the response stream of the request ends with an
+    /// [`ErrorKind::DumpTooLarge`] error (only visible through
+    /// [`ConnectionHandle::try_request`](crate::ConnectionHandle::try_request), `request` simply
+    /// ends the stream), and the rest of the dump is read from the socket and discarded.
+    pub fn set_max_dump_messages(&mut self, max: Option<usize>) {
+        self.protocol.max_dump_messages = max;
+    }
+
+    /// Set the size of the buffer messages are read into, 64kB by default (see
+    /// [`NetlinkFramed::set_reader_capacity`]). The buffer is allocated when this is called, so it
+    /// should be set before spawning the connection.
+    pub fn set_recv_capacity(&mut self, capacity: usize) {
+        self.socket.set_reader_capacity(capacity);
+    }
+
     pub fn poll_send_messages(&mut self, cx: &mut Context) {
         trace!("poll_send_messages called");
         let Connection {
             ref mut socket,
             ref mut protocol,
+            ref counters,
             ..
         } = self;
         let mut socket = Pin::new(socket);
@@ -103,6 +187,7 @@ where
 
             let (mut message, addr) = protocol.outgoing_messages.pop_front().unwrap();
             message.finalize();
+            let header = message.header;
 
             trace!("sending outgoing message");
             if let Err(e) = Pin::as_mut(&mut socket).start_send((message, addr)) {
@@ -110,6 +195,7 @@ where
                 self.socket_closed = true;
                 return;
             }
+            counters.sent(header.length, header.flags & NLM_F_DUMP == NLM_F_DUMP);
         }
 
         trace!("poll_send_messages done");
@@ -129,10 +215,24 @@ where
         let mut socket = Pin::new(&mut self.socket);
 
         loop {
+            if let Some(max) = self.max_pending_responses {
+                // The responses read during this call are only forwarded afterwards
+                let queued = self.protocol.incoming_responses.len();
+                if !self.backlog.poll_has_room(cx, queued, max) {
+                    trace!("too many pending responses, not reading from socket");
+                    return;
+                }
+            }
+
             trace!("polling socket");
             match socket.as_mut().poll_next(cx) {
                 Poll::Ready(Some((message, addr))) => {
                     trace!("read datagram from socket");
+                    if is_overrun(&message) {
+                        self.protocol.fail_sent_requests(overflow_error);
+                    } else {
+                        self.counters.received(message.header.length);
+                    }
                     self.protocol.handle_message(message, addr);
                 }
                 Poll::Ready(None) => {
@@ -219,6 +319,18 @@ where
                 done,
                 metadata: tx,
             } = response;
+            let message = match message {
+                Ok(message) => message,
+                Err(e) => {
+                    trace!("forwarding error to the handle");
+                    self.backlog.push();
+                    if tx.unbounded_send(Err(e.into())).is_err() {
+                        warn!("failed to forward error back to the handle");
+                        self.backlog.pop();
+                    }
+                    continue;
+                }
+            };
             if done {
                 use NetlinkPayload::*;
                 match &message.payload {
@@ -230,35 +342,86 @@ where
                     // dropping the last instance of that sender,
                     // hence closing the channel and signaling the
                     // handle that no more messages are expected.
-                    Noop | Done | Ack(_) => {
-                        trace!("not forwarding Noop/Ack/Done message to the handle");
+                    Noop | Done => {
+                        trace!("not forwarding Noop/Done message to the handle");
+                        continue;
+                    }
+                    // A successful acknowledgement resolves the request as well, but the
+                    // kernel may attach a warning to it. Only forward it in that case, so that
+                    // the handle can read the warning.
+                    Ack(_) if message.extended_ack().is_none() => {
+                        trace!("not forwarding Ack message to the handle");
+                        continue;
+                    }
+                    // The kernel dropped part of the response
+                    Overrun(_) => {
+                        trace!("forwarding overrun to the handle as an error");
+                        self.backlog.push();
+                        if tx.unbounded_send(Err(overflow_error().into())).is_err() {
+                            warn!("failed to forward error back to the handle");
+                            self.backlog.pop();
+                        }
                         continue;
                     }
-                    // I'm not sure how we should handle overrun messages
-                    Overrun(_) => unimplemented!("overrun is not handled yet"),
                     // We need to forward error messages and messages
                     // that are part of the netlink subprotocol,
                     // because only the user knows how they want to
                     // handle them.
-                    Error(_) | InnerMessage(_) => {}
+                    Ack(_) | Error(_) | InnerMessage(_) => {}
                 }
             }
 
             trace!("forwarding response to the handle");
-            if tx.unbounded_send(message).is_err() {
+            self.backlog.push();
+            if tx.unbounded_send(Ok(message)).is_err() {
                 // With an unboundedsender, an error can
                 // only happen if the receiver is closed.
                 warn!("failed to forward response back to the handle");
+                self.backlog.pop();
             }
         }
         trace!("forward_responses done");
     }
 
+    /// Abort the requests whose response stream was dropped, for instance by a timeout or a
+    /// `select!` that completed on another branch
+    fn abort_dropped_requests(&mut self) {
+        self.protocol.abort_requests(|tx| tx.is_closed());
+    }
+
+    /// Fail all the pending requests with [`ErrorKind::ConnectionClosed`], and drop everything
+    /// that has not been sent yet.
+    fn close(&mut self) {
+        trace!("close called");
+        for tx in self.protocol.drain_pending_requests() {
+            self.backlog.push();
+            if tx.unbounded_send(Err(ErrorKind::ConnectionClosed.into())).is_err() {
+                self.backlog.pop();
+            }
+        }
+        self.protocol.outgoing_messages.clear();
+        let _ = self.requests_rx.take();
+        let _ = self.unsolicited_messages_tx.take();
+        self.socket_closed = true;
+        self.counters.set_pending_requests(0);
+        trace!("close done");
+    }
+
     pub fn should_shut_down(&self) -> bool {
         self.socket_closed || (self.unsolicited_messages_tx.is_none() && self.requests_rx.is_none())
     }
 }
 
+/// Whether `message` is the one yielded by the socket when the kernel dropped messages
+fn is_overrun<T>(message: &NetlinkMessage<T>) -> bool {
+    matches!(message.payload, NetlinkPayload::Overrun(_)) && message.header.sequence_number == 0
+}
+
+/// Error of the requests whose responses the kernel may have dropped
+fn overflow_error<T: Debug>() -> ErrorKind<T> {
+    ErrorKind::SocketIo(io::Error::from_raw_os_error(libc::ENOBUFS))
+}
+
 impl<T, S, C> Future for Connection<T, S, C>
 where
     T: Debug + NetlinkSerializable + NetlinkDeserializable + Unpin,
@@ -271,6 +434,9 @@ where
         trace!("polling Connection");
         let pinned = self.get_mut();
 
+        // Before reading, so that the late responses to these requests are discarded
+        pinned.abort_dropped_requests();
+
         debug!("reading incoming messages");
         pinned.poll_read_messages(cx);
 
@@ -283,9 +449,20 @@ where
         debug!("handling requests");
         pinned.poll_requests(cx);
 
+        if pinned.shutdown.load(Ordering::SeqCst) {
+            // The requests received so far have been registered by `poll_requests` above, so that
+            // they are failed as well instead of silently dropped.
+            debug!("shutting down the connection");
+            pinned.close();
+            return Poll::Ready(());
+        }
+
         debug!("sending messages");
         pinned.poll_send_messages(cx);
 
+        let pending = pinned.protocol.pending_requests();
+        pinned.counters.set_pending_requests(pending);
+
         trace!("done polling Connection");
 
         if pinned.should_shut_down() {
@@ -42,6 +42,14 @@ where
 
     /// Error while reading from or writing to the netlink socket
     SocketIo(io::Error),
+
+    /// The sequence number assigned to a request is not in the range reserved to the
+    /// caller-assigned sequence numbers
+    InvalidSequenceNumber(u32),
+
+    /// The response to a request had more messages than the limit set with
+    /// [`Connection::set_max_dump_messages`](crate::Connection::set_max_dump_messages)
+    DumpTooLarge(usize),
 }
 
 impl<T> From<ErrorKind<T>> for Error<T>
@@ -60,9 +68,25 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use crate::ErrorKind::*;
         match self.kind() {
-            SocketIo(ref e) => write!(f, "{}: {}", self, e),
-            ConnectionClosed => write!(f, "{}", self),
-            NetlinkError(ref message) => write!(f, "{}: {:?}", self, message),
+            SocketIo(ref e) => write!(
+                f,
+                "Error while reading from or writing to the netlink socket: {}",
+                e
+            ),
+            ConnectionClosed => write!(f, "The netlink connection is closed"),
+            NetlinkError(ref message) => {
+                write!(f, "Received an error message as a response: {:?}", message)
+            }
+            InvalidSequenceNumber(ref sequence_number) => write!(
+                f,
+                "The sequence number is not a caller-assigned one: {}",
+                sequence_number
+            ),
+            DumpTooLarge(ref max) => write!(
+                f,
+                "The response to a request has too many messages: more than {}",
+                max
+            ),
         }
     }
 }
@@ -77,6 +101,8 @@ where
             SocketIo(_) => "Error while reading from or writing to the netlink socket",
             ConnectionClosed => "The netlink connection is closed",
             NetlinkError(_) => "Received an error message as a response",
+            InvalidSequenceNumber(_) => "The sequence number is not a caller-assigned one",
+            DumpTooLarge(_) => "The response to a request has too many messages",
         }
     }
 
@@ -10,13 +10,21 @@ use std::{
 };
 
 use futures::{Sink, Stream};
-use log::error;
+use log::{error, warn};
 
 use crate::{
     codecs::NetlinkMessageCodec,
     sys::{AsyncSocket, SocketAddr},
 };
-use netlink_packet_core::{NetlinkDeserializable, NetlinkMessage, NetlinkSerializable};
+use netlink_packet_core::{
+    NetlinkDeserializable,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    NetlinkSerializable,
+    NETLINK_HEADER_LEN,
+    NLMSG_OVERRUN,
+};
 
 pub struct NetlinkFramed<T, S, C> {
     socket: S,
@@ -26,6 +34,7 @@ pub struct NetlinkFramed<T, S, C> {
     msg_type: PhantomData<fn(T) -> T>, // invariant
     codec: PhantomData<fn(C) -> C>,    // invariant
     reader: BytesMut,
+    reader_capacity: usize,
     writer: BytesMut,
     in_addr: SocketAddr,
     out_addr: SocketAddr,
@@ -45,6 +54,7 @@ where
             ref mut socket,
             ref mut in_addr,
             ref mut reader,
+            ref reader_capacity,
             ..
         } = Pin::get_mut(self);
 
@@ -59,10 +69,16 @@ where
             }
 
             reader.clear();
-            reader.reserve(INITIAL_READER_CAPACITY);
+            reader.reserve(*reader_capacity);
 
             *in_addr = match ready!(socket.poll_recv_from(cx, reader)) {
                 Ok(addr) => addr,
+                // The receive buffer overflowed and the kernel dropped messages, but the socket
+                // remains usable: report the loss and keep reading
+                Err(e) if e.raw_os_error() == Some(libc::ENOBUFS) => {
+                    warn!("netlink socket receive buffer overflowed, messages were lost");
+                    return Poll::Ready(Some((overrun_message(), SocketAddr::new(0, 0))));
+                }
                 Err(e) => {
                     error!("failed to read from netlink socket: {:?}", e);
                     return Poll::Ready(None);
@@ -72,6 +88,18 @@ where
     }
 }
 
+/// Message yielded by [`NetlinkFramed`] when the socket reports `ENOBUFS`. This is a
+/// `NLMSG_OVERRUN` message with a zero sequence number, so it is never mistaken for the response
+/// to a request.
+fn overrun_message<T>() -> NetlinkMessage<T> {
+    let header = NetlinkHeader {
+        length: NETLINK_HEADER_LEN as u32,
+        message_type: NLMSG_OVERRUN,
+        ..Default::default()
+    };
+    NetlinkMessage::new(header, NetlinkPayload::Overrun(Vec::new()))
+}
+
 impl<T, S, C> Sink<(NetlinkMessage<T>, SocketAddr)> for NetlinkFramed<T, S, C>
 where
     T: NetlinkSerializable + Debug,
@@ -161,11 +189,23 @@ impl<T, S, C> NetlinkFramed<T, S, C> {
             out_addr: SocketAddr::new(0, 0),
             in_addr: SocketAddr::new(0, 0),
             reader: BytesMut::with_capacity(INITIAL_READER_CAPACITY),
+            reader_capacity: INITIAL_READER_CAPACITY,
             writer: BytesMut::with_capacity(INITIAL_WRITER_CAPACITY),
             flushed: true,
         }
     }
 
+    /// Set the size of the buffer datagrams are read into, 64kB by default. The buffer is allocated
+    /// right away and reused for every datagram, so it does not grow while reading.
+    ///
+    /// A datagram larger than the buffer is truncated and the messages it holds are lost. The
+    /// kernel caps the datagrams of a dump to 32kB, but a single large message, like a link with
+    /// many VFs, may exceed the default size.
+    pub fn set_reader_capacity(&mut self, capacity: usize) {
+        self.reader_capacity = capacity;
+        self.reader.reserve(capacity);
+    }
+
     /// Returns a reference to the underlying I/O stream wrapped by `Framed`.
     ///
     /// # Note
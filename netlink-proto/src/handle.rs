@@ -2,15 +2,28 @@
 
 use futures::{
     channel::mpsc::{unbounded, UnboundedSender},
+    future,
     Stream,
+    StreamExt,
+};
+use netlink_packet_core::{ErrorMessage, NetlinkHeader, NetlinkMessage, NetlinkPayload};
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
-use netlink_packet_core::NetlinkMessage;
-use std::fmt::Debug;
 
 use crate::{
     errors::{Error, ErrorKind},
     sys::SocketAddr,
+    Backlog,
+    ConnectionStats,
+    Counters,
     Request,
+    ResponseStream,
+    MIN_USER_SEQUENCE_NUMBER,
 };
 
 /// A handle to pass requests to a [`Connection`](struct.Connection.html).
@@ -20,28 +33,81 @@ where
     T: Debug,
 {
     requests_tx: UnboundedSender<Request<T>>,
+    /// Shared with the connection, set when it must shut down
+    shutdown: Arc<AtomicBool>,
+    /// Shared with the connection, to let it know when responses are consumed
+    backlog: Arc<Backlog>,
+    /// Shared with the connection, which updates them
+    counters: Arc<Counters>,
 }
 
 impl<T> ConnectionHandle<T>
 where
     T: Debug,
 {
-    pub(crate) fn new(requests_tx: UnboundedSender<Request<T>>) -> Self {
-        ConnectionHandle { requests_tx }
+    pub(crate) fn new(
+        requests_tx: UnboundedSender<Request<T>>,
+        shutdown: Arc<AtomicBool>,
+        backlog: Arc<Backlog>,
+        counters: Arc<Counters>,
+    ) -> Self {
+        ConnectionHandle {
+            requests_tx,
+            shutdown,
+            backlog,
+            counters,
+        }
     }
 
     /// Send a new request and get the response as a stream of messages. Note that some messages
     /// are not part of the response stream:
     ///
-    /// - **acknowledgements**: when an acknowledgement is received, the stream is closed
+    /// - **acknowledgements**: when an acknowledgement is received, the stream is closed. If the
+    /// kernel attached a warning to the acknowledgement (see
+    /// [`NetlinkMessage::extended_ack`]), the acknowledgement is the last message of the stream
+    /// instead. Either way, the request succeeded.
     /// - **end of dump messages**: similarly, upon receiving an "end of dump" message, the stream is
     /// closed
+    ///
+    /// If the connection is shut down before the response is complete, its last message is an
+    /// error message with the `ECONNRESET` error code (see [`ConnectionHandle::try_request`] to
+    /// get an [`ErrorKind::ConnectionClosed`] error instead). If the response has more messages
+    /// than allowed by
+    /// [`Connection::set_max_dump_messages`](crate::Connection::set_max_dump_messages), its last
+    /// message is an error message with the `EMSGSIZE` error code, so that the truncated response
+    /// is not mistaken for a complete one. Similarly, if the kernel dropped part of the response
+    /// because the socket receive buffer overflowed, its last message is an error message with the
+    /// `ENOBUFS` error code.
+    ///
+    /// Dropping the stream cancels the request: the rest of its response is read from the socket
+    /// and discarded, and its sequence number is not reused until then, so the responses to other
+    /// requests are never mixed up with it.
     pub fn request(
         &mut self,
         message: NetlinkMessage<T>,
         destination: SocketAddr,
     ) -> Result<impl Stream<Item = NetlinkMessage<T>>, Error<T>> {
-        let (tx, rx) = unbounded::<NetlinkMessage<T>>();
+        Ok(self
+            .try_request(message, destination)?
+            .filter_map(|response| future::ready(response_message(response))))
+    }
+
+    /// Same as [`ConnectionHandle::request`], but if the connection is shut down (see
+    /// [`ConnectionHandle::shutdown`]) before the response is complete, the last item of the
+    /// stream is an [`ErrorKind::ConnectionClosed`] error.
+    /// Likewise, a response with more messages than allowed by
+    /// [`Connection::set_max_dump_messages`](crate::Connection::set_max_dump_messages) ends with
+    /// an [`ErrorKind::DumpTooLarge`] error, and a response partly dropped by the kernel ends with
+    /// an [`ErrorKind::SocketIo`] `ENOBUFS` error.
+    ///
+    /// The stream also counts the messages received so far (see [`ResponseStream::received`]),
+    /// for instance to report the progress of a large dump.
+    pub fn try_request(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+    ) -> Result<ResponseStream<T>, Error<T>> {
+        let (tx, rx) = unbounded();
         let request = Request::from((message, destination, tx));
         debug!("handle: forwarding new request to connection");
         UnboundedSender::unbounded_send(&self.requests_tx, request).map_err(|e| {
@@ -55,7 +121,34 @@ where
                 panic!("unknown error: {:?}", e);
             }
         })?;
-        Ok(rx)
+        Ok(ResponseStream::new(rx, self.backlog.clone()))
+    }
+
+    /// Same as [`ConnectionHandle::request`], but the sequence number of `message` is not
+    /// overwritten by the connection, so that the caller can correlate the responses with it.
+    /// Like with [`ConnectionHandle::request`], a truncated dump ends with an `EMSGSIZE` error
+    /// message.
+    ///
+    /// To avoid collisions with the sequence numbers assigned by the connection, the sequence
+    /// number must be at least [`MIN_USER_SEQUENCE_NUMBER`](crate::MIN_USER_SEQUENCE_NUMBER).
+    /// The caller must also not reuse a sequence number while a request using it is pending.
+    pub fn request_with_sequence_number(
+        &mut self,
+        message: NetlinkMessage<T>,
+        destination: SocketAddr,
+    ) -> Result<impl Stream<Item = NetlinkMessage<T>>, Error<T>> {
+        let sequence_number = message.header.sequence_number;
+        if sequence_number < MIN_USER_SEQUENCE_NUMBER {
+            return Err(ErrorKind::InvalidSequenceNumber(sequence_number).into());
+        }
+        let (tx, rx) = unbounded();
+        let mut request = Request::from((message, destination, tx));
+        request.keep_sequence_number = true;
+        debug!("handle: forwarding new request to connection");
+        UnboundedSender::unbounded_send(&self.requests_tx, request)
+            .map_err(|_| Error::from(ErrorKind::ConnectionClosed))?;
+        Ok(ResponseStream::new(rx, self.backlog.clone())
+            .filter_map(|response| future::ready(response_message(response))))
     }
 
     pub fn notify(
@@ -63,10 +156,59 @@ where
         message: NetlinkMessage<T>,
         destination: SocketAddr,
     ) -> Result<(), Error<T>> {
-        let (tx, _rx) = unbounded::<NetlinkMessage<T>>();
+        let (tx, _rx) = unbounded();
         let request = Request::from((message, destination, tx));
         debug!("handle: forwarding new request to connection");
         UnboundedSender::unbounded_send(&self.requests_tx, request)
             .map_err(|_| ErrorKind::ConnectionClosed.into())
     }
+
+    /// Shut the connection down. The connection stops processing requests: the pending ones
+    /// complete with an [`ErrorKind::ConnectionClosed`] error, and so do the requests made
+    /// afterwards, from this handle or any other handle to the same connection. The
+    /// [`Connection`](struct.Connection.html) future then completes, closing the socket, so the
+    /// task driving it can be joined.
+    pub fn shutdown(&self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.requests_tx.close_channel();
+    }
+
+    /// Return the number of messages and bytes exchanged by the connection so far, and the number
+    /// of requests waiting for their response. All the handles to a connection share the same
+    /// counters, which keep their last value once the connection is closed.
+    pub fn stats(&self) -> ConnectionStats {
+        self.counters.snapshot()
+    }
+}
+
+/// Turn an item of a [`ResponseStream`] into an item of the stream returned by
+/// [`ConnectionHandle::request`]. The responses cut short by the connection, including when it is
+/// closed, end with an error message.
+fn response_message<T>(response: Result<NetlinkMessage<T>, Error<T>>) -> Option<NetlinkMessage<T>>
+where
+    T: Debug,
+{
+    match response {
+        Ok(message) => Some(message),
+        Err(err) => match err.into_inner() {
+            ErrorKind::ConnectionClosed => Some(error_message(libc::ECONNRESET)),
+            ErrorKind::DumpTooLarge(_) => Some(error_message(libc::EMSGSIZE)),
+            ErrorKind::SocketIo(e) => e.raw_os_error().map(error_message),
+            _ => None,
+        },
+    }
+}
+
+/// Error message standing for an error of the connection in the response to a request
+fn error_message<T>(errno: i32) -> NetlinkMessage<T>
+where
+    T: Debug,
+{
+    NetlinkMessage::new(
+        NetlinkHeader::default(),
+        NetlinkPayload::Error(ErrorMessage {
+            code: -errno,
+            header: vec![],
+        }),
+    )
 }
@@ -168,6 +168,10 @@ extern crate futures;
 #[macro_use]
 extern crate log;
 
+mod backlog;
+pub(crate) use self::backlog::Backlog;
+pub use self::backlog::ResponseStream;
+
 mod codecs;
 pub use crate::codecs::*;
 
@@ -175,9 +179,13 @@ mod framed;
 pub use crate::framed::*;
 
 mod protocol;
+pub use self::protocol::{DEFAULT_MAX_DUMP_MESSAGES, MIN_USER_SEQUENCE_NUMBER};
 pub(crate) use self::protocol::{Protocol, Response};
-pub(crate) type Request<T> =
-    self::protocol::Request<T, UnboundedSender<crate::packet::NetlinkMessage<T>>>;
+pub(crate) type Request<T> = self::protocol::Request<T, ResponseSender<T>>;
+/// Channel used by the connection to forward the responses to a request back to the handle. The
+/// connection sends an error if it shuts down before the request is fully processed.
+pub(crate) type ResponseSender<T> =
+    UnboundedSender<Result<crate::packet::NetlinkMessage<T>, Error<T>>>;
 
 mod connection;
 pub use crate::connection::*;
@@ -188,13 +196,21 @@ pub use crate::errors::*;
 mod handle;
 pub use crate::handle::*;
 
+mod stats;
+pub(crate) use self::stats::Counters;
+pub use self::stats::ConnectionStats;
+
 use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
-use std::{fmt::Debug, io};
+use std::{
+    fmt::Debug,
+    io,
+    sync::{atomic::AtomicBool, Arc},
+};
 
 pub use netlink_packet_core as packet;
 
 pub mod sys {
-    pub use netlink_sys::{protocols, AsyncSocket, AsyncSocketExt, SocketAddr};
+    pub use netlink_sys::{protocols, AsyncSocket, AsyncSocketExt, Socket, SocketAddr};
 
     #[cfg(feature = "tokio_socket")]
     pub use netlink_sys::TokioSocket;
@@ -267,9 +283,19 @@ where
 {
     let (requests_tx, requests_rx) = unbounded::<Request<T>>();
     let (messages_tx, messages_rx) = unbounded::<(packet::NetlinkMessage<T>, sys::SocketAddr)>();
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let backlog = Arc::new(Backlog::default());
+    let counters = Arc::new(Counters::default());
     Ok((
-        Connection::new(requests_rx, messages_tx, protocol)?,
-        ConnectionHandle::new(requests_tx),
+        Connection::new(
+            requests_rx,
+            messages_tx,
+            shutdown.clone(),
+            backlog.clone(),
+            counters.clone(),
+            protocol,
+        )?,
+        ConnectionHandle::new(requests_tx, shutdown, backlog, counters),
         messages_rx,
     ))
 }
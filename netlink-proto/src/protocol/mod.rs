@@ -4,5 +4,6 @@
 mod protocol;
 mod request;
 
+pub use protocol::MIN_USER_SEQUENCE_NUMBER;
 pub(crate) use protocol::{Protocol, Response};
 pub(crate) use request::Request;
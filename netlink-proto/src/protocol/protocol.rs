@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
-    collections::{hash_map, HashMap, VecDeque},
+    collections::{hash_map, HashMap, HashSet, VecDeque},
     fmt::Debug,
 };
 
@@ -14,7 +14,17 @@ use netlink_packet_core::{
 };
 
 use super::Request;
-use crate::sys::SocketAddr;
+use crate::{sys::SocketAddr, ErrorKind};
+
+/// Sequence numbers from this one onwards are never assigned by the connection, they are
+/// reserved for requests whose sequence number is chosen by the caller (see
+/// [`request_with_sequence_number`](crate::ConnectionHandle::request_with_sequence_number)).
+pub const MIN_USER_SEQUENCE_NUMBER: u32 = 0x8000_0000;
+
+/// Default maximum number of messages in the response to a single request (see
+/// [`Connection::set_max_dump_messages`](crate::Connection::set_max_dump_messages)). A full
+/// Internet routing table is about a million routes, so this leaves plenty of room.
+pub const DEFAULT_MAX_DUMP_MESSAGES: usize = 1 << 24;
 
 #[derive(Debug, Eq, PartialEq, Hash)]
 struct RequestId {
@@ -31,21 +41,30 @@ impl RequestId {
     }
 }
 
-#[derive(Debug, Eq, PartialEq)]
-pub(crate) struct Response<T, M> {
+#[derive(Debug)]
+pub(crate) struct Response<T, M>
+where
+    T: Debug,
+{
     pub done: bool,
-    pub message: NetlinkMessage<T>,
+    pub message: Result<NetlinkMessage<T>, ErrorKind<T>>,
     pub metadata: M,
 }
 
 #[derive(Debug)]
 struct PendingRequest<M> {
     expecting_ack: bool,
-    metadata: M,
+    /// Number of messages received so far in response to this request
+    received: usize,
+    /// `None` once the request has been aborted: the rest of its responses are discarded
+    metadata: Option<M>,
 }
 
-#[derive(Debug, Default)]
-pub(crate) struct Protocol<T, M> {
+#[derive(Debug)]
+pub(crate) struct Protocol<T, M>
+where
+    T: Debug,
+{
     /// Counter that is incremented for each message sent
     sequence_id: u32,
 
@@ -61,6 +80,9 @@ pub(crate) struct Protocol<T, M> {
 
     /// The messages to be sent out
     pub outgoing_messages: VecDeque<(NetlinkMessage<T>, SocketAddr)>,
+
+    /// Requests receiving more messages than this are aborted with `ErrorKind::DumpTooLarge`
+    pub max_dump_messages: Option<usize>,
 }
 
 impl<T, M> Protocol<T, M>
@@ -75,6 +97,7 @@ where
             incoming_responses: VecDeque::new(),
             incoming_requests: VecDeque::new(),
             outgoing_messages: VecDeque::new(),
+            max_dump_messages: Some(DEFAULT_MAX_DUMP_MESSAGES),
         }
     }
 
@@ -82,7 +105,12 @@ where
         let request_id = RequestId::new(message.header.sequence_number, source.port_number());
         debug!("handling messages (request id = {:?})", request_id);
         if let hash_map::Entry::Occupied(entry) = self.pending_requests.entry(request_id) {
-            Self::handle_response(&mut self.incoming_responses, entry, message);
+            Self::handle_response(
+                &mut self.incoming_responses,
+                self.max_dump_messages,
+                entry,
+                message,
+            );
         } else {
             self.incoming_requests.push_back((message, source));
         }
@@ -90,12 +118,11 @@ where
 
     fn handle_response(
         incoming_responses: &mut VecDeque<Response<T, M>>,
-        entry: hash_map::OccupiedEntry<RequestId, PendingRequest<M>>,
+        max_dump_messages: Option<usize>,
+        mut entry: hash_map::OccupiedEntry<RequestId, PendingRequest<M>>,
         message: NetlinkMessage<T>,
     ) {
-        let entry_key;
-        let mut request_id = entry.key();
-        debug!("handling response to request {:?}", request_id);
+        debug!("handling response to request {:?}", entry.key());
 
         // A request is processed if we receive an Ack, Error,
         // Done, Overrun, or InnerMessage without the
@@ -110,24 +137,52 @@ where
             _ => true,
         };
 
-        let metadata = if done {
-            trace!("request {:?} fully processed", request_id);
-            let (k, v) = entry.remove_entry();
-            entry_key = k;
-            request_id = &entry_key;
-            v.metadata
-        } else {
-            trace!("more responses to request {:?} may come", request_id);
-            entry.get().metadata.clone()
+        let pending = entry.get_mut();
+        pending.received += 1;
+        let received = pending.received;
+        let metadata = match pending.metadata.take() {
+            Some(metadata) => metadata,
+            None => {
+                trace!("discarding response to aborted request {:?}", entry.key());
+                if done {
+                    entry.remove();
+                }
+                return;
+            }
         };
 
-        let response = Response::<T, M> {
+        if let Some(max) = max_dump_messages.filter(|max| received > *max) {
+            warn!(
+                "request {:?} received more than {} messages, aborting it",
+                entry.key(),
+                max
+            );
+            // Keep the request around until its last response, so that the rest of the dump is
+            // not mistaken for unsolicited messages.
+            if done {
+                entry.remove();
+            }
+            incoming_responses.push_back(Response {
+                done: true,
+                message: Err(ErrorKind::DumpTooLarge(max)),
+                metadata,
+            });
+            return;
+        }
+
+        if done {
+            trace!("request {:?} fully processed", entry.key());
+            entry.remove();
+        } else {
+            trace!("more responses to request {:?} may come", entry.key());
+            entry.get_mut().metadata = Some(metadata.clone());
+        }
+
+        incoming_responses.push_back(Response {
             done,
-            message,
+            message: Ok(message),
             metadata,
-        };
-        incoming_responses.push_back(response);
-        debug!("done handling response to request {:?}", request_id);
+        });
     }
 
     pub fn request(&mut self, request: Request<T, M>) {
@@ -135,10 +190,13 @@ where
             mut message,
             metadata,
             destination,
+            keep_sequence_number,
         } = request;
 
-        self.set_sequence_id(&mut message);
-        let request_id = RequestId::new(self.sequence_id, destination.port_number());
+        if !keep_sequence_number {
+            self.set_sequence_id(&mut message, destination.port_number());
+        }
+        let request_id = RequestId::new(message.header.sequence_number, destination.port_number());
         let flags = message.header.flags;
         self.outgoing_messages.push_back((message, destination));
 
@@ -158,14 +216,157 @@ where
                 request_id,
                 PendingRequest {
                     expecting_ack,
-                    metadata,
+                    received: 0,
+                    metadata: Some(metadata),
                 },
             );
         }
     }
 
-    fn set_sequence_id(&mut self, message: &mut NetlinkMessage<T>) {
-        self.sequence_id += 1;
+    /// Abort the pending requests for which `is_dropped` returns `true`, because nobody waits for
+    /// their responses anymore. They are only forgotten after their last response: in the
+    /// meantime their sequence number is not reused, and the late responses are discarded instead
+    /// of being mistaken for unsolicited messages.
+    pub fn abort_requests<F>(&mut self, mut is_dropped: F)
+    where
+        F: FnMut(&M) -> bool,
+    {
+        for (request_id, request) in self.pending_requests.iter_mut() {
+            if request.metadata.as_ref().map_or(false, &mut is_dropped) {
+                trace!("request {:?} was dropped, aborting it", request_id);
+                request.metadata = None;
+            }
+        }
+    }
+
+    /// Fail the requests already sent with the error returned by `error`, and forget them. This is
+    /// used when the kernel dropped messages, since their responses may be among them: they would
+    /// wait forever otherwise. The requests waiting to be sent are left alone.
+    pub fn fail_sent_requests<F>(&mut self, mut error: F)
+    where
+        F: FnMut() -> ErrorKind<T>,
+    {
+        let queued: HashSet<RequestId> = self
+            .outgoing_messages
+            .iter()
+            .map(|(message, destination)| {
+                RequestId::new(message.header.sequence_number, destination.port_number())
+            })
+            .collect();
+        let incoming_responses = &mut self.incoming_responses;
+        self.pending_requests.retain(|request_id, request| {
+            if queued.contains(request_id) {
+                return true;
+            }
+            if let Some(metadata) = request.metadata.take() {
+                warn!("responses to request {:?} may have been lost", request_id);
+                incoming_responses.push_back(Response {
+                    done: true,
+                    message: Err(error()),
+                    metadata,
+                });
+            }
+            false
+        });
+    }
+
+    /// Number of requests for which we're awaiting a response, including the aborted ones
+    pub fn pending_requests(&self) -> usize {
+        self.pending_requests.len()
+    }
+
+    /// Forget all the pending requests, and return their metadata.
+    pub fn drain_pending_requests(&mut self) -> impl Iterator<Item = M> + '_ {
+        self.pending_requests
+            .drain()
+            .filter_map(|(_, request)| request.metadata)
+    }
+
+    fn set_sequence_id(&mut self, message: &mut NetlinkMessage<T>, port: u32) {
+        loop {
+            self.sequence_id += 1;
+            // Stay out of the range reserved to the caller-assigned sequence numbers. 0 is
+            // skipped as well, since it is the sequence number of the notifications.
+            if self.sequence_id >= MIN_USER_SEQUENCE_NUMBER {
+                self.sequence_id = 1;
+            }
+            // Once the counter wrapped around, the sequence number may still be used by a
+            // request that never completed, and its responses would be mixed up
+            let request_id = RequestId::new(self.sequence_id, port);
+            if !self.pending_requests.contains_key(&request_id) {
+                break;
+            }
+            trace!("sequence number {} still in use, skipping it", self.sequence_id);
+        }
         message.header.sequence_number = self.sequence_id;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_core::{AckMessage, NetlinkHeader};
+    use netlink_packet_route::{LinkMessage, RtnlMessage};
+
+    use super::*;
+
+    fn request(id: usize) -> Request<RtnlMessage, usize> {
+        let mut message = NetlinkMessage::from(RtnlMessage::DelLink(LinkMessage::default()));
+        message.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        Request::from((message, SocketAddr::new(0, 0), id))
+    }
+
+    fn ack(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+        let header = NetlinkHeader {
+            sequence_number,
+            ..Default::default()
+        };
+        let ack = AckMessage {
+            code: 0,
+            header: vec![],
+        };
+        NetlinkMessage::new(header, NetlinkPayload::Ack(ack))
+    }
+
+    fn sent_sequence_number(protocol: &mut Protocol<RtnlMessage, usize>) -> u32 {
+        let (message, _) = protocol.outgoing_messages.pop_front().unwrap();
+        message.header.sequence_number
+    }
+
+    #[test]
+    fn sequence_number_wraparound() {
+        let mut protocol = Protocol::<RtnlMessage, usize>::new();
+
+        // Request 0 never completes
+        protocol.request(request(0));
+        assert_eq!(sent_sequence_number(&mut protocol), 1);
+
+        protocol.sequence_id = MIN_USER_SEQUENCE_NUMBER - 2;
+        let mut sequence_numbers = vec![];
+        for id in 1..=3 {
+            protocol.request(request(id));
+            sequence_numbers.push(sent_sequence_number(&mut protocol));
+        }
+        // The counter wraps around, without using 0 nor the sequence number of request 0
+        assert_eq!(sequence_numbers, vec![MIN_USER_SEQUENCE_NUMBER - 1, 2, 3]);
+
+        // Acknowledge the requests out of order
+        for sequence_number in [3, MIN_USER_SEQUENCE_NUMBER - 1, 2, 1].iter() {
+            protocol.handle_message(ack(*sequence_number), SocketAddr::new(0, 0));
+        }
+        let completed: Vec<(usize, u32)> = protocol
+            .incoming_responses
+            .drain(..)
+            .map(|response| {
+                assert!(response.done);
+                let message = response.message.unwrap();
+                (response.metadata, message.header.sequence_number)
+            })
+            .collect();
+        assert_eq!(
+            completed,
+            vec![(3, 3), (1, MIN_USER_SEQUENCE_NUMBER - 1), (2, 2), (0, 1)]
+        );
+        assert!(protocol.pending_requests.is_empty());
+        assert!(protocol.incoming_requests.is_empty());
+    }
+}
@@ -11,6 +11,9 @@ pub(crate) struct Request<T, M> {
     pub metadata: M,
     pub message: NetlinkMessage<T>,
     pub destination: SocketAddr,
+    /// Whether the sequence number of the message was assigned by the caller, in which case it
+    /// must be left untouched
+    pub keep_sequence_number: bool,
 }
 
 impl<T, M> From<(NetlinkMessage<T>, SocketAddr, M)> for Request<T, M>
@@ -23,6 +26,7 @@ where
             message: parts.0,
             destination: parts.1,
             metadata: parts.2,
+            keep_sequence_number: false,
         }
     }
 }
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+/// Counters updated by the connection as it reads and writes the socket. They are shared between
+/// the connection and all its handles, which read them with [`Counters::snapshot`].
+///
+/// The counters are independent from each other, so they are updated with relaxed atomic
+/// operations: a snapshot taken while the connection is running may be a few messages behind on
+/// some counters.
+#[derive(Debug, Default)]
+pub(crate) struct Counters {
+    messages_sent: AtomicU64,
+    bytes_sent: AtomicU64,
+    messages_received: AtomicU64,
+    bytes_received: AtomicU64,
+    dumps: AtomicU64,
+    pending_requests: AtomicUsize,
+}
+
+impl Counters {
+    /// Called by the connection for each message written to the socket
+    pub fn sent(&self, length: u32, dump: bool) {
+        self.messages_sent.fetch_add(1, Ordering::Relaxed);
+        self.bytes_sent.fetch_add(length as u64, Ordering::Relaxed);
+        if dump {
+            self.dumps.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Called by the connection for each message read from the socket
+    pub fn received(&self, length: u32) {
+        self.messages_received.fetch_add(1, Ordering::Relaxed);
+        self.bytes_received
+            .fetch_add(length as u64, Ordering::Relaxed);
+    }
+
+    pub fn set_pending_requests(&self, pending: usize) {
+        self.pending_requests.store(pending, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> ConnectionStats {
+        ConnectionStats {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            dumps: self.dumps.load(Ordering::Relaxed),
+            pending_requests: self.pending_requests.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Activity of a connection since it was created, as returned by
+/// [`ConnectionHandle::stats`](crate::ConnectionHandle::stats).
+///
+/// Messages are counted one by one, even when several of them share a datagram, and the byte
+/// counts are the sum of their lengths as given by their header.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ConnectionStats {
+    /// Messages written to the socket: requests and notifications
+    pub messages_sent: u64,
+    pub bytes_sent: u64,
+    /// Messages read from the socket: responses, including the ones to cancelled requests, and
+    /// unsolicited messages
+    pub messages_received: u64,
+    pub bytes_received: u64,
+    /// Requests sent with the `NLM_F_DUMP` flag
+    pub dumps: u64,
+    /// Requests sent or about to be sent, whose response is not complete yet. A cancelled request
+    /// remains pending until its last response is read.
+    pub pending_requests: usize,
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that a successful acknowledgement (`NLMSG_ERROR` with a zero error code) resolves the
+//! request as a success, including when the kernel attached a warning to it.
+
+mod common;
+
+use futures::StreamExt;
+use netlink_packet_core::NETLINK_HEADER_LEN;
+use netlink_packet_route::{
+    ErrorMessage,
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_ACK,
+    NLM_F_ACK_TLVS,
+    NLM_F_CAPPED,
+    NLM_F_REQUEST,
+    RTM_NEWLINK,
+};
+use netlink_proto::new_connection_with_socket;
+use netlink_sys::{protocols::NETLINK_ROUTE, SocketAddr};
+
+use common::{MockSocket, Peer};
+
+const WARNING: &str = "this is only a warning";
+
+/// Socket that acknowledges every request. The acknowledgements of `RTM_NEWLINK` requests carry
+/// a warning, like the kernel does when the `NETLINK_EXT_ACK` socket option is set.
+#[derive(Default)]
+struct Acking;
+
+type AckingSocket = MockSocket<Acking>;
+
+fn ack(request: &[u8]) -> Vec<u8> {
+    let mut sequence_number = [0; 4];
+    sequence_number.copy_from_slice(&request[8..12]);
+    let mut message_type = [0; 2];
+    message_type.copy_from_slice(&request[4..6]);
+
+    let mut flags = NLM_F_CAPPED;
+    let mut header = request[..NETLINK_HEADER_LEN].to_vec();
+    if u16::from_ne_bytes(message_type) == RTM_NEWLINK {
+        flags |= NLM_F_ACK_TLVS;
+        // NLMSGERR_ATTR_MSG, padded to 4 bytes
+        let len = WARNING.len() + 1 + 4;
+        header.extend_from_slice(&(len as u16).to_ne_bytes());
+        header.extend_from_slice(&1u16.to_ne_bytes());
+        header.extend_from_slice(WARNING.as_bytes());
+        header.resize(header.len() + (4 - WARNING.len() % 4), 0);
+    }
+
+    let mut msg = NetlinkMessage::<RtnlMessage> {
+        header: NetlinkHeader {
+            flags,
+            sequence_number: u32::from_ne_bytes(sequence_number),
+            ..Default::default()
+        },
+        payload: NetlinkPayload::Ack(ErrorMessage { code: 0, header }),
+    };
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf[..]);
+    buf
+}
+
+impl Peer for Acking {
+    fn send(&mut self, datagram: &[u8]) -> Vec<Vec<u8>> {
+        vec![ack(datagram)]
+    }
+}
+
+fn acked_request(payload: RtnlMessage) -> NetlinkMessage<RtnlMessage> {
+    NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_REQUEST | NLM_F_ACK,
+            ..Default::default()
+        },
+        payload: payload.into(),
+    }
+}
+
+#[tokio::test]
+async fn ack() {
+    let (conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, AckingSocket>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let request = acked_request(RtnlMessage::DelLink(LinkMessage::default()));
+    let mut response = handle.try_request(request, SocketAddr::new(0, 0)).unwrap();
+    assert!(response.next().await.is_none());
+}
+
+#[tokio::test]
+async fn ack_with_warning() {
+    let (conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, AckingSocket>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let request = acked_request(RtnlMessage::NewLink(LinkMessage::default()));
+    let mut response = handle.try_request(request, SocketAddr::new(0, 0)).unwrap();
+
+    let msg = response.next().await.unwrap().unwrap();
+    assert!(matches!(msg.payload, NetlinkPayload::Ack(ErrorMessage { code: 0, .. })));
+    let extended_ack = msg.extended_ack().unwrap();
+    assert_eq!(extended_ack.message.as_deref(), Some(WARNING));
+    assert!(response.next().await.is_none());
+}
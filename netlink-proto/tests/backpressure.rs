@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: MIT
+
+//! Read a large simulated dump with a slow consumer, and check that the connection does not read
+//! from the socket faster than the responses are consumed.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_MULTIPART,
+    NLM_F_REQUEST,
+};
+use netlink_proto::new_connection_with_socket;
+use netlink_sys::{protocols::NETLINK_ROUTE, SocketAddr};
+
+use common::{MockSocket, Peer};
+
+const LINKS: usize = 10_000;
+const LINKS_PER_DATAGRAM: usize = 100;
+const MAX_PENDING_RESPONSES: usize = 64;
+
+/// Number of datagrams read by the connection so far
+static DATAGRAMS_READ: AtomicUsize = AtomicUsize::new(0);
+
+/// Socket that answers any request with a dump of `LINKS` links. The datagrams are only built
+/// once the request is sent, like the kernel does.
+#[derive(Default)]
+struct Dumping;
+
+type SimulatedDump = MockSocket<Dumping>;
+
+fn dump_message(sequence_number: u32, payload: NetlinkPayload<RtnlMessage>) -> Vec<u8> {
+    let mut msg = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_MULTIPART,
+            sequence_number,
+            ..Default::default()
+        },
+        payload,
+    };
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf[..]);
+    buf
+}
+
+impl Peer for Dumping {
+    fn send(&mut self, datagram: &[u8]) -> Vec<Vec<u8>> {
+        let mut sequence_number = [0; 4];
+        sequence_number.copy_from_slice(&datagram[8..12]);
+        let sequence_number = u32::from_ne_bytes(sequence_number);
+
+        let link = RtnlMessage::NewLink(LinkMessage::default());
+        let link = dump_message(sequence_number, link.into());
+        let mut datagrams = vec![link.repeat(LINKS_PER_DATAGRAM); LINKS / LINKS_PER_DATAGRAM];
+        datagrams.push(dump_message(sequence_number, NetlinkPayload::Done));
+        datagrams
+    }
+
+    fn received(&mut self, _datagram: &[u8]) {
+        DATAGRAMS_READ.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn slow_consumer() {
+    let (mut conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, SimulatedDump>(NETLINK_ROUTE).unwrap();
+    conn.set_max_pending_responses(Some(MAX_PENDING_RESPONSES));
+    tokio::spawn(conn);
+
+    let request = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_DUMP | NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetLink(LinkMessage::default()).into(),
+    };
+    let mut response = handle.try_request(request, SocketAddr::new(0, 0)).unwrap();
+
+    let mut received = 0;
+    while let Some(msg) = response.next().await {
+        let msg = msg.unwrap();
+        assert!(matches!(msg.payload, NetlinkPayload::InnerMessage(RtnlMessage::NewLink(_))));
+        received += 1;
+
+        // The connection may have decoded up to `MAX_PENDING_RESPONSES` messages that we did not
+        // receive yet, out of the last datagram it read.
+        let read = DATAGRAMS_READ.load(Ordering::SeqCst) * LINKS_PER_DATAGRAM;
+        assert!(
+            read <= received + MAX_PENDING_RESPONSES + LINKS_PER_DATAGRAM,
+            "read {} messages but only received {}",
+            read,
+            received
+        );
+
+        if received % 10 == 0 {
+            tokio::task::yield_now().await;
+        }
+    }
+    assert_eq!(received, LINKS);
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that dropping the response stream of a request before the reply arrives (as when a
+//! `select!` completes on another branch) does not disturb the other requests.
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures::{FutureExt, StreamExt};
+use netlink_packet_route::{
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_REQUEST,
+};
+use netlink_proto::new_connection_with_socket;
+use netlink_sys::{protocols::NETLINK_ROUTE, SocketAddr};
+
+use common::{MockSocket, Peer};
+
+/// Number of requests sent through the socket
+static REQUESTS_SENT: AtomicUsize = AtomicUsize::new(0);
+
+/// Socket that answers `RTM_GETLINK` requests with the link of the requested index, but only
+/// answers the first request once the second one is sent, so that the response to the first
+/// request arrives late.
+#[derive(Default)]
+struct Slow {
+    requests: Vec<NetlinkMessage<RtnlMessage>>,
+}
+
+type SlowSocket = MockSocket<Slow>;
+
+fn get_link(index: u32) -> NetlinkMessage<RtnlMessage> {
+    let mut link = LinkMessage::default();
+    link.header.index = index;
+    NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetLink(link).into(),
+    }
+}
+
+fn reply(request: &NetlinkMessage<RtnlMessage>) -> Vec<u8> {
+    let link = match request.payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::GetLink(ref link)) => link.clone(),
+        _ => panic!("unexpected request {:?}", request),
+    };
+    let mut msg = NetlinkMessage {
+        header: NetlinkHeader {
+            sequence_number: request.header.sequence_number,
+            ..Default::default()
+        },
+        payload: RtnlMessage::NewLink(link).into(),
+    };
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf[..]);
+    buf
+}
+
+impl Peer for Slow {
+    fn send(&mut self, datagram: &[u8]) -> Vec<Vec<u8>> {
+        self.requests
+            .push(NetlinkMessage::deserialize(datagram).unwrap());
+        REQUESTS_SENT.fetch_add(1, Ordering::SeqCst);
+        if self.requests.len() == 2 {
+            // Both responses arrive in the same datagram: the late one first
+            vec![self.requests.iter().flat_map(reply).collect()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn link_index(message: &NetlinkMessage<RtnlMessage>) -> Option<u32> {
+    match message.payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(ref link)) => Some(link.header.index),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn dropped_request() {
+    let (conn, mut handle, mut messages) =
+        new_connection_with_socket::<RtnlMessage, SlowSocket>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let mut first = handle
+        .try_request(get_link(1), SocketAddr::new(0, 0))
+        .unwrap();
+    // Let the connection send the request, then give up on it
+    while REQUESTS_SENT.load(Ordering::SeqCst) == 0 {
+        tokio::task::yield_now().await;
+    }
+    assert!(first.next().now_or_never().is_none());
+    drop(first);
+
+    let mut second = handle
+        .try_request(get_link(2), SocketAddr::new(0, 0))
+        .unwrap();
+    let message = second.next().await.unwrap().unwrap();
+    assert_eq!(link_index(&message), Some(2));
+    assert!(second.next().await.is_none());
+
+    // The late response to the first request was handled before the response to the second one,
+    // and it was discarded rather than forwarded as an unsolicited message
+    assert!(messages.next().now_or_never().is_none());
+}
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+//! Mock socket shared by the integration tests, which simulates the kernel end of the socket.
+
+// Every test only uses part of this module
+#![allow(dead_code)]
+
+use std::{
+    collections::VecDeque,
+    io,
+    task::{Context, Poll, Waker},
+};
+
+use bytes::BufMut;
+use netlink_sys::{AsyncSocket, Socket, SocketAddr};
+
+/// The simulated kernel end of a [`MockSocket`]
+pub trait Peer: Default + Unpin {
+    /// Return the datagrams received as soon as the socket is created. Errors are returned by the
+    /// socket when it reaches them.
+    fn connect(&mut self) -> Vec<io::Result<Vec<u8>>> {
+        vec![]
+    }
+
+    /// Handle a datagram sent through the socket, and return the datagrams received in response
+    fn send(&mut self, _datagram: &[u8]) -> Vec<Vec<u8>> {
+        vec![]
+    }
+
+    /// Called every time the socket receives a datagram
+    fn received(&mut self, _datagram: &[u8]) {}
+
+    /// Return the error the socket fails with once all the datagrams are received. By default,
+    /// the socket waits for more datagrams instead.
+    fn end(&mut self) -> Option<io::Error> {
+        None
+    }
+}
+
+/// Socket that exchanges datagrams with a [`Peer`] instead of the kernel. The datagrams are
+/// truncated to the space left in the read buffer, like `recvfrom` does.
+pub struct MockSocket<P> {
+    socket: Socket,
+    peer: P,
+    datagrams: VecDeque<io::Result<Vec<u8>>>,
+    reader: Option<Waker>,
+}
+
+impl<P: Peer> MockSocket<P> {
+    fn poll_datagram(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Vec<u8>>> {
+        match self.datagrams.pop_front() {
+            Some(Ok(datagram)) => {
+                self.peer.received(&datagram);
+                Poll::Ready(Ok(datagram))
+            }
+            Some(Err(e)) => Poll::Ready(Err(e)),
+            None => match self.peer.end() {
+                Some(e) => Poll::Ready(Err(e)),
+                None => {
+                    self.reader = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            },
+        }
+    }
+}
+
+impl<P: Peer> AsyncSocket for MockSocket<P> {
+    fn socket_ref(&self) -> &Socket {
+        &self.socket
+    }
+
+    fn socket_mut(&mut self) -> &mut Socket {
+        &mut self.socket
+    }
+
+    fn new(protocol: isize) -> io::Result<Self> {
+        let mut peer = P::default();
+        let datagrams = peer.connect().into();
+        Ok(MockSocket {
+            socket: Socket::new(protocol)?,
+            peer,
+            datagrams,
+            reader: None,
+        })
+    }
+
+    fn poll_send(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.poll_send_to(cx, buf, &SocketAddr::new(0, 0))
+    }
+
+    fn poll_send_to(
+        &mut self,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+        _addr: &SocketAddr,
+    ) -> Poll<io::Result<usize>> {
+        let replies = self.peer.send(buf);
+        self.datagrams.extend(replies.into_iter().map(Ok));
+        // Even without replies, the peer may fail the socket now
+        if let Some(waker) = self.reader.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_recv<B>(&mut self, cx: &mut Context<'_>, buf: &mut B) -> Poll<io::Result<()>>
+    where
+        B: BufMut,
+    {
+        self.poll_recv_from(cx, buf).map_ok(|_| ())
+    }
+
+    fn poll_recv_from<B>(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<SocketAddr>>
+    where
+        B: BufMut,
+    {
+        self.poll_datagram(cx).map_ok(|datagram| {
+            let len = datagram.len().min(buf.chunk_mut().len());
+            buf.put_slice(&datagram[..len]);
+            SocketAddr::new(0, 0)
+        })
+    }
+
+    fn poll_recv_from_full(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<(Vec<u8>, SocketAddr)>> {
+        self.poll_datagram(cx)
+            .map_ok(|datagram| (datagram, SocketAddr::new(0, 0)))
+    }
+}
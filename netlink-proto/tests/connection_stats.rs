@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that the counters of a connection reflect the requests made on it.
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+use netlink_proto::{
+    new_connection,
+    sys::{protocols::NETLINK_ROUTE, SocketAddr},
+    ConnectionStats,
+};
+
+fn request(flags: u16, payload: RtnlMessage) -> NetlinkMessage<RtnlMessage> {
+    let mut message = NetlinkMessage {
+        header: NetlinkHeader {
+            flags,
+            ..Default::default()
+        },
+        payload: payload.into(),
+    };
+    message.finalize();
+    message
+}
+
+#[tokio::test]
+async fn connection_stats() {
+    let (conn, mut handle, _) = new_connection(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+    assert_eq!(handle.stats(), ConnectionStats::default());
+
+    // The loopback interface always has index 1
+    let mut lo = LinkMessage::default();
+    lo.header.index = 1;
+    let requests = vec![
+        request(
+            NLM_F_DUMP | NLM_F_REQUEST,
+            RtnlMessage::GetLink(LinkMessage::default()),
+        ),
+        request(
+            NLM_F_DUMP | NLM_F_REQUEST,
+            RtnlMessage::GetRoute(RouteMessage::default()),
+        ),
+        request(NLM_F_REQUEST, RtnlMessage::GetLink(lo)),
+    ];
+    let bytes_sent: u64 = requests.iter().map(|r| r.header.length as u64).sum();
+
+    let mut responses = 0;
+    let mut response_bytes = 0;
+    for request in requests {
+        let mut response = handle.request(request, SocketAddr::new(0, 0)).unwrap();
+        while let Some(message) = response.next().await {
+            responses += 1;
+            response_bytes += message.header.length as u64;
+        }
+    }
+
+    let stats = handle.clone().stats();
+    assert_eq!(stats.messages_sent, 3);
+    assert_eq!(stats.bytes_sent, bytes_sent);
+    assert_eq!(stats.dumps, 2);
+    // The end of dump messages are counted too, but not forwarded to the response streams
+    assert_eq!(stats.messages_received, responses + 2);
+    assert!(stats.bytes_received > response_bytes);
+    assert_eq!(stats.pending_requests, 0);
+}
@@ -0,0 +1,99 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::{NetlinkMessage, NetlinkPayload, RtnlMessage};
+use netlink_proto::{decode_datagram, decode_message};
+
+#[rustfmt::skip]
+static LINK_DUMP: [u8; 244] = [
+    // RTM_NEWLINK
+    0x70, 0x00, 0x00, 0x00, // length = 112
+    0x10, 0x00, // type = RTM_NEWLINK
+    0x02, 0x00, // flags = NLM_F_MULTI
+    0x01, 0x00, 0x00, 0x00, // sequence number = 1
+    0x34, 0x12, 0x00, 0x00, // port = 0x1234
+    0x00, // address family
+    0x00, // reserved
+    0x04, 0x03, // link layer type 772 = loopback
+    0x01, 0x00, 0x00, 0x00, // interface index = 1
+    0x49, 0x00, 0x00, 0x00, // device flags: UP, LOOPBACK, RUNNING, LOWERUP
+    0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+    0x07, 0x00, 0x03, 0x00, 0x6c, 0x6f, 0x00, // device name L=7,T=3,V=lo
+    0x00, // padding
+    0x08, 0x00, 0x0d, 0x00, 0xe8, 0x03, 0x00, 0x00, // TxQueue length L=8,T=13,V=1000
+    0x05, 0x00, 0x10, 0x00, 0x00, // OperState L=5,T=16,V=0 (unknown)
+    0x00, 0x00, 0x00, // padding
+    0x05, 0x00, 0x11, 0x00, 0x00, // Link mode L=5,T=17,V=0
+    0x00, 0x00, 0x00, // padding
+    0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, // MTU L=8,T=4,V=65536
+    0x08, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, // Group L=8,T=27,V=0
+    0x08, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, // Promiscuity L=8,T=30,V=0
+    0x08, 0x00, 0x1f, 0x00, 0x01, 0x00, 0x00, 0x00, // Number of Tx Queues L=8,T=31,V=1
+    0x08, 0x00, 0x28, 0x00, 0xff, 0xff, 0x00, 0x00, // Maximum GSO segment count L=8,T=40,V=65535
+    0x08, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, // Maximum GSO size L=8,T=41,V=65536
+
+    // RTM_NEWLINK
+    0x70, 0x00, 0x00, 0x00, // length = 112
+    0x10, 0x00, // type = RTM_NEWLINK
+    0x02, 0x00, // flags = NLM_F_MULTI
+    0x01, 0x00, 0x00, 0x00, // sequence number = 1
+    0x34, 0x12, 0x00, 0x00, // port = 0x1234
+    0x00, // address family
+    0x00, // reserved
+    0x01, 0x00, // link layer type 1 = ethernet
+    0x02, 0x00, 0x00, 0x00, // interface index = 2
+    0x03, 0x10, 0x00, 0x00, // device flags: UP, BROADCAST, MULTICAST
+    0x00, 0x00, 0x00, 0x00, // reserved 2 (aka device change flag)
+    0x07, 0x00, 0x03, 0x00, 0x65, 0x30, 0x00, // device name L=7,T=3,V=e0
+    0x00, // padding
+    0x08, 0x00, 0x0d, 0x00, 0xe8, 0x03, 0x00, 0x00, // TxQueue length L=8,T=13,V=1000
+    0x05, 0x00, 0x10, 0x00, 0x06, // OperState L=5,T=16,V=6 (up)
+    0x00, 0x00, 0x00, // padding
+    0x05, 0x00, 0x11, 0x00, 0x00, // Link mode L=5,T=17,V=0
+    0x00, 0x00, 0x00, // padding
+    0x08, 0x00, 0x04, 0x00, 0xdc, 0x05, 0x00, 0x00, // MTU L=8,T=4,V=1500
+    0x08, 0x00, 0x1b, 0x00, 0x00, 0x00, 0x00, 0x00, // Group L=8,T=27,V=0
+    0x08, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, // Promiscuity L=8,T=30,V=0
+    0x08, 0x00, 0x1f, 0x00, 0x01, 0x00, 0x00, 0x00, // Number of Tx Queues L=8,T=31,V=1
+    0x08, 0x00, 0x28, 0x00, 0xff, 0xff, 0x00, 0x00, // Maximum GSO segment count L=8,T=40,V=65535
+    0x08, 0x00, 0x29, 0x00, 0x00, 0x00, 0x01, 0x00, // Maximum GSO size L=8,T=41,V=65536
+
+    // NLMSG_DONE
+    0x14, 0x00, 0x00, 0x00, // length = 20
+    0x03, 0x00, // type = NLMSG_DONE
+    0x02, 0x00, // flags = NLM_F_MULTI
+    0x01, 0x00, 0x00, 0x00, // sequence number = 1
+    0x34, 0x12, 0x00, 0x00, // port = 0x1234
+    0x00, 0x00, 0x00, 0x00, // error code
+];
+
+fn link_index(msg: &NetlinkMessage<RtnlMessage>) -> Option<u32> {
+    match msg.payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(ref link)) => Some(link.header.index),
+        _ => None,
+    }
+}
+
+#[test]
+fn decode_link_dump_datagram() {
+    let messages = decode_datagram::<RtnlMessage>(&LINK_DUMP[..]).unwrap();
+    assert_eq!(messages.len(), 3);
+    assert_eq!(link_index(&messages[0]), Some(1));
+    assert_eq!(link_index(&messages[1]), Some(2));
+    assert_eq!(messages[2].payload, NetlinkPayload::Done);
+    assert!(messages.iter().all(|msg| msg.header.sequence_number == 1));
+}
+
+#[test]
+fn decode_single_message() {
+    let msg = decode_message::<RtnlMessage>(&LINK_DUMP[..112]).unwrap();
+    assert_eq!(link_index(&msg), Some(1));
+    assert_eq!(msg.header.port_number, 0x1234);
+
+    // There are more messages in the whole datagram
+    assert!(decode_message::<RtnlMessage>(&LINK_DUMP[..]).is_err());
+}
+
+#[test]
+fn decode_truncated_datagram() {
+    assert!(decode_datagram::<RtnlMessage>(&LINK_DUMP[..200]).is_err());
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+
+//! Count the allocations made while decoding a large route dump with the in-place codec, which
+//! reuses the datagram buffer, and with the default codec, which splits every message off it.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use bytes::{BufMut, BytesMut};
+use netlink_packet_route::{
+    nlas::route::Nla,
+    NetlinkBuffer,
+    NetlinkMessage,
+    NetlinkPayload,
+    RouteMessage,
+    RtnlMessage,
+    AF_INET,
+    NLM_F_MULTIPART,
+};
+use netlink_proto::{NetlinkCodec, NetlinkInPlaceCodec, NetlinkMessageCodec};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ROUTES: u32 = 10_000;
+const DATAGRAM_SIZE: usize = 32 * 1024;
+const READER_CAPACITY: usize = 64 * 1024;
+
+// Build the datagrams the kernel would send for a dump of `ROUTES` IPv4 routes
+fn route_dump() -> Vec<Vec<u8>> {
+    let mut datagrams = vec![];
+    let mut datagram = vec![];
+    for i in 0..ROUTES {
+        let mut route = RouteMessage::default();
+        route.header.address_family = AF_INET as u8;
+        route.header.destination_prefix_length = 32;
+        route.nlas.push(Nla::Table(254));
+        route
+            .nlas
+            .push(Nla::Destination((0x0a00_0000 + i).to_be_bytes().to_vec()));
+        route.nlas.push(Nla::Oif(2));
+
+        let mut msg = NetlinkMessage::from(RtnlMessage::NewRoute(route));
+        msg.header.flags = NLM_F_MULTIPART;
+        msg.finalize();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.serialize(&mut buf[..]);
+
+        if datagram.len() + buf.len() > DATAGRAM_SIZE {
+            datagrams.push(std::mem::take(&mut datagram));
+        }
+        datagram.extend_from_slice(&buf);
+    }
+    datagrams.push(datagram);
+    datagrams
+}
+
+// Decode the dump the way `NetlinkFramed` does, and return the number of messages and the number
+// of allocations made
+fn decode_dump<F>(datagrams: &[Vec<u8>], mut decode: F) -> (usize, usize)
+where
+    F: FnMut(&mut BytesMut) -> Option<NetlinkMessage<RtnlMessage>>,
+{
+    let mut reader = BytesMut::with_capacity(READER_CAPACITY);
+    let mut messages = 0;
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for datagram in datagrams {
+        reader.clear();
+        reader.reserve(READER_CAPACITY);
+        reader.put_slice(datagram);
+        while let Some(msg) = decode(&mut reader) {
+            assert!(matches!(
+                msg.payload,
+                NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(_))
+            ));
+            messages += 1;
+        }
+    }
+    (messages, ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+// Parse the messages straight from the datagrams, and return the number of messages and the
+// number of allocations made: these are the allocations of the parsed messages themselves
+fn parse_dump(datagrams: &[Vec<u8>]) -> (usize, usize) {
+    let mut messages = 0;
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for datagram in datagrams {
+        let mut offset = 0;
+        while offset < datagram.len() {
+            let len = NetlinkBuffer::new_checked(&datagram[offset..])
+                .unwrap()
+                .length() as usize;
+            let msg =
+                NetlinkMessage::<RtnlMessage>::deserialize(&datagram[offset..][..len]).unwrap();
+            assert!(matches!(
+                msg.payload,
+                NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(_))
+            ));
+            messages += 1;
+            offset += len;
+        }
+    }
+    (messages, ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+#[test]
+fn route_dump_allocations() {
+    let datagrams = route_dump();
+
+    let (messages, parsing) = parse_dump(&datagrams);
+    assert_eq!(messages, ROUTES as usize);
+
+    // Reusing the buffer costs no allocation on top of the parsing
+    let (messages, in_place) =
+        decode_dump(&datagrams, |src| NetlinkInPlaceCodec::decode(src).unwrap());
+    assert_eq!(messages, ROUTES as usize);
+    assert_eq!(in_place, parsing);
+
+    // Splitting the first message off the buffer promotes it to a shared buffer, which allocates
+    // its reference count. The buffer is then reused since the split messages are dropped before
+    // the next datagram is read.
+    let (messages, split) = decode_dump(&datagrams, |src| NetlinkCodec::decode(src).unwrap());
+    assert_eq!(messages, ROUTES as usize);
+    assert_eq!(split, parsing + 1);
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that a dump with more messages than the limit set on the connection is aborted with a
+//! `DumpTooLarge` error, and that the rest of the dump is discarded.
+
+mod common;
+
+use futures::{FutureExt, StreamExt};
+use netlink_packet_route::{
+    ErrorMessage,
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_MULTIPART,
+    NLM_F_REQUEST,
+};
+use netlink_proto::{new_connection_with_socket, ErrorKind};
+use netlink_sys::{protocols::NETLINK_ROUTE, SocketAddr};
+
+use common::{MockSocket, Peer};
+
+const LINKS: usize = 10_000;
+const LINKS_PER_DATAGRAM: usize = 100;
+const MAX_DUMP_MESSAGES: usize = 1_000;
+
+/// Socket that answers any request with a dump of `LINKS` links
+#[derive(Default)]
+struct Dumping;
+
+type LargeDump = MockSocket<Dumping>;
+
+fn dump_message(sequence_number: u32, payload: NetlinkPayload<RtnlMessage>) -> Vec<u8> {
+    let mut msg = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_MULTIPART,
+            sequence_number,
+            ..Default::default()
+        },
+        payload,
+    };
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf[..]);
+    buf
+}
+
+impl Peer for Dumping {
+    fn send(&mut self, datagram: &[u8]) -> Vec<Vec<u8>> {
+        let mut sequence_number = [0; 4];
+        sequence_number.copy_from_slice(&datagram[8..12]);
+        let sequence_number = u32::from_ne_bytes(sequence_number);
+
+        let link = RtnlMessage::NewLink(LinkMessage::default());
+        let link = dump_message(sequence_number, link.into());
+        let mut datagrams = vec![link.repeat(LINKS_PER_DATAGRAM); LINKS / LINKS_PER_DATAGRAM];
+        datagrams.push(dump_message(sequence_number, NetlinkPayload::Done));
+        datagrams
+    }
+}
+
+fn dump_request() -> NetlinkMessage<RtnlMessage> {
+    NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_DUMP | NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetLink(LinkMessage::default()).into(),
+    }
+}
+
+#[tokio::test]
+async fn dump_too_large() {
+    let (mut conn, mut handle, mut messages) =
+        new_connection_with_socket::<RtnlMessage, LargeDump>(NETLINK_ROUTE).unwrap();
+    conn.set_max_dump_messages(Some(MAX_DUMP_MESSAGES));
+    tokio::spawn(conn);
+
+    // Run the dump twice: the second one only starts once the first one has been read entirely
+    for _ in 0..2 {
+        let mut response = handle.try_request(dump_request(), SocketAddr::new(0, 0)).unwrap();
+        for _ in 0..MAX_DUMP_MESSAGES {
+            let msg = response.next().await.unwrap().unwrap();
+            assert!(matches!(msg.payload, NetlinkPayload::InnerMessage(RtnlMessage::NewLink(_))));
+        }
+        let err = response.next().await.unwrap().unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::DumpTooLarge(MAX_DUMP_MESSAGES)));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "The response to a request has too many messages: more than {}",
+                MAX_DUMP_MESSAGES
+            )
+        );
+        assert!(response.next().await.is_none());
+    }
+
+    // The end of the first dump was not forwarded as unsolicited messages
+    assert!(messages.next().now_or_never().is_none());
+}
+
+#[tokio::test]
+async fn no_dump_limit() {
+    let (mut conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, LargeDump>(NETLINK_ROUTE).unwrap();
+    conn.set_max_dump_messages(None);
+    tokio::spawn(conn);
+
+    let response = handle.try_request(dump_request(), SocketAddr::new(0, 0)).unwrap();
+    let received: Vec<_> = response.collect().await;
+    assert_eq!(received.len(), LINKS);
+    assert!(received.iter().all(Result::is_ok));
+}
+
+#[tokio::test]
+async fn dump_too_large_error_message() {
+    let (mut conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, LargeDump>(NETLINK_ROUTE).unwrap();
+    conn.set_max_dump_messages(Some(MAX_DUMP_MESSAGES));
+    tokio::spawn(conn);
+
+    // `request` has no error items, so the truncation is reported as an error message
+    let response = handle
+        .request(dump_request(), SocketAddr::new(0, 0))
+        .unwrap();
+    let mut received: Vec<_> = response.collect().await;
+    let last = received.pop().unwrap();
+    assert!(matches!(
+        last.payload,
+        NetlinkPayload::Error(ErrorMessage { code, .. }) if code == -libc::EMSGSIZE
+    ));
+    assert_eq!(received.len(), MAX_DUMP_MESSAGES);
+}
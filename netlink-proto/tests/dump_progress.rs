@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that the response stream of a dump counts the messages received so far.
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RouteMessage,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+use netlink_proto::{
+    new_connection,
+    sys::{protocols::NETLINK_ROUTE, SocketAddr},
+};
+
+#[tokio::test]
+async fn dump_progress() {
+    let (conn, mut handle, _) = new_connection(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let request = NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_DUMP | NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetRoute(RouteMessage::default()).into(),
+    };
+    let mut response = handle.try_request(request, SocketAddr::new(0, 0)).unwrap();
+    assert_eq!(response.received(), 0);
+
+    let mut routes = 0;
+    while let Some(msg) = response.next().await {
+        let msg = msg.unwrap();
+        if let NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(_)) = msg.payload {
+            routes += 1;
+        }
+        assert_eq!(response.received(), routes);
+    }
+    // There is at least the route to the loopback address, in the local table
+    assert!(routes > 0);
+    assert_eq!(response.received(), routes);
+}
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+
+//! Check that an overflow of the socket receive buffer (`ENOBUFS`) is reported on the unsolicited
+//! messages stream instead of closing the connection, and fails the requests whose responses may
+//! have been lost.
+
+mod common;
+
+use std::io;
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    ErrorMessage,
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_ACK,
+    NLM_F_REQUEST,
+    RTM_NEWLINK,
+};
+use netlink_proto::{new_connection_with_socket, ErrorKind};
+use netlink_sys::{protocols::NETLINK_ROUTE, SocketAddr};
+
+use common::{MockSocket, Peer};
+
+/// Socket that receives a link notification, then reports `ENOBUFS`, then receives a second
+/// notification
+#[derive(Default)]
+struct Overflowing;
+
+type OverflowingSocket = MockSocket<Overflowing>;
+
+fn link_notification(index: u32) -> Vec<u8> {
+    let mut link = LinkMessage::default();
+    link.header.index = index;
+    let mut msg = NetlinkMessage {
+        header: NetlinkHeader {
+            message_type: RTM_NEWLINK,
+            ..Default::default()
+        },
+        payload: NetlinkPayload::InnerMessage(RtnlMessage::NewLink(link)),
+    };
+    msg.finalize();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.serialize(&mut buf[..]);
+    buf
+}
+
+impl Peer for Overflowing {
+    fn connect(&mut self) -> Vec<io::Result<Vec<u8>>> {
+        vec![
+            Ok(link_notification(1)),
+            Err(io::Error::from_raw_os_error(libc::ENOBUFS)),
+            Ok(link_notification(2)),
+        ]
+    }
+}
+
+/// Socket that never acknowledges the requests, and reports `ENOBUFS` after each of them as if the
+/// acknowledgement had been dropped
+#[derive(Default)]
+struct LosingAcks {
+    sent: bool,
+}
+
+impl Peer for LosingAcks {
+    fn send(&mut self, _datagram: &[u8]) -> Vec<Vec<u8>> {
+        self.sent = true;
+        vec![]
+    }
+
+    fn end(&mut self) -> Option<io::Error> {
+        if std::mem::take(&mut self.sent) {
+            Some(io::Error::from_raw_os_error(libc::ENOBUFS))
+        } else {
+            None
+        }
+    }
+}
+
+/// Socket that answers the requests with an overrun message, as if the kernel had dropped their
+/// responses
+#[derive(Default)]
+struct OverrunReplies;
+
+impl Peer for OverrunReplies {
+    fn send(&mut self, datagram: &[u8]) -> Vec<Vec<u8>> {
+        let request = NetlinkMessage::<RtnlMessage>::deserialize(datagram).unwrap();
+        let mut msg = NetlinkMessage::<RtnlMessage>::new(
+            NetlinkHeader {
+                sequence_number: request.header.sequence_number,
+                ..Default::default()
+            },
+            NetlinkPayload::Overrun(vec![]),
+        );
+        msg.finalize();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.serialize(&mut buf[..]);
+        vec![buf]
+    }
+}
+
+fn link_index(message: &NetlinkMessage<RtnlMessage>) -> Option<u32> {
+    match message.payload {
+        NetlinkPayload::InnerMessage(RtnlMessage::NewLink(ref link)) => Some(link.header.index),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn overrun_is_reported() {
+    let (conn, _handle, mut messages) =
+        new_connection_with_socket::<RtnlMessage, OverflowingSocket>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let (message, _) = messages.next().await.unwrap();
+    assert_eq!(link_index(&message), Some(1));
+
+    let (message, _) = messages.next().await.unwrap();
+    assert!(matches!(message.payload, NetlinkPayload::Overrun(_)));
+    assert_eq!(message.header.sequence_number, 0);
+
+    // The connection is still reading from the socket
+    let (message, _) = messages.next().await.unwrap();
+    assert_eq!(link_index(&message), Some(2));
+}
+
+fn del_link() -> NetlinkMessage<RtnlMessage> {
+    let mut request = NetlinkMessage::from(RtnlMessage::DelLink(LinkMessage::default()));
+    request.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+    request
+}
+
+#[tokio::test]
+async fn overrun_fails_requests() {
+    let (conn, mut handle, mut messages) =
+        new_connection_with_socket::<RtnlMessage, MockSocket<LosingAcks>>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let mut response = handle
+        .try_request(del_link(), SocketAddr::new(0, 0))
+        .unwrap();
+    let err = response.next().await.unwrap().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::SocketIo(e) if e.raw_os_error() == Some(libc::ENOBUFS)
+    ));
+    assert!(response.next().await.is_none());
+
+    // The overrun is still reported on the unsolicited messages stream
+    let (message, _) = messages.next().await.unwrap();
+    assert!(matches!(message.payload, NetlinkPayload::Overrun(_)));
+}
+
+#[tokio::test]
+async fn overrun_response() {
+    let (conn, mut handle, _) =
+        new_connection_with_socket::<RtnlMessage, MockSocket<OverrunReplies>>(NETLINK_ROUTE)
+            .unwrap();
+    tokio::spawn(conn);
+
+    let mut response = handle
+        .try_request(del_link(), SocketAddr::new(0, 0))
+        .unwrap();
+    let err = response.next().await.unwrap().unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::SocketIo(e) if e.raw_os_error() == Some(libc::ENOBUFS)
+    ));
+    assert!(response.next().await.is_none());
+
+    // `request` reports it as an error message
+    let response = handle.request(del_link(), SocketAddr::new(0, 0)).unwrap();
+    let messages: Vec<_> = response.collect().await;
+    assert_eq!(messages.len(), 1);
+    assert!(matches!(
+        messages[0].payload,
+        NetlinkPayload::Error(ErrorMessage { code, .. }) if code == -libc::ENOBUFS
+    ));
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: MIT
+
+//! Read a dump made of datagrams larger than the default read buffer, and check that a larger
+//! buffer set with `NetlinkFramed::set_reader_capacity` receives all of it without being
+//! reallocated.
+
+mod common;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::VecDeque,
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use futures::{executor::block_on, StreamExt};
+use netlink_packet_route::{
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+    AF_INET,
+    NLM_F_MULTIPART,
+};
+use netlink_proto::{NetlinkCodec, NetlinkFramed};
+use netlink_sys::{protocols::NETLINK_ROUTE, AsyncSocket};
+
+use common::{MockSocket, Peer};
+
+/// Counts the allocations of at least `DATAGRAM_SIZE` bytes, which can only be the read buffer
+struct CountingAllocator;
+
+static LARGE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() >= DATAGRAM_SIZE {
+            LARGE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ROUTES: u32 = 20_000;
+const DATAGRAM_SIZE: usize = 128 * 1024;
+const READER_CAPACITY: usize = 256 * 1024;
+
+// Build a dump of `ROUTES` IPv4 routes, in datagrams of up to `DATAGRAM_SIZE` bytes
+fn route_dump() -> VecDeque<Vec<u8>> {
+    let mut datagrams = VecDeque::new();
+    let mut datagram = Vec::with_capacity(DATAGRAM_SIZE);
+    for i in 0..ROUTES {
+        let mut route = RouteMessage::default();
+        route.header.address_family = AF_INET as u8;
+        route.header.destination_prefix_length = 32;
+        route.nlas.push(Nla::Table(254));
+        route
+            .nlas
+            .push(Nla::Destination((0x0a00_0000 + i).to_be_bytes().to_vec()));
+        route.nlas.push(Nla::Oif(2));
+
+        let mut msg = NetlinkMessage::from(RtnlMessage::NewRoute(route));
+        msg.header.flags = NLM_F_MULTIPART;
+        msg.finalize();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.serialize(&mut buf[..]);
+
+        if datagram.len() + buf.len() > DATAGRAM_SIZE {
+            datagrams.push_back(std::mem::replace(
+                &mut datagram,
+                Vec::with_capacity(DATAGRAM_SIZE),
+            ));
+        }
+        datagram.extend_from_slice(&buf);
+    }
+    datagrams.push_back(datagram);
+    datagrams
+}
+
+/// Socket that receives the datagrams of a dump, and then fails
+#[derive(Default)]
+struct Dump;
+
+type DumpSocket = MockSocket<Dump>;
+
+impl Peer for Dump {
+    fn connect(&mut self) -> Vec<io::Result<Vec<u8>>> {
+        route_dump().into_iter().map(Ok).collect()
+    }
+
+    // End the stream once the dump is read
+    fn end(&mut self) -> Option<io::Error> {
+        Some(io::Error::from_raw_os_error(libc::EBADF))
+    }
+}
+
+// Read the whole dump, and return the number of messages and of read buffer allocations
+fn read_dump(reader_capacity: Option<usize>) -> (usize, usize) {
+    let socket = DumpSocket::new(NETLINK_ROUTE).unwrap();
+    let mut framed = NetlinkFramed::<RtnlMessage, DumpSocket, NetlinkCodec>::new(socket);
+    if let Some(capacity) = reader_capacity {
+        framed.set_reader_capacity(capacity);
+    }
+
+    let before = LARGE_ALLOCATIONS.load(Ordering::Relaxed);
+    let messages = block_on(framed.count());
+    (messages, LARGE_ALLOCATIONS.load(Ordering::Relaxed) - before)
+}
+
+#[test]
+fn large_reader_capacity() {
+    // The default buffer truncates the datagrams, losing the messages past its end
+    let (messages, _) = read_dump(None);
+    assert!(messages < ROUTES as usize);
+
+    let (messages, allocations) = read_dump(Some(READER_CAPACITY));
+    assert_eq!(messages, ROUTES as usize);
+    assert_eq!(allocations, 0);
+}
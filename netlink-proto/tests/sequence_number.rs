@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+use netlink_proto::{
+    new_connection,
+    sys::{protocols::NETLINK_ROUTE, SocketAddr},
+    ErrorKind,
+    MIN_USER_SEQUENCE_NUMBER,
+};
+
+fn get_links(sequence_number: u32) -> NetlinkMessage<RtnlMessage> {
+    NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_DUMP | NLM_F_REQUEST,
+            sequence_number,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetLink(LinkMessage::default()).into(),
+    }
+}
+
+#[tokio::test]
+async fn request_with_sequence_number() {
+    let (conn, mut handle, _) = new_connection(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let sequence_number = MIN_USER_SEQUENCE_NUMBER + 42;
+    let mut response = handle
+        .request_with_sequence_number(get_links(sequence_number), SocketAddr::new(0, 0))
+        .unwrap();
+
+    let mut links = 0;
+    while let Some(msg) = response.next().await {
+        assert_eq!(msg.header.sequence_number, sequence_number);
+        if let NetlinkPayload::InnerMessage(RtnlMessage::NewLink(_)) = msg.payload {
+            links += 1;
+        }
+    }
+    // There is at least the loopback interface
+    assert!(links > 0);
+}
+
+#[tokio::test]
+async fn request_with_reserved_sequence_number() {
+    let (conn, mut handle, _) = new_connection::<RtnlMessage>(NETLINK_ROUTE).unwrap();
+    tokio::spawn(conn);
+
+    let res = handle.request_with_sequence_number(get_links(1), SocketAddr::new(0, 0));
+    assert!(matches!(
+        res.map(|_| ()).unwrap_err().into_inner(),
+        ErrorKind::InvalidSequenceNumber(1)
+    ));
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+use netlink_packet_route::{
+    LinkMessage,
+    NetlinkHeader,
+    NetlinkMessage,
+    RtnlMessage,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+use netlink_proto::{
+    new_connection,
+    sys::{protocols::NETLINK_ROUTE, SocketAddr},
+    ErrorKind,
+};
+
+fn get_links() -> NetlinkMessage<RtnlMessage> {
+    NetlinkMessage {
+        header: NetlinkHeader {
+            flags: NLM_F_DUMP | NLM_F_REQUEST,
+            ..Default::default()
+        },
+        payload: RtnlMessage::GetLink(LinkMessage::default()).into(),
+    }
+}
+
+#[tokio::test]
+async fn shutdown_with_pending_request() {
+    let (conn, mut handle, _) = new_connection(NETLINK_ROUTE).unwrap();
+
+    let mut response = handle.try_request(get_links(), SocketAddr::new(0, 0)).unwrap();
+    // Shut down before the connection is even polled, so that the request cannot have been
+    // answered.
+    handle.shutdown();
+    tokio::spawn(conn).await.unwrap();
+
+    let err = response.next().await.unwrap().unwrap_err();
+    assert!(matches!(err.into_inner(), ErrorKind::ConnectionClosed));
+    assert!(response.next().await.is_none());
+
+    let res = handle.request(get_links(), SocketAddr::new(0, 0));
+    assert!(matches!(
+        res.map(|_| ()).unwrap_err().into_inner(),
+        ErrorKind::ConnectionClosed
+    ));
+}
@@ -28,7 +28,7 @@ pub mod protocols {
 }
 
 mod socket;
-pub use self::socket::Socket;
+pub use self::socket::{OptionValue, Socket};
 
 mod addr;
 pub use self::addr::SocketAddr;
@@ -1,13 +1,56 @@
 // SPDX-License-Identifier: MIT
 
 use std::{
-    io::{Error, Result},
+    convert::TryFrom,
+    io::{Error, ErrorKind, Result},
     mem,
     os::unix::io::{AsRawFd, FromRawFd, RawFd},
+    time::{Duration, Instant},
 };
 
 use crate::SocketAddr;
 
+// Not defined by the oldest libc versions this crate supports
+const NETLINK_GET_STRICT_CHK: libc::c_int = 12;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Types that can hold the value of a socket option, for [`Socket::set_option`] and
+/// [`Socket::get_option`]: the integer types and the C structures some options take.
+///
+/// # Safety
+///
+/// Any bit pattern written by the kernel, including all zeroes, must be a valid value of the
+/// type. The trait is sealed, so it cannot be implemented outside of this crate.
+pub unsafe trait OptionValue: Copy + sealed::Sealed {}
+
+macro_rules! impl_option_value {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            unsafe impl OptionValue for $ty {}
+        )*
+    };
+}
+
+impl_option_value!(
+    u8,
+    i8,
+    u16,
+    i16,
+    u32,
+    i32,
+    u64,
+    i64,
+    usize,
+    isize,
+    libc::timeval,
+    libc::linger,
+    libc::ucred
+);
+
 /// A netlink socket.
 ///
 /// # Example
@@ -109,6 +152,25 @@ impl Socket {
         Ok(addr)
     }
 
+    /// Bind the socket to the given port id, and return the resulting address. This lets callers
+    /// manage the port ids of their sockets themselves, instead of relying on the kernel's
+    /// assignment. If another netlink socket of the same protocol already uses that port id, an
+    /// error of kind [`ErrorKind::AddrInUse`] (`EADDRINUSE`) is returned.
+    pub fn bind_with_portid(&mut self, port_number: u32) -> Result<SocketAddr> {
+        let addr = SocketAddr::new(port_number, 0);
+        self.bind(&addr)?;
+        Ok(addr)
+    }
+
+    /// Get the port id of the socket. For a socket bound with [`Socket::bind_auto`], or not
+    /// explicitly bound before sending a message, this is the port id assigned by the kernel.
+    /// It is 0 if the socket is not bound yet.
+    pub fn port_number(&self) -> Result<u32> {
+        let mut addr = SocketAddr::new(0, 0);
+        self.get_address(&mut addr)?;
+        Ok(addr.port_number())
+    }
+
     /// Get the socket address
     pub fn get_address(&self, addr: &mut SocketAddr) -> Result<()> {
         let (addr_ptr, mut addr_len) = addr.as_raw_mut();
@@ -134,6 +196,67 @@ impl Socket {
         Ok(())
     }
 
+    /// `SO_RCVTIMEO`. Set the timeout of the blocking receive operations. If no datagram is
+    /// received before the timeout expires, [`Socket::recv`] and [`Socket::recv_from`] fail with
+    /// an error of kind [`ErrorKind::WouldBlock`]. `None` means that receiving blocks forever.
+    ///
+    /// A receive operation interrupted by a signal is restarted, unless the timeout already
+    /// expired, in which case it fails the same way. Each restart waits for the full timeout again.
+    ///
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned for a zero duration.
+    pub fn set_recv_timeout(&mut self, timeout: Option<Duration>) -> Result<()> {
+        setsockopt(
+            self.0,
+            libc::SOL_SOCKET,
+            libc::SO_RCVTIMEO,
+            duration_to_timeval(timeout)?,
+        )
+    }
+
+    pub fn get_recv_timeout(&self) -> Result<Option<Duration>> {
+        let res = getsockopt::<libc::timeval>(self.0, libc::SOL_SOCKET, libc::SO_RCVTIMEO)?;
+        Ok(timeval_to_duration(res))
+    }
+
+    /// `SO_SNDTIMEO`. Set the timeout of the blocking send operations. If the datagram cannot be
+    /// sent before the timeout expires, [`Socket::send`] and [`Socket::send_to`] fail with an
+    /// error of kind [`ErrorKind::WouldBlock`]. `None` means that sending blocks forever.
+    ///
+    /// Interrupted send operations are restarted the same way as receive operations, see
+    /// [`Socket::set_recv_timeout`].
+    ///
+    /// An error of kind [`ErrorKind::InvalidInput`] is returned for a zero duration.
+    pub fn set_send_timeout(&mut self, timeout: Option<Duration>) -> Result<()> {
+        setsockopt(
+            self.0,
+            libc::SOL_SOCKET,
+            libc::SO_SNDTIMEO,
+            duration_to_timeval(timeout)?,
+        )
+    }
+
+    pub fn get_send_timeout(&self) -> Result<Option<Duration>> {
+        let res = getsockopt::<libc::timeval>(self.0, libc::SOL_SOCKET, libc::SO_SNDTIMEO)?;
+        Ok(timeval_to_duration(res))
+    }
+
+    /// Wait until a datagram can be received without blocking, or until `timeout` expires.
+    /// Returns `false` if the timeout expired first. `None` means waiting forever.
+    ///
+    /// This is meant for driving the socket from an event loop this crate does not integrate
+    /// with: put the socket in non-blocking mode with [`Socket::set_non_blocking`], then either
+    /// call this method or register the file descriptor returned by
+    /// [`as_raw_fd`](AsRawFd::as_raw_fd) with the event loop directly.
+    pub fn poll_readable(&self, timeout: Option<Duration>) -> Result<bool> {
+        poll(self.0, libc::POLLIN, timeout)
+    }
+
+    /// Wait until a datagram can be sent without blocking, or until `timeout` expires. See
+    /// [`Socket::poll_readable`].
+    pub fn poll_writable(&self, timeout: Option<Duration>) -> Result<bool> {
+        poll(self.0, libc::POLLOUT, timeout)
+    }
+
     /// Connect the socket to the given address. Netlink is a connection-less protocol, so a socket can communicate with
     /// multiple peers with the [`Socket::send_to`] and [`Socket::recv_from`] methods. However, if the socket only needs
     /// to communicate with one peer, it is convenient not to have to bother with the peer address. This is what
@@ -270,17 +393,79 @@ impl Socket {
         let buf_ptr = chunk.as_mut_ptr() as *mut libc::c_void;
         let buf_len = chunk.len() as libc::size_t;
 
-        let res = unsafe { libc::recvfrom(self.0, buf_ptr, buf_len, flags, addr_ptr, addrlen_ptr) };
-        if res < 0 {
-            return Err(Error::last_os_error());
-        } else {
-            // with `MSG_TRUNC` `res` might exceed `buf_len`
-            let written = std::cmp::min(buf_len, res as usize);
-            unsafe {
-                buf.advance_mut(written);
+        let res = retry_interrupted(self.0, libc::SO_RCVTIMEO, || unsafe {
+            libc::recvfrom(self.0, buf_ptr, buf_len, flags, addr_ptr, addrlen_ptr)
+        })?;
+        // with `MSG_TRUNC` `res` might exceed `buf_len`
+        let written = std::cmp::min(buf_len, res);
+        unsafe {
+            buf.advance_mut(written);
+        }
+        Ok((res, SocketAddr(addr)))
+    }
+
+    /// Same as [`Socket::recv_from`], but also return the id of the network namespace the datagram
+    /// comes from, if the kernel attached one. This is the case when the socket listens to all the
+    /// network namespaces (see [`Socket::set_listen_all_nsid`]) and the datagram comes from a
+    /// namespace that has an nsid assigned.
+    ///
+    /// Receive timestamps are not available this way: the kernel accepts the `SO_TIMESTAMP` and
+    /// `SO_TIMESTAMPNS` options on netlink sockets, but never attaches the matching control
+    /// messages to netlink datagrams. The time at which a notification arrived can only be taken
+    /// when it is read.
+    pub fn recv_from_with_nsid<B>(
+        &self,
+        buf: &mut B,
+        flags: libc::c_int,
+    ) -> Result<(usize, SocketAddr, Option<i32>)>
+    where
+        B: bytes::BufMut,
+    {
+        let mut addr = unsafe { mem::zeroed::<libc::sockaddr_nl>() };
+
+        let chunk = buf.chunk_mut();
+        let buf_len = chunk.len() as libc::size_t;
+        let mut iov = libc::iovec {
+            iov_base: chunk.as_mut_ptr() as *mut libc::c_void,
+            iov_len: buf_len,
+        };
+
+        // Room for the NETLINK_LISTEN_ALL_NSID control message, which carries an i32. We use a
+        // u64 buffer so that the control message header is properly aligned.
+        let cmsg_space =
+            unsafe { libc::CMSG_SPACE(mem::size_of::<i32>() as libc::c_uint) } as usize;
+        let mut cmsg_buf = vec![0u64; (cmsg_space + 7) / 8];
+
+        let mut msg = unsafe { mem::zeroed::<libc::msghdr>() };
+        msg.msg_name = &mut addr as *mut libc::sockaddr_nl as *mut libc::c_void;
+        msg.msg_namelen = mem::size_of_val(&addr) as libc::socklen_t;
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = cmsg_space as _;
+
+        let res = retry_interrupted(self.0, libc::SO_RCVTIMEO, || unsafe {
+            libc::recvmsg(self.0, &mut msg, flags)
+        })?;
+        // with `MSG_TRUNC` `res` might exceed `buf_len`
+        let written = std::cmp::min(buf_len, res);
+        unsafe {
+            buf.advance_mut(written);
+        }
+
+        let mut nsid = None;
+        unsafe {
+            let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+            while !cmsg.is_null() {
+                if (*cmsg).cmsg_level == libc::SOL_NETLINK
+                    && (*cmsg).cmsg_type == libc::NETLINK_LISTEN_ALL_NSID
+                {
+                    nsid = Some(std::ptr::read_unaligned(libc::CMSG_DATA(cmsg) as *const i32));
+                }
+                cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
             }
         }
-        Ok((res as usize, SocketAddr(addr)))
+        Ok((res, SocketAddr(addr), nsid))
     }
 
     /// For a connected socket, `recv` reads a datagram from the socket. The sender is the remote peer the socket is
@@ -293,17 +478,15 @@ impl Socket {
         let buf_ptr = chunk.as_mut_ptr() as *mut libc::c_void;
         let buf_len = chunk.len() as libc::size_t;
 
-        let res = unsafe { libc::recv(self.0, buf_ptr, buf_len, flags) };
-        if res < 0 {
-            return Err(Error::last_os_error());
-        } else {
-            // with `MSG_TRUNC` `res` might exceed `buf_len`
-            let written = std::cmp::min(buf_len, res as usize);
-            unsafe {
-                buf.advance_mut(written);
-            }
+        let res = retry_interrupted(self.0, libc::SO_RCVTIMEO, || unsafe {
+            libc::recv(self.0, buf_ptr, buf_len, flags)
+        })?;
+        // with `MSG_TRUNC` `res` might exceed `buf_len`
+        let written = std::cmp::min(buf_len, res);
+        unsafe {
+            buf.advance_mut(written);
         }
-        Ok(res as usize)
+        Ok(res)
     }
 
     /// Receive a full message. Unlike [`Socket::recv_from`], which truncates messages that exceed the length of the
@@ -328,11 +511,9 @@ impl Socket {
         let buf_ptr = buf.as_ptr() as *const libc::c_void;
         let buf_len = buf.len() as libc::size_t;
 
-        let res = unsafe { libc::sendto(self.0, buf_ptr, buf_len, flags, addr_ptr, addr_len) };
-        if res < 0 {
-            return Err(Error::last_os_error());
-        }
-        Ok(res as usize)
+        retry_interrupted(self.0, libc::SO_SNDTIMEO, || unsafe {
+            libc::sendto(self.0, buf_ptr, buf_len, flags, addr_ptr, addr_len)
+        })
     }
 
     /// For a connected socket, `send` sends the given buffer `buf` to the remote peer the socket is connected to. See
@@ -341,11 +522,9 @@ impl Socket {
         let buf_ptr = buf.as_ptr() as *const libc::c_void;
         let buf_len = buf.len() as libc::size_t;
 
-        let res = unsafe { libc::send(self.0, buf_ptr, buf_len, flags) };
-        if res < 0 {
-            return Err(Error::last_os_error());
-        }
-        Ok(res as usize)
+        retry_interrupted(self.0, libc::SO_SNDTIMEO, || unsafe {
+            libc::send(self.0, buf_ptr, buf_len, flags)
+        })
     }
 
     pub fn set_pktinfo(&mut self, value: bool) -> Result<()> {
@@ -403,6 +582,12 @@ impl Socket {
 
     /// `NETLINK_NO_ENOBUFS` (since Linux 2.6.30). This flag can be used by unicast and broadcast
     /// listeners to avoid receiving `ENOBUFS` errors.
+    ///
+    /// Without it, when a multicast message does not fit in the receive buffer the kernel drops it
+    /// and the next `recv` fails with `ENOBUFS`, which tells the application that it missed
+    /// messages and should dump the state it tracks again. With it, the messages are dropped
+    /// silently: `recv` keeps returning the messages that were queued, and nothing tells that some
+    /// were lost. Only set it when losing notifications is acceptable.
     pub fn set_no_enobufs(&mut self, value: bool) -> Result<()> {
         let value: libc::c_int = if value { 1 } else { 0 };
         setsockopt(self.0, libc::SOL_NETLINK, libc::NETLINK_NO_ENOBUFS, value)
@@ -416,8 +601,10 @@ impl Socket {
     /// `NETLINK_LISTEN_ALL_NSID` (since Linux 4.2). When set, this socket will receive netlink
     /// notifications from  all  network  namespaces that have an nsid assigned into the network
     /// namespace where the socket has been opened. The nsid is sent to user space via an ancillary
-    /// data.
-    pub fn set_listen_all_namespaces(&mut self, value: bool) -> Result<()> {
+    /// data, that can be read with [`Socket::recv_from_with_nsid`].
+    ///
+    /// Setting this option requires the `CAP_NET_BROADCAST` capability.
+    pub fn set_listen_all_nsid(&mut self, value: bool) -> Result<()> {
         let value: libc::c_int = if value { 1 } else { 0 };
         setsockopt(
             self.0,
@@ -427,12 +614,22 @@ impl Socket {
         )
     }
 
-    pub fn get_listen_all_namespaces(&self) -> Result<bool> {
+    pub fn get_listen_all_nsid(&self) -> Result<bool> {
         let res =
             getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_LISTEN_ALL_NSID)?;
         Ok(res == 1)
     }
 
+    /// Same as [`Socket::set_listen_all_nsid`].
+    pub fn set_listen_all_namespaces(&mut self, value: bool) -> Result<()> {
+        self.set_listen_all_nsid(value)
+    }
+
+    /// Same as [`Socket::get_listen_all_nsid`].
+    pub fn get_listen_all_namespaces(&self) -> Result<bool> {
+        self.get_listen_all_nsid()
+    }
+
     /// `NETLINK_CAP_ACK` (since Linux 4.2). The kernel may fail to allocate the necessary room
     /// for the acknowledgment message back to user space.  This option trims off the payload of
     /// the original netlink message. The netlink message header is still included, so the user can
@@ -446,6 +643,69 @@ impl Socket {
         let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, libc::NETLINK_CAP_ACK)?;
         Ok(res == 1)
     }
+
+    /// `NETLINK_GET_STRICT_CHK` (since Linux 4.20). When set, the kernel validates the header and
+    /// attributes of the dump requests, rejecting the ones it does not support instead of
+    /// ignoring them, and applies the filters they carry. The messages of a dump that the kernel
+    /// filtered have the `NLM_F_DUMP_FILTERED` flag.
+    pub fn set_strict_check(&mut self, value: bool) -> Result<()> {
+        let value: libc::c_int = if value { 1 } else { 0 };
+        setsockopt(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK, value)
+    }
+
+    pub fn get_strict_check(&self) -> Result<bool> {
+        let res = getsockopt::<libc::c_int>(self.0, libc::SOL_NETLINK, NETLINK_GET_STRICT_CHK)?;
+        Ok(res == 1)
+    }
+
+    /// `SO_RCVBUF`: set the size of the socket receive buffer. The kernel doubles the requested
+    /// value to leave room for bookkeeping overhead, so [`Socket::get_recv_buffer_size`] returns
+    /// twice the value set here.
+    pub fn set_recv_buffer_size(&mut self, size: usize) -> Result<()> {
+        let size = libc::c_int::try_from(size)
+            .map_err(|_| Error::new(ErrorKind::InvalidInput, "receive buffer size too large"))?;
+        self.set_option(libc::SOL_SOCKET, libc::SO_RCVBUF, &size)
+    }
+
+    pub fn get_recv_buffer_size(&self) -> Result<usize> {
+        let size: libc::c_int = self.get_option(libc::SOL_SOCKET, libc::SO_RCVBUF)?;
+        Ok(size as usize)
+    }
+
+    /// Set an arbitrary socket option, for options this crate does not provide a dedicated
+    /// method for. `optlen` is the size of `T`, so `value` must have exactly the type the kernel
+    /// expects for this option (usually a `libc::c_int`). Only the [`OptionValue`] types are
+    /// accepted, since the kernel could write any bytes back into the others.
+    ///
+    /// ```rust
+    /// use netlink_sys::{protocols::NETLINK_ROUTE, Socket};
+    ///
+    /// let mut socket = Socket::new(NETLINK_ROUTE).unwrap();
+    /// let value: libc::c_int = 1;
+    /// socket.set_option(libc::SOL_NETLINK, libc::NETLINK_EXT_ACK, &value).unwrap();
+    /// ```
+    pub fn set_option<T: OptionValue>(
+        &mut self,
+        level: libc::c_int,
+        name: libc::c_int,
+        value: &T,
+    ) -> Result<()> {
+        setsockopt(self.0, level, name, *value)
+    }
+
+    /// Read an arbitrary socket option. This is the counterpart of [`Socket::set_option`]. An
+    /// error of kind [`ErrorKind::InvalidData`] is returned if the kernel reports a length that
+    /// does not match the size of `T`.
+    pub fn get_option<T: OptionValue>(&self, level: libc::c_int, name: libc::c_int) -> Result<T> {
+        let (value, len) = getsockopt_with_len::<T>(self.0, level, name)?;
+        if len != mem::size_of::<T>() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("socket option has length {}, expected {}", len, mem::size_of::<T>()),
+            ));
+        }
+        Ok(value)
+    }
 }
 
 /// Wrapper around `getsockopt`:
@@ -454,6 +714,22 @@ impl Socket {
 /// int getsockopt(int socket, int level, int option_name, void *restrict option_value, socklen_t *restrict option_len);
 /// ```
 pub(crate) fn getsockopt<T: Copy>(fd: RawFd, level: libc::c_int, option: libc::c_int) -> Result<T> {
+    let (slot, slot_len) = getsockopt_with_len::<T>(fd, level, option)?;
+
+    // Ignore the options that require the legnth to be set by getsockopt.
+    // We'll deal with them individually.
+    assert_eq!(slot_len, mem::size_of::<T>());
+
+    Ok(slot)
+}
+
+/// Same as [`getsockopt`], but also return the option length reported by the kernel instead of
+/// asserting that it matches the size of `T`.
+fn getsockopt_with_len<T: Copy>(
+    fd: RawFd,
+    level: libc::c_int,
+    option: libc::c_int,
+) -> Result<(T, usize)> {
     unsafe {
         // Create storage for the options we're fetching
         let mut slot: T = mem::zeroed();
@@ -474,11 +750,7 @@ pub(crate) fn getsockopt<T: Copy>(fd: RawFd, level: libc::c_int, option: libc::c
             return Err(Error::last_os_error());
         }
 
-        // Ignore the options that require the legnth to be set by getsockopt.
-        // We'll deal with them individually.
-        assert_eq!(slot_len as usize, mem::size_of::<T>());
-
-        Ok(slot)
+        Ok((slot, slot_len as usize))
     }
 }
 
@@ -496,6 +768,97 @@ fn setsockopt<T>(fd: RawFd, level: libc::c_int, option: libc::c_int, payload: T)
     Ok(())
 }
 
+/// Wait for `events` on `fd` with `poll(2)`. Returns whether `fd` is ready, an error being
+/// reported as a readiness (`POLLERR`) so that the next operation on the socket returns it.
+/// Interrupted calls are retried with the full timeout.
+fn poll(fd: RawFd, events: libc::c_short, timeout: Option<Duration>) -> Result<bool> {
+    let timeout = match timeout {
+        Some(timeout) => timeout.as_millis().min(libc::c_int::MAX as u128) as libc::c_int,
+        None => -1,
+    };
+    let mut pollfd = libc::pollfd {
+        fd,
+        events,
+        revents: 0,
+    };
+    loop {
+        let res = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+        if res < 0 {
+            let err = Error::last_os_error();
+            if err.kind() == ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        return Ok(res > 0);
+    }
+}
+
+/// Run the `send`/`recv` like `call` until it is not interrupted by a signal, and return the
+/// number of bytes it transferred. `timeout_option` is the socket option holding the timeout of the
+/// operation (`SO_RCVTIMEO` or `SO_SNDTIMEO`): once it expired, an interrupted call fails with
+/// `EAGAIN`, like a call that timed out, instead of being restarted.
+fn retry_interrupted<F>(fd: RawFd, timeout_option: libc::c_int, mut call: F) -> Result<usize>
+where
+    F: FnMut() -> libc::ssize_t,
+{
+    let start = Instant::now();
+    loop {
+        let res = call();
+        if res >= 0 {
+            return Ok(res as usize);
+        }
+        let err = Error::last_os_error();
+        if err.kind() != ErrorKind::Interrupted {
+            return Err(err);
+        }
+        // The timeout is only looked up for interrupted calls, which are rare
+        let timeout = getsockopt::<libc::timeval>(fd, libc::SOL_SOCKET, timeout_option)?;
+        if let Some(timeout) = timeval_to_duration(timeout) {
+            if start.elapsed() >= timeout {
+                return Err(Error::from_raw_os_error(libc::EAGAIN));
+            }
+        }
+    }
+}
+
+// adapted from rust standard library: a zero timeval disables the timeout, so a zero duration
+// cannot be represented
+fn duration_to_timeval(duration: Option<Duration>) -> Result<libc::timeval> {
+    match duration {
+        Some(duration) if duration == Duration::new(0, 0) => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "cannot set a 0 duration timeout",
+        )),
+        Some(duration) => {
+            let mut timeout = libc::timeval {
+                tv_sec: duration.as_secs().min(libc::time_t::MAX as u64) as libc::time_t,
+                tv_usec: duration.subsec_micros() as libc::suseconds_t,
+            };
+            // Round up to the smallest timeout the kernel accepts rather than disabling it
+            if timeout.tv_sec == 0 && timeout.tv_usec == 0 {
+                timeout.tv_usec = 1;
+            }
+            Ok(timeout)
+        }
+        None => Ok(libc::timeval {
+            tv_sec: 0,
+            tv_usec: 0,
+        }),
+    }
+}
+
+fn timeval_to_duration(timeout: libc::timeval) -> Option<Duration> {
+    if timeout.tv_sec == 0 && timeout.tv_usec == 0 {
+        None
+    } else {
+        Some(Duration::new(
+            timeout.tv_sec as u64,
+            (timeout.tv_usec as u32) * 1000,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -526,6 +889,28 @@ mod test {
         assert!(addr.port_number() != 0);
     }
 
+    #[test]
+    fn bind_with_portid() {
+        let mut sock1 = Socket::new(NETLINK_ROUTE).unwrap();
+        let mut sock2 = Socket::new(NETLINK_ROUTE).unwrap();
+        let mut sock3 = Socket::new(NETLINK_ROUTE).unwrap();
+
+        // Pick port ids that the kernel is unlikely to assign automatically, which is either a
+        // process id or a value counting down from -4096
+        let addr = sock1.bind_with_portid(0x0127_0001).unwrap();
+        assert_eq!(addr.port_number(), 0x0127_0001);
+        sock2.bind_with_portid(0x0127_0002).unwrap();
+        assert_eq!(sock1.port_number().unwrap(), 0x0127_0001);
+        assert_eq!(sock2.port_number().unwrap(), 0x0127_0002);
+
+        let err = sock3.bind_with_portid(0x0127_0001).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AddrInUse);
+        assert_eq!(err.raw_os_error(), Some(libc::EADDRINUSE));
+
+        let addr = sock3.bind_auto().unwrap();
+        assert_eq!(sock3.port_number().unwrap(), addr.port_number());
+    }
+
     #[test]
     fn set_non_blocking() {
         let sock = Socket::new(NETLINK_ROUTE).unwrap();
@@ -533,6 +918,135 @@ mod test {
         sock.set_non_blocking(false).unwrap();
     }
 
+    #[test]
+    fn poll_readable() {
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+        sock.bind_auto().unwrap();
+        sock.connect(&SocketAddr::new(0, 0)).unwrap();
+        sock.set_non_blocking(true).unwrap();
+
+        // Nothing was requested yet
+        assert!(!sock.poll_readable(Some(Duration::from_millis(10))).unwrap());
+
+        assert!(sock.poll_writable(Some(Duration::from_secs(1))).unwrap());
+        // RTM_GETNEIGH dump request
+        let buf = [
+            28, 0, 0, 0, 30, 0, 1, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        sock.send(&buf[..], 0).unwrap();
+
+        assert!(sock.poll_readable(Some(Duration::from_secs(1))).unwrap());
+        let mut buf = vec![0; 8192];
+        let n = sock.recv(&mut &mut buf[..], 0).unwrap();
+        assert!(n >= 16);
+    }
+
+    #[test]
+    fn recv_timeout() {
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+        sock.bind_auto().unwrap();
+
+        let timeout = Duration::from_millis(100);
+        sock.set_recv_timeout(Some(timeout)).unwrap();
+        assert_eq!(sock.get_recv_timeout().unwrap(), Some(timeout));
+
+        // Nothing was requested, so nothing is ever received
+        let mut buf = vec![0; 1024];
+        let start = std::time::Instant::now();
+        let err = sock.recv(&mut &mut buf[..], 0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WouldBlock);
+        assert!(start.elapsed() >= timeout);
+
+        sock.set_recv_timeout(None).unwrap();
+        assert_eq!(sock.get_recv_timeout().unwrap(), None);
+        assert_eq!(
+            sock.set_recv_timeout(Some(Duration::new(0, 0)))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn send_timeout() {
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+        let timeout = Duration::from_secs(2);
+        sock.set_send_timeout(Some(timeout)).unwrap();
+        assert_eq!(sock.get_send_timeout().unwrap(), Some(timeout));
+        sock.set_send_timeout(None).unwrap();
+        assert_eq!(sock.get_send_timeout().unwrap(), None);
+    }
+
+    #[test]
+    fn recv_interrupted() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static ALARMS: AtomicUsize = AtomicUsize::new(0);
+        extern "C" fn on_alarm(_: libc::c_int) {
+            ALARMS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        // Without SA_RESTART, the kernel does not restart the interrupted call by itself
+        let mut previous = unsafe { mem::zeroed::<libc::sigaction>() };
+        unsafe {
+            let mut action = mem::zeroed::<libc::sigaction>();
+            action.sa_sigaction = on_alarm as extern "C" fn(libc::c_int) as libc::sighandler_t;
+            assert_eq!(libc::sigaction(libc::SIGALRM, &action, &mut previous), 0);
+        }
+
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+        sock.bind_auto().unwrap();
+        sock.connect(&SocketAddr::new(0, 0)).unwrap();
+        let timeout = Duration::from_secs(5);
+        sock.set_recv_timeout(Some(timeout)).unwrap();
+
+        // `alarm(2)` would signal any thread of the test process, so the alarm is sent to the
+        // receiving thread directly, once it sleeps in the receive operation. The request is only
+        // sent once the signal was handled, so that the receive operation was interrupted.
+        let receiver = unsafe { libc::pthread_self() };
+        let stat = format!("/proc/self/task/{}/stat", unsafe {
+            libc::syscall(libc::SYS_gettid)
+        });
+        let fd = sock.as_raw_fd();
+        let sender = std::thread::spawn(move || {
+            loop {
+                // The state follows the command name, which is between parentheses
+                let stat = std::fs::read_to_string(&stat).unwrap();
+                if stat[stat.rfind(')').unwrap()..].starts_with(") S") {
+                    break;
+                }
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            assert_eq!(unsafe { libc::pthread_kill(receiver, libc::SIGALRM) }, 0);
+            while ALARMS.load(Ordering::Relaxed) == 0 {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            // RTM_GETLINK request for the loopback interface
+            let buf: [u8; 32] = [
+                32, 0, 0, 0, 18, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0,
+            ];
+            let res = unsafe { libc::send(fd, buf.as_ptr() as *const libc::c_void, 32, 0) };
+            assert_eq!(res, 32);
+        });
+
+        let mut buf = vec![0; 8192];
+        let start = Instant::now();
+        let n = sock.recv(&mut &mut buf[..], 0).unwrap();
+        sender.join().unwrap();
+        unsafe {
+            assert_eq!(
+                libc::sigaction(libc::SIGALRM, &previous, std::ptr::null_mut()),
+                0
+            );
+        }
+        assert_eq!(ALARMS.load(Ordering::Relaxed), 1);
+        assert!(start.elapsed() < timeout);
+        assert!(n > 16);
+        // RTM_NEWLINK
+        assert_eq!(buf[4], 16);
+    }
+
     #[test]
     fn options() {
         let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
@@ -542,6 +1056,11 @@ mod test {
         sock.set_cap_ack(false).unwrap();
         assert!(!sock.get_cap_ack().unwrap());
 
+        sock.set_strict_check(true).unwrap();
+        assert!(sock.get_strict_check().unwrap());
+        sock.set_strict_check(false).unwrap();
+        assert!(!sock.get_strict_check().unwrap());
+
         sock.set_no_enobufs(true).unwrap();
         assert!(sock.get_no_enobufs().unwrap());
         sock.set_no_enobufs(false).unwrap();
@@ -558,4 +1077,126 @@ mod test {
         // sock.set_listen_all_namespaces(false).unwrap();
         // assert!(!sock.get_listen_all_namespaces().unwrap());
     }
+
+    #[test]
+    fn generic_option() {
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+
+        let size: libc::c_int = 65536;
+        sock.set_option(libc::SOL_SOCKET, libc::SO_RCVBUF, &size).unwrap();
+        // the kernel doubles the value to account for bookkeeping overhead
+        let res: libc::c_int = sock.get_option(libc::SOL_SOCKET, libc::SO_RCVBUF).unwrap();
+        assert_eq!(res, 2 * size);
+
+        sock.set_recv_buffer_size(32768).unwrap();
+        assert_eq!(sock.get_recv_buffer_size().unwrap(), 65536);
+
+        // a value of the wrong size is rejected by the kernel
+        let short: u8 = 1;
+        let err = sock.set_option(libc::SOL_SOCKET, libc::SO_RCVBUF, &short).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(libc::EINVAL));
+
+        // and so is an unknown option
+        let err = sock.get_option::<libc::c_int>(libc::SOL_NETLINK, 0xffff).unwrap_err();
+        assert_eq!(err.raw_os_error(), Some(libc::ENOPROTOOPT));
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "test_as_root"), ignore)]
+    fn no_enobufs() {
+        use crate::protocols::NETLINK_USERSOCK;
+
+        // Bit mask of the multicast groups, here only the first one
+        const GROUPS: u32 = 1;
+
+        // Multicast more messages than the receive buffer of the listener can hold, then return
+        // the number of messages it received and whether it got an ENOBUFS error
+        let overflow = |no_enobufs: bool| {
+            let mut listener = Socket::new(NETLINK_USERSOCK).unwrap();
+            listener.set_recv_buffer_size(1024).unwrap();
+            listener.bind(&SocketAddr::new(0, GROUPS)).unwrap();
+            listener.set_no_enobufs(no_enobufs).unwrap();
+            listener.set_non_blocking(true).unwrap();
+
+            let mut sender = Socket::new(NETLINK_USERSOCK).unwrap();
+            sender.bind_auto().unwrap();
+            // A 16 bytes header followed by 64 bytes of payload
+            let mut message = vec![80, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+            message.resize(80, 0xff);
+            for _ in 0..100 {
+                // The message is also unicast to the kernel, which has no NETLINK_USERSOCK socket
+                match sender.send_to(&message, &SocketAddr::new(0, GROUPS), 0) {
+                    Ok(_) => {}
+                    Err(e) if e.raw_os_error() == Some(libc::ECONNREFUSED) => {}
+                    Err(e) => panic!("{}", e),
+                }
+            }
+
+            let (mut received, mut enobufs) = (0, false);
+            let mut buf = vec![0; 8192];
+            loop {
+                match listener.recv(&mut &mut buf[..], 0) {
+                    Ok(_) => received += 1,
+                    Err(e) if e.raw_os_error() == Some(libc::ENOBUFS) => enobufs = true,
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                    Err(e) => panic!("{}", e),
+                }
+            }
+            (received, enobufs)
+        };
+
+        let (received, enobufs) = overflow(false);
+        assert!(enobufs);
+        assert!(received < 100);
+
+        let (received, enobufs) = overflow(true);
+        assert!(!enobufs);
+        assert!(received > 0 && received < 100);
+    }
+
+    #[test]
+    #[cfg_attr(not(feature = "test_as_root"), ignore)]
+    fn listen_all_nsid() {
+        use std::{process::Command, thread, time::Duration};
+
+        const NETNS: &str = "netlink-sys-nsid";
+        const NSID: i32 = 142;
+        // RTNLGRP_LINK
+        const LINK_GROUP: u32 = 1;
+
+        let ip = |args: &[&str]| {
+            let status = Command::new("ip").args(args).status().unwrap();
+            assert!(status.success(), "ip {:?} failed", args);
+        };
+        ip(&["netns", "add", NETNS]);
+        ip(&["netns", "set", NETNS, &NSID.to_string()]);
+
+        let mut sock = Socket::new(NETLINK_ROUTE).unwrap();
+        sock.bind_auto().unwrap();
+        sock.add_membership(LINK_GROUP).unwrap();
+        sock.set_listen_all_nsid(true).unwrap();
+        assert!(sock.get_listen_all_nsid().unwrap());
+        sock.set_non_blocking(true).unwrap();
+
+        ip(&["-n", NETNS, "link", "add", "dummy0", "type", "dummy"]);
+
+        let mut found = false;
+        let mut buf = vec![0; 8192];
+        for _ in 0..50 {
+            match sock.recv_from_with_nsid(&mut &mut buf[..], 0) {
+                Ok((_, _, nsid)) if nsid == Some(NSID) => {
+                    found = true;
+                    break;
+                }
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(100))
+                }
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        ip(&["netns", "del", NETNS]);
+        assert!(found, "no notification tagged with nsid {}", NSID);
+    }
 }
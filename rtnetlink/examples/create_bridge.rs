@@ -12,5 +12,6 @@ async fn main() -> Result<(), String> {
         .bridge("my-bridge-1".into())
         .execute()
         .await
+        .map(|_| ())
         .map_err(|e| format!("{}", e))
 }
@@ -33,7 +33,7 @@ async fn create_macvlan(handle: Handle, veth_name: String) -> Result<(), Error>
             .link()
             .add()
             .macvlan("test_macvlan".into(), link.header.index, 4u32);
-        request.execute().await?
+        request.execute().await?;
     } else {
         println!("no link link {} found", veth_name);
     }
@@ -12,5 +12,6 @@ async fn main() -> Result<(), String> {
         .veth("veth-rs-1".into(), "veth-rs-2".into())
         .execute()
         .await
+        .map(|_| ())
         .map_err(|e| format!("{}", e))
 }
@@ -32,7 +32,7 @@ async fn create_vxlan(handle: Handle, name: String) -> Result<(), Error> {
             .port(4789)
             .up()
             .execute()
-            .await?
+            .await?;
     } else {
         println!("no link link {} found", name);
     }
@@ -10,9 +10,11 @@ use netlink_packet_route::{
     RtnlMessage,
     AF_INET,
     AF_INET6,
+    IFA_F_HOMEADDRESS,
     NLM_F_ACK,
     NLM_F_CREATE,
     NLM_F_EXCL,
+    NLM_F_REPLACE,
     NLM_F_REQUEST,
 };
 
@@ -22,6 +24,7 @@ use crate::{try_nl, Error, Handle};
 pub struct AddressAddRequest {
     handle: Handle,
     message: AddressMessage,
+    replace: bool,
 }
 
 impl AddressAddRequest {
@@ -68,7 +71,26 @@ impl AddressAddRequest {
                 message.nlas.push(Nla::Broadcast(brd.octets().to_vec()));
             };
         }
-        AddressAddRequest { handle, message }
+        AddressAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Replace the address if it already exists instead of failing, updating its lifetimes and
+    /// flags. If the address does not exist yet, it is created. This is equivalent to
+    /// `ip address replace`.
+    pub fn replace(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+
+    /// Mark the address as a home address, for Mobile IPv6 (`IFA_F_HOMEADDRESS`). The kernel only
+    /// supports this flag on IPv6 addresses.
+    pub fn home_address(mut self) -> Self {
+        self.message.header.flags |= IFA_F_HOMEADDRESS as u8;
+        self
     }
 
     /// Execute the request.
@@ -76,9 +98,14 @@ impl AddressAddRequest {
         let AddressAddRequest {
             mut handle,
             message,
+            replace,
         } = self;
         let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        req.header.flags = if replace {
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_REPLACE | NLM_F_CREATE
+        } else {
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE
+        };
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -8,6 +8,7 @@ use futures::{
 use std::net::IpAddr;
 
 use netlink_packet_route::{
+    constants::*,
     nlas::address::Nla,
     AddressMessage,
     NetlinkMessage,
@@ -18,6 +19,48 @@ use netlink_packet_route::{
 
 use crate::{try_rtnl, Error, Handle};
 
+/// Scope of an address (the `ifa_scope` field), as shown by `ip address show scope`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AddressScope {
+    /// Valid everywhere (`RT_SCOPE_UNIVERSE`)
+    Global,
+    /// Only valid within the site, with deprecated IPv6 site-local addresses (`RT_SCOPE_SITE`)
+    Site,
+    /// Only valid on the link the address is assigned to (`RT_SCOPE_LINK`)
+    Link,
+    /// Only valid inside this host (`RT_SCOPE_HOST`)
+    Host,
+    /// Not a valid destination (`RT_SCOPE_NOWHERE`)
+    Nowhere,
+    Other(u8),
+}
+
+impl From<u8> for AddressScope {
+    fn from(value: u8) -> Self {
+        match value {
+            RT_SCOPE_UNIVERSE => AddressScope::Global,
+            RT_SCOPE_SITE => AddressScope::Site,
+            RT_SCOPE_LINK => AddressScope::Link,
+            RT_SCOPE_HOST => AddressScope::Host,
+            RT_SCOPE_NOWHERE => AddressScope::Nowhere,
+            other => AddressScope::Other(other),
+        }
+    }
+}
+
+impl From<AddressScope> for u8 {
+    fn from(value: AddressScope) -> Self {
+        match value {
+            AddressScope::Global => RT_SCOPE_UNIVERSE,
+            AddressScope::Site => RT_SCOPE_SITE,
+            AddressScope::Link => RT_SCOPE_LINK,
+            AddressScope::Host => RT_SCOPE_HOST,
+            AddressScope::Nowhere => RT_SCOPE_NOWHERE,
+            AddressScope::Other(other) => other,
+        }
+    }
+}
+
 pub struct AddressGetRequest {
     handle: Handle,
     message: AddressMessage,
@@ -70,6 +113,12 @@ impl AddressGetRequest {
         self
     }
 
+    /// Return only the addresses of the given scope (equivalent to `ip address show scope`).
+    pub fn set_scope_filter(mut self, scope: AddressScope) -> Self {
+        self.filter_builder.scope = Some(scope.into());
+        self
+    }
+
     /// Return only the addresses of the given prefix length.
     pub fn set_address_filter(mut self, address: IpAddr) -> Self {
         self.filter_builder.address = Some(address);
@@ -84,10 +133,11 @@ impl AddressGetRequest {
 // https://lists.infradead.org/pipermail/libnl/2013-June/001014.html
 // https://patchwork.ozlabs.org/patch/133440/
 #[derive(Default)]
-struct AddressFilterBuilder {
-    index: Option<u32>,
-    address: Option<IpAddr>,
-    prefix_len: Option<u8>,
+pub(crate) struct AddressFilterBuilder {
+    pub(crate) index: Option<u32>,
+    pub(crate) address: Option<IpAddr>,
+    pub(crate) prefix_len: Option<u8>,
+    pub(crate) scope: Option<u8>,
 }
 
 impl AddressFilterBuilder {
@@ -95,7 +145,7 @@ impl AddressFilterBuilder {
         Default::default()
     }
 
-    fn build(self) -> impl Fn(&AddressMessage) -> bool {
+    pub(crate) fn build(self) -> impl Fn(&AddressMessage) -> bool {
         use Nla::*;
 
         move |msg: &AddressMessage| {
@@ -111,6 +161,12 @@ impl AddressFilterBuilder {
                 }
             }
 
+            if let Some(scope) = self.scope {
+                if msg.header.scope != scope {
+                    return false;
+                }
+            }
+
             if let Some(address) = self.address {
                 for nla in msg.nlas.iter() {
                     if let Unspec(x) | Address(x) | Local(x) | Multicast(x) | Anycast(x) = nla {
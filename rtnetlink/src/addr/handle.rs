@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: MIT
 
-use std::net::IpAddr;
+use futures::stream::TryStreamExt;
+use std::{collections::HashMap, net::IpAddr, time::Duration};
 
-use super::{AddressAddRequest, AddressDelRequest, AddressGetRequest};
-use crate::Handle;
+use super::{wait, AddressAddRequest, AddressDelRequest, AddressGetRequest};
+use crate::{Error, Handle, LinkHandle};
 
-use netlink_packet_route::AddressMessage;
+use netlink_packet_route::{nlas::link::Nla, AddressMessage};
 
 pub struct AddressHandle(Handle);
 
@@ -19,11 +20,64 @@ impl AddressHandle {
         AddressGetRequest::new(self.0.clone())
     }
 
+    /// Retrieve the list of ip addresses along with the name of their interface, like
+    /// `ip addr show` does.
+    ///
+    /// This dumps the addresses, then the links. If the interface of an address is deleted in the
+    /// meantime, its name is `None`.
+    pub async fn get_with_links(&self) -> Result<Vec<(AddressMessage, Option<String>)>, Error> {
+        let addresses: Vec<AddressMessage> = self.get().execute().try_collect().await?;
+        let names = LinkHandle::new(self.0.clone())
+            .get()
+            .execute()
+            .try_fold(HashMap::new(), |mut names, link| async move {
+                let name = link.nlas.into_iter().find_map(|nla| match nla {
+                    Nla::IfName(name) => Some(name),
+                    _ => None,
+                });
+                if let Some(name) = name {
+                    names.insert(link.header.index, name);
+                }
+                Ok(names)
+            })
+            .await?;
+        Ok(addresses
+            .into_iter()
+            .map(|address| {
+                let name = names.get(&address.header.index).cloned();
+                (address, name)
+            })
+            .collect())
+    }
+
     /// Add an ip address on an interface (equivalent to `ip addr add`)
     pub fn add(&self, index: u32, address: IpAddr, prefix_len: u8) -> AddressAddRequest {
         AddressAddRequest::new(self.0.clone(), index, address, prefix_len)
     }
 
+    /// Wait until duplicate address detection completes for an address of the interface `index`,
+    /// and return the address. Until then, the address is tentative: it is configured, but cannot
+    /// be used. IPv4 addresses and the addresses added with `nodad` are never tentative.
+    ///
+    /// This watches the address notifications from a dedicated socket, reading them from a
+    /// blocking task. It fails with [`Error::DadFailed`] if another node uses the address, with
+    /// [`Error::AddressNotReady`] if the address is still tentative after `timeout`, and with
+    /// [`Error::AddressNotFound`] if the address is not configured on the interface, or removed
+    /// while waiting.
+    ///
+    /// Dropping the returned future, for instance when it runs under `tokio::time::timeout`, does
+    /// not stop the blocking task: it keeps its thread until a notification settles the state of
+    /// the address or `timeout` passes. Use `timeout` rather than an external timer to bound the
+    /// wait.
+    pub async fn wait_until_ready(
+        &self,
+        index: u32,
+        address: IpAddr,
+        timeout: Duration,
+    ) -> Result<AddressMessage, Error> {
+        wait::wait_until_ready(self.0.clone(), index, address, timeout).await
+    }
+
     /// Delete the given address
     pub fn del(&self, address: AddressMessage) -> AddressDelRequest {
         AddressDelRequest::new(self.0.clone(), address)
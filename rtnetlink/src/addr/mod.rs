@@ -11,3 +11,8 @@ pub use self::del::*;
 
 mod get;
 pub use self::get::*;
+
+mod wait;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+
+use futures::{future, stream::TryStreamExt};
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    packet::{
+        nlas::address::{CacheInfo, CacheInfoBuffer, Nla},
+        traits::{Emitable, Parseable},
+        AddressMessage,
+        IFA_F_HOMEADDRESS,
+    },
+    AddressScope,
+    Error,
+    Handle,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_addresses_by_scope() {
+    let rt = Runtime::new().unwrap();
+    let (link_local, global) = rt.block_on(_get_addresses_by_scope()).unwrap();
+    // The link also gets an autoconfigured link-local address
+    assert!(link_local.contains(&IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))));
+    assert_eq!(
+        global,
+        vec![IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0x1470, 0, 0, 0, 0, 1))]
+    );
+}
+
+async fn _get_addresses_by_scope() -> Result<(Vec<IpAddr>, Vec<IpAddr>), Error> {
+    const LINK_NAME: &str = "dummy147";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = handle
+        .link()
+        .get()
+        .set_name_filter(LINK_NAME.to_owned())
+        .execute()
+        .try_next()
+        .await?
+        .ok_or(Error::RequestFailed)?
+        .header
+        .index;
+
+    let res = async {
+        // The kernel derives the scope of IPv6 addresses from the address itself
+        for address in [
+            Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1),
+            Ipv6Addr::new(0x2001, 0xdb8, 0x1470, 0, 0, 0, 0, 1),
+        ] {
+            handle
+                .address()
+                .add(index, IpAddr::V6(address), 64)
+                .execute()
+                .await?;
+        }
+        let link_local = _addresses(&handle, index, AddressScope::Link).await?;
+        let global = _addresses(&handle, index, AddressScope::Global).await?;
+        Ok((link_local, global))
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+async fn _addresses(
+    handle: &Handle,
+    index: u32,
+    scope: AddressScope,
+) -> Result<Vec<IpAddr>, Error> {
+    handle
+        .address()
+        .get()
+        .set_link_index_filter(index)
+        .set_scope_filter(scope)
+        .execute()
+        .try_filter_map(|msg| {
+            let address = msg.nlas.iter().find_map(|nla| match nla {
+                Nla::Address(bytes) if bytes.len() == 16 => {
+                    let mut octets = [0; 16];
+                    octets.copy_from_slice(bytes);
+                    Some(IpAddr::V6(Ipv6Addr::from(octets)))
+                }
+                _ => None,
+            });
+            future::ready(Ok(address))
+        })
+        .try_collect()
+        .await
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_addresses_with_links() {
+    let rt = Runtime::new().unwrap();
+    let pairs = rt.block_on(_get_addresses_with_links()).unwrap();
+    assert_eq!(
+        pairs,
+        vec![
+            (Ipv4Addr::new(10, 152, 0, 1), Some("dummy152a".to_owned())),
+            (Ipv4Addr::new(10, 152, 1, 1), Some("dummy152b".to_owned())),
+        ]
+    );
+}
+
+async fn _get_addresses_with_links() -> Result<Vec<(Ipv4Addr, Option<String>)>, Error> {
+    const LINKS: [(&str, Ipv4Addr); 2] = [
+        ("dummy152a", Ipv4Addr::new(10, 152, 0, 1)),
+        ("dummy152b", Ipv4Addr::new(10, 152, 1, 1)),
+    ];
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let mut indexes = vec![];
+    for (name, _) in LINKS.iter() {
+        handle.link().add().dummy(name.to_string()).execute().await?;
+        let index = handle
+            .link()
+            .get()
+            .set_name_filter(name.to_string())
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::RequestFailed)?
+            .header
+            .index;
+        indexes.push(index);
+    }
+
+    let res = async {
+        for (index, (_, address)) in indexes.iter().zip(LINKS.iter()) {
+            handle
+                .address()
+                .add(*index, IpAddr::V4(*address), 24)
+                .execute()
+                .await?;
+        }
+        let addresses = LINKS.iter().map(|(_, address)| address).collect::<Vec<_>>();
+        let mut pairs: Vec<_> = handle
+            .address()
+            .get_with_links()
+            .await?
+            .into_iter()
+            .filter_map(|(msg, name)| {
+                msg.nlas.iter().find_map(|nla| match nla {
+                    Nla::Address(bytes) if bytes.len() == 4 => {
+                        let address = Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]);
+                        addresses.contains(&&address).then(|| (address, name.clone()))
+                    }
+                    _ => None,
+                })
+            })
+            .collect();
+        pairs.sort();
+        Ok(pairs)
+    }
+    .await;
+    for index in indexes {
+        handle.link().del(index).execute().await?;
+    }
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn replace_address() {
+    let rt = Runtime::new().unwrap();
+    let cache_info = rt.block_on(_replace_address()).unwrap();
+    assert!(cache_info.ifa_valid > 3600);
+    assert!(cache_info.ifa_preferred > 1800);
+}
+
+fn _cache_info_nla(valid: i32, preferred: i32) -> Nla {
+    let cache_info = CacheInfo {
+        ifa_valid: valid,
+        ifa_preferred: preferred,
+        ..Default::default()
+    };
+    let mut bytes = vec![0; cache_info.buffer_len()];
+    cache_info.emit(&mut bytes);
+    Nla::CacheInfo(bytes)
+}
+
+async fn _replace_address() -> Result<CacheInfo, Error> {
+    const LINK_NAME: &str = "dummy148r";
+    const ADDRESS: Ipv4Addr = Ipv4Addr::new(10, 148, 0, 1);
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let index = handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+
+    let res = async {
+        let mut request = handle.address().add(index, IpAddr::V4(ADDRESS), 24);
+        request.message_mut().nlas.push(_cache_info_nla(3600, 1800));
+        request.execute().await?;
+
+        // A plain add would fail with EEXIST here
+        let mut request = handle
+            .address()
+            .add(index, IpAddr::V4(ADDRESS), 24)
+            .replace();
+        request.message_mut().nlas.push(_cache_info_nla(7200, 3600));
+        request.execute().await?;
+
+        let msg = handle
+            .address()
+            .get()
+            .set_link_index_filter(index)
+            .set_address_filter(IpAddr::V4(ADDRESS))
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::RequestFailed)?;
+        msg.nlas
+            .iter()
+            .find_map(|nla| match nla {
+                Nla::CacheInfo(bytes) => CacheInfoBuffer::new_checked(bytes)
+                    .and_then(|buf| CacheInfo::parse(&buf))
+                    .ok(),
+                _ => None,
+            })
+            .ok_or(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn wait_until_address_ready() {
+    let rt = Runtime::new().unwrap();
+    let (tentative, ready) = rt.block_on(_wait_until_address_ready()).unwrap();
+    assert!(tentative.is_tentative());
+    assert!(!ready.is_tentative());
+    assert!(!ready.dad_failed());
+    assert_ne!(ready.flags() & IFA_F_HOMEADDRESS, 0);
+}
+
+async fn _wait_until_address_ready() -> Result<(AddressMessage, AddressMessage), Error> {
+    const LINK_NAME: &str = "veth198";
+    const PEER_NAME: &str = "veth198p";
+    const ADDRESS: Ipv6Addr = Ipv6Addr::new(0x2001, 0xdb8, 0x198, 0, 0, 0, 0, 1);
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    // Only the link named after the second argument is set up
+    handle
+        .link()
+        .add()
+        .veth(PEER_NAME.to_owned(), LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let mut indexes = vec![];
+    for name in [LINK_NAME, PEER_NAME] {
+        let index = handle
+            .link()
+            .get()
+            .set_name_filter(name.to_owned())
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::RequestFailed)?
+            .header
+            .index;
+        indexes.push(index);
+    }
+    let index = indexes[0];
+
+    let res = async {
+        // Duplicate address detection only starts once the link has a carrier, which requires
+        // both ends of the pair to be up
+        handle.link().set(indexes[1]).up().execute().await?;
+        handle
+            .address()
+            .add(index, IpAddr::V6(ADDRESS), 64)
+            .home_address()
+            .execute()
+            .await?;
+        let tentative = handle
+            .address()
+            .get()
+            .set_link_index_filter(index)
+            .set_address_filter(IpAddr::V6(ADDRESS))
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::RequestFailed)?;
+        // Detection takes about a second with the default settings
+        let ready = handle
+            .address()
+            .wait_until_ready(index, IpAddr::V6(ADDRESS), Duration::from_secs(10))
+            .await?;
+        Ok((tentative, ready))
+    }
+    .await;
+    // Deleting one end of the pair also deletes the other one
+    handle.link().del(index).execute().await?;
+    res
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    io,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+use futures::stream::TryStreamExt;
+use netlink_proto::decode_datagram;
+use nix::errno::Errno;
+
+use super::get::AddressFilterBuilder;
+use crate::{
+    ns::try_spawn_blocking,
+    packet::{
+        AddressMessage,
+        NetlinkPayload,
+        RtnlMessage,
+        RTNLGRP_IPV4_IFADDR,
+        RTNLGRP_IPV6_IFADDR,
+    },
+    sys::{protocols::NETLINK_ROUTE, Socket},
+    AddressHandle,
+    Error,
+    Handle,
+};
+
+/// What a blocking wait on the monitoring socket ended with
+enum Wait {
+    Done(Result<AddressMessage, Error>),
+    /// Notifications were lost, the address must be dumped again
+    Resync,
+}
+
+/// Returns the outcome of duplicate address detection for the given address, or `None` if it is
+/// still running
+fn outcome(message: AddressMessage, address: IpAddr) -> Option<Result<AddressMessage, Error>> {
+    if message.dad_failed() {
+        Some(Err(Error::DadFailed(address)))
+    } else if message.is_tentative() {
+        None
+    } else {
+        Some(Ok(message))
+    }
+}
+
+/// Keep the errno of a failed operation on the monitoring socket
+fn socket_error(err: io::Error) -> Error {
+    Error::SocketError(Errno::from_i32(err.raw_os_error().unwrap_or(0)))
+}
+
+/// Open a socket receiving the address notifications of the family of `address`
+fn monitor(address: IpAddr) -> io::Result<Socket> {
+    let mut socket = Socket::new(NETLINK_ROUTE)?;
+    socket.bind_auto()?;
+    socket.add_membership(match address {
+        IpAddr::V4(_) => RTNLGRP_IPV4_IFADDR,
+        IpAddr::V6(_) => RTNLGRP_IPV6_IFADDR,
+    })?;
+    Ok(socket)
+}
+
+/// Read the notifications received by `socket` until one of them settles the state of the address,
+/// or the deadline passes. This blocks the calling thread.
+fn wait_for_change(socket: &mut Socket, index: u32, address: IpAddr, deadline: Instant) -> Wait {
+    let filter = AddressFilterBuilder {
+        index: Some(index),
+        address: Some(address),
+        ..Default::default()
+    }
+    .build();
+
+    loop {
+        // Sockets do not accept a zero timeout
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining == Duration::from_secs(0) {
+            return Wait::Done(Err(Error::AddressNotReady(address)));
+        }
+        if let Err(err) = socket.set_recv_timeout(Some(remaining)) {
+            return Wait::Done(Err(socket_error(err)));
+        }
+
+        let datagram = match socket.recv_from_full() {
+            Ok((datagram, _)) => datagram,
+            Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                return Wait::Done(Err(Error::AddressNotReady(address)))
+            }
+            // The kernel reports the notifications dropped because the receive buffer was full
+            // with ENOBUFS
+            Err(err) if err.raw_os_error() == Some(Errno::ENOBUFS as i32) => return Wait::Resync,
+            Err(err) => return Wait::Done(Err(socket_error(err))),
+        };
+        let messages = match decode_datagram::<RtnlMessage>(&datagram) {
+            Ok(messages) => messages,
+            Err(err) => {
+                log::warn!("failed to decode an address notification: {:?}", err);
+                continue;
+            }
+        };
+        for message in messages {
+            match message.payload {
+                NetlinkPayload::InnerMessage(RtnlMessage::NewAddress(message))
+                    if filter(&message) =>
+                {
+                    if let Some(res) = outcome(message, address) {
+                        return Wait::Done(res);
+                    }
+                }
+                NetlinkPayload::InnerMessage(RtnlMessage::DelAddress(message))
+                    if filter(&message) =>
+                {
+                    return Wait::Done(Err(Error::AddressNotFound(address)));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub(crate) async fn wait_until_ready(
+    handle: Handle,
+    index: u32,
+    address: IpAddr,
+    timeout: Duration,
+) -> Result<AddressMessage, Error> {
+    let deadline = Instant::now() + timeout;
+    // Join the notification group before looking at the address, so that no change is missed
+    // between the dump and the wait
+    let mut socket = monitor(address).map_err(socket_error)?;
+    loop {
+        let message = AddressHandle::new(handle.clone())
+            .get()
+            .set_link_index_filter(index)
+            .set_address_filter(address)
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::AddressNotFound(address))?;
+        if let Some(res) = outcome(message, address) {
+            return res;
+        }
+
+        let (returned, wait) = try_spawn_blocking(move || {
+            let wait = wait_for_change(&mut socket, index, address, deadline);
+            (socket, wait)
+        })
+        .await;
+        match wait {
+            Wait::Done(res) => return res,
+            Wait::Resync => socket = returned,
+        }
+    }
+}
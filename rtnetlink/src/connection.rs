@@ -6,7 +6,7 @@ use futures::channel::mpsc::UnboundedReceiver;
 
 use crate::{
     packet::{NetlinkMessage, RtnlMessage},
-    proto::Connection,
+    proto::{Connection, NetlinkCodec, NetlinkMessageCodec},
     sys::{protocols::NETLINK_ROUTE, AsyncSocket, SocketAddr},
     Handle,
 };
@@ -30,6 +30,22 @@ pub fn new_connection_with_socket<S>() -> io::Result<(
 where
     S: AsyncSocket,
 {
-    let (conn, handle, messages) = netlink_proto::new_connection_with_socket(NETLINK_ROUTE)?;
+    new_connection_with_codec::<S, NetlinkCodec>()
+}
+
+/// Variant of [`new_connection_with_socket`] that also lets the caller choose how messages are
+/// decoded from and encoded to the datagrams, for instance to work around a kernel that sends
+/// malformed messages, or to log the messages exchanged.
+#[allow(clippy::type_complexity)]
+pub fn new_connection_with_codec<S, C>() -> io::Result<(
+    Connection<RtnlMessage, S, C>,
+    Handle,
+    UnboundedReceiver<(NetlinkMessage<RtnlMessage>, SocketAddr)>,
+)>
+where
+    S: AsyncSocket,
+    C: NetlinkMessageCodec,
+{
+    let (conn, handle, messages) = netlink_proto::new_connection_with_codec(NETLINK_ROUTE)?;
     Ok((conn, Handle::new(handle), messages))
 }
@@ -1,5 +1,8 @@
 // SPDX-License-Identifier: MIT
 
+use std::{io, net::IpAddr};
+
+use nix::errno::Errno;
 use thiserror::Error;
 
 use crate::packet::{ErrorMessage, NetlinkMessage, RtnlMessage};
@@ -15,6 +18,20 @@ pub enum Error {
     #[error("A netlink request failed")]
     RequestFailed,
 
+    #[error("No link has the requested index {0}")]
+    LinkNotFound(u32),
+
+    #[error(
+        "Invalid link name {0:?}: it must be 1 to 15 bytes long, without '/', ':' or whitespaces"
+    )]
+    InvalidLinkName(String),
+
+    #[error("Link {0} is busy, it must be down to be renamed")]
+    LinkBusy(u32),
+
+    #[error("Invalid master link index 0, use nomaster() to detach a link from its master")]
+    InvalidMasterIndex,
+
     #[error("Namespace error {0}")]
     NamespaceError(String),
 
@@ -23,9 +40,71 @@ pub enum Error {
     )]
     InvalidHardwareAddress(Vec<u8>),
 
+    #[error("The preferred source address {0} is not configured on any interface")]
+    InvalidPrefSource(IpAddr),
+
+    #[error("IPv4 routes cannot have a source prefix, only IPv6 supports source-specific routing")]
+    Ipv4SourcePrefix,
+
+    #[error("A flower filter cannot match both IPv4 and IPv6 addresses")]
+    MixedIpFamilies,
+
     #[error("Failed to parse an IP address: {0:?}")]
     InvalidIp(Vec<u8>),
 
     #[error("Failed to parse a network address (IP and mask): {0:?}/{1:?}")]
     InvalidAddress(Vec<u8>, Vec<u8>),
+
+    #[error("Address {0} is not configured on the interface")]
+    AddressNotFound(IpAddr),
+
+    #[error("Duplicate address detection failed for {0}: another node uses this address")]
+    DadFailed(IpAddr),
+
+    #[error(
+        "Address {0} is still tentative, duplicate address detection did not complete in time"
+    )]
+    AddressNotReady(IpAddr),
+
+    #[error("A netlink socket operation failed: {0}")]
+    SocketError(Errno),
+}
+
+impl Error {
+    /// Return the errno the kernel answered the request with, if the error comes from the
+    /// kernel. This includes the variants that replace a netlink error message, like
+    /// [`Error::LinkNotFound`] (`ENODEV`), and the errors of the operations on a socket
+    /// ([`Error::SocketError`]).
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self {
+            Error::NetlinkError(err) => Some(err.code.abs()),
+            Error::LinkNotFound(_) => Some(Errno::ENODEV as i32),
+            Error::LinkBusy(_) => Some(Errno::EBUSY as i32),
+            Error::InvalidPrefSource(_) | Error::InvalidMasterIndex => Some(Errno::EINVAL as i32),
+            Error::SocketError(errno) => Some(*errno as i32),
+            _ => None,
+        }
+    }
+
+    /// Return the [`io::ErrorKind`] matching the errno the kernel answered the request with (see
+    /// [`Error::raw_os_error`]), for instance [`io::ErrorKind::NotFound`] for `ENOENT`,
+    /// [`io::ErrorKind::AlreadyExists`] for `EEXIST` or [`io::ErrorKind::PermissionDenied`] for
+    /// `EPERM` and `EACCES`.
+    ///
+    /// The kernel answers `ESRCH` when the route or rule to delete does not exist, and `ENODEV`
+    /// when a link does not exist, so these are mapped to [`io::ErrorKind::NotFound`] as well.
+    pub fn io_error_kind(&self) -> Option<io::ErrorKind> {
+        let errno = self.raw_os_error()?;
+        match Errno::from_i32(errno) {
+            Errno::ESRCH | Errno::ENODEV => Some(io::ErrorKind::NotFound),
+            _ => Some(io::Error::from_raw_os_error(errno).kind()),
+        }
+    }
+
+    /// Return whether the kernel refused the request because the process lacks the required
+    /// privileges (`EPERM` or `EACCES`). Most requests that modify the configuration, like adding
+    /// a link or a route, require `CAP_NET_ADMIN`.
+    pub fn is_permission_denied(&self) -> bool {
+        self.io_error_kind() == Some(io::ErrorKind::PermissionDenied)
+    }
 }
@@ -1,13 +1,24 @@
 // SPDX-License-Identifier: MIT
 
-use futures::Stream;
+use futures::{Stream, TryStreamExt};
 
 use crate::{
-    packet::{NetlinkMessage, RtnlMessage},
+    packet::{
+        constants::AF_UNSPEC,
+        AddressMessage,
+        LinkMessage,
+        NeighbourMessage,
+        NetlinkMessage,
+        RouteMessage,
+        RtnlMessage,
+    },
     AddressHandle,
     Error,
+    IpVersion,
     LinkHandle,
+    MdbHandle,
     NeighbourHandle,
+    NsidHandle,
     QDiscHandle,
     RouteHandle,
     RuleHandle,
@@ -17,6 +28,16 @@ use crate::{
 };
 use netlink_proto::{sys::SocketAddr, ConnectionHandle};
 
+/// The links, addresses, routes and neighbours of the system, as returned by [`Handle::snapshot`]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Snapshot {
+    pub links: Vec<LinkMessage>,
+    pub addresses: Vec<AddressMessage>,
+    /// The routes of all the tables and address families
+    pub routes: Vec<RouteMessage>,
+    pub neighbours: Vec<NeighbourMessage>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Handle(ConnectionHandle<RtnlMessage>);
 
@@ -41,6 +62,31 @@ impl Handle {
         Ok(())
     }
 
+    /// Dump the links, addresses, routes and neighbours, for instance to build the initial state
+    /// of a program that then follows the changes with multicast notifications.
+    ///
+    /// The dumps are made one after the other: the kernel only runs one dump at a time on a
+    /// netlink socket, and answers `EBUSY` to a dump request while another one is in progress. To
+    /// run them concurrently, make them on several connections.
+    pub async fn snapshot(&self) -> Result<Snapshot, Error> {
+        let links = self.link().get().execute().try_collect().await?;
+        let addresses = self.address().get().execute().try_collect().await?;
+        let routes = self
+            .route()
+            .get(IpVersion::V4)
+            .set_address_family(AF_UNSPEC as u8)
+            .execute()
+            .try_collect()
+            .await?;
+        let neighbours = self.neighbours().get().execute().try_collect().await?;
+        Ok(Snapshot {
+            links,
+            addresses,
+            routes,
+            neighbours,
+        })
+    }
+
     /// Create a new handle, specifically for link requests (equivalent to `ip link` commands)
     pub fn link(&self) -> LinkHandle {
         LinkHandle::new(self.clone())
@@ -66,6 +112,18 @@ impl Handle {
         NeighbourHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for bridge multicast database requests (equivalent to
+    /// `bridge mdb` commands)
+    pub fn mdb(&self) -> MdbHandle {
+        MdbHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for network namespace id requests (equivalent to
+    /// `ip netns set` and `ip netns list-id` commands)
+    pub fn nsid(&self) -> NsidHandle {
+        NsidHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for traffic control qdisc requests
     /// (equivalent to `tc qdisc show` commands)
     pub fn qdisc(&self) -> QDiscHandle {
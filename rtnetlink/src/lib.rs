@@ -35,12 +35,31 @@ pub use crate::traffic_control::*;
 mod neighbour;
 pub use crate::neighbour::*;
 
+mod nsid;
+pub use crate::nsid::*;
+
+mod mdb;
+pub use crate::mdb::*;
+
 pub mod constants;
 
 pub use netlink_packet_route as packet;
 pub mod proto {
-    pub use netlink_proto::{Connection, ConnectionHandle, Error, ErrorKind};
+    pub use netlink_proto::{
+        Connection,
+        ConnectionHandle,
+        Error,
+        ErrorKind,
+        NetlinkCodec,
+        NetlinkMessageCodec,
+    };
+    /// The `netlink-packet-core` crate, with the traits needed to implement a
+    /// [`NetlinkMessageCodec`]
+    pub use netlink_proto::packet;
 }
 pub use netlink_proto::sys;
 
 mod macros;
+
+#[cfg(test)]
+mod test;
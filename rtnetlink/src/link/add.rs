@@ -1,22 +1,47 @@
 // SPDX-License-Identifier: MIT
 
-use futures::stream::StreamExt;
+use std::os::unix::io::RawFd;
+
+use futures::stream::{StreamExt, TryStreamExt};
 
 use crate::{
     packet::{
-        nlas::link::{Info, InfoData, InfoKind, InfoMacVlan, InfoVlan, InfoVxlan, Nla, VethInfo},
+        nlas::link::{
+            Info,
+            InfoBareUdp,
+            InfoData,
+            InfoGre,
+            InfoGtp,
+            InfoIpTun,
+            InfoIpoib,
+            InfoKind,
+            InfoMacSec,
+            InfoMacVlan,
+            InfoVlan,
+            InfoVxlan,
+            IpoibMode,
+            Nla,
+            VethInfo,
+            VlanQosMapping,
+        },
+        InterfaceFlags,
         LinkMessage,
         NetlinkMessage,
+        NetlinkPayload,
         RtnlMessage,
-        IFF_UP,
+        GRE_KEY,
+        GTP_ROLE_GGSN,
+        GTP_ROLE_SGSN,
         NLM_F_ACK,
         NLM_F_CREATE,
+        NLM_F_ECHO,
         NLM_F_EXCL,
         NLM_F_REQUEST,
     },
     try_nl,
     Error,
     Handle,
+    LinkGetRequest,
 };
 
 /// A request to create a new vxlan link.
@@ -30,7 +55,7 @@ pub struct VxlanAddRequest {
 
 impl VxlanAddRequest {
     /// Execute the request.
-    pub async fn execute(self) -> Result<(), Error> {
+    pub async fn execute(self) -> Result<u32, Error> {
         let s = self
             .request
             .link_info(InfoKind::Vxlan, Some(InfoData::Vxlan(self.info_data)));
@@ -44,6 +69,12 @@ impl VxlanAddRequest {
         self
     }
 
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
     /// Adds the `dev` attribute to the VXLAN
     /// This is equivalent to `ip link add name NAME type vxlan id VNI dev LINK`,
     ///  dev LINK - specifies the physical device to use
@@ -223,6 +254,11 @@ impl VxlanAddRequest {
         self
     }
 
+    /// Adds the `external` attribute to the VXLAN
+    /// This is equivalent to `ip link add name NAME type vxlan [no]external`.
+    /// external - specifies that the VNI and the remote endpoint are taken from the metadata of
+    /// each packet, as used by OVS or eBPF programs. See also
+    /// [`LinkAddRequest::vxlan_external`], which creates such a VXLAN without a VNI.
     pub fn collect_metadata(mut self, collect_metadata: u8) -> Self {
         self.info_data
             .push(InfoVxlan::CollectMetadata(collect_metadata));
@@ -238,6 +274,409 @@ impl VxlanAddRequest {
     }
 }
 
+/// A request to create a new GRE link.
+/// This is equivalent to `ip link add NAME type gre|gretap ...` commands.
+/// It provides methods to customize the creation of the GRE interface.
+pub struct GreAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    info_data: Vec<InfoGre>,
+}
+
+impl GreAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        let data = match self.kind {
+            InfoKind::GreTap => InfoData::GreTap(self.info_data),
+            _ => InfoData::GreTun(self.info_data),
+        };
+        self.request.link_info(self.kind, Some(data)).execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `local` attribute to the GRE link
+    /// This is equivalent to `ip link add name NAME type gre local ADDR`.
+    pub fn local(mut self, addr: std::net::Ipv4Addr) -> Self {
+        self.info_data.push(InfoGre::Local(addr.octets().to_vec()));
+        self
+    }
+
+    /// Adds the `remote` attribute to the GRE link
+    /// This is equivalent to `ip link add name NAME type gre remote ADDR`.
+    pub fn remote(mut self, addr: std::net::Ipv4Addr) -> Self {
+        self.info_data.push(InfoGre::Remote(addr.octets().to_vec()));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the GRE link
+    /// This is equivalent to `ip link add name NAME type gre ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGre::Ttl(ttl));
+        self
+    }
+
+    /// Adds the `key` attribute to the GRE link
+    /// This is equivalent to `ip link add name NAME type gre key KEY`, which sets both the input
+    /// and output keys.
+    pub fn key(mut self, key: u32) -> Self {
+        self.info_data.push(InfoGre::IFlags(GRE_KEY));
+        self.info_data.push(InfoGre::OFlags(GRE_KEY));
+        self.info_data.push(InfoGre::IKey(key));
+        self.info_data.push(InfoGre::OKey(key));
+        self
+    }
+
+    /// Adds the `external` attribute to the GRE link
+    /// This is equivalent to `ip link add name NAME type gre [no]external`.
+    /// external - specifies that the key and endpoints are taken from the metadata of each
+    /// packet, in which case the `local`, `remote` and `key` attributes should be omitted.
+    pub fn collect_metadata(mut self, collect_metadata: bool) -> Self {
+        self.info_data.retain(|nla| *nla != InfoGre::CollectMetadata);
+        if collect_metadata {
+            self.info_data.push(InfoGre::CollectMetadata);
+        }
+        self
+    }
+}
+
+/// A request to create a new ipip or sit link.
+/// This is equivalent to `ip link add NAME type ipip|sit ...` commands.
+/// It provides methods to customize the creation of the tunnel.
+pub struct IpTunAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    info_data: Vec<InfoIpTun>,
+}
+
+impl IpTunAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        let data = match self.kind {
+            InfoKind::SitTun => InfoData::SitTun(self.info_data),
+            _ => InfoData::IpTun(self.info_data),
+        };
+        self.request
+            .link_info(self.kind, Some(data))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `local` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip local ADDR`.
+    pub fn local(mut self, addr: std::net::Ipv4Addr) -> Self {
+        self.info_data
+            .push(InfoIpTun::Local(addr.octets().to_vec()));
+        self
+    }
+
+    /// Adds the `remote` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip remote ADDR`.
+    pub fn remote(mut self, addr: std::net::Ipv4Addr) -> Self {
+        self.info_data
+            .push(InfoIpTun::Remote(addr.octets().to_vec()));
+        self
+    }
+
+    /// Adds the `ttl` attribute to the tunnel
+    /// This is equivalent to `ip link add name NAME type ipip ttl TTL`. A TTL of 0 means the
+    /// TTL of the inner packet is used.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoIpTun::Ttl(ttl));
+        self
+    }
+
+    /// Sets the 6rd prefix of a sit tunnel
+    /// This is equivalent to `ip tunnel 6rd dev NAME 6rd-prefix PREFIX/LEN`. It is only
+    /// supported by the tunnels created with [`LinkAddRequest::sit`].
+    pub fn six_rd_prefix(mut self, prefix: std::net::Ipv6Addr, prefix_len: u16) -> Self {
+        self.info_data
+            .push(InfoIpTun::SixRdPrefix(prefix.octets().to_vec()));
+        self.info_data.push(InfoIpTun::SixRdPrefixLen(prefix_len));
+        self
+    }
+
+    /// Sets the 6rd relay prefix of a sit tunnel
+    /// This is equivalent to `ip tunnel 6rd dev NAME 6rd-relay_prefix PREFIX/LEN`. It is only
+    /// supported by the tunnels created with [`LinkAddRequest::sit`].
+    pub fn six_rd_relay_prefix(mut self, prefix: std::net::Ipv4Addr, prefix_len: u16) -> Self {
+        self.info_data
+            .push(InfoIpTun::SixRdRelayPrefix(prefix.octets().to_vec()));
+        self.info_data
+            .push(InfoIpTun::SixRdRelayPrefixLen(prefix_len));
+        self
+    }
+}
+
+/// A request to create a new MACsec link.
+/// This is equivalent to `ip link add link LINK name NAME type macsec ...` commands.
+/// It provides methods to customize the creation of the MACsec interface. The secure channels
+/// and associations are not covered, they are configured once the link exists.
+pub struct MacSecAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoMacSec>,
+}
+
+impl MacSecAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        self.request
+            .link_info(InfoKind::MacSec, Some(InfoData::MacSec(self.info_data)))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `sci` attribute to the MACsec link
+    /// This is equivalent to `ip link add ... type macsec sci SCI`. By default, the kernel derives
+    /// the secure channel identifier from the hardware address of the link and the port.
+    pub fn sci(mut self, sci: u64) -> Self {
+        self.info_data.push(InfoMacSec::Sci(sci));
+        self
+    }
+
+    /// Adds the `encrypt` attribute to the MACsec link
+    /// This is equivalent to `ip link add ... type macsec encrypt on|off`.
+    pub fn encrypt(mut self, encrypt: bool) -> Self {
+        self.info_data.push(InfoMacSec::Encrypt(encrypt as u8));
+        self
+    }
+
+    /// Adds the `cipher` attribute to the MACsec link
+    /// This is equivalent to `ip link add ... type macsec cipher CIPHER`, where `cipher` is one of
+    /// the `MACSEC_CIPHER_ID_*` constants.
+    pub fn cipher(mut self, cipher: u64) -> Self {
+        self.info_data.push(InfoMacSec::CipherSuite(cipher));
+        self
+    }
+}
+
+/// A request to create a new bareudp link.
+/// This is equivalent to `ip link add NAME type bareudp dstport PORT ethertype ETHERTYPE ...`
+/// commands. Both the destination port and the ethertype are required by the kernel.
+pub struct BareUdpAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoBareUdp>,
+}
+
+impl BareUdpAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        self.request
+            .link_info(InfoKind::BareUdp, Some(InfoData::BareUdp(self.info_data)))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `dstport` attribute to the bareudp link
+    /// This is equivalent to `ip link add name NAME type bareudp dstport PORT`.
+    pub fn dst_port(mut self, port: u16) -> Self {
+        self.info_data.push(InfoBareUdp::Port(port));
+        self
+    }
+
+    /// Adds the `ethertype` attribute to the bareudp link, which is the protocol of the
+    /// tunnelled packets (for example `0x8847` for unicast MPLS).
+    /// This is equivalent to `ip link add name NAME type bareudp ethertype ETHERTYPE`.
+    pub fn ethertype(mut self, ethertype: u16) -> Self {
+        self.info_data.push(InfoBareUdp::EtherType(ethertype));
+        self
+    }
+}
+
+/// Role of a GTP interface (the `IFLA_GTP_ROLE` attribute).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GtpRole {
+    /// Gateway GPRS support node (`GTP_ROLE_GGSN`), on the network side. This is the default.
+    Ggsn,
+    /// Serving GPRS support node (`GTP_ROLE_SGSN`), on the mobile side
+    Sgsn,
+    Other(u32),
+}
+
+impl From<u32> for GtpRole {
+    fn from(value: u32) -> Self {
+        match value {
+            GTP_ROLE_GGSN => GtpRole::Ggsn,
+            GTP_ROLE_SGSN => GtpRole::Sgsn,
+            other => GtpRole::Other(other),
+        }
+    }
+}
+
+impl From<GtpRole> for u32 {
+    fn from(value: GtpRole) -> Self {
+        match value {
+            GtpRole::Ggsn => GTP_ROLE_GGSN,
+            GtpRole::Sgsn => GTP_ROLE_SGSN,
+            GtpRole::Other(other) => other,
+        }
+    }
+}
+
+/// A request to create a new GTP link.
+/// This is equivalent to `ip link add NAME type gtp ...` commands.
+///
+/// The kernel does not open the GTP sockets itself: the caller must create UDP sockets, bind
+/// them to the GTP ports (3386 for GTPv0, 2152 for GTPv1-U), and pass their file descriptors
+/// with [`fd0`](GtpAddRequest::fd0) and [`fd1`](GtpAddRequest::fd1). At least one of them is
+/// required. The kernel rejects file descriptors that are not UDP sockets with `EINVAL`, and
+/// sockets already used by another tunnel with `EBUSY`. It holds its own reference to the
+/// sockets, so they can be closed once the link is created.
+pub struct GtpAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoGtp>,
+}
+
+impl GtpAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        self.request
+            .link_info(InfoKind::Gtp, Some(InfoData::Gtp(self.info_data)))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `IFLA_GTP_FD0` attribute, the UDP socket used for GTPv0.
+    pub fn fd0(mut self, fd: RawFd) -> Self {
+        self.info_data.push(InfoGtp::Fd0(fd));
+        self
+    }
+
+    /// Adds the `IFLA_GTP_FD1` attribute, the UDP socket used for GTPv1-U.
+    pub fn fd1(mut self, fd: RawFd) -> Self {
+        self.info_data.push(InfoGtp::Fd1(fd));
+        self
+    }
+
+    /// Adds the `role` attribute to the GTP link
+    /// This is equivalent to `ip link add name NAME type gtp role ggsn|sgsn`.
+    pub fn role(mut self, role: GtpRole) -> Self {
+        self.info_data.push(InfoGtp::Role(role.into()));
+        self
+    }
+
+    /// Adds the `hsize` attribute to the GTP link, which is the size of the hash tables of the
+    /// PDP contexts.
+    /// This is equivalent to `ip link add name NAME type gtp hsize SIZE`.
+    pub fn pdp_hashsize(mut self, size: u32) -> Self {
+        self.info_data.push(InfoGtp::PdpHashsize(size));
+        self
+    }
+}
+
+/// A request to create a new IPoIB child interface.
+/// This is equivalent to `ip link add link LINK name NAME type ipoib ...` commands.
+pub struct IpoibAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoIpoib>,
+}
+
+impl IpoibAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<u32, Error> {
+        self.request
+            .link_info(InfoKind::Ipoib, Some(InfoData::Ipoib(self.info_data)))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Set the MTU of the link at creation (see [`LinkAddRequest::mtu`])
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.request = self.request.mtu(mtu);
+        self
+    }
+
+    /// Adds the `pkey` attribute to the IPoIB link
+    /// This is equivalent to `ip link add ... type ipoib pkey PKEY`.
+    ///
+    /// The high bit of a partition key is the membership type: set for full members, clear for
+    /// limited members. The kernel always sets it on the child interface, so `0x0001` and
+    /// `0x8001` designate the same child, and the pkey read back from the kernel is `0x8001` in
+    /// both cases. `0x0000` and `0x8000` are invalid and rejected with `EINVAL`. Without a pkey,
+    /// the child uses the partition key of its parent.
+    pub fn pkey(mut self, pkey: u16) -> Self {
+        self.info_data.push(InfoIpoib::Pkey(pkey));
+        self
+    }
+
+    /// Adds the `mode` attribute to the IPoIB link
+    /// This is equivalent to `ip link add ... type ipoib mode datagram|connected`.
+    pub fn mode(mut self, mode: IpoibMode) -> Self {
+        self.info_data.push(InfoIpoib::Mode(mode.into()));
+        self
+    }
+}
+
 /// A request to create a new link. This is equivalent to the `ip link add` commands.
 ///
 /// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
@@ -256,20 +695,43 @@ impl LinkAddRequest {
         }
     }
 
-    /// Execute the request.
-    pub async fn execute(self) -> Result<(), Error> {
+    /// Execute the request, and return the index of the new link.
+    ///
+    /// The request sets `NLM_F_ECHO`, so that the kernel sends the new link back, along with its
+    /// index. Kernels older than 6.1 ignore that flag, in which case the link is looked up by
+    /// name once created. If the kernel did not echo the link and the name was not set (for links
+    /// named by the kernel after a template like `eth%d`), the index cannot be found and
+    /// [`Error::RequestFailed`] is returned, even though the link was created.
+    pub async fn execute(self) -> Result<u32, Error> {
         let LinkAddRequest {
             mut handle,
             message,
         } = self;
+        let name = message.nlas.iter().find_map(|nla| match nla {
+            Nla::IfName(name) => Some(name.clone()),
+            _ => None,
+        });
         let mut req = NetlinkMessage::from(RtnlMessage::NewLink(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_ECHO | NLM_F_EXCL | NLM_F_CREATE;
 
+        let mut index = None;
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
+            if let NetlinkPayload::InnerMessage(RtnlMessage::NewLink(ref link)) = message.payload {
+                index.get_or_insert(link.header.index);
+            }
             try_nl!(message);
         }
-        Ok(())
+        if let Some(index) = index {
+            return Ok(index);
+        }
+
+        let name = name.ok_or(Error::RequestFailed)?;
+        let mut links = LinkGetRequest::new(handle).set_name_filter(name).execute();
+        match links.try_next().await? {
+            Some(link) => Ok(link.header.index),
+            None => Err(Error::RequestFailed),
+        }
     }
 
     /// Return a mutable reference to the request message.
@@ -293,7 +755,7 @@ impl LinkAddRequest {
     ///     request.message_mut().header.flags &= !IFF_UP;
     ///     request.message_mut().header.change_mask &= !IFF_UP;
     ///     // send the request
-    ///     request.execute().await.map_err(|e| format!("{}", e))
+    ///     request.execute().await.map(|_| ()).map_err(|e| format!("{}", e))
     /// }
     pub fn message_mut(&mut self) -> &mut LinkMessage {
         &mut self.message
@@ -336,6 +798,24 @@ impl LinkAddRequest {
             .up()
     }
 
+    /// Set the ingress QoS map of a VLAN created with [`vlan()`](#method.vlan). Each `(from, to)`
+    /// pair maps the priority of the VLAN header `from` to the packet priority `to`.
+    /// This is equivalent to `ip link add ... type vlan id VLAN_ID ingress-qos-map FROM:TO ...`.
+    ///
+    /// This has no effect if the request does not create a VLAN.
+    pub fn ingress_qos_map(self, map: &[(u32, u32)]) -> Self {
+        self.append_vlan_info(InfoVlan::IngressQos(qos_mappings(map)))
+    }
+
+    /// Set the egress QoS map of a VLAN created with [`vlan()`](#method.vlan). Each `(from, to)`
+    /// pair maps the packet priority `from` to the priority of the VLAN header `to`.
+    /// This is equivalent to `ip link add ... type vlan id VLAN_ID egress-qos-map FROM:TO ...`.
+    ///
+    /// This has no effect if the request does not create a VLAN.
+    pub fn egress_qos_map(self, map: &[(u32, u32)]) -> Self {
+        self.append_vlan_info(InfoVlan::EgressQos(qos_mappings(map)))
+    }
+
     /// Create macvlan on a link.
     /// This is equivalent to `ip link add NAME name link LINK type macvlan mode MACVLAN_MODE`,
     /// but instead of specifying a link name (`LINK`), we specify a link index.
@@ -361,6 +841,130 @@ impl LinkAddRequest {
         }
     }
 
+    /// Create a VxLAN in external mode, where the VNI and the remote endpoint are taken from the
+    /// metadata of each packet.
+    /// This is equivalent to `ip link add name NAME type vxlan external`,
+    /// it returns a VxlanAddRequest to further customize the vxlan
+    /// interface creation.
+    pub fn vxlan_external(self, name: String) -> VxlanAddRequest {
+        let s = self.name(name);
+        VxlanAddRequest {
+            request: s,
+            info_data: vec![InfoVxlan::CollectMetadata(1)],
+        }
+    }
+
+    /// Create a GRE tunnel.
+    /// This is equivalent to `ip link add name NAME type gre`,
+    /// it returns a GreAddRequest to further customize the tunnel creation.
+    pub fn gre(self, name: String) -> GreAddRequest {
+        GreAddRequest {
+            request: self.name(name),
+            kind: InfoKind::GreTun,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a GRE tunnel carrying ethernet frames.
+    /// This is equivalent to `ip link add name NAME type gretap`,
+    /// it returns a GreAddRequest to further customize the tunnel creation.
+    pub fn gretap(self, name: String) -> GreAddRequest {
+        GreAddRequest {
+            request: self.name(name),
+            kind: InfoKind::GreTap,
+            info_data: vec![],
+        }
+    }
+
+    /// Create an IPv4 in IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type ipip`,
+    /// it returns a IpTunAddRequest to further customize the tunnel creation.
+    pub fn ipip(self, name: String) -> IpTunAddRequest {
+        IpTunAddRequest {
+            request: self.name(name),
+            kind: InfoKind::IpTun,
+            info_data: vec![],
+        }
+    }
+
+    /// Create an IPv6 in IPv4 tunnel.
+    /// This is equivalent to `ip link add name NAME type sit`,
+    /// it returns a IpTunAddRequest to further customize the tunnel creation.
+    pub fn sit(self, name: String) -> IpTunAddRequest {
+        IpTunAddRequest {
+            request: self.name(name),
+            kind: InfoKind::SitTun,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a MACsec link on top of a link.
+    /// This is equivalent to `ip link add link LINK name NAME type macsec`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// It returns a MacSecAddRequest to further customize the MACsec interface creation.
+    pub fn macsec(self, name: String, index: u32) -> MacSecAddRequest {
+        MacSecAddRequest {
+            request: self.name(name).append_nla(Nla::Link(index)),
+            info_data: vec![],
+        }
+    }
+
+    /// Create a bareudp tunnel.
+    /// This is equivalent to `ip link add name NAME type bareudp`,
+    /// it returns a BareUdpAddRequest to further customize the tunnel creation.
+    pub fn bareudp(self, name: String) -> BareUdpAddRequest {
+        BareUdpAddRequest {
+            request: self.name(name),
+            info_data: vec![],
+        }
+    }
+
+    /// Create a GTP tunnel.
+    /// This is equivalent to `ip link add name NAME type gtp`,
+    /// it returns a GtpAddRequest to further customize the tunnel creation.
+    pub fn gtp(self, name: String) -> GtpAddRequest {
+        GtpAddRequest {
+            request: self.name(name),
+            info_data: vec![],
+        }
+    }
+
+    /// Create an IPoIB child interface on top of an InfiniBand link.
+    /// This is equivalent to `ip link add link LINK name NAME type ipoib`,
+    /// but instead of specifying a link name (`LINK`), we specify a link index.
+    /// It returns an IpoibAddRequest to further customize the IPoIB interface creation.
+    pub fn ipoib(self, name: String, parent_index: u32) -> IpoibAddRequest {
+        IpoibAddRequest {
+            request: self.name(name).append_nla(Nla::Link(parent_index)),
+            info_data: vec![],
+        }
+    }
+
+    /// Create a link of a kind this crate does not know about, such as `ip link add NAME type
+    /// KIND`. The options of the link are given as a list of `(type, value)` attributes that are
+    /// sent as is in the `IFLA_INFO_DATA` attribute, which is left out if the list is empty.
+    /// Nested attributes must be serialized by the caller.
+    ///
+    /// Unlike the other constructors, this does not set the link up: use
+    /// [`flags()`](#method.flags) for that.
+    pub fn kind_raw(self, name: String, kind: String, info_data: Vec<(u16, Vec<u8>)>) -> Self {
+        if info_data.is_empty() {
+            return self.name(name).link_info(InfoKind::Other(kind), None);
+        }
+        // Each attribute is a 4 bytes header (length and type) followed by the value, padded to
+        // 4 bytes
+        let mut data = vec![];
+        for (kind, value) in info_data {
+            let len = 4 + value.len();
+            data.extend_from_slice(&(len as u16).to_ne_bytes());
+            data.extend_from_slice(&kind.to_ne_bytes());
+            data.extend_from_slice(&value);
+            data.resize(data.len() + (4 - len % 4) % 4, 0);
+        }
+        self.name(name)
+            .link_info(InfoKind::Other(kind), Some(InfoData::Other(data)))
+    }
+
     /// Create a new bridge.
     /// This is equivalent to `ip link add link NAME type bridge`.
     pub fn bridge(self, name: String) -> Self {
@@ -369,12 +973,103 @@ impl LinkAddRequest {
             .append_nla(Nla::IfName(name))
     }
 
-    fn up(mut self) -> Self {
-        self.message.header.flags = IFF_UP;
-        self.message.header.change_mask = IFF_UP;
+    /// Set the hardware address of the link being created.
+    /// This is equivalent to `ip link add NAME address ADDRESS ...`.
+    ///
+    /// For a veth pair, this is the address of the `peer_name` interface.
+    pub fn address(self, address: [u8; 6]) -> Self {
+        self.append_nla(Nla::Address(address.to_vec()))
+    }
+
+    /// Set the broadcast address of the link being created.
+    /// This is equivalent to `ip link add NAME broadcast ADDRESS ...`.
+    pub fn broadcast(self, address: [u8; 6]) -> Self {
+        self.append_nla(Nla::Broadcast(address.to_vec()))
+    }
+
+    /// Set the MTU of the link being created.
+    /// This is equivalent to `ip link add NAME mtu MTU ...`.
+    ///
+    /// The kernel creates the link with this MTU, so no packet goes through the link with the
+    /// default MTU of its kind, as is the case when the MTU is set once the link exists. The
+    /// request fails with `EINVAL` if the MTU is out of the range the link kind supports.
+    pub fn mtu(self, mtu: u32) -> Self {
+        self.append_nla(Nla::Mtu(mtu))
+    }
+
+    /// Set the network namespace in which the parent link (the `IFLA_LINK` attribute, set by
+    /// [`vlan()`](#method.vlan) or [`macvlan()`](#method.macvlan) for instance) is looked up,
+    /// while the new link is created in the namespace of the netlink socket.
+    /// This is equivalent to `ip link add link LINK netns NSNAME ...` commands, with `LINK` in
+    /// `NSNAME`.
+    ///
+    /// `nsid` is an id of the other namespace from the point of view of the namespace of the
+    /// netlink socket. It must have been assigned beforehand, with
+    /// [`NsidHandle::assign`](crate::NsidHandle::assign) for instance, otherwise the request fails
+    /// with `EINVAL`.
+    pub fn link_netnsid(self, nsid: i32) -> Self {
+        self.append_nla(Nla::NetnsId(nsid))
+    }
+
+    /// Set the name of the parent device of the link being created, for the link kinds that
+    /// create an interface on top of a device rather than on top of another interface (the
+    /// subfunctions of some SmartNICs for instance).
+    ///
+    /// This is not the same as the parent link set by [`vlan()`](#method.vlan) or
+    /// [`macvlan()`](#method.macvlan) (the `IFLA_LINK` attribute): `IFLA_LINK` is the index of a
+    /// network interface, whereas `IFLA_PARENT_DEV_NAME` is the name of a device on its bus, such
+    /// as the PCI address `0000:03:00.0`, which does not need to be a network interface. Link
+    /// kinds that do not use it ignore it.
+    pub fn parent_dev_name(self, name: String) -> Self {
+        self.append_nla(Nla::ParentDevName(name))
+    }
+
+    /// Set the number of transmit queues of the link being created.
+    /// This is equivalent to `ip link add NAME numtxqueues QUEUES ...`.
+    ///
+    /// The kernel only honors this at creation time, it cannot be changed afterwards.
+    pub fn num_tx_queues(self, queues: u32) -> Self {
+        self.append_nla(Nla::NumTxQueues(queues))
+    }
+
+    /// Set the number of receive queues of the link being created.
+    /// This is equivalent to `ip link add NAME numrxqueues QUEUES ...`.
+    ///
+    /// The kernel only honors this at creation time, it cannot be changed afterwards.
+    pub fn num_rx_queues(self, queues: u32) -> Self {
+        self.append_nla(Nla::NumRxQueues(queues))
+    }
+
+    /// Set the flags of the link being created: the flags in `change_mask` are set or cleared
+    /// depending on whether they are in `flags`, the flags already set by the request (like the
+    /// `IFF_UP` flag set by [`dummy()`](#method.dummy)) are left untouched. For instance, passing
+    /// `InterfaceFlags::NOARP` as both is equivalent to `ip link add NAME arp off ...`.
+    pub fn flags(mut self, flags: InterfaceFlags, change_mask: InterfaceFlags) -> Self {
+        let header = &mut self.message.header;
+        header.flags = (header.flags & !change_mask.bits()) | (flags & change_mask).bits();
+        header.change_mask |= change_mask.bits();
+        self
+    }
+
+    /// Create the link up, in addition to the flags already set.
+    /// This is equivalent to `ip link add NAME up ...`.
+    ///
+    /// The kernel brings the link up as part of its creation, so unlike a
+    /// [`LinkSetRequest::up`](crate::LinkSetRequest::up) sent afterwards, there is no window
+    /// during which the link exists but is down. The constructors of most link kinds, like
+    /// [`dummy()`](#method.dummy), already set it.
+    pub fn up(mut self) -> Self {
+        self.message.header.flags |= InterfaceFlags::UP.bits();
+        self.message.header.change_mask |= InterfaceFlags::UP.bits();
         self
     }
 
+    /// Set the length of the transmit queue of the link being created.
+    /// This is equivalent to `ip link add NAME txqueuelen LEN ...`.
+    pub fn txqueuelen(self, len: u32) -> Self {
+        self.append_nla(Nla::TxQueueLen(len))
+    }
+
     fn link_info(self, kind: InfoKind, data: Option<InfoData>) -> Self {
         let mut link_info_nlas = vec![Info::Kind(kind)];
         if let Some(data) = data {
@@ -383,6 +1078,26 @@ impl LinkAddRequest {
         self.append_nla(Nla::Info(link_info_nlas))
     }
 
+    fn append_vlan_info(mut self, info: InfoVlan) -> Self {
+        let vlan_infos = self
+            .message
+            .nlas
+            .iter_mut()
+            .filter_map(|nla| match nla {
+                Nla::Info(infos) => Some(infos),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|info| match info {
+                Info::Data(InfoData::Vlan(vlan_infos)) => Some(vlan_infos),
+                _ => None,
+            });
+        if let Some(vlan_infos) = vlan_infos {
+            vlan_infos.push(info);
+        }
+        self
+    }
+
     fn name(mut self, name: String) -> Self {
         self.message.nlas.push(Nla::IfName(name));
         self
@@ -393,3 +1108,9 @@ impl LinkAddRequest {
         self
     }
 }
+
+fn qos_mappings(map: &[(u32, u32)]) -> Vec<VlanQosMapping> {
+    map.iter()
+        .map(|&(from, to)| VlanQosMapping::Mapping { from, to })
+        .collect()
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        constants::*,
+        nlas::link::{AfSpecBridge, BridgeVlanInfo, Nla},
+        LinkMessage,
+        NetlinkMessage,
+        RtnlMessage,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+/// A request to add a VLAN, or a range of VLANs, to a bridge port. This is equivalent to
+/// `bridge vlan add vid VID dev DEV`.
+pub struct BridgeVlanAddRequest {
+    handle: Handle,
+    message: LinkMessage,
+    vid: u16,
+    vid_end: Option<u16>,
+    flags: u16,
+    bridge_flags: Option<u16>,
+}
+
+impl BridgeVlanAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32, vid: u16) -> Self {
+        let mut message = LinkMessage::default();
+        message.header.interface_family = AF_BRIDGE as u8;
+        message.header.index = index;
+        BridgeVlanAddRequest {
+            handle,
+            message,
+            vid,
+            vid_end: None,
+            flags: 0,
+            bridge_flags: None,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanAddRequest {
+            mut handle,
+            mut message,
+            vid,
+            vid_end,
+            flags,
+            bridge_flags,
+        } = self;
+
+        let mut nlas = vec![];
+        if let Some(bridge_flags) = bridge_flags {
+            nlas.push(AfSpecBridge::Flags(bridge_flags));
+        }
+        match vid_end {
+            Some(vid_end) if vid_end != vid => {
+                // The kernel expects a range as two consecutive entries, marking its first and
+                // last VLAN.
+                nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                    flags: flags | BRIDGE_VLAN_INFO_RANGE_BEGIN,
+                    vid,
+                }));
+                nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo {
+                    flags: flags | BRIDGE_VLAN_INFO_RANGE_END,
+                    vid: vid_end,
+                }));
+            }
+            _ => nlas.push(AfSpecBridge::VlanInfo(BridgeVlanInfo { flags, vid })),
+        }
+        message.nlas.push(Nla::AfSpecBridge(nlas));
+
+        let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut LinkMessage {
+        &mut self.message
+    }
+
+    /// Use the VLAN as the port VLAN id: untagged frames received on the port are assigned to it
+    /// (`bridge vlan add ... pvid`).
+    pub fn pvid(mut self) -> Self {
+        self.flags |= BRIDGE_VLAN_INFO_PVID;
+        self
+    }
+
+    /// Send the frames of the VLAN untagged on the port (`bridge vlan add ... untagged`).
+    pub fn untagged(mut self) -> Self {
+        self.flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+        self
+    }
+
+    /// Add all the VLANs from the initial VLAN id up to and including `vid_end` in a single
+    /// request (`bridge vlan add vid VID-VID_END ...`). The kernel refuses ranges for a PVID.
+    pub fn range(mut self, vid_end: u16) -> Self {
+        self.vid_end = Some(vid_end);
+        self
+    }
+
+    /// Add the VLAN to the bridge device itself rather than to one of its ports
+    /// (`bridge vlan add ... self`).
+    pub fn bridge_self(mut self) -> Self {
+        self.bridge_flags = Some(BRIDGE_FLAGS_SELF);
+        self
+    }
+}
@@ -5,9 +5,16 @@ use futures::{
     stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
+use nix::errno::Errno;
 
 use crate::{
-    packet::{constants::*, nlas::link::Nla, LinkMessage, NetlinkMessage, RtnlMessage},
+    packet::{
+        constants::*,
+        nlas::link::{ExtFilterMask, Nla},
+        LinkMessage,
+        NetlinkMessage,
+        RtnlMessage,
+    },
     try_rtnl,
     Error,
     Handle,
@@ -42,6 +49,21 @@ impl LinkGetRequest {
         self
     }
 
+    /// Ask the kernel to include extra information in the link messages, or to leave some out
+    /// (`IFLA_EXT_MASK`). For instance, [`ExtFilterMask::VF`] adds the virtual functions of
+    /// SR-IOV devices, and [`ExtFilterMask::SKIP_STATS`] makes large dumps faster by leaving
+    /// some statistics out.
+    ///
+    /// By default no mask is sent, and the kernel only includes the basic information. This
+    /// replaces the mask set by [`LinkGetRequest::set_filter_mask`], but not its address family.
+    pub fn with_ext_filter(mut self, mask: ExtFilterMask) -> Self {
+        self.message
+            .nlas
+            .retain(|nla| !matches!(nla, Nla::ExtMask(_)));
+        self.message.nlas.push(Nla::ExtMask(mask.bits()));
+        self
+    }
+
     /// Execute the request
     pub fn execute(self) -> impl TryStream<Ok = LinkMessage, Error = Error> {
         let LinkGetRequest {
@@ -51,6 +73,7 @@ impl LinkGetRequest {
             filter_builder,
         } = self;
 
+        let index = message.header.index;
         let mut req = NetlinkMessage::from(RtnlMessage::GetLink(message));
 
         if dump {
@@ -64,6 +87,16 @@ impl LinkGetRequest {
             Ok(response) => Either::Left(
                 response
                     .map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewLink)))
+                    .map_err(move |e| match e {
+                        // When a single link is requested, the kernel answers
+                        // with ENODEV if no such link exists.
+                        Error::NetlinkError(ref err)
+                            if !dump && index != 0 && err.code == -(Errno::ENODEV as i32) =>
+                        {
+                            Error::LinkNotFound(index)
+                        }
+                        e => e,
+                    })
                     .try_filter(move |msg| future::ready(filter(msg))),
             ),
             Err(e) => Either::Right(future::err::<LinkMessage, Error>(e).into_stream()),
@@ -75,21 +108,48 @@ impl LinkGetRequest {
         &mut self.message
     }
 
+    /// Only retrieve the link with the given index. Instead of dumping all
+    /// the links, a single `RTM_GETLINK` request is sent for that index. If
+    /// the link does not exist, the stream yields `Error::LinkNotFound`.
     pub fn match_index(mut self, index: u32) -> Self {
         self.dump = false;
         self.message.header.index = index;
         self
     }
 
+    /// Only retrieve the link that has the given alternative name (equivalent to `ip link show
+    /// altname ALT_IFNAME`). Like [`match_index`](#method.match_index), a single `RTM_GETLINK`
+    /// request is sent, and the kernel looks the link up. Alternative names can be up to 127
+    /// bytes long, unlike the primary name of a link which is limited to 15 bytes.
+    ///
+    /// Looking links up by alternative name requires Linux 5.5 or later. If no link has this
+    /// name, the stream yields a netlink error with `ENODEV`.
+    pub fn match_alt_name(mut self, alt_name: String) -> Self {
+        self.dump = false;
+        self.message.nlas.push(Nla::AltIfName(alt_name));
+        self
+    }
+
     pub fn set_name_filter(mut self, name: String) -> Self {
         self.filter_builder.name = Some(name);
         self
     }
+
+    /// Only retrieve the links enslaved to the link with the given index (equivalent to
+    /// `ip link show master MASTER`). The master is sent along with the dump request so that
+    /// kernels supporting dump filtering only return these links, and the links are filtered
+    /// again on their `IFLA_MASTER` attribute in case the kernel ignored it.
+    pub fn set_master_filter(mut self, master_index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(master_index));
+        self.filter_builder.master = Some(master_index);
+        self
+    }
 }
 
 #[derive(Default)]
 struct LinkFilterBuilder {
     name: Option<String>,
+    master: Option<u32>,
 }
 
 impl LinkFilterBuilder {
@@ -99,6 +159,11 @@ impl LinkFilterBuilder {
 
     fn build(self) -> impl Fn(&LinkMessage) -> bool {
         move |msg: &LinkMessage| {
+            if let Some(master) = self.master {
+                if !msg.nlas.iter().any(|nla| *nla == Nla::Master(master)) {
+                    return false;
+                }
+            }
             if let Some(name) = &self.name {
                 for nla in msg.nlas.iter() {
                     if let Nla::IfName(s) = nla {
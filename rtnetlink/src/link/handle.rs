@@ -1,12 +1,15 @@
 // SPDX-License-Identifier: MIT
 
 use super::{
+    BridgeVlanAddRequest,
     LinkAddRequest,
     LinkDelPropRequest,
     LinkDelRequest,
     LinkGetRequest,
+    LinkGetStatsRequest,
     LinkNewPropRequest,
     LinkSetRequest,
+    LinkTeardownRequest,
 };
 use crate::Handle;
 
@@ -33,12 +36,47 @@ impl LinkHandle {
         LinkDelPropRequest::new(self.0.clone(), index)
     }
 
+    /// Add an alternative name to a link (equivalent to `ip link property add dev DEV altname
+    /// ALT_IFNAME`). A link can have several alternative names, and they can be up to 127 bytes
+    /// long. Alternative names are link properties, so this sends a `RTM_NEWLINKPROP` message
+    /// rather than a `RTM_SETLINK` one, and requires Linux 5.5 or later.
+    pub fn add_alt_name(&self, index: u32, alt_name: String) -> LinkNewPropRequest {
+        self.property_add(index).alt_ifname(&[&alt_name])
+    }
+
+    /// Remove an alternative name from a link (equivalent to `ip link property del dev DEV
+    /// altname ALT_IFNAME`), with a `RTM_DELLINKPROP` message.
+    pub fn del_alt_name(&self, index: u32, alt_name: String) -> LinkDelPropRequest {
+        self.property_del(index).alt_ifname(&[&alt_name])
+    }
+
     pub fn del(&mut self, index: u32) -> LinkDelRequest {
         LinkDelRequest::new(self.0.clone(), index)
     }
 
+    /// Bring a link down, and optionally delete its addresses and neighbour entries with
+    /// [`LinkTeardownRequest::flush_addresses`] and [`LinkTeardownRequest::flush_neighbours`].
+    /// The link itself is kept.
+    pub fn teardown(&self, index: u32) -> LinkTeardownRequest {
+        LinkTeardownRequest::new(self.0.clone(), index)
+    }
+
+    /// Add a VLAN to a bridge port (equivalent to `bridge vlan add vid VID dev DEV`). Use
+    /// [`LinkGetRequest::set_filter_mask`] with `AF_BRIDGE` and `RTEXT_FILTER_BRVLAN` to read the
+    /// VLANs of the ports back.
+    pub fn bridge_vlan_add(&self, index: u32, vid: u16) -> BridgeVlanAddRequest {
+        BridgeVlanAddRequest::new(self.0.clone(), index, vid)
+    }
+
     /// Retrieve the list of links (equivalent to `ip link show`)
     pub fn get(&mut self) -> LinkGetRequest {
         LinkGetRequest::new(self.0.clone())
     }
+
+    /// Retrieve the statistics of a link with a `RTM_GETSTATS` request. `filter_mask` selects the
+    /// statistics to report, as a combination of the `IFLA_STATS_FILTER_*` constants, for
+    /// instance `IFLA_STATS_FILTER_LINK_64` for the counters also found in `ip -s link show`.
+    pub fn get_stats(&self, index: u32, filter_mask: u32) -> LinkGetStatsRequest {
+        LinkGetStatsRequest::new(self.0.clone(), index, filter_mask)
+    }
 }
@@ -21,5 +21,14 @@ pub use self::property_add::*;
 mod property_del;
 pub use self::property_del::*;
 
+mod bridge_vlan;
+pub use self::bridge_vlan::*;
+
+mod stats;
+pub use self::stats::*;
+
+mod teardown;
+pub use self::teardown::*;
+
 #[cfg(test)]
 mod test;
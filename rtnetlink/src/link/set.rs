@@ -3,12 +3,11 @@
 use crate::{
     packet::{
         nlas::link::Nla,
+        InterfaceFlags,
         LinkMessage,
         NetlinkMessage,
+        NetlinkPayload,
         RtnlMessage,
-        IFF_NOARP,
-        IFF_PROMISC,
-        IFF_UP,
         NLM_F_ACK,
         NLM_F_CREATE,
         NLM_F_EXCL,
@@ -19,18 +18,42 @@ use crate::{
     Handle,
 };
 use futures::stream::StreamExt;
+use nix::errno::Errno;
 use std::os::unix::io::RawFd;
 
+// Size of the kernel's interface name buffers, including the terminating nul byte
+const IFNAMSIZ: usize = 16;
+
+/// Check a link name the same way the kernel does (see `dev_valid_name`), so that the error
+/// says what is wrong with it instead of a bare EINVAL.
+pub(crate) fn validate_link_name(name: &str) -> Result<(), Error> {
+    if name.is_empty()
+        || name.len() >= IFNAMSIZ
+        || name == "."
+        || name == ".."
+        || name.chars().any(|c| c == '/' || c == ':' || c.is_whitespace())
+    {
+        return Err(Error::InvalidLinkName(name.to_owned()));
+    }
+    Ok(())
+}
+
 pub struct LinkSetRequest {
     handle: Handle,
     message: LinkMessage,
+    /// Set by `master(0)`, which would detach the link instead of attaching it
+    invalid_master: bool,
 }
 
 impl LinkSetRequest {
     pub(crate) fn new(handle: Handle, index: u32) -> Self {
         let mut message = LinkMessage::default();
         message.header.index = index;
-        LinkSetRequest { handle, message }
+        LinkSetRequest {
+            handle,
+            message,
+            invalid_master: false,
+        }
     }
 
     /// Execute the request
@@ -38,13 +61,30 @@ impl LinkSetRequest {
         let LinkSetRequest {
             mut handle,
             message,
+            invalid_master,
         } = self;
+        if invalid_master {
+            return Err(Error::InvalidMasterIndex);
+        }
+        let index = message.header.index;
+        let mut rename = false;
+        for nla in message.nlas.iter() {
+            if let Nla::IfName(name) = nla {
+                validate_link_name(name)?;
+                rename = true;
+            }
+        }
         let mut req = NetlinkMessage::from(RtnlMessage::SetLink(message));
         req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
-            try_nl!(message);
+            match message.payload {
+                NetlinkPayload::Error(ref err) if rename && err.code == -(Errno::EBUSY as i32) => {
+                    return Err(Error::LinkBusy(index));
+                }
+                _ => try_nl!(message),
+            }
         }
         Ok(())
     }
@@ -57,57 +97,73 @@ impl LinkSetRequest {
     /// Attach the link to a bridge (its _master_). This is equivalent to `ip link set LINK master
     /// BRIDGE`. To succeed, both the bridge and the link that is being attached must be UP.
     ///
-    /// To Remove a link from a bridge, set its master to zero.
-    /// This is equvalent to `ip link set LINK nomaster`
+    /// The kernel treats a zero index as a request to detach the link, so `master(0)` makes the
+    /// request fail with [`Error::InvalidMasterIndex`] instead: use
+    /// [`nomaster`](LinkSetRequest::nomaster) to detach a link.
     pub fn master(mut self, master_index: u32) -> Self {
-        self.message.nlas.push(Nla::Master(master_index));
+        if master_index == 0 {
+            self.invalid_master = true;
+        } else {
+            self.message.nlas.push(Nla::Master(master_index));
+        }
         self
     }
 
-    /// Detach the link from its _master_. This is equivalent to `ip link set LINK nomaster`.
-    ///To succeed, the link that is being detached must be UP.
+    /// Detach the link from its _master_ (bridge, bond...), by setting `IFLA_MASTER` to zero. This
+    /// is equivalent to `ip link set LINK nomaster`. To succeed, the link that is being detached
+    /// must be UP.
     pub fn nomaster(mut self) -> Self {
         self.message.nlas.push(Nla::Master(0u32));
         self
     }
 
-    /// Set the link with the given index up (equivalent to `ip link set dev DEV up`)
-    pub fn up(mut self) -> Self {
-        self.message.header.flags |= IFF_UP;
-        self.message.header.change_mask |= IFF_UP;
+    /// Set the flags of the link with the given index: the flags in `change_mask` are set or
+    /// cleared depending on whether they are in `flags`, the others are left untouched. For
+    /// instance, `flags(InterfaceFlags::UP, InterfaceFlags::UP | InterfaceFlags::PROMISC)` sets
+    /// the link up and disables promiscuous mode.
+    pub fn flags(mut self, flags: InterfaceFlags, change_mask: InterfaceFlags) -> Self {
+        let header = &mut self.message.header;
+        header.flags = (header.flags & !change_mask.bits()) | (flags & change_mask).bits();
+        header.change_mask |= change_mask.bits();
         self
     }
 
+    /// Set the link with the given index up (equivalent to `ip link set dev DEV up`)
+    pub fn up(self) -> Self {
+        self.flags(InterfaceFlags::UP, InterfaceFlags::UP)
+    }
+
     /// Set the link with the given index down (equivalent to `ip link set dev DEV down`)
-    pub fn down(mut self) -> Self {
-        self.message.header.flags &= !IFF_UP;
-        self.message.header.change_mask |= IFF_UP;
-        self
+    pub fn down(self) -> Self {
+        self.flags(InterfaceFlags::empty(), InterfaceFlags::UP)
     }
 
     /// Enable or disable promiscious mode of the link with the given index (equivalent to `ip link set dev DEV promisc on/off`)
-    pub fn promiscuous(mut self, enable: bool) -> Self {
-        if enable {
-            self.message.header.flags |= IFF_PROMISC;
+    pub fn promiscuous(self, enable: bool) -> Self {
+        let flags = if enable {
+            InterfaceFlags::PROMISC
         } else {
-            self.message.header.flags &= !IFF_PROMISC;
-        }
-        self.message.header.change_mask |= IFF_PROMISC;
-        self
+            InterfaceFlags::empty()
+        };
+        self.flags(flags, InterfaceFlags::PROMISC)
     }
 
     /// Enable or disable the ARP protocol of the link with the given index (equivalent to `ip link set dev DEV arp on/off`)
-    pub fn arp(mut self, enable: bool) -> Self {
-        if enable {
-            self.message.header.flags &= !IFF_NOARP;
+    pub fn arp(self, enable: bool) -> Self {
+        let flags = if enable {
+            InterfaceFlags::empty()
         } else {
-            self.message.header.flags |= IFF_NOARP;
-        }
-        self.message.header.change_mask |= IFF_NOARP;
-        self
+            InterfaceFlags::NOARP
+        };
+        self.flags(flags, InterfaceFlags::NOARP)
     }
 
-    /// Set the name of the link with the given index (equivalent to `ip link set DEV name NAME`)
+    /// Set the name of the link with the given index (equivalent to `ip link set DEV name NAME`).
+    ///
+    /// Most kernels only rename links that are down: the request fails with `Error::LinkBusy` if
+    /// the link is up.
+    /// Names that the kernel would reject, such as names longer than 15 bytes, make the request
+    /// fail with `Error::InvalidLinkName` without being sent.
     pub fn name(mut self, name: String) -> Self {
         self.message.nlas.push(Nla::IfName(name));
         self
@@ -119,12 +175,45 @@ impl LinkSetRequest {
         self
     }
 
+    /// Set the largest GSO packet the link may build, in bytes (equivalent to `ip link set DEV
+    /// gso_max_size SIZE`). It cannot exceed the TSO limit of the device, reported in
+    /// [`Nla::TsoMaxSize`].
+    ///
+    /// Older kernels only accept this attribute when creating a link, and silently ignore it
+    /// here.
+    pub fn gso_max_size(mut self, size: u32) -> Self {
+        self.message.nlas.push(Nla::GsoMaxSize(size));
+        self
+    }
+
+    /// Set the maximum number of segments of the GSO packets the link may build (equivalent to
+    /// `ip link set DEV gso_max_segs SEGMENTS`). It cannot exceed the TSO limit of the device,
+    /// reported in [`Nla::TsoMaxSegs`].
+    ///
+    /// Like [`gso_max_size`](LinkSetRequest::gso_max_size), older kernels ignore it.
+    pub fn gso_max_segs(mut self, segments: u32) -> Self {
+        self.message.nlas.push(Nla::GsoMaxSegs(segments));
+        self
+    }
+
     /// Set the hardware address of the link with the given index (equivalent to `ip link set DEV address ADDRESS`)
     pub fn address(mut self, address: Vec<u8>) -> Self {
         self.message.nlas.push(Nla::Address(address));
         self
     }
 
+    /// Set the broadcast address of the link (equivalent to `ip link set DEV broadcast ADDRESS`)
+    ///
+    /// This is often combined with a [`down`](LinkSetRequest::down) then
+    /// [`up`](LinkSetRequest::up) cycle after a failover, in two separate requests: bringing the
+    /// link up makes the kernel announce its addresses with gratuitous ARP (when the `arp_notify`
+    /// sysctl of the interface is set) and unsolicited neighbour advertisements (`ndisc_notify`),
+    /// so that the neighbours update their caches.
+    pub fn broadcast(mut self, address: [u8; 6]) -> Self {
+        self.message.nlas.push(Nla::Broadcast(address.to_vec()));
+        self
+    }
+
     /// Move this network device into the network namespace of the process with the given `pid`.
     pub fn setns_by_pid(mut self, pid: u32) -> Self {
         self.message.nlas.push(Nla::NetNsPid(pid));
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use nix::errno::Errno;
+
+use crate::{
+    packet::{NetlinkMessage, NetlinkPayload, RtnlMessage, StatsMessage, NLM_F_REQUEST},
+    Error,
+    Handle,
+};
+
+/// A request for the statistics of a link (`RTM_GETSTATS`), equivalent to `ip stats show dev
+/// DEV`. Unlike `RTM_GETLINK`, it can report extended statistics specific to the kind of the
+/// link.
+pub struct LinkGetStatsRequest {
+    handle: Handle,
+    message: StatsMessage,
+}
+
+impl LinkGetStatsRequest {
+    pub(crate) fn new(handle: Handle, index: u32, filter_mask: u32) -> Self {
+        let mut message = StatsMessage::default();
+        message.header.ifindex = index;
+        message.header.filter_mask = filter_mask;
+        LinkGetStatsRequest { handle, message }
+    }
+
+    /// Execute the request. If the link does not exist, this returns `Error::LinkNotFound`.
+    pub async fn execute(self) -> Result<StatsMessage, Error> {
+        let LinkGetStatsRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let index = message.header.ifindex;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+        req.header.flags = NLM_F_REQUEST;
+
+        let mut response = handle.request(req)?;
+        let mut stats = None;
+        while let Some(message) = response.next().await {
+            let (header, payload) = message.into_parts();
+            match payload {
+                NetlinkPayload::InnerMessage(RtnlMessage::NewStats(msg)) => stats = Some(msg),
+                // The kernel answers with ENODEV if no such link exists
+                NetlinkPayload::Error(err) if err.code == -(Errno::ENODEV as i32) => {
+                    return Err(Error::LinkNotFound(index))
+                }
+                NetlinkPayload::Error(err) => return Err(Error::NetlinkError(err)),
+                payload => {
+                    return Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                        header, payload,
+                    )))
+                }
+            }
+        }
+        stats.ok_or(Error::RequestFailed)
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+}
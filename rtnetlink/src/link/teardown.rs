@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+use nix::errno::Errno;
+
+use crate::{
+    packet::{AddressMessage, NeighbourMessage},
+    AddressHandle,
+    Error,
+    Handle,
+    LinkSetRequest,
+    NeighbourHandle,
+};
+
+/// A request to bring a link down and clean up its state, see [`LinkHandle::teardown`]
+///
+/// [`LinkHandle::teardown`]: crate::LinkHandle::teardown
+pub struct LinkTeardownRequest {
+    handle: Handle,
+    index: u32,
+    flush_addresses: bool,
+    flush_neighbours: bool,
+}
+
+/// Whether deleting an entry failed because it does not exist anymore
+fn already_gone(err: &Error) -> bool {
+    let errno = err.raw_os_error();
+    errno == Some(Errno::ENOENT as i32) || errno == Some(Errno::EADDRNOTAVAIL as i32)
+}
+
+impl LinkTeardownRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        LinkTeardownRequest {
+            handle,
+            index,
+            flush_addresses: false,
+            flush_neighbours: false,
+        }
+    }
+
+    /// Also delete all the addresses of the link, of all families (equivalent to
+    /// `ip address flush dev DEV`)
+    pub fn flush_addresses(mut self) -> Self {
+        self.flush_addresses = true;
+        self
+    }
+
+    /// Also delete all the neighbour entries of the link, including the permanent ones (equivalent
+    /// to `ip neighbour flush dev DEV nud all`). Proxy entries are left alone.
+    pub fn flush_neighbours(mut self) -> Self {
+        self.flush_neighbours = true;
+        self
+    }
+
+    /// Execute the request.
+    ///
+    /// The link is brought down first, then its addresses are flushed, then its neighbours. While
+    /// the link is up, traffic keeps creating neighbour entries and the kernel can configure
+    /// addresses again (IPv6 autoconfiguration for instance), so flushing first could leave some
+    /// state behind. Going down also makes the kernel remove some of this state itself, like the
+    /// IPv6 addresses and the dynamic neighbour entries: the entries that disappear between the
+    /// dump and their deletion are not an error.
+    pub async fn execute(self) -> Result<(), Error> {
+        let LinkTeardownRequest {
+            handle,
+            index,
+            flush_addresses,
+            flush_neighbours,
+        } = self;
+
+        LinkSetRequest::new(handle.clone(), index)
+            .down()
+            .execute()
+            .await?;
+
+        if flush_addresses {
+            let addresses = AddressHandle::new(handle.clone());
+            let messages: Vec<AddressMessage> = addresses
+                .get()
+                .set_link_index_filter(index)
+                .execute()
+                .try_collect()
+                .await?;
+            for message in messages {
+                if let Err(err) = addresses.del(message).execute().await {
+                    if !already_gone(&err) {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        if flush_neighbours {
+            let neighbours = NeighbourHandle::new(handle.clone());
+            let messages: Vec<NeighbourMessage> = neighbours
+                .get()
+                .set_link_index_filter(index)
+                .execute()
+                .try_collect()
+                .await?;
+            for message in messages {
+                if let Err(err) = neighbours.del(message).execute().await {
+                    if !already_gone(&err) {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
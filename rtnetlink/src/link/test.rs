@@ -1,16 +1,52 @@
 // SPDX-License-Identifier: MIT
 
-use futures::stream::TryStreamExt;
+use std::{
+    fs::File,
+    net::{IpAddr, Ipv4Addr, UdpSocket},
+    os::unix::io::AsRawFd,
+};
+
+use futures::{future, stream::TryStreamExt};
 use tokio::runtime::Runtime;
 
 use crate::{
     new_connection,
-    packet::rtnl::link::{
-        nlas::{Info, InfoKind, Nla},
-        LinkMessage,
+    packet::rtnl::{
+        constants::*,
+        link::{
+            nlas::{
+                AfSpecBridge,
+                AfSpecInet,
+                BridgeVlanInfo,
+                ExtFilterMask,
+                Inet6,
+                Info,
+                InfoBareUdp,
+                InfoData,
+                InfoGtp,
+                InfoIpTun,
+                InfoIpoib,
+                InfoKind,
+                InfoMacSec,
+                InfoVlan,
+                InfoVxlan,
+                IpoibMode,
+                Nla,
+                State,
+                VlanQosMapping,
+            },
+            LinkMessage,
+            LinkMessageBuffer,
+        },
+        traits::{Emitable, Parseable},
+        InterfaceFlags,
+        StatsMessage,
     },
     Error,
+    GtpRole,
     LinkHandle,
+    NetworkNamespace,
+    NETNS_PATH,
 };
 
 const IFACE_NAME: &str = "wg142"; // rand?
@@ -31,6 +67,1266 @@ fn create_get_delete_wg() {
     rt.block_on(_del_wg(&mut handle, msg.header.index)).unwrap();
 }
 
+#[test]
+fn get_link_by_index() {
+    let rt = Runtime::new().unwrap();
+    // The loopback interface always has index 1.
+    let msg = rt.block_on(_get_by_index(1)).unwrap();
+    assert_eq!(msg.header.index, 1);
+    assert!(has_nla(&msg, &Nla::IfName("lo".to_owned())));
+}
+
+#[test]
+fn get_link_by_index_not_found() {
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(_get_by_index(u32::MAX));
+    assert_eq!(res, Err(Error::LinkNotFound(u32::MAX)));
+}
+
+#[test]
+fn link_not_found_error_kind() {
+    let rt = Runtime::new().unwrap();
+    let err = rt.block_on(_get_by_index(u32::MAX)).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(nix::errno::Errno::ENODEV as i32));
+    assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::NotFound));
+}
+
+#[test]
+fn get_link_skip_stats() {
+    let rt = Runtime::new().unwrap();
+    let (with_stats, without_stats) = rt
+        .block_on(async {
+            Ok::<_, Error>((
+                _lo_inet6_stats(ExtFilterMask::empty()).await?,
+                _lo_inet6_stats(ExtFilterMask::SKIP_STATS).await?,
+            ))
+        })
+        .unwrap();
+    // IPv6 may be disabled, in which case there are no IPv6 statistics at all
+    if with_stats.is_some() {
+        assert_eq!(with_stats, Some(true));
+        assert_eq!(without_stats, Some(false));
+    }
+}
+
+/// Return whether the IPv6 attributes of the loopback interface, if any, include its statistics
+async fn _lo_inet6_stats(mask: ExtFilterMask) -> Result<Option<bool>, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut links = handle
+        .link()
+        .get()
+        .match_index(1)
+        .with_ext_filter(mask)
+        .execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    let inet6 = msg.nlas.into_iter().find_map(|nla| match nla {
+        Nla::AfSpecInet(af_spec) => af_spec.into_iter().find_map(|nla| match nla {
+            AfSpecInet::Inet6(inet6) => Some(inet6),
+            _ => None,
+        }),
+        _ => None,
+    });
+    Ok(inet6.map(|nlas| {
+        nlas.iter()
+            .any(|nla| matches!(nla, Inet6::Stats(_) | Inet6::IcmpStats(_)))
+    }))
+}
+
+#[test]
+fn get_links_with_vf_info() {
+    let rt = Runtime::new().unwrap();
+    let links: Vec<LinkMessage> = rt
+        .block_on(async {
+            let (conn, handle, _) = new_connection().unwrap();
+            tokio::spawn(conn);
+            handle
+                .link()
+                .get()
+                .with_ext_filter(ExtFilterMask::VF)
+                .execute()
+                .try_collect()
+                .await
+        })
+        .unwrap();
+    let sriov: Vec<_> = links
+        .iter()
+        .filter(|link| {
+            link.nlas
+                .iter()
+                .any(|nla| matches!(nla, Nla::NumVf(n) if *n > 0))
+        })
+        .collect();
+    // Without SR-IOV hardware, there is nothing to check
+    for link in sriov {
+        assert!(link
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, Nla::VfInfoList(_))));
+    }
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_existing_link_error_kind() {
+    let rt = Runtime::new().unwrap();
+    let err = rt.block_on(_create_dummy_twice()).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(nix::errno::Errno::EEXIST as i32));
+    assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::AlreadyExists));
+}
+
+#[test]
+fn create_link_permission_denied() {
+    // Root is allowed to create links
+    if nix::unistd::geteuid().is_root() {
+        return;
+    }
+    let rt = Runtime::new().unwrap();
+    let err = rt
+        .block_on(async {
+            let (conn, handle, _) = new_connection().unwrap();
+            tokio::spawn(conn);
+            handle.link().add().dummy("dummy185".into()).execute().await
+        })
+        .unwrap_err();
+    assert!(err.is_permission_denied());
+    assert_eq!(err.raw_os_error(), Some(nix::errno::Errno::EPERM as i32));
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_link_returns_index() {
+    let rt = Runtime::new().unwrap();
+    let (index, msg) = rt.block_on(_create_link_returns_index()).unwrap();
+    assert_eq!(index, msg.header.index);
+}
+
+async fn _create_link_returns_index() -> Result<(u32, LinkMessage), Error> {
+    const NAME: &str = "dummy144";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok((index, msg))
+}
+
+async fn _create_dummy_twice() -> Result<(), Error> {
+    const NAME: &str = "dummy143dup";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let res = link_handle.add().dummy(NAME.to_owned()).execute().await.map(|_| ());
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    res
+}
+
+async fn _get_by_index(index: u32) -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut links = handle.link().get().match_index(index).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    // A single link was requested, so nothing should follow it
+    assert!(links.try_next().await?.is_none());
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_link_by_alt_name() {
+    let rt = Runtime::new().unwrap();
+    let (index, msg) = rt.block_on(_get_link_by_alt_name()).unwrap();
+    assert_eq!(msg.header.index, index);
+    assert_eq!(msg.alt_names(), vec![ALT_NAME.to_owned()]);
+}
+
+// Longer than the 15 bytes allowed for the primary name
+const ALT_NAME: &str = "dummy156-with-a-rather-long-alternative-name";
+
+async fn _get_link_by_alt_name() -> Result<(u32, LinkMessage), Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy("dummy156".to_owned()).execute().await?;
+    let res = async {
+        link_handle
+            .property_add(index)
+            .alt_ifname(&[ALT_NAME])
+            .execute()
+            .await?;
+        let mut links = link_handle
+            .get()
+            .match_alt_name(ALT_NAME.to_owned())
+            .execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        Ok((index, msg))
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_del_alt_names() {
+    let rt = Runtime::new().unwrap();
+    let (added, deleted) = rt.block_on(_add_del_alt_names()).unwrap();
+    assert_eq!(added, vec!["dummy157-first".to_owned(), "dummy157-second".to_owned()]);
+    assert_eq!(deleted, vec!["dummy157-second".to_owned()]);
+}
+
+async fn _add_del_alt_names() -> Result<(Vec<String>, Vec<String>), Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy("dummy157".to_owned()).execute().await?;
+    let res = async {
+        for alt_name in ["dummy157-first", "dummy157-second"] {
+            link_handle
+                .add_alt_name(index, alt_name.to_owned())
+                .execute()
+                .await?;
+        }
+        let mut links = link_handle.get().match_index(index).execute();
+        let added = links.try_next().await?.ok_or(Error::RequestFailed)?.alt_names();
+
+        link_handle
+            .del_alt_name(index, "dummy157-first".to_owned())
+            .execute()
+            .await?;
+        let mut links = link_handle.get().match_index(index).execute();
+        let deleted = links.try_next().await?.ok_or(Error::RequestFailed)?.alt_names();
+        Ok((added, deleted))
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn set_broadcast_address() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_set_broadcast_address()).unwrap();
+    assert!(has_nla(&msg, &Nla::Broadcast(BROADCAST_ADDRESS.to_vec())));
+}
+
+const BROADCAST_ADDRESS: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x01, 0x66];
+
+async fn _set_broadcast_address() -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy("dummy166".to_owned()).execute().await?;
+    let res = async {
+        link_handle
+            .set(index)
+            .broadcast(BROADCAST_ADDRESS)
+            .execute()
+            .await?;
+        let mut links = link_handle.get().match_index(index).execute();
+        links.try_next().await?.ok_or(Error::RequestFailed)
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_dummy_with_address() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_dummy_with_address()).unwrap();
+    assert!(has_nla(&msg, &Nla::Address(DUMMY_ADDRESS.to_vec())));
+}
+
+const DUMMY_NAME: &str = "dummy142";
+const DUMMY_ADDRESS: [u8; 6] = [0x02, 0x00, 0x00, 0x00, 0x00, 0x01];
+
+async fn _create_dummy_with_address() -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .dummy(DUMMY_NAME.to_owned())
+        .address(DUMMY_ADDRESS)
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(DUMMY_NAME.to_owned())
+        .execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_dummy_with_mtu() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_dummy_with_mtu()).unwrap();
+    assert!(has_nla(&msg, &Nla::Mtu(9000)));
+}
+
+async fn _create_dummy_with_mtu() -> Result<LinkMessage, Error> {
+    const NAME: &str = "dummy176";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle
+        .add()
+        .dummy(NAME.to_owned())
+        .mtu(9000)
+        .execute()
+        .await?;
+    let res = _get_by_index(index).await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_link_up_with_txqueuelen() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_link_up_with_txqueuelen()).unwrap();
+    assert!(msg.interface_flags().contains(InterfaceFlags::UP));
+    // Dummy links do not report their operational state, but they are not down either
+    assert!(has_nla(&msg, &Nla::OperState(State::Unknown)));
+    assert!(has_nla(&msg, &Nla::TxQueueLen(5000)));
+}
+
+async fn _create_link_up_with_txqueuelen() -> Result<LinkMessage, Error> {
+    const NAME: &str = "dummy196";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    // Unlike dummy(), kind_raw() does not set the link up
+    let index = link_handle
+        .add()
+        .kind_raw(NAME.to_owned(), "dummy".to_owned(), vec![])
+        .up()
+        .txqueuelen(5000)
+        .execute()
+        .await?;
+    let res = _get_by_index(index).await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn teardown_link() {
+    let rt = Runtime::new().unwrap();
+    let (msg, addresses, neighbours) = rt.block_on(_teardown_link()).unwrap();
+    assert!(!msg.interface_flags().contains(InterfaceFlags::UP));
+    assert_eq!(addresses, 0);
+    assert_eq!(neighbours, 0);
+}
+
+async fn _teardown_link() -> Result<(LinkMessage, usize, usize), Error> {
+    const NAME: &str = "dummy199";
+    const LLADDR: [u8; 6] = [0x02, 0, 0, 0, 0x01, 0x99];
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let res = async {
+        // Unlike IPv6 addresses, IPv4 addresses are kept when the link goes down
+        handle
+            .address()
+            .add(index, IpAddr::V4(Ipv4Addr::new(10, 199, 0, 1)), 24)
+            .execute()
+            .await?;
+        handle
+            .neighbours()
+            .add(index, IpAddr::V4(Ipv4Addr::new(10, 199, 0, 2)))
+            .state(NUD_PERMANENT)
+            .link_local_address(&LLADDR)
+            .execute()
+            .await?;
+
+        link_handle
+            .teardown(index)
+            .flush_addresses()
+            .flush_neighbours()
+            .execute()
+            .await?;
+
+        let msg = _get_by_index(index).await?;
+        let addresses = handle
+            .address()
+            .get()
+            .set_link_index_filter(index)
+            .execute()
+            .try_fold(0, |count, _| future::ready(Ok(count + 1)))
+            .await?;
+        let neighbours = handle
+            .neighbours()
+            .get()
+            .set_link_index_filter(index)
+            .execute()
+            .try_fold(0, |count, _| future::ready(Ok(count + 1)))
+            .await?;
+        Ok((msg, addresses, neighbours))
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn set_gso_limits() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_set_gso_limits()).unwrap();
+    assert!(has_nla(&msg, &Nla::GsoMaxSize(32768)));
+    assert!(has_nla(&msg, &Nla::GsoMaxSegs(100)));
+    // Older kernels do not report the TSO limits
+    for nla in msg.nlas.iter() {
+        match nla {
+            Nla::TsoMaxSize(size) => assert!(*size >= 32768),
+            Nla::TsoMaxSegs(segments) => assert!(*segments >= 100),
+            _ => {}
+        }
+    }
+}
+
+async fn _set_gso_limits() -> Result<LinkMessage, Error> {
+    const NAME: &str = "dummy188";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let res = async {
+        link_handle
+            .set(index)
+            .gso_max_size(32768)
+            .gso_max_segs(100)
+            .execute()
+            .await?;
+        let mut links = link_handle.get().match_index(index).execute();
+        links.try_next().await?.ok_or(Error::RequestFailed)
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_vlan_with_egress_qos_map() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_vlan_with_egress_qos_map()).unwrap();
+    let vlan_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::Vlan(vlan_infos)) => Some(vlan_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(vlan_infos.contains(&InfoVlan::EgressQos(vec![VlanQosMapping::Mapping {
+        from: 1,
+        to: 3
+    }])));
+}
+
+const VLAN_PARENT_NAME: &str = "dummy143";
+const VLAN_NAME: &str = "vlan143";
+
+async fn _create_vlan_with_egress_qos_map() -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .dummy(VLAN_PARENT_NAME.to_owned())
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(VLAN_PARENT_NAME.to_owned())
+        .execute();
+    let parent = links.try_next().await?.ok_or(Error::RequestFailed)?;
+
+    let res = async {
+        link_handle
+            .add()
+            .vlan(VLAN_NAME.to_owned(), parent.header.index, 143)
+            .egress_qos_map(&[(1, 3)])
+            .execute()
+            .await?;
+        let mut links = link_handle
+            .get()
+            .set_name_filter(VLAN_NAME.to_owned())
+            .execute();
+        links.try_next().await?.ok_or(Error::RequestFailed)
+    }
+    .await;
+    // Deleting the parent also deletes the VLAN
+    link_handle.del(parent.header.index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_multiqueue_veth() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_multiqueue_veth()).unwrap();
+    assert!(has_nla(&msg, &Nla::NumTxQueues(4)));
+    assert!(has_nla(&msg, &Nla::NumRxQueues(2)));
+}
+
+const VETH_NAME: &str = "veth144";
+const VETH_PEER_NAME: &str = "veth144p";
+
+async fn _create_multiqueue_veth() -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    // The queue counts apply to the link named after the second argument
+    link_handle
+        .add()
+        .veth(VETH_PEER_NAME.to_owned(), VETH_NAME.to_owned())
+        .num_tx_queues(4)
+        .num_rx_queues(2)
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(VETH_NAME.to_owned())
+        .execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    // Deleting one end of the pair also deletes the other one
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_external_vxlan() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_external_vxlan()).unwrap();
+    let vxlan_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::Vxlan(vxlan_infos)) => Some(vxlan_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(vxlan_infos.contains(&InfoVxlan::CollectMetadata(1)));
+}
+
+const VXLAN_NAME: &str = "vxlan145";
+
+async fn _create_external_vxlan() -> Result<LinkMessage, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .vxlan_external(VXLAN_NAME.to_owned())
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(VXLAN_NAME.to_owned())
+        .execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_links_by_master() {
+    let rt = Runtime::new().unwrap();
+    let mut names = rt.block_on(_get_links_by_master()).unwrap();
+    names.sort();
+    assert_eq!(names, vec![PORT_NAMES[0].to_owned(), PORT_NAMES[1].to_owned()]);
+}
+
+const BRIDGE_NAME: &str = "br146";
+const PORT_NAMES: [&str; 2] = ["dummy146a", "dummy146b"];
+const OTHER_NAME: &str = "dummy146c";
+
+async fn _get_links_by_master() -> Result<Vec<String>, Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    let mut indexes = vec![];
+    link_handle
+        .add()
+        .bridge(BRIDGE_NAME.to_owned())
+        .execute()
+        .await?;
+    for name in [BRIDGE_NAME, PORT_NAMES[0], PORT_NAMES[1], OTHER_NAME].iter() {
+        if *name != BRIDGE_NAME {
+            link_handle.add().dummy((*name).to_owned()).execute().await?;
+        }
+        let mut links = link_handle.get().set_name_filter((*name).to_owned()).execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        indexes.push(msg.header.index);
+    }
+
+    let res = async {
+        for index in &indexes[1..3] {
+            link_handle.set(*index).master(indexes[0]).execute().await?;
+        }
+        let mut names = vec![];
+        let mut links = link_handle.get().set_master_filter(indexes[0]).execute();
+        while let Some(msg) = links.try_next().await? {
+            for nla in msg.nlas.into_iter() {
+                if let Nla::IfName(name) = nla {
+                    names.push(name);
+                }
+            }
+        }
+        Ok(names)
+    }
+    .await;
+    for index in indexes {
+        link_handle.del(index).execute().await?;
+    }
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn set_nomaster() {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(_set_nomaster()).unwrap();
+}
+
+async fn _set_nomaster() -> Result<(), Error> {
+    const BRIDGE_NAME: &str = "br172";
+    const PORT_NAME: &str = "dummy172";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    let mut indexes = vec![];
+    link_handle
+        .add()
+        .bridge(BRIDGE_NAME.to_owned())
+        .execute()
+        .await?;
+    link_handle
+        .add()
+        .dummy(PORT_NAME.to_owned())
+        .execute()
+        .await?;
+    for name in [BRIDGE_NAME, PORT_NAME].iter() {
+        let mut links = link_handle
+            .get()
+            .set_name_filter((*name).to_owned())
+            .execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        indexes.push(msg.header.index);
+    }
+    let (bridge, port) = (indexes[0], indexes[1]);
+
+    let res = async {
+        for index in &indexes {
+            link_handle.set(*index).up().execute().await?;
+        }
+        link_handle.set(port).master(bridge).execute().await?;
+        let mut links = link_handle.get().match_index(port).execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        assert!(msg.nlas.contains(&Nla::Master(bridge)));
+
+        // A zero index would detach the link as well, but is rejected before being sent
+        let err = link_handle.set(port).master(0).execute().await.unwrap_err();
+        assert!(matches!(err, Error::InvalidMasterIndex));
+
+        link_handle.set(port).nomaster().execute().await?;
+        let mut links = link_handle.get().match_index(port).execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        assert!(!msg.nlas.iter().any(|nla| matches!(nla, Nla::Master(_))));
+        Ok(())
+    }
+    .await;
+    for index in indexes {
+        link_handle.del(index).execute().await?;
+    }
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_bridge_vlan() {
+    let rt = Runtime::new().unwrap();
+    let vlans = rt.block_on(_add_bridge_vlan()).unwrap();
+    assert!(vlans.contains(&BridgeVlanInfo {
+        flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+        vid: 10,
+    }));
+    // Without RTEXT_FILTER_BRVLAN_COMPRESSED, ranges are dumped one VLAN at a time
+    for vid in 20..=30 {
+        assert!(vlans.contains(&BridgeVlanInfo { flags: 0, vid }));
+    }
+}
+
+async fn _add_bridge_vlan() -> Result<Vec<BridgeVlanInfo>, Error> {
+    const BRIDGE_NAME: &str = "br148";
+    const PORT_NAME: &str = "dummy148";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    let mut indexes = vec![];
+    link_handle
+        .add()
+        .bridge(BRIDGE_NAME.to_owned())
+        .execute()
+        .await?;
+    link_handle.add().dummy(PORT_NAME.to_owned()).execute().await?;
+    for name in [BRIDGE_NAME, PORT_NAME].iter() {
+        let mut links = link_handle.get().set_name_filter((*name).to_owned()).execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        indexes.push(msg.header.index);
+    }
+    let (bridge, port) = (indexes[0], indexes[1]);
+
+    let res = async {
+        link_handle.set(port).master(bridge).execute().await?;
+        link_handle
+            .bridge_vlan_add(port, 10)
+            .pvid()
+            .untagged()
+            .execute()
+            .await?;
+        link_handle
+            .bridge_vlan_add(port, 20)
+            .range(30)
+            .execute()
+            .await?;
+
+        let mut vlans = vec![];
+        let mut links = link_handle
+            .get()
+            .set_filter_mask(AF_BRIDGE as u8, RTEXT_FILTER_BRVLAN)
+            .execute();
+        while let Some(msg) = links.try_next().await? {
+            if msg.header.index != port {
+                continue;
+            }
+            for nla in msg.nlas.into_iter() {
+                if let Nla::AfSpecBridge(nlas) = nla {
+                    for nla in nlas {
+                        if let AfSpecBridge::VlanInfo(info) = nla {
+                            vlans.push(info);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(vlans)
+    }
+    .await;
+    for index in indexes {
+        link_handle.del(index).execute().await?;
+    }
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn rename_link() {
+    let rt = Runtime::new().unwrap();
+    let names = rt.block_on(_rename_link()).unwrap();
+    assert_eq!(names, vec!["uplink149".to_owned()]);
+}
+
+async fn _rename_link() -> Result<Vec<String>, Error> {
+    const OLD_NAME: &str = "dummy149";
+    const NEW_NAME: &str = "uplink149";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    link_handle.add().dummy(OLD_NAME.to_owned()).execute().await?;
+    let mut links = link_handle.get().set_name_filter(OLD_NAME.to_owned()).execute();
+    let index = links
+        .try_next()
+        .await?
+        .ok_or(Error::RequestFailed)?
+        .header
+        .index;
+
+    let res = async {
+        link_handle.set(index).down().execute().await?;
+        link_handle
+            .set(index)
+            .name(NEW_NAME.to_owned())
+            .execute()
+            .await?;
+        let msg = link_handle
+            .get()
+            .match_index(index)
+            .execute()
+            .try_next()
+            .await?
+            .ok_or(Error::RequestFailed)?;
+        Ok(msg
+            .nlas
+            .into_iter()
+            .filter_map(|nla| match nla {
+                Nla::IfName(name) => Some(name),
+                _ => None,
+            })
+            .collect())
+    }
+    .await;
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+fn rename_link_invalid_name() {
+    let rt = Runtime::new().unwrap();
+    let err = rt
+        .block_on(async {
+            let (conn, handle, _) = new_connection().unwrap();
+            tokio::spawn(conn);
+            handle
+                .link()
+                .set(1)
+                .name("a-name-way-too-long".to_owned())
+                .execute()
+                .await
+        })
+        .unwrap_err();
+    assert_eq!(
+        err,
+        Error::InvalidLinkName("a-name-way-too-long".to_owned())
+    );
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_get_delete_macsec() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_macsec()).unwrap();
+    let macsec_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::MacSec(macsec_infos)) => Some(macsec_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(macsec_infos.contains(&InfoMacSec::CipherSuite(MACSEC_CIPHER_ID_GCM_AES_256)));
+    assert!(macsec_infos.contains(&InfoMacSec::Encrypt(1)));
+}
+
+async fn _create_macsec() -> Result<LinkMessage, Error> {
+    const PARENT_NAME: &str = "dummy150";
+    const MACSEC_NAME: &str = "macsec150";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .dummy(PARENT_NAME.to_owned())
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(PARENT_NAME.to_owned())
+        .execute();
+    let parent = links.try_next().await?.ok_or(Error::RequestFailed)?;
+
+    let res = async {
+        link_handle
+            .add()
+            .macsec(MACSEC_NAME.to_owned(), parent.header.index)
+            .cipher(MACSEC_CIPHER_ID_GCM_AES_256)
+            .encrypt(true)
+            .execute()
+            .await?;
+        let mut links = link_handle
+            .get()
+            .set_name_filter(MACSEC_NAME.to_owned())
+            .execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        link_handle.del(msg.header.index).execute().await?;
+        Ok(msg)
+    }
+    .await;
+    link_handle.del(parent.header.index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_get_delete_bareudp() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_bareudp()).unwrap();
+    let bareudp_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::BareUdp(bareudp_infos)) => Some(bareudp_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(bareudp_infos.contains(&InfoBareUdp::Port(6635)));
+    assert!(bareudp_infos.contains(&InfoBareUdp::EtherType(0x8847)));
+}
+
+async fn _create_bareudp() -> Result<LinkMessage, Error> {
+    const NAME: &str = "bareudp151";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .bareudp(NAME.to_owned())
+        .dst_port(6635)
+        .ethertype(0x8847)
+        .execute()
+        .await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_get_delete_ipip() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_ipip()).unwrap();
+    let iptun_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::IpTun(iptun_infos)) => Some(iptun_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(iptun_infos.contains(&InfoIpTun::Local(vec![10, 186, 0, 1])));
+    assert!(iptun_infos.contains(&InfoIpTun::Remote(vec![10, 186, 0, 2])));
+    assert!(iptun_infos.contains(&InfoIpTun::Ttl(64)));
+}
+
+async fn _create_ipip() -> Result<LinkMessage, Error> {
+    const NAME: &str = "ipip186";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .ipip(NAME.to_owned())
+        .local("10.186.0.1".parse().unwrap())
+        .remote("10.186.0.2".parse().unwrap())
+        .ttl(64)
+        .execute()
+        .await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_get_delete_gtp() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(_create_gtp()).unwrap();
+    let gtp_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::Gtp(gtp_infos)) => Some(gtp_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    assert!(gtp_infos.contains(&InfoGtp::Role(GTP_ROLE_SGSN)));
+}
+
+async fn _create_gtp() -> Result<LinkMessage, Error> {
+    const NAME: &str = "gtp150";
+
+    // The GTP links use sockets bound to the GTPv0 and GTPv1-U ports
+    let gtp0 = UdpSocket::bind("0.0.0.0:3386").unwrap();
+    let gtp1u = UdpSocket::bind("0.0.0.0:2152").unwrap();
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle
+        .add()
+        .gtp(NAME.to_owned())
+        .fd0(gtp0.as_raw_fd())
+        .fd1(gtp1u.as_raw_fd())
+        .role(GtpRole::Sgsn)
+        .pdp_hashsize(1024)
+        .execute()
+        .await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(msg)
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_macvlan_with_parent_in_other_netns() {
+    const NS_NAME: &str = "link-netnsid-test";
+
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(async {
+        NetworkNamespace::add(NS_NAME.to_owned()).await?;
+        let res = _create_macvlan_with_parent_in(NS_NAME).await;
+        NetworkNamespace::del(NS_NAME.to_owned()).await?;
+        res
+    });
+    let (parent_index, msg) = res.unwrap();
+    assert!(has_nla(&msg, &Nla::Link(parent_index)));
+    assert!(has_nla(&msg, &Nla::NetnsId(153)));
+}
+
+async fn _create_macvlan_with_parent_in(ns_name: &str) -> Result<(u32, LinkMessage), Error> {
+    const NSID: i32 = 153;
+    const PARENT_NAME: &str = "dummy153";
+    const MACVLAN_NAME: &str = "macvlan153";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    // The nsid must be known before it can be used in IFLA_LINK_NETNSID
+    let ns_file = File::open(format!("{}{}", NETNS_PATH, ns_name)).unwrap();
+    handle.nsid().assign(ns_file.as_raw_fd(), NSID).execute().await?;
+
+    link_handle
+        .add()
+        .dummy(PARENT_NAME.to_owned())
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(PARENT_NAME.to_owned())
+        .execute();
+    let parent_index = links.try_next().await?.ok_or(Error::RequestFailed)?.header.index;
+    // The link keeps its index in the other namespace, which only contains the loopback
+    link_handle
+        .set(parent_index)
+        .setns_by_fd(ns_file.as_raw_fd())
+        .execute()
+        .await?;
+
+    link_handle
+        .add()
+        .macvlan(MACVLAN_NAME.to_owned(), parent_index, MACVLAN_MODE_BRIDGE)
+        .link_netnsid(NSID)
+        .execute()
+        .await?;
+    let mut links = link_handle
+        .get()
+        .set_name_filter(MACVLAN_NAME.to_owned())
+        .execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    // The parent is deleted along with the namespace
+    Ok((parent_index, msg))
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn create_get_delete_ipoib() {
+    let rt = Runtime::new().unwrap();
+    // IPoIB interfaces can only be created on top of an InfiniBand link
+    let msg = match rt.block_on(_create_ipoib()).unwrap() {
+        Some(msg) => msg,
+        None => return,
+    };
+    let ipoib_infos = msg
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::Info(infos) => infos.iter().find_map(|info| match info {
+                Info::Data(InfoData::Ipoib(ipoib_infos)) => Some(ipoib_infos),
+                _ => None,
+            }),
+            _ => None,
+        })
+        .unwrap();
+    // The kernel sets the full membership bit
+    assert!(ipoib_infos.contains(&InfoIpoib::Pkey(0x8154)));
+    assert!(ipoib_infos.contains(&InfoIpoib::Mode(IpoibMode::Datagram.into())));
+}
+
+async fn _create_ipoib() -> Result<Option<LinkMessage>, Error> {
+    const NAME: &str = "ipoib154";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    let parent_index = link_handle
+        .get()
+        .execute()
+        .try_filter(|msg| future::ready(msg.header.link_layer_type == ARPHRD_INFINIBAND))
+        .try_next()
+        .await?
+        .map(|msg| msg.header.index);
+    let parent_index = match parent_index {
+        Some(index) => index,
+        None => return Ok(None),
+    };
+
+    link_handle
+        .add()
+        .ipoib(NAME.to_owned(), parent_index)
+        .pkey(0x0154)
+        .mode(IpoibMode::Datagram)
+        .execute()
+        .await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    link_handle.del(msg.header.index).execute().await?;
+    Ok(Some(msg))
+}
+
+// Creating a link with a parent device requires hardware, so only check the request
+#[test]
+fn parent_dev_name_serialization() {
+    let rt = Runtime::new().unwrap();
+    let msg = rt.block_on(async {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+        let mut request = handle.link().add().parent_dev_name("0000:03:00.0".to_owned());
+        request.message_mut().clone()
+    });
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    // A nul terminated string, padded to 4 bytes
+    #[rustfmt::skip]
+    let nla = [
+        0x11, 0x00, 0x38, 0x00, // length 17, type 56 (IFLA_PARENT_DEV_NAME)
+        0x30, 0x30, 0x30, 0x30, 0x3a, 0x30, 0x33, 0x3a, 0x30, 0x30, 0x2e, 0x30, // 0000:03:00.0
+        0x00, 0x00, 0x00, 0x00, // nul byte and padding
+    ];
+    assert!(buf.windows(nla.len()).any(|window| window == nla));
+
+    let parsed = LinkMessage::parse(&LinkMessageBuffer::new(&buf)).unwrap();
+    assert!(has_nla(&parsed, &Nla::ParentDevName("0000:03:00.0".to_owned())));
+}
+
+#[test]
+fn raw_kind_serialization() {
+    let rt = Runtime::new().unwrap();
+    let (typed, raw) = rt.block_on(async {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+        let mut typed = handle.link().add().dummy("dummy154".into());
+        let mut raw = handle
+            .link()
+            .add()
+            .kind_raw("dummy154".into(), "dummy".into(), vec![])
+            .flags(InterfaceFlags::UP, InterfaceFlags::UP);
+        (typed.message_mut().clone(), raw.message_mut().clone())
+    });
+    assert_eq!(emit_link(&typed), emit_link(&raw));
+
+    let (typed, raw) = rt.block_on(async {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+        let mut typed = handle.link().add().vlan("vlan154".into(), 6, 42);
+        let mut raw = handle
+            .link()
+            .add()
+            .kind_raw(
+                "vlan154".into(),
+                "vlan".into(),
+                vec![(IFLA_VLAN_ID, 42u16.to_ne_bytes().to_vec())],
+            )
+            .flags(InterfaceFlags::UP, InterfaceFlags::UP);
+        raw.message_mut().nlas.push(Nla::Link(6));
+        (typed.message_mut().clone(), raw.message_mut().clone())
+    });
+    assert_eq!(emit_link(&typed), emit_link(&raw));
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_link_stats() {
+    let rt = Runtime::new().unwrap();
+    let (index, stats) = rt.block_on(_get_link_stats()).unwrap();
+    assert_eq!(stats.header.ifindex, index);
+    assert_eq!(stats.header.filter_mask, IFLA_STATS_FILTER_LINK_64);
+    // Nothing was sent through the new link, so it has no error
+    let link_64 = stats.link_64().unwrap();
+    assert_eq!(link_64.rx_errors, 0);
+    assert_eq!(link_64.tx_errors, 0);
+}
+
+async fn _get_link_stats() -> Result<(u32, StatsMessage), Error> {
+    const NAME: &str = "dummy182";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+    link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let index = links.try_next().await?.ok_or(Error::RequestFailed)?.header.index;
+
+    let res = link_handle
+        .get_stats(index, IFLA_STATS_FILTER_LINK_64)
+        .execute()
+        .await;
+    link_handle.del(index).execute().await?;
+    Ok((index, res?))
+}
+
+fn emit_link(msg: &LinkMessage) -> Vec<u8> {
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    buf
+}
+
 fn has_nla(msg: &LinkMessage, nla: &Nla) -> bool {
     msg.nlas.iter().any(|x| x == nla)
 }
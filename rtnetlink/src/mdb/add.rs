@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::mdb::{MdbEntry, MdbGroup, Nla},
+        MdbMessage,
+        NetlinkMessage,
+        RtnlMessage,
+        AF_BRIDGE,
+        MDB_PERMANENT,
+        MDB_TEMPORARY,
+        NLM_F_ACK,
+        NLM_F_CREATE,
+        NLM_F_EXCL,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+/// A request to add an entry to the multicast database of a bridge. The bridge must be up, with
+/// multicast snooping enabled (the default).
+pub struct MdbAddRequest {
+    handle: Handle,
+    message: MdbMessage,
+    entry: MdbEntry,
+}
+
+impl MdbAddRequest {
+    pub(crate) fn new(handle: Handle, bridge: u32, port: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+        let entry = MdbEntry {
+            ifindex: port,
+            state: MDB_TEMPORARY,
+            flags: 0,
+            vid: 0,
+            group,
+        };
+        MdbAddRequest {
+            handle,
+            message,
+            entry,
+        }
+    }
+
+    /// Make the entry permanent instead of letting it expire like the entries learnt by
+    /// snooping (equivalent to `bridge mdb add ... permanent`)
+    pub fn permanent(mut self) -> Self {
+        self.entry.state = MDB_PERMANENT;
+        self
+    }
+
+    /// Only forward the group in the given VLAN (equivalent to `bridge mdb add ... vid VID`)
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.entry.vid = vid;
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbAddRequest {
+            mut handle,
+            mut message,
+            entry,
+        } = self;
+        message.nlas.push(Nla::SetEntry(entry));
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::mdb::{MdbEntry, MdbGroup, Nla},
+        MdbMessage,
+        NetlinkMessage,
+        RtnlMessage,
+        AF_BRIDGE,
+        MDB_TEMPORARY,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct MdbDelRequest {
+    handle: Handle,
+    message: MdbMessage,
+    entry: MdbEntry,
+}
+
+impl MdbDelRequest {
+    pub(crate) fn new(handle: Handle, bridge: u32, port: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+        // The kernel looks the entry up by port, group and VLAN, the state is ignored
+        let entry = MdbEntry {
+            ifindex: port,
+            state: MDB_TEMPORARY,
+            flags: 0,
+            vid: 0,
+            group,
+        };
+        MdbDelRequest {
+            handle,
+            message,
+            entry,
+        }
+    }
+
+    /// Delete the entry of the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.entry.vid = vid;
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbDelRequest {
+            mut handle,
+            mut message,
+            entry,
+        } = self;
+        message.nlas.push(Nla::SetEntry(entry));
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
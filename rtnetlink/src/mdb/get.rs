@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+
+use crate::{
+    packet::{
+        MdbMessage,
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+        AF_BRIDGE,
+        NLM_F_DUMP,
+        NLM_F_REQUEST,
+    },
+    Error,
+    Handle,
+};
+
+pub struct MdbGetRequest {
+    handle: Handle,
+    message: MdbMessage,
+    bridge_filter: Option<u32>,
+}
+
+impl MdbGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        MdbGetRequest {
+            handle,
+            message,
+            bridge_filter: None,
+        }
+    }
+
+    /// Only return the database of the given bridge (equivalent to `bridge mdb show dev
+    /// BRIDGE`). The kernel dumps the databases of all the bridges regardless, the filter is
+    /// applied on the responses.
+    pub fn set_bridge_filter(mut self, bridge: u32) -> Self {
+        self.bridge_filter = Some(bridge);
+        self
+    }
+
+    /// Execute the request. Each message holds the entries of one bridge (see
+    /// [`MdbMessage::entries`]), whose index is in the header of the message.
+    pub fn execute(self) -> impl TryStream<Ok = MdbMessage, Error = Error> {
+        let MdbGetRequest {
+            mut handle,
+            message,
+            bridge_filter,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| {
+                        let (header, payload) = msg.into_parts();
+                        match payload {
+                            NetlinkPayload::InnerMessage(RtnlMessage::NewMdb(msg)) => Ok(msg),
+                            NetlinkPayload::Error(err) => Err(Error::NetlinkError(err)),
+                            _ => Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                                header, payload,
+                            ))),
+                        }
+                    })
+                    .try_filter(move |msg| {
+                        let matches =
+                            bridge_filter.map_or(true, |index| index == msg.header.ifindex);
+                        future::ready(matches)
+                    }),
+            ),
+            Err(e) => Either::Right(future::err::<MdbMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request message
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
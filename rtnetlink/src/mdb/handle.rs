@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+use super::{MdbAddRequest, MdbDelRequest, MdbGetRequest};
+use crate::{packet::nlas::mdb::MdbGroup, Handle};
+
+pub struct MdbHandle(Handle);
+
+impl MdbHandle {
+    pub fn new(handle: Handle) -> Self {
+        MdbHandle(handle)
+    }
+
+    /// List the multicast database entries of the bridges (equivalent to `bridge mdb show`)
+    pub fn get(&self) -> MdbGetRequest {
+        MdbGetRequest::new(self.0.clone())
+    }
+
+    /// Forward the traffic of a multicast group to a port of a bridge (equivalent to `bridge mdb
+    /// add dev BRIDGE port PORT grp GROUP`). `group` can be built from an `IpAddr`.
+    pub fn add(&self, bridge: u32, port: u32, group: impl Into<MdbGroup>) -> MdbAddRequest {
+        MdbAddRequest::new(self.0.clone(), bridge, port, group.into())
+    }
+
+    /// Delete a multicast database entry (equivalent to `bridge mdb del dev BRIDGE port PORT grp
+    /// GROUP`)
+    pub fn del(&self, bridge: u32, port: u32, group: impl Into<MdbGroup>) -> MdbDelRequest {
+        MdbDelRequest::new(self.0.clone(), bridge, port, group.into())
+    }
+}
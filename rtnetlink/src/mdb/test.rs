@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr};
+
+use futures::stream::TryStreamExt;
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    packet::{
+        nlas::mdb::{MdbEntry, MdbGroup},
+        MDB_PERMANENT,
+    },
+    Error,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_mdb_entry() {
+    let rt = Runtime::new().unwrap();
+    let (port, entries) = rt.block_on(_add_mdb_entry()).unwrap();
+    let entry = entries
+        .iter()
+        .find(|entry| entry.ifindex == port)
+        .expect("the entry was not dumped");
+    assert_eq!(entry.group, MdbGroup::Ipv4(Ipv4Addr::new(239, 1, 1, 1)));
+    assert_eq!(entry.state, MDB_PERMANENT);
+}
+
+/// Add an entry for a port of a new bridge and return the index of the port along with the
+/// entries dumped for the bridge
+async fn _add_mdb_entry() -> Result<(u32, Vec<MdbEntry>), Error> {
+    const BRIDGE_NAME: &str = "br174";
+    const PORT_NAME: &str = "dummy174";
+    let group = IpAddr::V4(Ipv4Addr::new(239, 1, 1, 1));
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+    let mut link_handle = handle.link();
+
+    let mut indexes = vec![];
+    link_handle
+        .add()
+        .bridge(BRIDGE_NAME.to_owned())
+        .execute()
+        .await?;
+    link_handle
+        .add()
+        .dummy(PORT_NAME.to_owned())
+        .execute()
+        .await?;
+    for name in [BRIDGE_NAME, PORT_NAME].iter() {
+        let mut links = link_handle
+            .get()
+            .set_name_filter((*name).to_owned())
+            .execute();
+        let msg = links.try_next().await?.ok_or(Error::RequestFailed)?;
+        indexes.push(msg.header.index);
+    }
+    let (bridge, port) = (indexes[0], indexes[1]);
+
+    let res = async {
+        link_handle.set(port).master(bridge).execute().await?;
+        // The kernel rejects entries on bridges and ports that are down
+        for index in &indexes {
+            link_handle.set(*index).up().execute().await?;
+        }
+        handle
+            .mdb()
+            .add(bridge, port, group)
+            .permanent()
+            .execute()
+            .await?;
+
+        let mut entries = vec![];
+        let mut messages = handle.mdb().get().set_bridge_filter(bridge).execute();
+        while let Some(msg) = messages.try_next().await? {
+            entries.extend(msg.entries().map(|info| info.entry));
+        }
+
+        handle.mdb().del(bridge, port, group).execute().await?;
+        Ok((port, entries))
+    }
+    .await;
+    for index in indexes {
+        link_handle.del(index).execute().await?;
+    }
+    res
+}
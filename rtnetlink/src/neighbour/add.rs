@@ -40,6 +40,19 @@ impl NeighbourAddRequest {
         NeighbourAddRequest { handle, message }
     }
 
+    pub(crate) fn new_bridge(handle: Handle, index: u32, lla: &[u8]) -> Self {
+        let mut message = NeighbourMessage::default();
+
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.header.state = NUD_PERMANENT;
+        message.header.flags = NTF_SELF;
+
+        message.nlas.push(Nla::LinkLocalAddress(lla.to_vec()));
+
+        NeighbourAddRequest { handle, message }
+    }
+
     /// Set a bitmask of states for the neighbor cache entry.
     /// It should be a combination of `NUD_*` constants.
     pub fn state(mut self, state: u16) -> Self {
@@ -67,6 +80,25 @@ impl NeighbourAddRequest {
         self
     }
 
+    /// Set the destination address of the entry. For the forwarding database entries of a VXLAN
+    /// link, this is the address of the remote VTEP (equivalent to `bridge fdb add ... dst
+    /// ADDRESS`).
+    pub fn destination(mut self, destination: IpAddr) -> Self {
+        self.message.nlas.push(Nla::Destination(match destination {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        }));
+        self
+    }
+
+    /// Set the index of the interface through which the remote VTEP of a VXLAN forwarding
+    /// database entry is reached, via the `NDA_IFINDEX` attribute (equivalent to `bridge fdb add
+    /// ... dst ADDRESS via DEVICE`).
+    pub fn via_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
     /// Execute the request.
     pub async fn execute(self) -> Result<(), Error> {
         let NeighbourAddRequest {
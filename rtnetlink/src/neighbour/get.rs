@@ -2,13 +2,13 @@
 
 use futures::{
     future::{self, Either},
-    stream::{StreamExt, TryStream},
+    stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
 
 use netlink_packet_route::{
     constants::*,
-    neighbour::NeighbourMessage,
+    neighbour::{NeighbourMessage, NeighbourState, Nla},
     NetlinkPayload,
     RtnlMessage,
 };
@@ -20,12 +20,19 @@ use crate::{Error, Handle, IpVersion};
 pub struct NeighbourGetRequest {
     handle: Handle,
     message: NeighbourMessage,
+    state_filter: Option<NeighbourState>,
+    link_index_filter: Option<u32>,
 }
 
 impl NeighbourGetRequest {
     pub(crate) fn new(handle: Handle) -> Self {
         let message = NeighbourMessage::default();
-        NeighbourGetRequest { handle, message }
+        NeighbourGetRequest {
+            handle,
+            message,
+            state_filter: None,
+            link_index_filter: None,
+        }
     }
 
     /// List neighbor proxies in the system (equivalent to: `ip neighbor show proxy`).
@@ -39,27 +46,64 @@ impl NeighbourGetRequest {
         self
     }
 
+    /// Only return the neighbours in one of the given states (equivalent to: `ip neighbor show
+    /// nud STATE`). For instance, `NeighbourState::FAILED | NeighbourState::INCOMPLETE` returns
+    /// the neighbours whose address could not be resolved (yet).
+    ///
+    /// The kernel dumps all the neighbours regardless, the filter is applied on the responses.
+    pub fn state_filter(mut self, state: NeighbourState) -> Self {
+        self.state_filter = Some(state);
+        self
+    }
+
+    /// Only return the neighbours of the given interface (equivalent to: `ip neighbor show dev
+    /// DEV`). Combined with [`NeighbourGetRequest::set_family`], this returns for instance the
+    /// IPv6 neighbours of a single interface.
+    ///
+    /// The interface is sent to the kernel as a `NDA_IFINDEX` attribute, so that the kernel only
+    /// dumps the neighbours of the interface. Older kernels ignore it, so the filter is applied
+    /// on the responses as well.
+    pub fn set_link_index_filter(mut self, index: u32) -> Self {
+        self.link_index_filter = Some(index);
+        self
+    }
+
     /// Execute the request
     pub fn execute(self) -> impl TryStream<Ok = NeighbourMessage, Error = Error> {
         let NeighbourGetRequest {
             mut handle,
-            message,
+            mut message,
+            state_filter,
+            link_index_filter,
         } = self;
 
+        if let Some(index) = link_index_filter {
+            message.nlas.push(Nla::IfIndex(index));
+        }
+
         let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
         req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
 
         match handle.request(req) {
-            Ok(response) => Either::Left(response.map(move |msg| {
-                let (header, payload) = msg.into_parts();
-                match payload {
-                    NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(msg)) => Ok(msg),
-                    NetlinkPayload::Error(err) => Err(Error::NetlinkError(err)),
-                    _ => Err(Error::UnexpectedMessage(NetlinkMessage::new(
-                        header, payload,
-                    ))),
-                }
-            })),
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| {
+                        let (header, payload) = msg.into_parts();
+                        match payload {
+                            NetlinkPayload::InnerMessage(RtnlMessage::NewNeighbour(msg)) => Ok(msg),
+                            NetlinkPayload::Error(err) => Err(Error::NetlinkError(err)),
+                            _ => Err(Error::UnexpectedMessage(NetlinkMessage::new(
+                                header, payload,
+                            ))),
+                        }
+                    })
+                    .try_filter(move |msg| {
+                        let state = NeighbourState::from_bits_truncate(msg.header.state);
+                        let matches = state_filter.map_or(true, |filter| filter.intersects(state))
+                            && link_index_filter.map_or(true, |index| msg.header.ifindex == index);
+                        future::ready(matches)
+                    }),
+            ),
             Err(e) => Either::Right(future::err::<NeighbourMessage, Error>(e).into_stream()),
         }
     }
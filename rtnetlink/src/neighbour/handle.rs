@@ -21,6 +21,12 @@ impl NeighbourHandle {
         NeighbourAddRequest::new(self.0.clone(), index, destination)
     }
 
+    /// Add a new forwarding database entry to a bridge port or a VXLAN link, for the link layer
+    /// address `lla` (equivalent to `bridge fdb add LLADDR dev DEV self`)
+    pub fn add_bridge(&self, index: u32, lla: &[u8]) -> NeighbourAddRequest {
+        NeighbourAddRequest::new_bridge(self.0.clone(), index, lla)
+    }
+
     /// Delete a neighbour entry (equivalent to `ip neighbour delete`)
     pub fn del(&self, message: NeighbourMessage) -> NeighbourDelRequest {
         NeighbourDelRequest::new(self.0.clone(), message)
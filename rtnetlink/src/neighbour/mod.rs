@@ -11,3 +11,6 @@ pub use self::add::*;
 
 mod del;
 pub use self::del::*;
+
+#[cfg(test)]
+mod test;
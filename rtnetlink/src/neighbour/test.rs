@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use futures::stream::TryStreamExt;
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    packet::{constants::*, neighbour::Nla, NeighbourMessage, NeighbourState},
+    Error,
+    Handle,
+    IpVersion,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_neighbours_by_state() {
+    let rt = Runtime::new().unwrap();
+    let (stale, permanent_or_failed) = rt.block_on(_get_neighbours_by_state()).unwrap();
+    assert_eq!(stale, vec![Ipv4Addr::new(10, 140, 0, 3)]);
+    assert_eq!(permanent_or_failed, vec![Ipv4Addr::new(10, 140, 0, 2)]);
+}
+
+async fn _get_neighbours_by_state() -> Result<(Vec<Ipv4Addr>, Vec<Ipv4Addr>), Error> {
+    const NAME: &str = "dummy140";
+    const LLADDR: [u8; 6] = [0x02, 0, 0, 0, 0x01, 0x40];
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let mut link_handle = handle.link();
+    link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let index = links.try_next().await?.ok_or(Error::RequestFailed)?.header.index;
+
+    let res = async {
+        for (address, state) in [(2, NUD_PERMANENT), (3, NUD_STALE)] {
+            handle
+                .neighbours()
+                .add(index, IpAddr::V4(Ipv4Addr::new(10, 140, 0, address)))
+                .state(state)
+                .link_local_address(&LLADDR)
+                .execute()
+                .await?;
+        }
+        let stale = _get_neighbours(&handle, index, NeighbourState::STALE).await?;
+        let permanent_or_failed = _get_neighbours(
+            &handle,
+            index,
+            NeighbourState::PERMANENT | NeighbourState::FAILED,
+        )
+        .await?;
+        Ok((stale, permanent_or_failed))
+    }
+    .await;
+
+    link_handle.del(index).execute().await?;
+    res
+}
+
+async fn _get_neighbours(
+    handle: &Handle,
+    index: u32,
+    state: NeighbourState,
+) -> Result<Vec<Ipv4Addr>, Error> {
+    let neighbours: Vec<_> = handle
+        .neighbours()
+        .get()
+        .set_family(IpVersion::V4)
+        .state_filter(state)
+        .execute()
+        .try_collect()
+        .await?;
+    Ok(neighbours
+        .into_iter()
+        .filter(|neighbour| neighbour.header.ifindex == index)
+        .filter_map(|neighbour| {
+            neighbour.nlas.into_iter().find_map(|nla| match nla {
+                Nla::Destination(addr) if addr.len() == 4 => {
+                    Some(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]))
+                }
+                _ => None,
+            })
+        })
+        .collect())
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_neighbours_by_link_and_family() {
+    let rt = Runtime::new().unwrap();
+    let (index, neighbours) = rt.block_on(_get_neighbours_by_link_and_family()).unwrap();
+    assert_eq!(neighbours.len(), 1);
+    assert_eq!(neighbours[0].header.family, AF_INET6 as u8);
+    assert_eq!(neighbours[0].header.ifindex, index);
+    let destination = Ipv6Addr::new(0x2001, 0xdb8, 0x192, 0, 0, 0, 0, 2);
+    assert!(neighbours[0]
+        .nlas
+        .contains(&Nla::Destination(destination.octets().to_vec())));
+}
+
+async fn _get_neighbours_by_link_and_family() -> Result<(u32, Vec<NeighbourMessage>), Error> {
+    const NAME: &str = "dummy192";
+    const LLADDR: [u8; 6] = [0x02, 0, 0, 0, 0x01, 0x92];
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let mut link_handle = handle.link();
+    link_handle.add().dummy(NAME.to_owned()).execute().await?;
+    let mut links = link_handle.get().set_name_filter(NAME.to_owned()).execute();
+    let index = links
+        .try_next()
+        .await?
+        .ok_or(Error::RequestFailed)?
+        .header
+        .index;
+
+    let res = async {
+        let addresses = [
+            IpAddr::V4(Ipv4Addr::new(10, 192, 0, 2)),
+            IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0x192, 0, 0, 0, 0, 2)),
+        ];
+        for address in addresses {
+            handle
+                .neighbours()
+                .add(index, address)
+                .state(NUD_PERMANENT)
+                .link_local_address(&LLADDR)
+                .execute()
+                .await?;
+        }
+        // The kernel adds NOARP entries for the IPv6 multicast groups of the link, which are
+        // left out by the state filter
+        handle
+            .neighbours()
+            .get()
+            .set_family(IpVersion::V6)
+            .set_link_index_filter(index)
+            .state_filter(NeighbourState::PERMANENT)
+            .execute()
+            .try_collect()
+            .await
+    }
+    .await;
+
+    link_handle.del(index).execute().await?;
+    res.map(|neighbours| (index, neighbours))
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_vxlan_fdb_entry() {
+    let rt = Runtime::new().unwrap();
+    let (vxlan_index, dummy_index, entry) = rt.block_on(_add_vxlan_fdb_entry()).unwrap();
+    let entry = entry.unwrap();
+    assert_eq!(entry.header.ifindex, vxlan_index);
+    assert!(entry.nlas.contains(&Nla::Destination(vec![10, 155, 0, 1])));
+    assert!(entry.nlas.contains(&Nla::IfIndex(dummy_index)));
+}
+
+async fn _add_vxlan_fdb_entry() -> Result<(u32, u32, Option<NeighbourMessage>), Error> {
+    const LLADDR: [u8; 6] = [0x02, 0, 0, 0, 0x01, 0x55];
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let mut link_handle = handle.link();
+    let dummy_index = link_handle
+        .add()
+        .dummy("dummy155".to_owned())
+        .execute()
+        .await?;
+    let res = async {
+        let vxlan_index = link_handle
+            .add()
+            .vxlan("vxlan155".to_owned(), 155)
+            .port(4789)
+            .execute()
+            .await?;
+        let res = async {
+            handle
+                .neighbours()
+                .add_bridge(vxlan_index, &LLADDR)
+                .destination(IpAddr::V4(Ipv4Addr::new(10, 155, 0, 1)))
+                .via_interface(dummy_index)
+                .execute()
+                .await?;
+
+            let mut request = handle.neighbours().get();
+            request.message_mut().header.family = AF_BRIDGE as u8;
+            let entries: Vec<_> = request.execute().try_collect().await?;
+            let entry = entries.into_iter().find(|entry| {
+                entry.header.ifindex == vxlan_index
+                    && entry.nlas.contains(&Nla::LinkLocalAddress(LLADDR.to_vec()))
+            });
+            Ok((vxlan_index, dummy_index, entry))
+        }
+        .await;
+        link_handle.del(vxlan_index).execute().await?;
+        res
+    }
+    .await;
+
+    link_handle.del(dummy_index).execute().await?;
+    res
+}
@@ -15,7 +15,7 @@ use std::{option::Option, path::Path, process::exit};
 // if "only" smol or smol+tokio were enabled, we use smol because
 // it doesn't require an active tokio runtime - just to be sure.
 #[cfg(feature = "smol_socket")]
-async fn try_spawn_blocking<F, R>(fut: F) -> R
+pub(crate) async fn try_spawn_blocking<F, R>(fut: F) -> R
 where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
@@ -25,7 +25,7 @@ where
 
 // only tokio enabled, so use tokio
 #[cfg(all(not(feature = "smol_socket"), feature = "tokio_socket"))]
-async fn try_spawn_blocking<F, R>(fut: F) -> R
+pub(crate) async fn try_spawn_blocking<F, R>(fut: F) -> R
 where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
@@ -41,7 +41,7 @@ where
 // neither smol nor tokio - just run blocking op directly.
 // hopefully not too blocking...
 #[cfg(all(not(feature = "smol_socket"), not(feature = "tokio_socket")))]
-async fn try_spawn_blocking<F, R>(fut: F) -> R
+pub(crate) async fn try_spawn_blocking<F, R>(fut: F) -> R
 where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
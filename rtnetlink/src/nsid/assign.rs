@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::unix::io::RawFd;
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::nsid::Nla,
+        NetlinkMessage,
+        NsidMessage,
+        RtnlMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+    NsidGetRequest,
+};
+
+pub struct NsidAssignRequest {
+    handle: Handle,
+    fd: RawFd,
+    message: NsidMessage,
+}
+
+impl NsidAssignRequest {
+    pub(crate) fn new(handle: Handle, fd: RawFd, nsid: i32) -> Self {
+        let mut message = NsidMessage::default();
+        message.nlas.push(Nla::Fd(fd as u32));
+        message.nlas.push(Nla::Id(nsid));
+        NsidAssignRequest {
+            handle,
+            fd,
+            message,
+        }
+    }
+
+    /// Execute the request, and return the id that has been assigned to the namespace. This is
+    /// mostly useful when the kernel was asked to pick the id.
+    pub async fn execute(self) -> Result<i32, Error> {
+        let NsidAssignRequest {
+            mut handle,
+            fd,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNsId(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message)
+        }
+
+        // The acknowledgement does not carry the id, so ask the kernel for it
+        NsidGetRequest::new(handle, fd).execute().await
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NsidMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::unix::io::RawFd;
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::nsid::Nla,
+        NetlinkMessage,
+        NsidMessage,
+        RtnlMessage,
+        NETNSA_NSID_NOT_ASSIGNED,
+        NLM_F_REQUEST,
+    },
+    try_rtnl,
+    Error,
+    Handle,
+};
+
+pub struct NsidGetRequest {
+    handle: Handle,
+    message: NsidMessage,
+}
+
+impl NsidGetRequest {
+    pub(crate) fn new(handle: Handle, fd: RawFd) -> Self {
+        let mut message = NsidMessage::default();
+        message.nlas.push(Nla::Fd(fd as u32));
+        NsidGetRequest { handle, message }
+    }
+
+    /// Execute the request, and return the id of the namespace. If no id has been assigned to
+    /// the namespace, `NETNSA_NSID_NOT_ASSIGNED` (-1) is returned.
+    pub async fn execute(self) -> Result<i32, Error> {
+        let NsidGetRequest {
+            mut handle,
+            message,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNsId(message));
+        req.header.flags = NLM_F_REQUEST;
+
+        let mut response = handle.request(req)?;
+        let mut nsid = None;
+        while let Some(message) = response.next().await {
+            let msg = try_rtnl!(message, RtnlMessage::NewNsId);
+            nsid = msg.nlas.iter().find_map(|nla| match nla {
+                Nla::Id(id) => Some(*id),
+                _ => None,
+            });
+        }
+        Ok(nsid.unwrap_or(NETNSA_NSID_NOT_ASSIGNED))
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NsidMessage {
+        &mut self.message
+    }
+}
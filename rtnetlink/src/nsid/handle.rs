@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+use std::os::unix::io::RawFd;
+
+use super::{NsidAssignRequest, NsidGetRequest};
+use crate::Handle;
+
+pub struct NsidHandle(Handle);
+
+impl NsidHandle {
+    pub fn new(handle: Handle) -> Self {
+        NsidHandle(handle)
+    }
+
+    /// Assign an id to the network namespace referred to by `fd` (equivalent to `ip netns set
+    /// <name> <nsid>`). Use `NETNSA_NSID_NOT_ASSIGNED` (-1) to let the kernel pick the id.
+    pub fn assign(&self, fd: RawFd, nsid: i32) -> NsidAssignRequest {
+        NsidAssignRequest::new(self.0.clone(), fd, nsid)
+    }
+
+    /// Retrieve the id of the network namespace referred to by `fd`
+    pub fn get(&self, fd: RawFd) -> NsidGetRequest {
+        NsidGetRequest::new(self.0.clone(), fd)
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod assign;
+pub use self::assign::*;
+
+mod get;
+pub use self::get::*;
+
+#[cfg(test)]
+mod test;
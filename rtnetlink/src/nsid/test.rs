@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+use std::{fs::File, os::unix::io::AsRawFd};
+
+use tokio::runtime::Runtime;
+
+use crate::{new_connection, packet::NETNSA_NSID_NOT_ASSIGNED, Error, NetworkNamespace, NETNS_PATH};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn assign_get_nsid() {
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(_assign_get_nsid("nsid-test-fixed", 142));
+    assert_eq!(res.unwrap(), (NETNSA_NSID_NOT_ASSIGNED, 142, 142));
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn assign_get_nsid_auto() {
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(_assign_get_nsid("nsid-test-auto", NETNSA_NSID_NOT_ASSIGNED));
+    let (before, assigned, after) = res.unwrap();
+    assert_eq!(before, NETNSA_NSID_NOT_ASSIGNED);
+    assert!(assigned >= 0);
+    assert_eq!(assigned, after);
+}
+
+// Return the nsid before the assignment, the assigned nsid, and the nsid read back afterwards
+async fn _assign_get_nsid(ns_name: &str, nsid: i32) -> Result<(i32, i32, i32), Error> {
+    NetworkNamespace::add(ns_name.to_owned()).await?;
+    let res = _assign_get_nsid_in(ns_name, nsid).await;
+    NetworkNamespace::del(ns_name.to_owned()).await?;
+    res
+}
+
+async fn _assign_get_nsid_in(ns_name: &str, nsid: i32) -> Result<(i32, i32, i32), Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let ns_file = File::open(format!("{}{}", NETNS_PATH, ns_name)).unwrap();
+    let fd = ns_file.as_raw_fd();
+
+    let before = handle.nsid().get(fd).execute().await?;
+    let assigned = handle.nsid().assign(fd, nsid).execute().await?;
+    let after = handle.nsid().get(fd).execute().await?;
+    Ok((before, assigned, after))
+}
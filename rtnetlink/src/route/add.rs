@@ -1,15 +1,16 @@
 // SPDX-License-Identifier: MIT
 
-use futures::stream::StreamExt;
+use futures::stream::{StreamExt, TryStreamExt};
 use std::{
     marker::PhantomData,
-    net::{Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
 
 use netlink_packet_route::{
     constants::*,
-    nlas::route::Nla,
+    nlas::route::{Nla, RouteVia},
     NetlinkMessage,
+    RouteFlags,
     RouteMessage,
     RtnlMessage,
 };
@@ -20,6 +21,8 @@ use crate::{try_nl, Error, Handle};
 pub struct RouteAddRequest<T = ()> {
     handle: Handle,
     message: RouteMessage,
+    append: bool,
+    validate_prefsrc: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -35,6 +38,8 @@ impl<T> RouteAddRequest<T> {
         RouteAddRequest {
             handle,
             message,
+            append: false,
+            validate_prefsrc: false,
             _phantom: Default::default(),
         }
     }
@@ -46,16 +51,37 @@ impl<T> RouteAddRequest<T> {
     }
 
     /// Sets the output interface index.
+    ///
+    /// If no gateway is set, the route is installed as an on-link route
+    /// through that interface (equivalent to `ip route add PREFIX dev DEV`),
+    /// and the scope defaults to link instead of universe.
     pub fn output_interface(mut self, index: u32) -> Self {
         self.message.nlas.push(Nla::Oif(index));
         self
     }
 
+    /// Sets the routing realms (`RTA_FLOW`), as packed by
+    /// [`Realm`](netlink_packet_route::nlas::route::Realm). This is equivalent
+    /// to `ip route add ... realms FROM/TO`.
+    pub fn realm(mut self, realm: u32) -> Self {
+        self.message.nlas.push(Nla::Flow(realm));
+        self
+    }
+
     /// Sets the route table.
     ///
-    /// Default is main route table.
-    pub fn table(mut self, table: u8) -> Self {
-        self.message.header.table = table;
+    /// Default is main route table. The header only holds 8 bits, so tables above 255 are set
+    /// with the `RTA_TABLE` attribute instead, and `RT_TABLE_COMPAT` in the header.
+    pub fn table(mut self, table: u32) -> Self {
+        self.message
+            .nlas
+            .retain(|nla| !matches!(nla, Nla::Table(_)));
+        if table > 255 {
+            self.message.header.table = RT_TABLE_COMPAT;
+            self.message.nlas.push(Nla::Table(table));
+        } else {
+            self.message.header.table = table as u8;
+        }
         self
     }
 
@@ -69,7 +95,8 @@ impl<T> RouteAddRequest<T> {
 
     /// Sets the route scope.
     ///
-    /// Default is universe route scope.
+    /// Default is universe route scope, or link scope for routes that only
+    /// have an output interface and no gateway.
     pub fn scope(mut self, scope: u8) -> Self {
         self.message.header.scope = scope;
         self
@@ -83,12 +110,60 @@ impl<T> RouteAddRequest<T> {
         self
     }
 
+    /// Sets the gateway in an address family that can differ from the one of the route
+    /// (`RTA_VIA`), such as an IPv6 gateway for an IPv4 route.
+    /// This is equivalent to `ip route add ... via inet6 ADDRESS`.
+    ///
+    /// The attribute embeds the address family of the gateway, which is derived from `addr`. An
+    /// output interface is usually required as well, and IPv4 routes with an IPv6 gateway need a
+    /// kernel 5.2 or newer.
+    pub fn via(mut self, addr: IpAddr) -> Self {
+        self.message.nlas.push(Nla::Via(RouteVia::from(addr)));
+        self
+    }
+
+    /// Use the gateway even if no route through the output interface covers it
+    /// (`RTNH_F_ONLINK`). This is equivalent to `ip route add ... onlink`, which is typically
+    /// needed for point-to-point links whose peer is outside of the local subnet.
+    ///
+    /// The nexthops of a multipath route each have their own flags, and the kernel ignores those
+    /// of the route header for them: the flag is then set on every nexthop of the
+    /// `RTA_MULTIPATH` attribute as well.
+    pub fn onlink(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTNH_F_ONLINK;
+        self
+    }
+
+    /// Append the route to an existing one instead of failing if it already exists.
+    /// This is equivalent to `ip route append`.
+    ///
+    /// For IPv6, the nexthop of this route is added to the existing route, which makes it a
+    /// multipath route. For IPv4, a new route with the same destination is created.
+    pub fn append(mut self) -> Self {
+        self.append = true;
+        self
+    }
+
+    /// Before sending the request, check that the preferred source address of the route (see
+    /// `pref_source`) is configured on one of the interfaces, and fail with
+    /// [`Error::InvalidPrefSource`] otherwise. The kernel rejects such routes with a plain
+    /// `EINVAL`.
+    ///
+    /// This dumps the addresses of all the interfaces, not only the output interface of the
+    /// route, since the kernel accepts any local address.
+    pub fn validate_prefsrc(mut self) -> Self {
+        self.validate_prefsrc = true;
+        self
+    }
+
     /// Build an IP v4 route request
     pub fn v4(mut self) -> RouteAddRequest<Ipv4Addr> {
         self.message.header.address_family = AF_INET as u8;
         RouteAddRequest {
             handle: self.handle,
             message: self.message,
+            append: self.append,
+            validate_prefsrc: self.validate_prefsrc,
             _phantom: Default::default(),
         }
     }
@@ -99,6 +174,8 @@ impl<T> RouteAddRequest<T> {
         RouteAddRequest {
             handle: self.handle,
             message: self.message,
+            append: self.append,
+            validate_prefsrc: self.validate_prefsrc,
             _phantom: Default::default(),
         }
     }
@@ -107,11 +184,59 @@ impl<T> RouteAddRequest<T> {
     pub async fn execute(self) -> Result<(), Error> {
         let RouteAddRequest {
             mut handle,
-            message,
+            mut message,
+            append,
+            validate_prefsrc,
             ..
         } = self;
+
+        if message.header.address_family == AF_INET as u8
+            && (message.header.source_prefix_length != 0
+                || message.nlas.iter().any(|nla| matches!(nla, Nla::Source(_))))
+        {
+            return Err(Error::Ipv4SourcePrefix);
+        }
+
+        if let Some(pref_source) = message.pref_source().filter(|_| validate_prefsrc) {
+            let addresses: Vec<_> = handle
+                .address()
+                .get()
+                .set_address_filter(pref_source)
+                .execute()
+                .try_collect()
+                .await?;
+            if addresses.is_empty() {
+                return Err(Error::InvalidPrefSource(pref_source));
+            }
+        }
+
+        // Like iproute2, routes that only go through a device are given a
+        // link scope when the scope was left to its default value.
+        if message.header.kind == RTN_UNICAST
+            && message.header.scope == RT_SCOPE_UNIVERSE
+            && message.output_interface().is_some()
+            && !message
+                .nlas
+                .iter()
+                .any(|nla| matches!(nla, Nla::Gateway(_) | Nla::MultiPath(_) | Nla::Via(_)))
+        {
+            message.header.scope = RT_SCOPE_LINK;
+        }
+
+        if message.header.flags.contains(RouteFlags::RTNH_F_ONLINK) {
+            for nla in message.nlas.iter_mut() {
+                if let Nla::MultiPath(ref mut nexthops) = nla {
+                    set_nexthops_flags(nexthops, RTNH_F_ONLINK as u8);
+                }
+            }
+        }
+
         let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE;
+        req.header.flags = if append {
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_APPEND | NLM_F_CREATE
+        } else {
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_EXCL | NLM_F_CREATE
+        };
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -126,8 +251,27 @@ impl<T> RouteAddRequest<T> {
     }
 }
 
+/// Set `flags` in the `rtnh_flags` field of every `struct rtnexthop` of a `RTA_MULTIPATH`
+/// attribute
+fn set_nexthops_flags(nexthops: &mut [u8], flags: u8) {
+    let mut offset = 0;
+    while nexthops.len() >= offset + 8 {
+        let len = u16::from_ne_bytes([nexthops[offset], nexthops[offset + 1]]) as usize;
+        if len < 8 {
+            break;
+        }
+        nexthops[offset + 2] |= flags;
+        offset += (len + 3) & !3;
+    }
+}
+
 impl RouteAddRequest<Ipv4Addr> {
     /// Sets the source address prefix.
+    ///
+    /// The kernel silently ignores the source prefix of IPv4 routes, installing a route that
+    /// matches all the sources instead, so [`execute`](RouteAddRequest::execute) refuses such
+    /// routes with [`Error::Ipv4SourcePrefix`]. Use a routing rule with a source prefix instead.
+    #[deprecated(note = "IPv4 routes cannot have a source prefix, use a routing rule instead")]
     pub fn source_prefix(mut self, addr: Ipv4Addr, prefix_length: u8) -> Self {
         self.message.header.source_prefix_length = prefix_length;
         let src = addr.octets().to_vec();
@@ -159,7 +303,11 @@ impl RouteAddRequest<Ipv4Addr> {
 }
 
 impl RouteAddRequest<Ipv6Addr> {
-    /// Sets the source address prefix.
+    /// Sets the source address prefix (`RTA_SRC`), making the route only match the packets from
+    /// that prefix. This is equivalent to `ip -6 route add ... from PREFIX`.
+    ///
+    /// Source-specific routes require a kernel built with `CONFIG_IPV6_SUBTREES`, other kernels
+    /// answer `EINVAL`.
     pub fn source_prefix(mut self, addr: Ipv6Addr, prefix_length: u8) -> Self {
         self.message.header.source_prefix_length = prefix_length;
         let src = addr.octets().to_vec();
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use futures::{future, Stream, StreamExt};
+
+use crate::{
+    packet::{NetlinkMessage, NetlinkPayload, RouteMessage, RtnlMessage, AF_INET6, NLM_F_REPLACE},
+    sys::SocketAddr,
+};
+
+/// A change of the routing tables, decoded from the `RTM_NEWROUTE` and `RTM_DELROUTE`
+/// notifications the kernel sends to the members of the `RTNLGRP_IPV4_ROUTE` and
+/// `RTNLGRP_IPV6_ROUTE` groups (see [`route_events`]).
+///
+/// Replacing a route (`ip route replace`) is not reported the same way by all kernels: some send
+/// a `Removed` event for the old route followed by an `Added` event for the new one, others only
+/// send the `Added` event, with [`RouteChange::replace`] set. Code keeping track of the routing
+/// tables should thus treat an `Added` event for a route it already knows as an update.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouteEvent {
+    Added(RouteChange),
+    Removed(RouteChange),
+    /// Notifications were lost because the socket receive buffer overflowed (see
+    /// [`Connection`](crate::proto::Connection)). The routing tables must be dumped again to find
+    /// out about the changes that were missed.
+    ResyncRequired,
+}
+
+/// The route an event is about
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RouteChange {
+    /// Destination prefix of the route. Default routes, which have no `RTA_DST` attribute, are
+    /// reported as `0.0.0.0/0` or `::/0`.
+    pub destination: (IpAddr, u8),
+    pub gateway: Option<IpAddr>,
+    /// Routing table id, including tables above 255
+    pub table: u32,
+    /// Whether the notification has the `NLM_F_REPLACE` flag, meaning the route replaced an
+    /// existing one
+    pub replace: bool,
+    /// The notification itself, for the attributes not extracted above
+    pub message: RouteMessage,
+}
+
+impl RouteChange {
+    fn new(message: RouteMessage, replace: bool) -> Self {
+        let destination = message.destination_prefix().unwrap_or_else(|| {
+            if message.header.address_family as u16 == AF_INET6 {
+                (IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0)
+            } else {
+                (IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+            }
+        });
+        RouteChange {
+            destination,
+            gateway: message.gateway(),
+            table: message.table(),
+            replace,
+            message,
+        }
+    }
+}
+
+impl RouteEvent {
+    /// Decode a `RTM_NEWROUTE` or `RTM_DELROUTE` notification, or the `NLMSG_OVERRUN` message
+    /// reporting lost notifications. Other messages yield `None`.
+    pub fn from_message(message: NetlinkMessage<RtnlMessage>) -> Option<Self> {
+        let replace = message.header.flags & NLM_F_REPLACE != 0;
+        match message.payload {
+            NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(route)) => {
+                Some(RouteEvent::Added(RouteChange::new(route, replace)))
+            }
+            NetlinkPayload::InnerMessage(RtnlMessage::DelRoute(route)) => {
+                Some(RouteEvent::Removed(RouteChange::new(route, replace)))
+            }
+            NetlinkPayload::Overrun(_) => Some(RouteEvent::ResyncRequired),
+            _ => None,
+        }
+    }
+
+    /// Returns the route that was added or removed, or `None` for
+    /// [`ResyncRequired`](RouteEvent::ResyncRequired)
+    pub fn route(&self) -> Option<&RouteChange> {
+        match self {
+            RouteEvent::Added(route) | RouteEvent::Removed(route) => Some(route),
+            RouteEvent::ResyncRequired => None,
+        }
+    }
+}
+
+/// Turn the notifications received by a connection (the third item returned by
+/// [`new_connection`](crate::new_connection)) into a stream of route events, skipping the other
+/// notifications. Losing notifications does not end the stream: it yields
+/// [`RouteEvent::ResyncRequired`] and goes on.
+///
+/// The kernel only sends route notifications to the sockets that joined the
+/// `RTNLGRP_IPV4_ROUTE` and/or `RTNLGRP_IPV6_ROUTE` groups, for instance with
+/// `connection.socket_mut().socket_mut().add_membership(RTNLGRP_IPV4_ROUTE)` before spawning the
+/// connection.
+pub fn route_events<S>(messages: S) -> impl Stream<Item = RouteEvent>
+where
+    S: Stream<Item = (NetlinkMessage<RtnlMessage>, SocketAddr)>,
+{
+    messages.filter_map(|(message, _)| future::ready(RouteEvent::from_message(message)))
+}
@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future, stream::TryStreamExt};
+use nix::errno::Errno;
+
+use netlink_packet_route::{constants::*, RouteMessage};
+
+use crate::{Error, Handle, IpVersion, RouteDelRequest, RouteGetRequest};
+
+pub struct RouteFlushRequest {
+    handle: Handle,
+    ip_version: IpVersion,
+    table: u32,
+    protocol: Option<u8>,
+    scope: Option<u8>,
+}
+
+impl RouteFlushRequest {
+    pub(crate) fn new(handle: Handle, ip_version: IpVersion) -> Self {
+        RouteFlushRequest {
+            handle,
+            ip_version,
+            table: RT_TABLE_MAIN as u32,
+            protocol: None,
+            scope: None,
+        }
+    }
+
+    /// Flush the routes of the given table instead of the main table (equivalent to
+    /// `ip route flush table TABLE`). With `RT_TABLE_UNSPEC`, the routes of all the tables are
+    /// flushed, including the local table.
+    pub fn table(mut self, table: u32) -> Self {
+        self.table = table;
+        self
+    }
+
+    /// Only flush the routes with the given protocol, such as `RTPROT_STATIC` (equivalent to
+    /// `ip route flush proto PROTOCOL`)
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    /// Only flush the routes with the given scope, such as `RT_SCOPE_LINK` (equivalent to
+    /// `ip route flush scope SCOPE`)
+    pub fn scope(mut self, scope: u8) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    fn matches(&self, route: &RouteMessage) -> bool {
+        (self.table == RT_TABLE_UNSPEC as u32 || route.table() == self.table)
+            && self.protocol.map_or(true, |p| route.header.protocol == p)
+            && self.scope.map_or(true, |s| route.header.scope == s)
+    }
+
+    /// Execute the request, and return the number of routes that were deleted.
+    ///
+    /// This dumps the routes, then deletes the matching ones one by one. The kernel may not apply
+    /// the filters of the dump (see [`RouteGetRequest::execute_filtered`]), so the routes are
+    /// filtered here. A route that disappears between the dump and its deletion, for instance
+    /// because its interface went down, is not an error: it is simply not counted.
+    pub async fn execute(self) -> Result<usize, Error> {
+        let mut req = RouteGetRequest::new(self.handle.clone(), self.ip_version.clone());
+        if self.table != RT_TABLE_UNSPEC as u32 {
+            req = req.set_table_filter(self.table);
+        }
+        if let Some(protocol) = self.protocol {
+            req = req.set_protocol_filter(protocol);
+        }
+        let routes: Vec<RouteMessage> = req
+            .execute()
+            .try_filter(|route| future::ready(self.matches(route)))
+            .try_collect()
+            .await?;
+
+        let mut deleted = 0;
+        for route in routes {
+            match RouteDelRequest::new(self.handle.clone(), route)
+                .execute()
+                .await
+            {
+                Ok(()) => deleted += 1,
+                Err(e)
+                    if e.raw_os_error() == Some(Errno::ESRCH as i32)
+                        || e.raw_os_error() == Some(Errno::ENOENT as i32) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(deleted)
+    }
+}
@@ -2,17 +2,39 @@
 
 use futures::{
     future::{self, Either},
-    stream::{StreamExt, TryStream},
+    stream::{StreamExt, TryStream, TryStreamExt},
     FutureExt,
 };
 
-use netlink_packet_route::{constants::*, NetlinkMessage, RouteMessage, RtnlMessage};
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteFlags,
+    RouteMessage,
+    RtnlMessage,
+};
 
 use crate::{try_rtnl, Error, Handle};
 
 pub struct RouteGetRequest {
     handle: Handle,
     message: RouteMessage,
+    // By default, all the routes are dumped. When `dump` is `false`, the kernel performs a route
+    // lookup for the destination set in the message instead.
+    dump: bool,
+}
+
+/// A route returned by [`RouteGetRequest::execute_filtered`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DumpedRoute {
+    pub route: RouteMessage,
+    /// Whether the kernel applied the filters of the request, which it reports with the
+    /// `NLM_F_DUMP_FILTERED` flag. When `false`, the kernel ignored them and dumped all the
+    /// routes, so the caller has to filter them.
+    pub filtered: bool,
 }
 
 /// Internet Protocol (IP) version.
@@ -54,27 +76,110 @@ impl RouteGetRequest {
         message.header.table = RT_TABLE_UNSPEC;
         message.header.protocol = RTPROT_UNSPEC;
 
-        RouteGetRequest { handle, message }
+        RouteGetRequest {
+            handle,
+            message,
+            dump: true,
+        }
     }
 
     pub fn message_mut(&mut self) -> &mut RouteMessage {
         &mut self.message
     }
 
+    /// Set the address family of the routes to dump (the `rtm_family` field), overriding the IP
+    /// version the request was created with. The kernel only dumps the routes of that family, for
+    /// instance `AF_INET` or `AF_INET6`. With `AF_UNSPEC`, the routes of all the families are
+    /// dumped in the same stream, and can be told apart with their `header.address_family` field.
+    pub fn set_address_family(mut self, family: u8) -> Self {
+        self.message.header.address_family = family;
+        self
+    }
+
+    /// Only dump the routes of the given table. This filter is applied by the kernel, see
+    /// [`RouteGetRequest::execute_filtered`].
+    pub fn set_table_filter(mut self, table: u32) -> Self {
+        self.message.nlas.push(Nla::Table(table));
+        self
+    }
+
+    /// Only dump the routes with the given protocol, such as `RTPROT_STATIC`. This filter is
+    /// applied by the kernel, see [`RouteGetRequest::execute_filtered`].
+    pub fn set_protocol_filter(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Only dump the routes of the given kind, such as `RTN_UNICAST`. This filter is applied by
+    /// the kernel, see [`RouteGetRequest::execute_filtered`].
+    pub fn set_kind_filter(mut self, kind: u8) -> Self {
+        self.message.header.kind = kind;
+        self
+    }
+
+    /// Only dump the routes going through the given interface. This filter is applied by the
+    /// kernel, see [`RouteGetRequest::execute_filtered`].
+    pub fn set_output_interface_filter(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
     pub fn execute(self) -> impl TryStream<Ok = RouteMessage, Error = Error> {
+        self.execute_filtered().map_ok(|dumped| dumped.route)
+    }
+
+    /// Like [`RouteGetRequest::execute`], but also report whether the kernel applied the filters
+    /// of the request.
+    ///
+    /// The kernel only filters dumps (since Linux 4.20) on sockets that enabled strict checking,
+    /// for instance with
+    /// `connection.socket_mut().socket_mut().set_strict_check(true)` before spawning the
+    /// connection. Otherwise, or on older kernels, the filters are ignored and all the routes are
+    /// returned.
+    pub fn execute_filtered(self) -> impl TryStream<Ok = DumpedRoute, Error = Error> {
         let RouteGetRequest {
             mut handle,
             message,
+            dump,
         } = self;
 
         let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
-        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        if dump {
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        } else {
+            req.header.flags = NLM_F_REQUEST;
+        }
 
         match handle.request(req) {
-            Ok(response) => {
-                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewRoute))))
-            }
-            Err(e) => Either::Right(future::err::<RouteMessage, Error>(e).into_stream()),
+            Ok(response) => Either::Left(response.map(move |msg| {
+                let filtered = msg.header.flags & NLM_F_DUMP_FILTERED != 0;
+                let route = try_rtnl!(msg, RtnlMessage::NewRoute);
+                Ok(DumpedRoute { route, filtered })
+            })),
+            Err(e) => Either::Right(future::err::<DumpedRoute, Error>(e).into_stream()),
         }
     }
+
+    /// Instead of dumping the routes, look up the route the kernel would use to reach
+    /// `destination` (equivalent to `ip route get ADDRESS`). For a multipath route, the result
+    /// only holds the nexthop that was selected.
+    pub fn get_for(mut self, destination: IpAddr) -> Self {
+        self.dump = false;
+        let (family, prefix_length, octets) = match destination {
+            IpAddr::V4(addr) => (AF_INET, 32, addr.octets().to_vec()),
+            IpAddr::V6(addr) => (AF_INET6, 128, addr.octets().to_vec()),
+        };
+        self.message.header.address_family = family as u8;
+        self.message.header.destination_prefix_length = prefix_length;
+        self.message.nlas.push(Nla::Destination(octets));
+        self
+    }
+
+    /// Like [`RouteGetRequest::get_for`], but return the FIB entry that matches `destination`
+    /// instead of the forwarding result (equivalent to `ip route get fibmatch ADDRESS`). This
+    /// sets `RTM_F_FIB_MATCH`, so that all the nexthops of a multipath route are returned.
+    pub fn get_for_all(mut self, destination: IpAddr) -> Self {
+        self.message.header.flags |= RouteFlags::RTM_F_FIB_MATCH;
+        self.get_for(destination)
+    }
 }
@@ -1,7 +1,19 @@
 // SPDX-License-Identifier: MIT
 
-use crate::{Handle, IpVersion, RouteAddRequest, RouteDelRequest, RouteGetRequest};
-use netlink_packet_route::RouteMessage;
+use std::collections::BTreeSet;
+
+use futures::stream::TryStreamExt;
+
+use crate::{
+    Error,
+    Handle,
+    IpVersion,
+    RouteAddRequest,
+    RouteDelRequest,
+    RouteFlushRequest,
+    RouteGetRequest,
+};
+use netlink_packet_route::{constants::RT_TABLE_UNSPEC, RouteMessage};
 
 pub struct RouteHandle(Handle);
 
@@ -15,6 +27,20 @@ impl RouteHandle {
         RouteGetRequest::new(self.0.clone(), ip_version)
     }
 
+    /// Dump the routes of all the tables and return the distinct ids of the tables they belong
+    /// to (equivalent to `ip route show table all`).
+    pub async fn tables(&self, ip_version: IpVersion) -> Result<BTreeSet<u32>, Error> {
+        let mut req = self.get(ip_version);
+        // RT_TABLE_UNSPEC is the wildcard that makes the kernel dump every table
+        req.message_mut().header.table = RT_TABLE_UNSPEC;
+        req.execute()
+            .try_fold(BTreeSet::new(), |mut tables, route| async move {
+                tables.insert(route.table());
+                Ok(tables)
+            })
+            .await
+    }
+
     /// Add an routing table entry (equivalent to `ip route add`)
     pub fn add(&self) -> RouteAddRequest {
         RouteAddRequest::new(self.0.clone())
@@ -24,4 +50,10 @@ impl RouteHandle {
     pub fn del(&self, route: RouteMessage) -> RouteDelRequest {
         RouteDelRequest::new(self.0.clone(), route)
     }
+
+    /// Delete all the routes of the main table, or of the table, protocol or scope selected on
+    /// the request (equivalent to `ip route flush`)
+    pub fn flush(&self, ip_version: IpVersion) -> RouteFlushRequest {
+        RouteFlushRequest::new(self.0.clone(), ip_version)
+    }
 }
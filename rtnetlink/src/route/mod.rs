@@ -11,3 +11,12 @@ pub use self::del::*;
 
 mod get;
 pub use self::get::*;
+
+mod flush;
+pub use self::flush::*;
+
+mod event;
+pub use self::event::*;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,853 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::BTreeSet,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use futures::{
+    future,
+    stream::{StreamExt, TryStreamExt},
+};
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    packet::{
+        constants::*,
+        nlas::route::{Nla, Realm},
+        RouteFlags,
+        RouteMessage,
+    },
+    route_events,
+    sys::{protocols::NETLINK_ROUTE, AsyncSocket, Socket},
+    DumpedRoute,
+    Error,
+    Handle,
+    IpVersion,
+    RouteAddRequest,
+    RouteEvent,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_device_only_route() {
+    let rt = Runtime::new().unwrap();
+    let route = rt
+        .block_on(_add_route("rt-dev-only", Ipv4Addr::new(10, 1, 0, 0), |req| req))
+        .unwrap();
+    assert_eq!(route.gateway(), None);
+    assert_eq!(route.header.scope, RT_SCOPE_LINK);
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_route_with_realm() {
+    let rt = Runtime::new().unwrap();
+    let route = rt
+        .block_on(_add_route("rt-realm", Ipv4Addr::new(10, 2, 0, 0), |req| {
+            req.realm(Realm::new(3, 5).into())
+        }))
+        .unwrap();
+    assert_eq!(route.realm(), Some(Realm::new(3, 5)));
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_route_with_inet6_via() {
+    let via = IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+    let rt = Runtime::new().unwrap();
+    let route = rt
+        .block_on(_add_route("rt-via", Ipv4Addr::new(10, 3, 0, 0), |req| req.via(via)))
+        .unwrap();
+    assert_eq!(route.via(), Some(via));
+    assert_eq!(route.gateway(), None);
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_onlink_default_route() {
+    let rt = Runtime::new().unwrap();
+    let route = rt.block_on(_add_onlink_default_route()).unwrap();
+    assert!(route.header.flags.contains(RouteFlags::RTNH_F_ONLINK));
+    assert_eq!(route.gateway(), Some(IpAddr::V4(Ipv4Addr::new(169, 254, 1, 1))));
+    assert_eq!(route.destination_prefix(), None);
+}
+
+async fn _add_onlink_default_route() -> Result<RouteMessage, Error> {
+    const LINK_NAME: &str = "rt-onlink";
+    // Keep the default route away from the main table
+    const TABLE: u32 = 162;
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        // The link has no address, so no route covers the gateway
+        handle
+            .route()
+            .add()
+            .v4()
+            .table(TABLE)
+            .gateway(Ipv4Addr::new(169, 254, 1, 1))
+            .output_interface(index)
+            .onlink()
+            .execute()
+            .await?;
+
+        let mut routes = handle.route().get(IpVersion::V4).execute();
+        while let Some(route) = routes.try_next().await? {
+            if route.table() == TABLE && route.output_interface() == Some(index) {
+                return Ok(route);
+            }
+        }
+        Err(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_route_with_validated_prefsrc() {
+    let rt = Runtime::new().unwrap();
+    let invalid = rt.block_on(_add_route_with_validated_prefsrc()).unwrap();
+    assert!(matches!(
+        invalid,
+        Err(Error::InvalidPrefSource(IpAddr::V4(addr))) if addr == Ipv4Addr::new(10, 168, 9, 9)
+    ));
+}
+
+/// Add a route with a valid preferred source, then return the result of adding one with an
+/// invalid preferred source
+async fn _add_route_with_validated_prefsrc() -> Result<Result<(), Error>, Error> {
+    // The address is configured on the first link, the routes go through the second one
+    const ADDRESS_LINK_NAME: &str = "rt-prefsrc1";
+    const ROUTE_LINK_NAME: &str = "rt-prefsrc2";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    for name in [ADDRESS_LINK_NAME, ROUTE_LINK_NAME] {
+        handle.link().add().dummy(name.to_owned()).execute().await?;
+    }
+    let address_index = _link_index(&handle, ADDRESS_LINK_NAME).await?;
+    let route_index = _link_index(&handle, ROUTE_LINK_NAME).await?;
+    let res = async {
+        handle.link().set(address_index).up().execute().await?;
+        handle.link().set(route_index).up().execute().await?;
+        handle
+            .address()
+            .add(address_index, IpAddr::V4(Ipv4Addr::new(10, 168, 0, 1)), 24)
+            .execute()
+            .await?;
+
+        handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(Ipv4Addr::new(10, 169, 0, 0), 16)
+            .output_interface(route_index)
+            .pref_source(Ipv4Addr::new(10, 168, 0, 1))
+            .validate_prefsrc()
+            .execute()
+            .await?;
+
+        Ok(handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(Ipv4Addr::new(10, 170, 0, 0), 16)
+            .output_interface(route_index)
+            .pref_source(Ipv4Addr::new(10, 168, 9, 9))
+            .validate_prefsrc()
+            .execute()
+            .await)
+    }
+    .await;
+    handle.link().del(address_index).execute().await?;
+    handle.link().del(route_index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn append_route_nexthop() {
+    let rt = Runtime::new().unwrap();
+    let route = rt.block_on(_append_route_nexthop()).unwrap();
+    assert!(route.nlas.iter().any(|nla| matches!(nla, Nla::MultiPath(_))));
+}
+
+async fn _append_route_nexthop() -> Result<RouteMessage, Error> {
+    const LINK_NAME: &str = "rt-append";
+    let destination = Ipv6Addr::new(0x2001, 0xdb8, 0x1450, 0, 0, 0, 0, 0);
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        let address = Ipv6Addr::new(0x2001, 0xdb8, 0x145, 0, 0, 0, 0, 1);
+        handle
+            .address()
+            .add(index, IpAddr::V6(address), 64)
+            .execute()
+            .await?;
+        for (i, gateway) in [2, 3].iter().enumerate() {
+            let mut req = handle
+                .route()
+                .add()
+                .v6()
+                .destination_prefix(destination, 48)
+                .gateway(Ipv6Addr::new(0x2001, 0xdb8, 0x145, 0, 0, 0, 0, *gateway))
+                .output_interface(index);
+            if i > 0 {
+                req = req.append();
+            }
+            req.execute().await?;
+        }
+
+        let mut routes = handle.route().get(IpVersion::V6).execute();
+        while let Some(route) = routes.try_next().await? {
+            if route.destination_prefix() == Some((IpAddr::V6(destination), 48)) {
+                return Ok(route);
+            }
+        }
+        Err(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+/// Whether the kernel supports `NETLINK_GET_STRICT_CHK`, which Linux 4.20 introduced
+fn _strict_check_supported() -> bool {
+    let mut socket = Socket::new(NETLINK_ROUTE).unwrap();
+    socket.set_strict_check(true).is_ok()
+}
+
+#[test]
+fn dump_filtered_by_kernel() {
+    if !_strict_check_supported() {
+        return;
+    }
+    let rt = Runtime::new().unwrap();
+    let routes = rt.block_on(_dump_local_table(true)).unwrap();
+    // There is at least the route to the loopback address
+    assert!(!routes.is_empty());
+    for dumped in routes {
+        assert!(dumped.filtered);
+        assert_eq!(dumped.route.table(), RT_TABLE_LOCAL as u32);
+    }
+}
+
+#[test]
+fn dump_not_filtered_by_kernel() {
+    // Without strict checking, the kernel ignores the filters like the kernels that do not support
+    // them
+    let rt = Runtime::new().unwrap();
+    let routes = rt.block_on(_dump_local_table(false)).unwrap();
+    assert!(routes.iter().all(|dumped| !dumped.filtered));
+
+    // The routes may change between the two dumps, but the tables they are in hardly do
+    let all_routes: Vec<RouteMessage> = rt
+        .block_on(async {
+            let (conn, handle, _) = new_connection().unwrap();
+            tokio::spawn(conn);
+            handle.route().get(IpVersion::V4).execute().try_collect().await
+        })
+        .unwrap();
+    let tables: BTreeSet<u32> = routes.iter().map(|dumped| dumped.route.table()).collect();
+    let all_tables: BTreeSet<u32> = all_routes.iter().map(|route| route.table()).collect();
+    assert_eq!(tables, all_tables);
+}
+
+async fn _dump_local_table(strict_check: bool) -> Result<Vec<DumpedRoute>, Error> {
+    let (mut conn, handle, _) = new_connection().unwrap();
+    conn.socket_mut()
+        .socket_mut()
+        .set_strict_check(strict_check)
+        .unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .route()
+        .get(IpVersion::V4)
+        .set_table_filter(RT_TABLE_LOCAL as u32)
+        .execute_filtered()
+        .try_collect()
+        .await
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn get_route_fib_match() {
+    let rt = Runtime::new().unwrap();
+    let (fib_match, lookup) = rt.block_on(_get_route_fib_match()).unwrap();
+    assert!(fib_match.header.flags.contains(RouteFlags::RTM_F_FIB_MATCH));
+    let multipath = fib_match
+        .nlas
+        .iter()
+        .find_map(|nla| match nla {
+            Nla::MultiPath(bytes) => Some(bytes.clone()),
+            _ => None,
+        })
+        .expect("fib-match lookup did not return the multipath nexthops");
+    assert_eq!(_count_nexthops(&multipath), 2);
+    // A plain lookup returns the result of the forwarding decision, with a single nexthop
+    assert!(lookup.gateway().is_some());
+}
+
+async fn _get_route_fib_match() -> Result<(RouteMessage, RouteMessage), Error> {
+    const LINK_NAME: &str = "rt-fibmatch";
+    let destination = Ipv6Addr::new(0x2001, 0xdb8, 0x1460, 0, 0, 0, 0, 0);
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        let address = Ipv6Addr::new(0x2001, 0xdb8, 0x146, 0, 0, 0, 0, 1);
+        handle
+            .address()
+            .add(index, IpAddr::V6(address), 64)
+            .execute()
+            .await?;
+        for (i, gateway) in [2, 3].iter().enumerate() {
+            let mut req = handle
+                .route()
+                .add()
+                .v6()
+                .destination_prefix(destination, 48)
+                .gateway(Ipv6Addr::new(0x2001, 0xdb8, 0x146, 0, 0, 0, 0, *gateway))
+                .output_interface(index);
+            if i > 0 {
+                req = req.append();
+            }
+            req.execute().await?;
+        }
+
+        let target = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0x1460, 0, 0, 0, 0, 1));
+        let mut routes = handle
+            .route()
+            .get(IpVersion::V6)
+            .get_for_all(target)
+            .execute();
+        let fib_match = routes.try_next().await?.ok_or(Error::RequestFailed)?;
+        let mut routes = handle.route().get(IpVersion::V6).get_for(target).execute();
+        let lookup = routes.try_next().await?.ok_or(Error::RequestFailed)?;
+        Ok((fib_match, lookup))
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+/// Count the `struct rtnexthop` entries of an `RTA_MULTIPATH` attribute.
+fn _count_nexthops(mut multipath: &[u8]) -> usize {
+    let mut count = 0;
+    while multipath.len() >= 8 {
+        let len = u16::from_ne_bytes([multipath[0], multipath[1]]) as usize;
+        if len < 8 || len > multipath.len() {
+            break;
+        }
+        count += 1;
+        multipath = &multipath[((len + 3) & !3).min(multipath.len())..];
+    }
+    count
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn list_route_tables() {
+    let rt = Runtime::new().unwrap();
+    let tables = rt.block_on(_list_route_tables()).unwrap();
+    assert!(tables.contains(&100));
+    assert!(tables.contains(&200));
+    assert!(tables.contains(&(RT_TABLE_LOCAL as u32)));
+}
+
+async fn _list_route_tables() -> Result<std::collections::BTreeSet<u32>, Error> {
+    const LINK_NAME: &str = "rt-tables";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        for table in [100, 200] {
+            handle
+                .route()
+                .add()
+                .v4()
+                .destination_prefix(Ipv4Addr::new(10, 3, 0, 0), 16)
+                .output_interface(index)
+                .table(table)
+                .execute()
+                .await?;
+        }
+        handle.route().tables(IpVersion::V4).await
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn flush_route_table() {
+    let rt = Runtime::new().unwrap();
+    let (deleted, remaining) = rt.block_on(_flush_route_table()).unwrap();
+    assert_eq!(deleted, 3);
+    assert_eq!(remaining, vec![(1177, Ipv4Addr::new(10, 177, 3, 0))]);
+}
+
+async fn _flush_route_table() -> Result<(usize, Vec<(u32, Ipv4Addr)>), Error> {
+    const LINK_NAME: &str = "rt-flush";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        // Table 177 is only used by this test, so that flushing it cannot race with the others
+        let routes = [(177, 0), (177, 1), (177, 2), (1177, 3)];
+        for (table, subnet) in routes {
+            handle
+                .route()
+                .add()
+                .v4()
+                .destination_prefix(Ipv4Addr::new(10, 177, subnet, 0), 24)
+                .output_interface(index)
+                .table(table)
+                .execute()
+                .await?;
+        }
+        let deleted = handle
+            .route()
+            .flush(IpVersion::V4)
+            .table(177)
+            .execute()
+            .await?;
+        // Flushing an empty table succeeds
+        handle
+            .route()
+            .flush(IpVersion::V4)
+            .table(177)
+            .execute()
+            .await?;
+
+        let remaining = handle
+            .route()
+            .get(IpVersion::V4)
+            .execute()
+            .try_filter_map(|route| async move {
+                Ok(match route.destination_prefix() {
+                    Some((IpAddr::V4(destination), 24))
+                        if route.output_interface() == Some(index) =>
+                    {
+                        Some((route.table(), destination))
+                    }
+                    _ => None,
+                })
+            })
+            .try_collect()
+            .await?;
+        Ok((deleted, remaining))
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_route_in_table_above_255() {
+    let rt = Runtime::new().unwrap();
+    let route = rt.block_on(_add_route_in_table_above_255()).unwrap();
+    assert_eq!(route.header.table, RT_TABLE_COMPAT);
+    assert_eq!(route.table(), 1000);
+}
+
+async fn _add_route_in_table_above_255() -> Result<RouteMessage, Error> {
+    const LINK_NAME: &str = "rt-table1000";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(Ipv4Addr::new(10, 173, 0, 0), 16)
+            .output_interface(index)
+            .table(1000)
+            .execute()
+            .await?;
+
+        let mut routes = handle.route().get(IpVersion::V4).execute();
+        while let Some(route) = routes.try_next().await? {
+            if route.output_interface() == Some(index) {
+                return Ok(route);
+            }
+        }
+        Err(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_ipv6_source_specific_route() {
+    let rt = Runtime::new().unwrap();
+    let route = rt.block_on(_add_ipv6_source_specific_route()).unwrap();
+    let source = Ipv6Addr::new(0x2001, 0xdb8, 0x1901, 0, 0, 0, 0, 0);
+    assert_eq!(route.header.source_prefix_length, 64);
+    assert_eq!(route.source_prefix(), Some((IpAddr::V6(source), 64)));
+}
+
+async fn _add_ipv6_source_specific_route() -> Result<RouteMessage, Error> {
+    const LINK_NAME: &str = "rt-ssr190";
+    let destination = Ipv6Addr::new(0x2001, 0xdb8, 0x190, 0, 0, 0, 0, 0);
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        handle
+            .route()
+            .add()
+            .v6()
+            .destination_prefix(destination, 64)
+            .source_prefix(Ipv6Addr::new(0x2001, 0xdb8, 0x1901, 0, 0, 0, 0, 0), 64)
+            .output_interface(index)
+            .execute()
+            .await?;
+
+        let mut routes = handle.route().get(IpVersion::V6).execute();
+        while let Some(route) = routes.try_next().await? {
+            if route.destination_prefix() == Some((IpAddr::V6(destination), 64)) {
+                return Ok(route);
+            }
+        }
+        Err(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[allow(deprecated)]
+fn add_ipv4_source_specific_route() {
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(async {
+        let (conn, handle, _) = new_connection().unwrap();
+        tokio::spawn(conn);
+        // Refused before anything is sent, so this does not require any privilege
+        handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(Ipv4Addr::new(10, 190, 0, 0), 16)
+            .source_prefix(Ipv4Addr::new(10, 191, 0, 0), 16)
+            .output_interface(1)
+            .execute()
+            .await
+    });
+    assert_eq!(res, Err(Error::Ipv4SourcePrefix));
+}
+
+#[test]
+fn dump_routes_by_family() {
+    let rt = Runtime::new().unwrap();
+    let (v4, v6, all) = rt.block_on(_dump_routes_by_family()).unwrap();
+    // There is at least the loopback route in the local table
+    assert!(!v4.is_empty());
+    assert!(v4.iter().all(|family| *family == AF_INET as u8));
+    // IPv6 may be disabled, in which case there is no IPv6 route at all
+    assert!(v6.iter().all(|family| *family == AF_INET6 as u8));
+    assert!(all.contains(&(AF_INET as u8)));
+    assert_eq!(all.contains(&(AF_INET6 as u8)), !v6.is_empty());
+}
+
+async fn _dump_routes_by_family() -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let families = |family: u8| {
+        handle
+            .route()
+            .get(IpVersion::V4)
+            .set_address_family(family)
+            .execute()
+            .map_ok(|route| route.header.address_family)
+            .try_collect::<Vec<_>>()
+    };
+    Ok((
+        families(AF_INET as u8).await?,
+        families(AF_INET6 as u8).await?,
+        families(AF_UNSPEC as u8).await?,
+    ))
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn delete_missing_route() {
+    let rt = Runtime::new().unwrap();
+    let err = rt.block_on(_delete_missing_route()).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(nix::errno::Errno::ESRCH as i32));
+    assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::NotFound));
+}
+
+async fn _delete_missing_route() -> Result<(), Error> {
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    let mut route = RouteMessage::default();
+    route.header.address_family = AF_INET as u8;
+    route.header.table = RT_TABLE_MAIN;
+    route.header.destination_prefix_length = 16;
+    route.nlas.push(Nla::Destination(vec![10, 143, 0, 0]));
+    handle.route().del(route).execute().await
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn route_added_event() {
+    let rt = Runtime::new().unwrap();
+    let event = rt.block_on(_route_added_event()).unwrap();
+    match event {
+        RouteEvent::Added(route) => {
+            assert_eq!(
+                route.destination,
+                (IpAddr::V4(Ipv4Addr::new(10, 252, 0, 0)), 16)
+            );
+            assert_eq!(route.table, RT_TABLE_MAIN as u32);
+        }
+        event => panic!("unexpected event {:?}", event),
+    }
+}
+
+async fn _route_added_event() -> Result<RouteEvent, Error> {
+    const LINK_NAME: &str = "rt-events";
+    let destination = Ipv4Addr::new(10, 252, 0, 0);
+
+    let (mut conn, handle, messages) = new_connection().unwrap();
+    conn.socket_mut()
+        .socket_mut()
+        .add_membership(RTNLGRP_IPV4_ROUTE)
+        .unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(destination, 16)
+            .output_interface(index)
+            .execute()
+            .await?;
+        let mut events = Box::pin(route_events(messages).filter(|event| {
+            let prefix = event.route().map(|route| route.destination);
+            future::ready(prefix == Some((IpAddr::V4(destination), 16)))
+        }));
+        events.next().await.ok_or(Error::RequestFailed)
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn route_events_resync() {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(_route_events_resync()).unwrap();
+}
+
+async fn _route_events_resync() -> Result<(), Error> {
+    const LINK_NAME: &str = "rt-resync";
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    // The monitoring connection only starts reading once all the routes have been added, so
+    // that their notifications overflow its small receive buffer
+    let (mut monitor, _, messages) = new_connection().unwrap();
+    let socket = monitor.socket_mut().socket_mut();
+    socket.set_recv_buffer_size(4096).unwrap();
+    socket.add_membership(RTNLGRP_IPV4_ROUTE).unwrap();
+
+    handle
+        .link()
+        .add()
+        .dummy(LINK_NAME.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, LINK_NAME).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        for i in 0..128 {
+            handle
+                .route()
+                .add()
+                .v4()
+                .destination_prefix(Ipv4Addr::new(10, 253, i, 0), 24)
+                .output_interface(index)
+                .execute()
+                .await?;
+        }
+
+        tokio::spawn(monitor);
+        let mut events = Box::pin(route_events(messages));
+        while events.next().await.ok_or(Error::RequestFailed)? != RouteEvent::ResyncRequired {}
+
+        // The stream goes on after the overflow
+        let destination = Ipv4Addr::new(10, 253, 255, 0);
+        handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(destination, 24)
+            .output_interface(index)
+            .execute()
+            .await?;
+        loop {
+            let event = events.next().await.ok_or(Error::RequestFailed)?;
+            if event.route().map(|route| route.destination) == Some((IpAddr::V4(destination), 24))
+            {
+                return Ok(());
+            }
+        }
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+/// Create a dummy link named `link_name`, add a route to `destination`/16 through
+/// it, customized by `f`, and return that route as found in the routes dump.
+async fn _add_route<F>(
+    link_name: &str,
+    destination: Ipv4Addr,
+    f: F,
+) -> Result<RouteMessage, Error>
+where
+    F: FnOnce(RouteAddRequest<Ipv4Addr>) -> RouteAddRequest<Ipv4Addr>,
+{
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(link_name.to_owned())
+        .execute()
+        .await?;
+    let index = _link_index(&handle, link_name).await?;
+    let res = async {
+        handle.link().set(index).up().execute().await?;
+        let req = handle
+            .route()
+            .add()
+            .v4()
+            .destination_prefix(destination, 16)
+            .output_interface(index);
+        f(req).execute().await?;
+        _find_route(&handle, index, destination).await
+    }
+    .await;
+    handle.link().del(index).execute().await?;
+    res
+}
+
+async fn _link_index(handle: &Handle, name: &str) -> Result<u32, Error> {
+    let mut links = handle
+        .link()
+        .get()
+        .set_name_filter(name.to_owned())
+        .execute();
+    let link = links.try_next().await?.ok_or(Error::RequestFailed)?;
+    Ok(link.header.index)
+}
+
+async fn _find_route(
+    handle: &Handle,
+    index: u32,
+    destination: Ipv4Addr,
+) -> Result<RouteMessage, Error> {
+    let mut routes = handle.route().get(IpVersion::V4).execute();
+    while let Some(route) = routes.try_next().await? {
+        if route.output_interface() == Some(index)
+            && route.destination_prefix() == Some((IpAddr::V4(destination), 16))
+        {
+            return Ok(route);
+        }
+    }
+    Err(Error::RequestFailed)
+}
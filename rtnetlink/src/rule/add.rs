@@ -63,6 +63,16 @@ impl<T> RuleAddRequest<T> {
         self
     }
 
+    /// Sets the IP protocol to match, for instance `IPPROTO_UDP`.
+    /// This is equivalent to `ip rule add ipproto PROTOCOL ...`.
+    ///
+    /// Kernels older than 4.17 do not know this attribute and ignore it, so the rule then matches
+    /// all the protocols.
+    pub fn ip_protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::IpProto(protocol));
+        self
+    }
+
     /// Set action.
     pub fn action(mut self, action: u8) -> Self {
         self.message.header.action = action;
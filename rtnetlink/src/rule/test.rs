@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future, stream::TryStreamExt};
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    packet::{constants::*, nlas::rule::Nla, RuleMessage},
+    Error,
+    IpVersion,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn add_rule_with_ip_protocol() {
+    let rt = Runtime::new().unwrap();
+    let rule = rt.block_on(_add_rule_with_ip_protocol()).unwrap();
+    assert!(rule.nlas.contains(&Nla::IpProto(17)));
+}
+
+async fn _add_rule_with_ip_protocol() -> Result<RuleMessage, Error> {
+    const TABLE: u8 = 151;
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .rule()
+        .add()
+        .v4()
+        .table(TABLE)
+        .action(FR_ACT_TO_TBL)
+        .ip_protocol(17) // IPPROTO_UDP
+        .execute()
+        .await?;
+    let rule = handle
+        .rule()
+        .get(IpVersion::V4)
+        .execute()
+        .try_filter(|rule| future::ready(rule.header.table == TABLE))
+        .try_next()
+        .await?
+        .ok_or(Error::RequestFailed)?;
+    handle.rule().del(rule.clone()).execute().await?;
+    Ok(rule)
+}
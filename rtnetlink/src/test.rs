@@ -0,0 +1,306 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::VecDeque,
+    fmt::Debug,
+    io,
+    marker::PhantomData,
+    net::{IpAddr, Ipv4Addr},
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+use bytes::{BufMut, BytesMut};
+use futures::{stream::TryStreamExt, FutureExt};
+use nix::errno::Errno;
+use tokio::runtime::Runtime;
+
+use crate::{
+    new_connection,
+    new_connection_with_codec,
+    new_connection_with_socket,
+    packet::{
+        constants::*,
+        neighbour::Nla,
+        LinkMessage,
+        NetlinkHeader,
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+    },
+    proto::{
+        packet::{NetlinkDeserializable, NetlinkSerializable},
+        NetlinkCodec,
+        NetlinkMessageCodec,
+    },
+    sys::{protocols::NETLINK_ROUTE, AsyncSocket, Socket, SocketAddr, TokioSocket},
+    Error,
+    Handle,
+    Snapshot,
+};
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn snapshot() {
+    let rt = Runtime::new().unwrap();
+    let (index, snapshot) = rt.block_on(_snapshot()).unwrap();
+    assert!(snapshot.links.iter().any(|link| link.header.index == index));
+    assert!(snapshot
+        .addresses
+        .iter()
+        .any(|address| address.header.index == index));
+    assert!(snapshot
+        .routes
+        .iter()
+        .any(|route| route.output_interface() == Some(index)));
+    assert!(snapshot.neighbours.iter().any(|neighbour| {
+        neighbour.header.ifindex == index
+            && neighbour
+                .nlas
+                .contains(&Nla::Destination(vec![10, 178, 0, 2]))
+    }));
+}
+
+async fn _snapshot() -> Result<(u32, Snapshot), Error> {
+    const NAME: &str = "dummy178";
+
+    let (conn, handle, _) = new_connection().unwrap();
+    tokio::spawn(conn);
+
+    handle
+        .link()
+        .add()
+        .dummy(NAME.to_owned())
+        .execute()
+        .await?;
+    let mut links = handle
+        .link()
+        .get()
+        .set_name_filter(NAME.to_owned())
+        .execute();
+    let index = links
+        .try_next()
+        .await?
+        .ok_or(Error::RequestFailed)?
+        .header
+        .index;
+
+    let res = async {
+        // The address also installs a route through the link
+        handle
+            .address()
+            .add(index, IpAddr::V4(Ipv4Addr::new(10, 178, 0, 1)), 24)
+            .execute()
+            .await?;
+        handle
+            .neighbours()
+            .add(index, IpAddr::V4(Ipv4Addr::new(10, 178, 0, 2)))
+            .state(NUD_PERMANENT)
+            .link_local_address(&[0x02, 0, 0, 0, 0x01, 0x78])
+            .execute()
+            .await?;
+        handle.snapshot().await
+    }
+    .await;
+
+    handle.link().del(index).execute().await?;
+    res.map(|snapshot| (index, snapshot))
+}
+
+static ENCODED: AtomicUsize = AtomicUsize::new(0);
+static DECODED: AtomicUsize = AtomicUsize::new(0);
+
+/// Codec that counts the messages it handles, and leaves the actual work to [`NetlinkCodec`]
+struct CountingCodec;
+
+impl NetlinkMessageCodec for CountingCodec {
+    fn decode<T>(src: &mut BytesMut) -> io::Result<Option<NetlinkMessage<T>>>
+    where
+        T: NetlinkDeserializable + Debug,
+    {
+        let message = NetlinkCodec::decode(src)?;
+        if message.is_some() {
+            DECODED.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(message)
+    }
+
+    fn encode<T>(msg: NetlinkMessage<T>, buf: &mut BytesMut) -> io::Result<()>
+    where
+        T: NetlinkSerializable + Debug,
+    {
+        ENCODED.fetch_add(1, Ordering::Relaxed);
+        NetlinkCodec::encode(msg, buf)
+    }
+}
+
+#[test]
+fn dump_links_with_custom_codec() {
+    let rt = Runtime::new().unwrap();
+    let links = rt
+        .block_on(async {
+            let (conn, handle, _) =
+                new_connection_with_codec::<TokioSocket, CountingCodec>().unwrap();
+            tokio::spawn(conn);
+            handle.link().get().execute().try_collect::<Vec<_>>().await
+        })
+        .unwrap();
+    // There is at least the loopback interface
+    assert!(!links.is_empty());
+    assert_eq!(ENCODED.load(Ordering::Relaxed), 1);
+    // The end of dump message is decoded too
+    assert_eq!(DECODED.load(Ordering::Relaxed), links.len() + 1);
+}
+
+/// The simulated kernel end of a [`MockSocket`]
+trait Peer: Unpin {
+    /// Return what the socket reads in response to `request`: datagrams, or errors
+    fn reply(request: NetlinkMessage<RtnlMessage>) -> Vec<io::Result<Vec<u8>>>;
+}
+
+/// Socket that exchanges datagrams with a [`Peer`] instead of the kernel
+struct MockSocket<P> {
+    socket: Socket,
+    datagrams: VecDeque<io::Result<Vec<u8>>>,
+    reader: Option<Waker>,
+    peer: PhantomData<P>,
+}
+
+impl<P: Peer> MockSocket<P> {
+    fn poll_datagram(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Vec<u8>>> {
+        match self.datagrams.pop_front() {
+            Some(datagram) => Poll::Ready(datagram),
+            None => {
+                self.reader = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<P: Peer> AsyncSocket for MockSocket<P> {
+    fn socket_ref(&self) -> &Socket {
+        &self.socket
+    }
+
+    fn socket_mut(&mut self) -> &mut Socket {
+        &mut self.socket
+    }
+
+    fn new(protocol: isize) -> io::Result<Self> {
+        Ok(MockSocket {
+            socket: Socket::new(protocol)?,
+            datagrams: VecDeque::new(),
+            reader: None,
+            peer: PhantomData,
+        })
+    }
+
+    fn poll_send(&mut self, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        self.poll_send_to(cx, buf, &SocketAddr::new(0, 0))
+    }
+
+    fn poll_send_to(
+        &mut self,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+        _addr: &SocketAddr,
+    ) -> Poll<io::Result<usize>> {
+        let request = NetlinkMessage::deserialize(buf).unwrap();
+        self.datagrams.extend(P::reply(request));
+        if let Some(waker) = self.reader.take() {
+            waker.wake();
+        }
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_recv<B>(&mut self, cx: &mut Context<'_>, buf: &mut B) -> Poll<io::Result<()>>
+    where
+        B: BufMut,
+    {
+        self.poll_recv_from(cx, buf).map_ok(|_| ())
+    }
+
+    fn poll_recv_from<B>(
+        &mut self,
+        cx: &mut Context<'_>,
+        buf: &mut B,
+    ) -> Poll<io::Result<SocketAddr>>
+    where
+        B: BufMut,
+    {
+        self.poll_datagram(cx).map_ok(|datagram| {
+            buf.put_slice(&datagram);
+            SocketAddr::new(0, 0)
+        })
+    }
+
+    fn poll_recv_from_full(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<(Vec<u8>, SocketAddr)>> {
+        self.poll_datagram(cx)
+            .map_ok(|datagram| (datagram, SocketAddr::new(0, 0)))
+    }
+}
+
+/// Answers a link dump with a single link, then reports `ENOBUFS`, as if the kernel had dropped
+/// the rest of the dump because the receive buffer was full
+struct OverflowingDump;
+
+impl Peer for OverflowingDump {
+    fn reply(request: NetlinkMessage<RtnlMessage>) -> Vec<io::Result<Vec<u8>>> {
+        let mut link = NetlinkMessage::new(
+            NetlinkHeader {
+                sequence_number: request.header.sequence_number,
+                flags: NLM_F_MULTIPART,
+                ..Default::default()
+            },
+            NetlinkPayload::InnerMessage(RtnlMessage::NewLink(LinkMessage::default())),
+        );
+        link.finalize();
+        let mut datagram = vec![0; link.buffer_len()];
+        link.serialize(&mut datagram);
+        vec![
+            Ok(datagram),
+            Err(io::Error::from_raw_os_error(Errno::ENOBUFS as i32)),
+        ]
+    }
+}
+
+#[test]
+fn dump_interrupted_by_overflow() {
+    let rt = Runtime::new().unwrap();
+    let res = rt.block_on(async {
+        let (conn, handle, _) =
+            new_connection_with_socket::<MockSocket<OverflowingDump>>().unwrap();
+        tokio::spawn(conn);
+        handle.link().get().execute().try_collect::<Vec<_>>().await
+    });
+    // The links read before the overflow are not returned as a complete dump
+    let err = res.unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(Errno::ENOBUFS as i32));
+}
+
+#[test]
+fn shutdown_with_pending_requests() {
+    let rt = Runtime::new().unwrap();
+    let (links, del) = rt.block_on(async {
+        let (conn, proto_handle, _) =
+            netlink_proto::new_connection::<RtnlMessage>(NETLINK_ROUTE).unwrap();
+        let handle = Handle::new(proto_handle.clone());
+        let links = handle.link().get().execute().try_collect::<Vec<_>>();
+        let mut del = Box::pin(handle.link().del(1).execute());
+        // Send the request
+        assert!((&mut del).now_or_never().is_none());
+
+        // Shut down before the connection is even polled, so that the requests cannot have been
+        // answered
+        proto_handle.shutdown();
+        tokio::spawn(conn).await.unwrap();
+        (links.await, del.await)
+    });
+    let reset = Some(Errno::ECONNRESET as i32);
+    assert_eq!(links.unwrap_err().raw_os_error(), reset);
+    assert_eq!(del.unwrap_err().raw_os_error(), reset);
+}
@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        tc::{
+            action::{mirred::TcMirred, police::TcPolice, Action},
+            filter,
+            Nla,
+        },
+        traits::Emitable,
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+        TcHandle,
+        TcMessage,
+        ETH_P_ALL,
+        ETH_P_IP,
+        ETH_P_IPV6,
+        IPPROTO_TCP,
+        IPPROTO_UDP,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        TC_H_CLSACT,
+        TC_H_MAJ_MASK,
+        TC_H_MIN_EGRESS,
+        TC_H_MIN_INGRESS,
+        TC_H_MIN_MASK,
+        TC_H_ROOT,
+    },
+    Error,
+    Handle,
+};
+
+/// A request to create a filter (equivalent to `tc filter add dev <iface_name>`).
+pub struct TrafficFilterNewRequest {
+    handle: Handle,
+    message: TcMessage,
+    flags: u16,
+    u32_nlas: Option<Vec<filter::u32::Nla>>,
+    flower_nlas: Option<Vec<filter::flower::Nla>>,
+    actions: Vec<Action>,
+}
+
+impl TrafficFilterNewRequest {
+    pub(crate) fn new(handle: Handle, ifindex: i32, flags: u16) -> Self {
+        let mut message = TcMessage::default();
+        message.header.index = ifindex;
+        message.header.info = ETH_P_ALL.to_be() as u32;
+        TrafficFilterNewRequest {
+            handle,
+            message,
+            flags,
+            u32_nlas: None,
+            flower_nlas: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Set the handle of the filter. By default the kernel picks one.
+    pub fn handle(mut self, major: u16, minor: u16) -> Self {
+        self.message.header.handle = TcHandle::new(major, minor).into();
+        self
+    }
+
+    /// Set the qdisc or class the filter is attached to
+    pub fn parent(mut self, parent: u32) -> Self {
+        self.message.header.parent = parent;
+        self
+    }
+
+    /// Attach the filter to the root qdisc of the interface
+    pub fn root(self) -> Self {
+        self.parent(TC_H_ROOT)
+    }
+
+    /// Attach the filter to the ingress hook of the `ingress` or `clsact` qdisc of the interface
+    /// (equivalent to `tc filter add dev <iface_name> ingress`)
+    pub fn ingress(self) -> Self {
+        self.parent((TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_INGRESS)
+    }
+
+    /// Attach the filter to the egress hook of the `clsact` qdisc of the interface (equivalent
+    /// to `tc filter add dev <iface_name> egress`)
+    pub fn egress(self) -> Self {
+        self.parent((TC_H_CLSACT & TC_H_MAJ_MASK) | TC_H_MIN_EGRESS)
+    }
+
+    /// Set the priority of the filter. By default the kernel picks one.
+    pub fn priority(mut self, priority: u16) -> Self {
+        let info = self.message.header.info;
+        self.message.header.info = ((priority as u32) << 16) | (info & !TC_H_MAJ_MASK);
+        self
+    }
+
+    /// Set the ethernet protocol the filter applies to, such as `ETH_P_IP`. Defaults to
+    /// `ETH_P_ALL`.
+    pub fn protocol(mut self, protocol: u16) -> Self {
+        let info = self.message.header.info;
+        self.message.header.info = (info & TC_H_MAJ_MASK) | protocol.to_be() as u32;
+        self
+    }
+
+    /// Use a `u32` filter with the given options (equivalent to `tc filter add ... u32`)
+    pub fn u32(mut self, nlas: Vec<filter::u32::Nla>) -> Self {
+        self.message
+            .nlas
+            .push(Nla::Kind(filter::u32::KIND.to_string()));
+        self.u32_nlas = Some(nlas);
+        self
+    }
+
+    /// Use a `flower` filter (equivalent to `tc filter add ... flower`), whose match keys are set
+    /// with the returned request. The other parameters of the filter, and its actions, must be
+    /// set beforehand.
+    pub fn flower(mut self) -> FlowerFilterNewRequest {
+        self.message
+            .nlas
+            .push(Nla::Kind(filter::flower::KIND.to_string()));
+        FlowerFilterNewRequest {
+            request: self,
+            nlas: Vec::new(),
+        }
+    }
+
+    /// Append a `mirred` action to the actions of the filter. The actions are attached to the
+    /// options of the filter, so this requires a `u32` or a `flower` filter (see
+    /// [`u32`](TrafficFilterNewRequest::u32) and [`flower`](TrafficFilterNewRequest::flower)).
+    pub fn mirred(mut self, parms: TcMirred) -> Self {
+        let mut action = Action::mirred(parms);
+        action.tab = self.actions.len() as u16 + 1;
+        self.actions.push(action);
+        self
+    }
+
+    /// Redirect the matching packets to the egress of the interface `dst_index` (equivalent to
+    /// `action mirred egress redirect dev <iface_name>`)
+    pub fn redirect(self, dst_index: u32) -> Self {
+        self.mirred(TcMirred::egress_redirect(dst_index))
+    }
+
+    /// Copy the matching packets to the egress of the interface `dst_index` (equivalent to
+    /// `action mirred egress mirror dev <iface_name>`)
+    pub fn mirror(self, dst_index: u32) -> Self {
+        self.mirred(TcMirred::egress_mirror(dst_index))
+    }
+
+    /// Append a `police` action to the actions of the filter, limiting the matching packets to
+    /// the rate of `parms` (equivalent to `action police rate RATE burst BURST conform-exceed
+    /// EXCEED`). Like [`mirred`](TrafficFilterNewRequest::mirred), this requires a `u32` or a
+    /// `flower` filter.
+    pub fn police(mut self, parms: TcPolice) -> Self {
+        let mut action = Action::police(parms);
+        action.tab = self.actions.len() as u16 + 1;
+        self.actions.push(action);
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let TrafficFilterNewRequest {
+            mut handle,
+            mut message,
+            flags,
+            u32_nlas,
+            flower_nlas,
+            actions,
+        } = self;
+
+        if let Some(mut nlas) = u32_nlas {
+            if !actions.is_empty() {
+                nlas.push(filter::u32::Nla::Act(actions));
+            }
+            let mut options = vec![0; nlas.as_slice().buffer_len()];
+            nlas.as_slice().emit(&mut options);
+            message.nlas.push(Nla::Options(options));
+        } else if let Some(mut nlas) = flower_nlas {
+            if !actions.is_empty() {
+                nlas.push(filter::flower::Nla::Act(actions));
+            }
+            let mut options = vec![0; nlas.as_slice().buffer_len()];
+            nlas.as_slice().emit(&mut options);
+            message.nlas.push(Nla::Options(options));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewTrafficFilter(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            if let NetlinkPayload::Error(err) = message.payload {
+                return Err(Error::NetlinkError(err));
+            }
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut TcMessage {
+        &mut self.message
+    }
+}
+
+/// A request to create a `flower` filter, which matches packets on the fields of their headers
+/// (equivalent to `tc filter add dev <iface_name> ... flower ...`). It is created by
+/// [`TrafficFilterNewRequest::flower`].
+///
+/// The keys set by this request are matched exactly, or under the mask given by their prefix
+/// length for IP addresses. All of them must match for the packet to be classified.
+pub struct FlowerFilterNewRequest {
+    request: TrafficFilterNewRequest,
+    nlas: Vec<filter::flower::Nla>,
+}
+
+impl FlowerFilterNewRequest {
+    /// Match the packets sent to the first `prefix_len` bits of `addr` (equivalent to `dst_ip
+    /// ADDR/LEN`). This also sets the protocol of the filter to IPv4 or IPv6, depending on `addr`,
+    /// so the request fails with [`Error::MixedIpFamilies`] if the source and destination
+    /// addresses are not of the same family.
+    pub fn dst_ip(mut self, addr: IpAddr, prefix_len: u8) -> Self {
+        use filter::flower::Nla::*;
+        match addr {
+            IpAddr::V4(addr) => {
+                self.nlas.push(KeyIpv4Dst(addr));
+                self.nlas.push(KeyIpv4DstMask(ipv4_mask(prefix_len)));
+                self.request = self.request.protocol(ETH_P_IP);
+            }
+            IpAddr::V6(addr) => {
+                self.nlas.push(KeyIpv6Dst(addr));
+                self.nlas.push(KeyIpv6DstMask(ipv6_mask(prefix_len)));
+                self.request = self.request.protocol(ETH_P_IPV6);
+            }
+        }
+        self
+    }
+
+    /// Match the packets sent from the first `prefix_len` bits of `addr` (equivalent to `src_ip
+    /// ADDR/LEN`). Like [`dst_ip`](FlowerFilterNewRequest::dst_ip), this sets the protocol of the
+    /// filter.
+    pub fn src_ip(mut self, addr: IpAddr, prefix_len: u8) -> Self {
+        use filter::flower::Nla::*;
+        match addr {
+            IpAddr::V4(addr) => {
+                self.nlas.push(KeyIpv4Src(addr));
+                self.nlas.push(KeyIpv4SrcMask(ipv4_mask(prefix_len)));
+                self.request = self.request.protocol(ETH_P_IP);
+            }
+            IpAddr::V6(addr) => {
+                self.nlas.push(KeyIpv6Src(addr));
+                self.nlas.push(KeyIpv6SrcMask(ipv6_mask(prefix_len)));
+                self.request = self.request.protocol(ETH_P_IPV6);
+            }
+        }
+        self
+    }
+
+    /// Match the IP protocol of the packets, for instance 17 for UDP (equivalent to `ip_proto
+    /// PROTO`). The protocol of the filter must be IPv4 or IPv6, otherwise the kernel ignores
+    /// this key.
+    pub fn ip_proto(mut self, proto: u8) -> Self {
+        self.nlas
+            .retain(|nla| !matches!(nla, filter::flower::Nla::KeyIpProto(_)));
+        self.nlas.push(filter::flower::Nla::KeyIpProto(proto));
+        self
+    }
+
+    /// Match the TCP packets sent to `port` (equivalent to `ip_proto tcp dst_port PORT`)
+    pub fn tcp_dst_port(self, port: u16) -> Self {
+        self.ip_proto(IPPROTO_TCP)
+            .append_nla(filter::flower::Nla::KeyTcpDst(port))
+    }
+
+    /// Match the TCP packets sent from `port` (equivalent to `ip_proto tcp src_port PORT`)
+    pub fn tcp_src_port(self, port: u16) -> Self {
+        self.ip_proto(IPPROTO_TCP)
+            .append_nla(filter::flower::Nla::KeyTcpSrc(port))
+    }
+
+    /// Match the UDP packets sent to `port` (equivalent to `ip_proto udp dst_port PORT`)
+    pub fn udp_dst_port(self, port: u16) -> Self {
+        self.ip_proto(IPPROTO_UDP)
+            .append_nla(filter::flower::Nla::KeyUdpDst(port))
+    }
+
+    /// Match the UDP packets sent from `port` (equivalent to `ip_proto udp src_port PORT`)
+    pub fn udp_src_port(self, port: u16) -> Self {
+        self.ip_proto(IPPROTO_UDP)
+            .append_nla(filter::flower::Nla::KeyUdpSrc(port))
+    }
+
+    /// Match the DSCP of the packets, the 6 high bits of their TOS (IPv4) or traffic class (IPv6)
+    /// byte (equivalent to `ip_tos DSCP<<2/0xfc`). The ECN bits are not matched.
+    pub fn dscp(self, dscp: u8) -> Self {
+        self.append_nla(filter::flower::Nla::KeyIpTos(dscp << 2))
+            .append_nla(filter::flower::Nla::KeyIpTosMask(0xfc))
+    }
+
+    /// Classify the matching packets in the class `major:minor` (equivalent to `classid
+    /// MAJOR:MINOR`)
+    pub fn classid(self, major: u16, minor: u16) -> Self {
+        self.append_nla(filter::flower::Nla::ClassId(
+            TcHandle::new(major, minor).into(),
+        ))
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        use filter::flower::Nla::*;
+        let FlowerFilterNewRequest {
+            mut request,
+            mut nlas,
+        } = self;
+        let ipv4 = nlas
+            .iter()
+            .any(|nla| matches!(nla, KeyIpv4Src(_) | KeyIpv4Dst(_)));
+        let ipv6 = nlas
+            .iter()
+            .any(|nla| matches!(nla, KeyIpv6Src(_) | KeyIpv6Dst(_)));
+        if ipv4 && ipv6 {
+            return Err(Error::MixedIpFamilies);
+        }
+        // The IP keys are only taken into account by the kernel once the ethernet type is matched
+        let protocol = u16::from_be((request.message.header.info & TC_H_MIN_MASK) as u16);
+        if protocol != ETH_P_ALL {
+            nlas.push(KeyEthType(protocol));
+        }
+        request.flower_nlas = Some(nlas);
+        request.execute().await
+    }
+
+    fn append_nla(mut self, nla: filter::flower::Nla) -> Self {
+        self.nlas.push(nla);
+        self
+    }
+}
+
+fn ipv4_mask(prefix_len: u8) -> Ipv4Addr {
+    let mask = u32::MAX
+        .checked_shl(32 - u32::from(prefix_len.min(32)))
+        .unwrap_or(0);
+    Ipv4Addr::from(mask)
+}
+
+fn ipv6_mask(prefix_len: u8) -> Ipv6Addr {
+    let mask = u128::MAX
+        .checked_shl(128 - u32::from(prefix_len.min(128)))
+        .unwrap_or(0);
+    Ipv6Addr::from(mask)
+}
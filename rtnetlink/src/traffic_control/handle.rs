@@ -5,8 +5,12 @@ use super::{
     TrafficChainGetRequest,
     TrafficClassGetRequest,
     TrafficFilterGetRequest,
+    TrafficFilterNewRequest,
+};
+use crate::{
+    packet::{NLM_F_CREATE, NLM_F_EXCL},
+    Handle,
 };
-use crate::Handle;
 
 pub struct QDiscHandle(Handle);
 
@@ -53,6 +57,11 @@ impl TrafficFilterHandle {
     pub fn get(&mut self) -> TrafficFilterGetRequest {
         TrafficFilterGetRequest::new(self.handle.clone(), self.ifindex)
     }
+
+    /// Create a new filter (equivalent to `tc filter add dev <iface_name>`)
+    pub fn add(&mut self) -> TrafficFilterNewRequest {
+        TrafficFilterNewRequest::new(self.handle.clone(), self.ifindex, NLM_F_EXCL | NLM_F_CREATE)
+    }
 }
 
 pub struct TrafficChainHandle {
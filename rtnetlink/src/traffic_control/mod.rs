@@ -6,5 +6,8 @@ pub use self::handle::*;
 mod get;
 pub use self::get::*;
 
+mod add_filter;
+pub use self::add_filter::*;
+
 #[cfg(test)]
 mod test;
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: MIT
 
-use std::process::Command;
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    process::Command,
+};
 
 use futures::stream::TryStreamExt;
 use tokio::runtime::Runtime;
@@ -8,12 +11,29 @@ use tokio::runtime::Runtime;
 use crate::{
     new_connection,
     packet::{
-        rtnl::tc::nlas::Nla::{Chain, HwOffload, Kind},
+        nlas::NlasIterator,
+        rtnl::tc::nlas::{
+            action::{
+                mirred::{self, TcMirred},
+                police::{self, TcPolice},
+                ActNla,
+                ActOpt,
+            },
+            filter,
+            Nla::{Chain, HwOffload, Kind, Options},
+        },
+        traits::Parseable,
         ErrorMessage,
+        TcHandle,
         TcMessage,
         AF_UNSPEC,
+        ETH_P_IP,
+        TCA_EGRESS_REDIR,
+        TC_ACT_SHOT,
+        TC_ACT_STOLEN,
+        TC_U32_TERMINAL,
     },
-    Error::NetlinkError,
+    Error::{self, NetlinkError},
 };
 
 static TEST_DUMMY_NIC: &str = "netlink-test";
@@ -285,3 +305,277 @@ fn test_get_traffic_classes_filters_and_chains() {
         assert_eq!(chains[0].nlas[0], Chain([0u8, 0, 0, 0].to_vec()));
     }
 }
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn test_add_u32_filter_with_mirred_redirect() {
+    let (dst_index, mirred) = Runtime::new()
+        .unwrap()
+        .block_on(_add_u32_filter_with_mirred_redirect())
+        .unwrap();
+    assert_eq!(mirred.len(), 1);
+    assert_eq!(mirred[0].eaction, TCA_EGRESS_REDIR);
+    assert_eq!(mirred[0].action, TC_ACT_STOLEN);
+    assert_eq!(mirred[0].ifindex, dst_index);
+}
+
+async fn _add_u32_filter_with_mirred_redirect() -> Result<(u32, Vec<TcMirred>), Error> {
+    const SRC: &str = "dummy145a";
+    const DST: &str = "dummy145b";
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut link_handle = handle.link();
+    let src_index = link_handle.add().dummy(SRC.to_owned()).execute().await?;
+    let dst_index = link_handle.add().dummy(DST.to_owned()).execute().await?;
+
+    let res: Result<Vec<TcMirred>, Error> = async {
+        let output = Command::new("tc")
+            .args(&["qdisc", "add", "dev", SRC, "root", "handle", "1:", "prio"])
+            .output()
+            .expect("failed to run tc command");
+        assert!(output.status.success(), "failed to add qdisc to {}: {:?}", SRC, output);
+
+        // Equivalent to `tc filter add dev dummy145a parent 1: prio 1 u32 match u32 0 0 action
+        // mirred egress redirect dev dummy145b`
+        handle
+            .traffic_filter(src_index as i32)
+            .add()
+            .parent(TcHandle::new(1, 0).into())
+            .priority(1)
+            .u32(vec![filter::u32::Nla::Sel(filter::u32::Sel {
+                flags: TC_U32_TERMINAL,
+                keys: vec![filter::u32::Key::default()],
+                ..Default::default()
+            })])
+            .redirect(dst_index)
+            .execute()
+            .await?;
+
+        let filters: Vec<TcMessage> = handle
+            .traffic_filter(src_index as i32)
+            .get()
+            .execute()
+            .try_collect()
+            .await?;
+        Ok(filters.iter().flat_map(_mirred_actions).collect())
+    }
+    .await;
+
+    link_handle.del(src_index).execute().await?;
+    link_handle.del(dst_index).execute().await?;
+    res.map(|mirred| (dst_index, mirred))
+}
+
+fn _mirred_actions(message: &TcMessage) -> Vec<TcMirred> {
+    _action_options(message)
+        .into_iter()
+        .filter_map(|opt| match opt {
+            ActOpt::Mirred(mirred::Nla::Parms(parms)) => Some(parms),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Options of all the actions of the `u32` filter `message`
+fn _action_options(message: &TcMessage) -> Vec<ActOpt> {
+    message
+        .nlas
+        .iter()
+        .filter_map(|nla| match nla {
+            Options(options) => Some(options),
+            _ => None,
+        })
+        .flat_map(NlasIterator::new)
+        .filter_map(|nla| match filter::u32::Nla::parse(&nla.unwrap()).unwrap() {
+            filter::u32::Nla::Act(actions) => Some(actions),
+            _ => None,
+        })
+        .flatten()
+        .flat_map(|action| action.nlas)
+        .filter_map(|nla| match nla {
+            ActNla::Options(opts) => Some(opts),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn test_add_u32_filter_with_police() {
+    let police = Runtime::new()
+        .unwrap()
+        .block_on(_add_u32_filter_with_police())
+        .unwrap();
+    assert_eq!(police.len(), 1);
+    // 1mbit
+    assert_eq!(police[0].rate.rate, 125_000);
+    assert_eq!(police[0].action, TC_ACT_SHOT);
+}
+
+async fn _add_u32_filter_with_police() -> Result<Vec<TcPolice>, Error> {
+    const NAME: &str = "dummy159";
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy(NAME.to_owned()).execute().await?;
+
+    let res: Result<Vec<TcPolice>, Error> = async {
+        let output = Command::new("tc")
+            .args(&["qdisc", "add", "dev", NAME, "root", "handle", "1:", "prio"])
+            .output()
+            .expect("failed to run tc command");
+        assert!(output.status.success(), "failed to add qdisc to {}: {:?}", NAME, output);
+
+        // Equivalent to `tc filter add dev dummy159 parent 1: prio 1 u32 match u32 0 0 action
+        // police rate 1mbit burst 10k conform-exceed drop`
+        handle
+            .traffic_filter(index as i32)
+            .add()
+            .parent(TcHandle::new(1, 0).into())
+            .priority(1)
+            .u32(vec![filter::u32::Nla::Sel(filter::u32::Sel {
+                flags: TC_U32_TERMINAL,
+                keys: vec![filter::u32::Key::default()],
+                ..Default::default()
+            })])
+            .police(TcPolice::new(125_000, 10_240, 0, TC_ACT_SHOT))
+            .execute()
+            .await?;
+
+        let filters: Vec<TcMessage> = handle
+            .traffic_filter(index as i32)
+            .get()
+            .execute()
+            .try_collect()
+            .await?;
+        Ok(filters.iter().flat_map(_police_actions).collect())
+    }
+    .await;
+
+    link_handle.del(index).execute().await?;
+    res
+}
+
+fn _police_actions(message: &TcMessage) -> Vec<TcPolice> {
+    _action_options(message)
+        .into_iter()
+        .filter_map(|opt| match opt {
+            ActOpt::Police(police::Nla::Tbf(parms)) => Some(parms),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+#[cfg_attr(not(feature = "test_as_root"), ignore)]
+fn test_add_flower_filter() {
+    use filter::flower::Nla::*;
+
+    let options = Runtime::new()
+        .unwrap()
+        .block_on(_add_flower_filter())
+        .unwrap();
+    for key in &[
+        KeyEthType(ETH_P_IP),
+        KeyIpProto(6),
+        KeyIpv4Dst(Ipv4Addr::new(192, 0, 2, 0)),
+        KeyIpv4DstMask(Ipv4Addr::new(255, 255, 255, 0)),
+        KeyTcpDst(80),
+        // The kernel reports the masks that were not sent
+        KeyTcpDstMask(0xffff),
+        KeyIpTos(0xb8),
+        KeyIpTosMask(0xfc),
+        ClassId(TcHandle::new(1, 1).into()),
+    ] {
+        assert!(
+            options.contains(key),
+            "{:?} not found in {:?}",
+            key,
+            options
+        );
+    }
+}
+
+async fn _add_flower_filter() -> Result<Vec<filter::flower::Nla>, Error> {
+    const NAME: &str = "dummy197";
+
+    let (connection, handle, _) = new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut link_handle = handle.link();
+    let index = link_handle.add().dummy(NAME.to_owned()).execute().await?;
+
+    let res: Result<Vec<filter::flower::Nla>, Error> = async {
+        let output = Command::new("tc")
+            .args(&["qdisc", "add", "dev", NAME, "root", "handle", "1:", "prio"])
+            .output()
+            .expect("failed to run tc command");
+        assert!(
+            output.status.success(),
+            "failed to add qdisc to {}: {:?}",
+            NAME,
+            output
+        );
+
+        // Equivalent to `tc filter add dev dummy197 parent 1: protocol ip prio 1 flower dst_ip
+        // 192.0.2.0/24 ip_proto tcp dst_port 80 ip_tos 0xb8/0xfc classid 1:1`
+        handle
+            .traffic_filter(index as i32)
+            .add()
+            .parent(TcHandle::new(1, 0).into())
+            .priority(1)
+            .flower()
+            .dst_ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)), 24)
+            .tcp_dst_port(80)
+            .dscp(46)
+            .classid(1, 1)
+            .execute()
+            .await?;
+
+        let filters: Vec<TcMessage> = handle
+            .traffic_filter(index as i32)
+            .get()
+            .execute()
+            .try_collect()
+            .await?;
+        Ok(filters
+            .iter()
+            .flat_map(|message| &message.nlas)
+            .filter_map(|nla| match nla {
+                Options(options) => Some(options),
+                _ => None,
+            })
+            .flat_map(NlasIterator::new)
+            .map(|nla| filter::flower::Nla::parse(&nla.unwrap()).unwrap())
+            .collect())
+    }
+    .await;
+
+    link_handle.del(index).execute().await?;
+    res
+}
+
+#[test]
+fn flower_filter_mixed_ip_families() {
+    let res = Runtime::new().unwrap().block_on(async {
+        let (connection, handle, _) = new_connection().unwrap();
+        tokio::spawn(connection);
+        handle
+            .traffic_filter(1)
+            .add()
+            .flower()
+            .dst_ip(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)), 24)
+            .src_ip(
+                IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+                32,
+            )
+            .execute()
+            .await
+    });
+    assert_eq!(res, Err(Error::MixedIpFamilies));
+}